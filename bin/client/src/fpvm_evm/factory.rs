@@ -1,6 +1,6 @@
 //! [`EvmFactory`] implementation for the EVM in the FPVM environment.
 
-use super::precompiles::OpFpvmPrecompiles;
+use super::precompiles::{DefaultPrecompileRegistry, OpFpvmPrecompiles, PrecompileRegistry};
 use alloy_evm::{Database, EvmEnv, EvmFactory};
 use alloy_op_evm::OpEvm;
 use kona_preimage::{HintWriterClient, PreimageOracleClient};
@@ -16,12 +16,17 @@ use revm::{
 };
 
 /// Factory producing [`OpEvm`]s with FPVM-accelerated precompile overrides enabled.
+///
+/// The accelerated precompiles are sourced from a [`PrecompileRegistry`], which defaults to the
+/// [`DefaultPrecompileRegistry`].
 #[derive(Debug, Clone)]
-pub struct FpvmOpEvmFactory<H, O> {
+pub struct FpvmOpEvmFactory<H, O, R = DefaultPrecompileRegistry> {
     /// The hint writer.
     hint_writer: H,
     /// The oracle reader.
     oracle_reader: O,
+    /// The registry of accelerated precompiles.
+    registry: R,
 }
 
 impl<H, O> FpvmOpEvmFactory<H, O>
@@ -29,9 +34,31 @@ where
     H: HintWriterClient + Clone + Send + Sync,
     O: PreimageOracleClient + Clone + Send + Sync,
 {
-    /// Creates a new [`FpvmOpEvmFactory`].
-    pub fn new(hint_writer: H, oracle_reader: O) -> Self {
-        Self { hint_writer, oracle_reader }
+    /// Creates a new [`FpvmOpEvmFactory`] that accelerates the precompiles in the
+    /// [`DefaultPrecompileRegistry`].
+    pub const fn new(hint_writer: H, oracle_reader: O) -> Self {
+        Self { hint_writer, oracle_reader, registry: DefaultPrecompileRegistry }
+    }
+}
+
+impl<H, O, R> FpvmOpEvmFactory<H, O, R>
+where
+    H: HintWriterClient + Clone + Send + Sync,
+    O: PreimageOracleClient + Clone + Send + Sync,
+    R: PrecompileRegistry,
+{
+    /// Replaces the [`PrecompileRegistry`] that the accelerated precompiles are sourced from.
+    pub fn with_registry<R2: PrecompileRegistry>(self, registry: R2) -> FpvmOpEvmFactory<H, O, R2> {
+        FpvmOpEvmFactory {
+            hint_writer: self.hint_writer,
+            oracle_reader: self.oracle_reader,
+            registry,
+        }
+    }
+
+    /// Returns a reference to the inner [`PrecompileRegistry`].
+    pub const fn registry(&self) -> &R {
+        &self.registry
     }
 
     /// Returns a reference to the inner [`HintWriterClient`].
@@ -45,19 +72,20 @@ where
     }
 }
 
-impl<H, O> EvmFactory for FpvmOpEvmFactory<H, O>
+impl<H, O, R> EvmFactory for FpvmOpEvmFactory<H, O, R>
 where
     H: HintWriterClient + Clone + Send + Sync + 'static,
     O: PreimageOracleClient + Clone + Send + Sync + 'static,
+    R: PrecompileRegistry,
 {
-    type Evm<DB: Database, I: Inspector<OpContext<DB>>> = OpEvm<DB, I, OpFpvmPrecompiles<H, O>>;
+    type Evm<DB: Database, I: Inspector<OpContext<DB>>> = OpEvm<DB, I, OpFpvmPrecompiles<H, O, R>>;
     type Context<DB: Database> = OpContext<DB>;
     type Tx = OpTransaction<TxEnv>;
     type Error<DBError: core::error::Error + Send + Sync + 'static> =
         EVMError<DBError, OpTransactionError>;
     type HaltReason = OpHaltReason;
     type Spec = OpSpecId;
-    type Precompiles = OpFpvmPrecompiles<H, O>;
+    type Precompiles = OpFpvmPrecompiles<H, O, R>;
 
     fn create_evm<DB: Database>(
         &self,
@@ -74,6 +102,7 @@ where
                 spec_id,
                 self.hint_writer.clone(),
                 self.oracle_reader.clone(),
+                self.registry.clone(),
             ),
             frame_stack: FrameStack::new(),
         });
@@ -97,6 +126,7 @@ where
                 spec_id,
                 self.hint_writer.clone(),
                 self.oracle_reader.clone(),
+                self.registry.clone(),
             ),
             frame_stack: FrameStack::new(),
         });
@@ -3,6 +3,10 @@
 //! [`EvmFactory`]: alloy_evm::EvmFactory
 
 mod precompiles;
+pub use precompiles::{
+    AcceleratedPrecompile, AcceleratedPrecompileFn, DefaultPrecompileRegistry, HostPrecompile,
+    OpFpvmPrecompiles, PrecompileGasFn, PrecompileRegistry,
+};
 
 mod factory;
 pub use factory::FpvmOpEvmFactory;
@@ -14,6 +14,11 @@ use revm::precompile::{
     bls12_381_const::{G1_ADD_BASE_GAS_FEE, G1_ADD_INPUT_LENGTH},
 };
 
+/// Returns the gas required by a BLS12-381 G1 addition precompile call.
+pub(crate) const fn bls12_g1_add_gas(_input: &[u8]) -> Result<u64, PrecompileError> {
+    Ok(G1_ADD_BASE_GAS_FEE)
+}
+
 /// Performs an FPVM-accelerated BLS12-381 G1 addition check.
 ///
 /// Notice, there is no input size limit for this precompile.
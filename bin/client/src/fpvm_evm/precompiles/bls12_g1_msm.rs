@@ -19,17 +19,9 @@ use revm::precompile::{
 /// See: <https://specs.optimism.io/protocol/isthmus/exec-engine.html#evm-changes>
 const BLS12_MAX_G1_MSM_SIZE_ISTHMUS: usize = 513760;
 
-/// Performs an FPVM-accelerated `bls12` g1 msm check precompile call after the Isthmus Hardfork.
-pub(crate) fn fpvm_bls12_g1_msm<H, O>(
-    input: &[u8],
-    gas_limit: u64,
-    hint_writer: &H,
-    oracle_reader: &O,
-) -> PrecompileResult
-where
-    H: HintWriterClient + Send + Sync,
-    O: PreimageOracleClient + Send + Sync,
-{
+/// Returns the gas required by a BLS12-381 G1 msm precompile call, validating the size of
+/// the input.
+pub(crate) fn bls12_g1_msm_gas(input: &[u8]) -> Result<u64, PrecompileError> {
     if input.len() > BLS12_MAX_G1_MSM_SIZE_ISTHMUS {
         return Err(PrecompileError::Other(alloc::format!(
             "G1MSM input length must be at most {}",
@@ -47,7 +39,21 @@ where
     }
 
     let k = input_len / G1_MSM_INPUT_LENGTH;
-    let required_gas = msm_required_gas(k, &DISCOUNT_TABLE_G1_MSM, G1_MSM_BASE_GAS_FEE);
+    Ok(msm_required_gas(k, &DISCOUNT_TABLE_G1_MSM, G1_MSM_BASE_GAS_FEE))
+}
+
+/// Performs an FPVM-accelerated `bls12` g1 msm check precompile call after the Isthmus Hardfork.
+pub(crate) fn fpvm_bls12_g1_msm<H, O>(
+    input: &[u8],
+    gas_limit: u64,
+    hint_writer: &H,
+    oracle_reader: &O,
+) -> PrecompileResult
+where
+    H: HintWriterClient + Send + Sync,
+    O: PreimageOracleClient + Send + Sync,
+{
+    let required_gas = bls12_g1_msm_gas(input)?;
     if required_gas > gas_limit {
         return Err(PrecompileError::OutOfGas);
     }
@@ -14,6 +14,11 @@ use revm::precompile::{
     bls12_381_const::{G2_ADD_BASE_GAS_FEE, G2_ADD_INPUT_LENGTH},
 };
 
+/// Returns the gas required by a BLS12-381 G2 addition precompile call.
+pub(crate) const fn bls12_g2_add_gas(_input: &[u8]) -> Result<u64, PrecompileError> {
+    Ok(G2_ADD_BASE_GAS_FEE)
+}
+
 /// Performs an FPVM-accelerated BLS12-381 G2 addition check.
 ///
 /// Notice, there is no input size limit for this precompile.
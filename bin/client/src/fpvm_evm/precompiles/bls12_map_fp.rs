@@ -14,6 +14,11 @@ use revm::precompile::{
     bls12_381_const::{MAP_FP_TO_G1_BASE_GAS_FEE, PADDED_FP_LENGTH},
 };
 
+/// Returns the gas required by a BLS12-381 map fp precompile call.
+pub(crate) const fn bls12_map_fp_gas(_input: &[u8]) -> Result<u64, PrecompileError> {
+    Ok(MAP_FP_TO_G1_BASE_GAS_FEE)
+}
+
 /// Performs an FPVM-accelerated BLS12-381 map fp check.
 ///
 /// Notice, there is no input size limit for this precompile.
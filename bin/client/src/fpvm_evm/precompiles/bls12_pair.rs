@@ -17,17 +17,9 @@ use revm::precompile::{
 /// The max pairing size for BLS12-381 input given a 20M gas limit.
 const BLS12_MAX_PAIRING_SIZE_ISTHMUS: usize = 235_008;
 
-/// Performs an FPVM-accelerated BLS12-381 pairing check.
-pub(crate) fn fpvm_bls12_pairing<H, O>(
-    input: &[u8],
-    gas_limit: u64,
-    hint_writer: &H,
-    oracle_reader: &O,
-) -> PrecompileResult
-where
-    H: HintWriterClient + Send + Sync,
-    O: PreimageOracleClient + Send + Sync,
-{
+/// Returns the gas required by a BLS12-381 pairing precompile call, validating the size of
+/// the input.
+pub(crate) fn bls12_pairing_gas(input: &[u8]) -> Result<u64, PrecompileError> {
     let input_len = input.len();
 
     if input_len > BLS12_MAX_PAIRING_SIZE_ISTHMUS {
@@ -44,7 +36,21 @@ where
     }
 
     let k = input_len / PAIRING_INPUT_LENGTH;
-    let required_gas: u64 = PAIRING_MULTIPLIER_BASE * k as u64 + PAIRING_OFFSET_BASE;
+    Ok(PAIRING_MULTIPLIER_BASE * k as u64 + PAIRING_OFFSET_BASE)
+}
+
+/// Performs an FPVM-accelerated BLS12-381 pairing check.
+pub(crate) fn fpvm_bls12_pairing<H, O>(
+    input: &[u8],
+    gas_limit: u64,
+    hint_writer: &H,
+    oracle_reader: &O,
+) -> PrecompileResult
+where
+    H: HintWriterClient + Send + Sync,
+    O: PreimageOracleClient + Send + Sync,
+{
+    let required_gas = bls12_pairing_gas(input)?;
     if required_gas > gas_limit {
         return Err(PrecompileError::OutOfGas);
     }
@@ -13,6 +13,21 @@ use revm::precompile::{
 
 const BN256_MAX_PAIRING_SIZE_GRANITE: usize = 112_687;
 
+/// Returns the gas required by an `ecpairing` precompile call.
+pub(crate) const fn bn128_pair_gas(input: &[u8]) -> Result<u64, PrecompileError> {
+    Ok((input.len() / PAIR_ELEMENT_LEN) as u64 * ISTANBUL_PAIR_PER_POINT + ISTANBUL_PAIR_BASE)
+}
+
+/// Returns the gas required by an `ecpairing` precompile call, with the input size limited by the
+/// Granite hardfork.
+pub(crate) const fn bn128_pair_granite_gas(input: &[u8]) -> Result<u64, PrecompileError> {
+    if input.len() > BN256_MAX_PAIRING_SIZE_GRANITE {
+        return Err(PrecompileError::Bn128PairLength);
+    }
+
+    bn128_pair_gas(input)
+}
+
 /// Runs the FPVM-accelerated `ecpairing` precompile call.
 pub(crate) fn fpvm_bn128_pair<H, O>(
     input: &[u8],
@@ -24,8 +39,7 @@ where
     H: HintWriterClient + Send + Sync,
     O: PreimageOracleClient + Send + Sync,
 {
-    let gas_used =
-        (input.len() / PAIR_ELEMENT_LEN) as u64 * ISTANBUL_PAIR_PER_POINT + ISTANBUL_PAIR_BASE;
+    let gas_used = bn128_pair_gas(input)?;
 
     if gas_used > gas_limit {
         return Err(PrecompileError::OutOfGas);
@@ -57,9 +71,7 @@ where
     H: HintWriterClient + Send + Sync,
     O: PreimageOracleClient + Send + Sync,
 {
-    if input.len() > BN256_MAX_PAIRING_SIZE_GRANITE {
-        return Err(PrecompileError::Bn128PairLength);
-    }
+    bn128_pair_granite_gas(input)?;
 
     fpvm_bn128_pair(input, gas_limit, hint_writer, oracle_reader)
 }
@@ -9,6 +9,14 @@ use revm::precompile::{PrecompileError, PrecompileOutput, PrecompileResult};
 /// Address of the `ecrecover` precompile.
 pub(crate) const ECRECOVER_ADDR: Address = revm::precompile::u64_to_address(1);
 
+/// The gas cost of the `ecrecover` precompile.
+const ECRECOVER_BASE: u64 = 3_000;
+
+/// Returns the gas required by an `ecrecover` precompile call.
+pub(crate) const fn ec_recover_gas(_input: &[u8]) -> Result<u64, PrecompileError> {
+    Ok(ECRECOVER_BASE)
+}
+
 /// Runs the FPVM-accelerated `ecrecover` precompile call.
 pub(crate) fn fpvm_ec_recover<H, O>(
     input: &[u8],
@@ -20,8 +28,6 @@ where
     H: HintWriterClient + Send + Sync,
     O: PreimageOracleClient + Send + Sync,
 {
-    const ECRECOVER_BASE: u64 = 3_000;
-
     if ECRECOVER_BASE > gas_limit {
         return Err(PrecompileError::OutOfGas);
     }
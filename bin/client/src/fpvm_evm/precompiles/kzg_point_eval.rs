@@ -9,6 +9,14 @@ use revm::precompile::{PrecompileError, PrecompileOutput, PrecompileResult};
 /// Address of the KZG point evaluation precompile.
 pub(crate) const KZG_POINT_EVAL_ADDR: Address = revm::precompile::u64_to_address(0x0A);
 
+/// The gas cost of the KZG point evaluation precompile.
+const GAS_COST: u64 = 50_000;
+
+/// Returns the gas required by a `kzgPointEval` precompile call.
+pub(crate) const fn kzg_point_eval_gas(_input: &[u8]) -> Result<u64, PrecompileError> {
+    Ok(GAS_COST)
+}
+
 /// Runs the FPVM-accelerated `kzgPointEval` precompile call.
 pub(crate) fn fpvm_kzg_point_eval<H, O>(
     input: &[u8],
@@ -20,8 +28,6 @@ where
     H: HintWriterClient + Send + Sync,
     O: PreimageOracleClient + Send + Sync,
 {
-    const GAS_COST: u64 = 50_000;
-
     if gas_limit < GAS_COST {
         return Err(PrecompileError::OutOfGas);
     }
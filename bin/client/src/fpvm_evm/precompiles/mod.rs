@@ -4,7 +4,13 @@
 //! [`PrecompileProvider`]: revm::handler::PrecompileProvider
 
 mod provider;
-pub(crate) use provider::OpFpvmPrecompiles;
+pub use provider::OpFpvmPrecompiles;
+
+mod registry;
+pub use registry::{
+    AcceleratedPrecompile, AcceleratedPrecompileFn, DefaultPrecompileRegistry, HostPrecompile,
+    PrecompileGasFn, PrecompileRegistry,
+};
 
 mod bls12_g1_add;
 mod bls12_g1_msm;
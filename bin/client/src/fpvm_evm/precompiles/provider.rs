@@ -1,9 +1,9 @@
 //! [`PrecompileProvider`] for FPVM-accelerated OP Stack precompiles.
 
 use crate::fpvm_evm::precompiles::{
-    ecrecover::ECRECOVER_ADDR, kzg_point_eval::KZG_POINT_EVAL_ADDR,
+    AcceleratedPrecompile, DefaultPrecompileRegistry, PrecompileRegistry,
 };
-use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use alloc::{boxed::Box, string::String};
 use alloy_primitives::{Address, Bytes};
 use kona_preimage::{HintWriterClient, PreimageOracleClient};
 use op_revm::{
@@ -14,17 +14,19 @@ use revm::{
     context::{Cfg, ContextTr},
     handler::{EthPrecompiles, PrecompileProvider},
     interpreter::{Gas, InputsImpl, InstructionResult, InterpreterResult},
-    precompile::{PrecompileError, PrecompileResult, Precompiles, bls12_381_const, bn128},
+    precompile::{PrecompileError, Precompiles},
     primitives::{hardfork::SpecId, hash_map::HashMap},
 };
 
 /// The FPVM-accelerated precompiles.
 #[derive(Debug)]
-pub struct OpFpvmPrecompiles<H, O> {
+pub struct OpFpvmPrecompiles<H, O, R = DefaultPrecompileRegistry> {
     /// The default [`EthPrecompiles`] provider.
     inner: EthPrecompiles,
     /// The accelerated precompiles for the current [`OpSpecId`].
-    accelerated_precompiles: HashMap<Address, AcceleratedPrecompileFn<H, O>>,
+    accelerated_precompiles: HashMap<Address, AcceleratedPrecompile<H, O>>,
+    /// The [`PrecompileRegistry`] that the accelerated precompiles are sourced from.
+    registry: R,
     /// The [`OpSpecId`] of the precompiles.
     spec: OpSpecId,
     /// The inner [`HintWriterClient`].
@@ -33,14 +35,19 @@ pub struct OpFpvmPrecompiles<H, O> {
     oracle_reader: O,
 }
 
-impl<H, O> OpFpvmPrecompiles<H, O>
+impl<H, O, R> OpFpvmPrecompiles<H, O, R>
 where
     H: HintWriterClient + Clone + Send + Sync + 'static,
     O: PreimageOracleClient + Clone + Send + Sync + 'static,
+    R: PrecompileRegistry,
 {
-    /// Create a new precompile provider with the given [`OpSpecId`].
+    /// Create a new precompile provider with the given [`OpSpecId`], accelerating the precompiles
+    /// in the given [`PrecompileRegistry`].
+    ///
+    /// Only precompiles that the EVM implements under the [`OpSpecId`] are accelerated; entries at
+    /// any other address are ignored, so that the registry never changes the set of precompiles.
     #[inline]
-    pub fn new_with_spec(spec: OpSpecId, hint_writer: H, oracle_reader: O) -> Self {
+    pub fn new_with_spec(spec: OpSpecId, hint_writer: H, oracle_reader: O, registry: R) -> Self {
         let precompiles = match spec {
             spec @ (OpSpecId::BEDROCK |
             OpSpecId::REGOLITH |
@@ -51,23 +58,17 @@ where
             OpSpecId::ISTHMUS | OpSpecId::INTEROP | OpSpecId::OSAKA => isthmus(),
         };
 
-        let accelerated_precompiles = match spec {
-            OpSpecId::BEDROCK | OpSpecId::REGOLITH | OpSpecId::CANYON => {
-                accelerated_bedrock::<H, O>()
-            }
-            OpSpecId::ECOTONE | OpSpecId::FJORD => accelerated_ecotone::<H, O>(),
-            OpSpecId::GRANITE | OpSpecId::HOLOCENE => accelerated_granite::<H, O>(),
-            OpSpecId::ISTHMUS | OpSpecId::INTEROP | OpSpecId::OSAKA => {
-                accelerated_isthmus::<H, O>()
-            }
-        };
+        let accelerated_precompiles = registry
+            .accelerated::<H, O>(spec)
+            .into_iter()
+            .filter(|p| precompiles.contains(&p.address))
+            .map(|p| (p.address, p))
+            .collect();
 
         Self {
             inner: EthPrecompiles { precompiles, spec: SpecId::default() },
-            accelerated_precompiles: accelerated_precompiles
-                .into_iter()
-                .map(|p| (p.address, p.precompile))
-                .collect(),
+            accelerated_precompiles,
+            registry,
             spec,
             hint_writer,
             oracle_reader,
@@ -75,10 +76,11 @@ where
     }
 }
 
-impl<CTX, H, O> PrecompileProvider<CTX> for OpFpvmPrecompiles<H, O>
+impl<CTX, H, O, R> PrecompileProvider<CTX> for OpFpvmPrecompiles<H, O, R>
 where
     H: HintWriterClient + Clone + Send + Sync + 'static,
     O: PreimageOracleClient + Clone + Send + Sync + 'static,
+    R: PrecompileRegistry,
     CTX: ContextTr<Cfg: Cfg<Spec = OpSpecId>>,
 {
     type Output = InterpreterResult;
@@ -88,7 +90,12 @@ where
         if spec == self.spec {
             return false;
         }
-        *self = Self::new_with_spec(spec, self.hint_writer.clone(), self.oracle_reader.clone());
+        *self = Self::new_with_spec(
+            spec,
+            self.hint_writer.clone(),
+            self.oracle_reader.clone(),
+            self.registry.clone(),
+        );
         true
     }
 
@@ -118,11 +125,14 @@ where
         };
 
         // Priority:
-        // 1. If the precompile has an accelerated version, use that.
+        // 1. If the precompile has an accelerated version, check the call against its gas schedule
+        //    and use that.
         // 2. If the precompile is not accelerated, use the default version.
         // 3. If the precompile is not found, return None.
         let output = if let Some(accelerated) = self.accelerated_precompiles.get(address) {
-            (accelerated)(&input, gas_limit, &self.hint_writer, &self.oracle_reader)
+            accelerated.check_gas(&input, gas_limit).and_then(|_| {
+                (accelerated.precompile)(&input, gas_limit, &self.hint_writer, &self.oracle_reader)
+            })
         } else if let Some(precompile) = self.inner.precompiles.get(address) {
             (*precompile)(&input, gas_limit)
         } else {
@@ -160,101 +170,87 @@ where
     }
 }
 
-/// A precompile function that can be accelerated by the FPVM.
-type AcceleratedPrecompileFn<H, O> = fn(&[u8], u64, &H, &O) -> PrecompileResult;
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fpvm_evm::precompiles::{HostPrecompile, ecrecover::ECRECOVER_ADDR};
+    use alloc::{vec, vec::Vec};
+    use kona_preimage::{BidirectionalChannel, HintWriter, NativeChannel, OracleReader};
+    use op_revm::OpContext;
+    use revm::{
+        database_interface::EmptyDB,
+        precompile::{PrecompileResult, u64_to_address},
+    };
 
-/// A tuple type for accelerated precompiles with an associated [`Address`].
-struct AcceleratedPrecompile<H, O> {
-    /// The address of the precompile.
-    address: Address,
-    /// The precompile function.
-    precompile: AcceleratedPrecompileFn<H, O>,
-}
+    type TestPrecompiles<R> =
+        OpFpvmPrecompiles<HintWriter<NativeChannel>, OracleReader<NativeChannel>, R>;
+    type TestContext = OpContext<EmptyDB>;
+
+    /// A registry that accelerates `ecrecover`, and a precompile at an address that the EVM does
+    /// not implement.
+    #[derive(Debug, Clone)]
+    struct CustomRegistry;
+
+    impl PrecompileRegistry for CustomRegistry {
+        fn accelerated<H, O>(&self, _: OpSpecId) -> Vec<AcceleratedPrecompile<H, O>>
+        where
+            H: HintWriterClient + Send + Sync,
+            O: PreimageOracleClient + Send + Sync,
+        {
+            vec![
+                AcceleratedPrecompile::new(ECRECOVER_ADDR, noop::<H, O>, |_| Ok(0)),
+                AcceleratedPrecompile::new(u64_to_address(0xff), noop::<H, O>, |_| Ok(0)),
+            ]
+        }
 
-impl<H, O> AcceleratedPrecompile<H, O> {
-    /// Create a new accelerated precompile.
-    fn new(address: Address, precompile: AcceleratedPrecompileFn<H, O>) -> Self {
-        Self { address, precompile }
+        fn host_precompiles(&self) -> Vec<HostPrecompile> {
+            Vec::new()
+        }
     }
-}
 
-/// The accelerated precompiles for the bedrock spec.
-fn accelerated_bedrock<H, O>() -> Vec<AcceleratedPrecompile<H, O>>
-where
-    H: HintWriterClient + Send + Sync,
-    O: PreimageOracleClient + Send + Sync,
-{
-    vec![
-        AcceleratedPrecompile::new(ECRECOVER_ADDR, super::ecrecover::fpvm_ec_recover::<H, O>),
-        AcceleratedPrecompile::new(
-            bn128::pair::ADDRESS,
-            super::bn128_pair::fpvm_bn128_pair::<H, O>,
-        ),
-    ]
-}
+    fn noop<H, O>(_: &[u8], _: u64, _: &H, _: &O) -> PrecompileResult {
+        Err(PrecompileError::Other("noop".into()))
+    }
 
-/// The accelerated precompiles for the ecotone spec.
-fn accelerated_ecotone<H, O>() -> Vec<AcceleratedPrecompile<H, O>>
-where
-    H: HintWriterClient + Send + Sync,
-    O: PreimageOracleClient + Send + Sync,
-{
-    let mut base = accelerated_bedrock::<H, O>();
-    base.push(AcceleratedPrecompile::new(
-        KZG_POINT_EVAL_ADDR,
-        super::kzg_point_eval::fpvm_kzg_point_eval::<H, O>,
-    ));
-    base
-}
+    fn precompiles<R: PrecompileRegistry>(spec: OpSpecId, registry: R) -> TestPrecompiles<R> {
+        let (hint, preimage) =
+            (BidirectionalChannel::new().unwrap(), BidirectionalChannel::new().unwrap());
+        OpFpvmPrecompiles::new_with_spec(
+            spec,
+            HintWriter::new(hint.client),
+            OracleReader::new(preimage.client),
+            registry,
+        )
+    }
 
-/// The accelerated precompiles for the granite spec.
-fn accelerated_granite<H, O>() -> Vec<AcceleratedPrecompile<H, O>>
-where
-    H: HintWriterClient + Send + Sync,
-    O: PreimageOracleClient + Send + Sync,
-{
-    let mut base = accelerated_ecotone::<H, O>();
-    base.push(AcceleratedPrecompile::new(
-        bn128::pair::ADDRESS,
-        super::bn128_pair::fpvm_bn128_pair_granite::<H, O>,
-    ));
-    base
-}
+    #[test]
+    fn test_accelerated_precompiles_keep_evm_precompile_set() {
+        for spec in [OpSpecId::BEDROCK, OpSpecId::GRANITE, OpSpecId::ISTHMUS] {
+            let precompiles = precompiles(spec, DefaultPrecompileRegistry);
+            let inner = PrecompileProvider::<TestContext>::warm_addresses(&precompiles.inner)
+                .collect::<Vec<_>>();
 
-/// The accelerated precompiles for the isthmus spec.
-fn accelerated_isthmus<H, O>() -> Vec<AcceleratedPrecompile<H, O>>
-where
-    H: HintWriterClient + Send + Sync,
-    O: PreimageOracleClient + Send + Sync,
-{
-    let mut base = accelerated_granite::<H, O>();
-    base.push(AcceleratedPrecompile::new(
-        bls12_381_const::G1_ADD_ADDRESS,
-        super::bls12_g1_add::fpvm_bls12_g1_add::<H, O>,
-    ));
-    base.push(AcceleratedPrecompile::new(
-        bls12_381_const::G1_MSM_ADDRESS,
-        super::bls12_g1_msm::fpvm_bls12_g1_msm::<H, O>,
-    ));
-    base.push(AcceleratedPrecompile::new(
-        bls12_381_const::G2_ADD_ADDRESS,
-        super::bls12_g2_add::fpvm_bls12_g2_add::<H, O>,
-    ));
-    base.push(AcceleratedPrecompile::new(
-        bls12_381_const::G2_MSM_ADDRESS,
-        super::bls12_g2_msm::fpvm_bls12_g2_msm::<H, O>,
-    ));
-    base.push(AcceleratedPrecompile::new(
-        bls12_381_const::MAP_FP_TO_G1_ADDRESS,
-        super::bls12_map_fp::fpvm_bls12_map_fp::<H, O>,
-    ));
-    base.push(AcceleratedPrecompile::new(
-        bls12_381_const::MAP_FP2_TO_G2_ADDRESS,
-        super::bls12_map_fp2::fpvm_bls12_map_fp2::<H, O>,
-    ));
-    base.push(AcceleratedPrecompile::new(
-        bls12_381_const::PAIRING_ADDRESS,
-        super::bls12_pair::fpvm_bls12_pairing::<H, O>,
-    ));
-    base
+            assert_eq!(
+                PrecompileProvider::<TestContext>::warm_addresses(&precompiles).collect::<Vec<_>>(),
+                inner
+            );
+            for address in precompiles.accelerated_precompiles.keys() {
+                assert!(PrecompileProvider::<TestContext>::contains(&precompiles, address));
+            }
+        }
+    }
+
+    #[test]
+    fn test_ignores_accelerated_precompiles_outside_evm() {
+        let precompiles = precompiles(OpSpecId::ISTHMUS, CustomRegistry);
+        let custom = u64_to_address(0xff);
+
+        assert!(precompiles.accelerated_precompiles.contains_key(&ECRECOVER_ADDR));
+        assert!(!precompiles.accelerated_precompiles.contains_key(&custom));
+        assert!(!PrecompileProvider::<TestContext>::contains(&precompiles, &custom));
+        assert!(
+            !PrecompileProvider::<TestContext>::warm_addresses(&precompiles)
+                .any(|address| address == custom)
+        );
+    }
 }
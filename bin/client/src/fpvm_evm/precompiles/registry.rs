@@ -0,0 +1,294 @@
+//! Contains the [`PrecompileRegistry`] trait, which describes the set of precompiles that are
+//! accelerated by the FPVM, and the [`DefaultPrecompileRegistry`] used by kona.
+
+use super::{
+    bls12_g1_add::{bls12_g1_add_gas, fpvm_bls12_g1_add},
+    bls12_g1_msm::{bls12_g1_msm_gas, fpvm_bls12_g1_msm},
+    bls12_g2_add::{bls12_g2_add_gas, fpvm_bls12_g2_add},
+    bls12_g2_msm::{bls12_g2_msm_gas, fpvm_bls12_g2_msm},
+    bls12_map_fp::{bls12_map_fp_gas, fpvm_bls12_map_fp},
+    bls12_map_fp2::{bls12_map_fp2_gas, fpvm_bls12_map_fp2},
+    bls12_pair::{bls12_pairing_gas, fpvm_bls12_pairing},
+    bn128_pair::{
+        bn128_pair_gas, bn128_pair_granite_gas, fpvm_bn128_pair, fpvm_bn128_pair_granite,
+    },
+    ecrecover::{ECRECOVER_ADDR, ec_recover_gas, fpvm_ec_recover},
+    kzg_point_eval::{KZG_POINT_EVAL_ADDR, fpvm_kzg_point_eval, kzg_point_eval_gas},
+};
+use alloc::{vec, vec::Vec};
+use alloy_primitives::Address;
+use core::fmt::Debug;
+use kona_preimage::{HintWriterClient, PreimageOracleClient};
+use op_revm::OpSpecId;
+use revm::precompile::{PrecompileError, PrecompileFn, PrecompileResult, bls12_381_const, bn128};
+
+/// A precompile function that can be accelerated by the FPVM.
+pub type AcceleratedPrecompileFn<H, O> = fn(&[u8], u64, &H, &O) -> PrecompileResult;
+
+/// The gas schedule of a precompile.
+///
+/// Returns the gas that a call with the given input costs, or an error if the input is rejected
+/// before any gas is charged (i.e. it exceeds the precompile's input size limit.)
+pub type PrecompileGasFn = fn(&[u8]) -> Result<u64, PrecompileError>;
+
+/// Checks a call with the given input and gas limit against a [`PrecompileGasFn`], returning the
+/// gas that the call costs.
+fn check_gas(gas: PrecompileGasFn, input: &[u8], gas_limit: u64) -> Result<u64, PrecompileError> {
+    let required_gas = gas(input)?;
+    if required_gas > gas_limit {
+        return Err(PrecompileError::OutOfGas);
+    }
+    Ok(required_gas)
+}
+
+/// A precompile that is accelerated by the FPVM, with an associated [`Address`] and gas schedule.
+#[derive(Debug)]
+pub struct AcceleratedPrecompile<H, O> {
+    /// The address of the precompile.
+    pub address: Address,
+    /// The accelerated precompile function, which is run by the client program.
+    pub precompile: AcceleratedPrecompileFn<H, O>,
+    /// The gas schedule of the precompile.
+    pub gas: PrecompileGasFn,
+}
+
+impl<H, O> AcceleratedPrecompile<H, O> {
+    /// Create a new accelerated precompile.
+    pub const fn new(
+        address: Address,
+        precompile: AcceleratedPrecompileFn<H, O>,
+        gas: PrecompileGasFn,
+    ) -> Self {
+        Self { address, precompile, gas }
+    }
+
+    /// Checks a call against the precompile's gas schedule, returning the gas that the call
+    /// costs.
+    pub fn check_gas(&self, input: &[u8], gas_limit: u64) -> Result<u64, PrecompileError> {
+        check_gas(self.gas, input, gas_limit)
+    }
+}
+
+/// A precompile that the host executes natively on behalf of the client program, in response to
+/// an `L1Precompile` hint.
+#[derive(Debug, Clone, Copy)]
+pub struct HostPrecompile {
+    /// The address of the precompile.
+    pub address: Address,
+    /// The native precompile function. If [`None`], the host executes [revm]'s implementation of
+    /// the precompile at [`Self::address`].
+    pub precompile: Option<PrecompileFn>,
+    /// The gas schedule of the precompile.
+    pub gas: PrecompileGasFn,
+}
+
+impl HostPrecompile {
+    /// Create a new host precompile, executed by [revm]'s implementation of the precompile at the
+    /// given [`Address`].
+    pub const fn new(address: Address, gas: PrecompileGasFn) -> Self {
+        Self { address, precompile: None, gas }
+    }
+
+    /// Sets the native precompile function, for precompiles that [revm] does not implement.
+    pub const fn with_precompile(mut self, precompile: PrecompileFn) -> Self {
+        self.precompile = Some(precompile);
+        self
+    }
+
+    /// Checks a call against the precompile's gas schedule, returning the gas that the call
+    /// costs.
+    pub fn check_gas(&self, input: &[u8], gas_limit: u64) -> Result<u64, PrecompileError> {
+        check_gas(self.gas, input, gas_limit)
+    }
+}
+
+/// A registry of the precompiles that are accelerated by the FPVM.
+///
+/// The client program runs the [`AcceleratedPrecompile`]s returned by
+/// [`PrecompileRegistry::accelerated`] in place of the EVM's own implementation, and the host
+/// serves the resulting `L1Precompile` hints with the matching
+/// [`PrecompileRegistry::host_precompiles`]. Both sides check each call against the entry's gas
+/// schedule.
+///
+/// Downstream forks may add or remove accelerated precompiles by implementing this trait, i.e. by
+/// wrapping the [`DefaultPrecompileRegistry`] and editing the entries that it returns. A registry
+/// only replaces the implementation of precompiles: entries at addresses that the EVM does not
+/// implement under the active [`OpSpecId`] are ignored by the client program, and never become
+/// callable or warm.
+pub trait PrecompileRegistry: Debug + Clone + Send + Sync + 'static {
+    /// Returns the precompiles that are accelerated under the given [`OpSpecId`].
+    ///
+    /// If two entries share an address, the latter takes precedence.
+    fn accelerated<H, O>(&self, spec: OpSpecId) -> Vec<AcceleratedPrecompile<H, O>>
+    where
+        H: HintWriterClient + Send + Sync,
+        O: PreimageOracleClient + Send + Sync;
+
+    /// Returns the precompiles that the host may execute on behalf of the client program, across
+    /// all [`OpSpecId`]s.
+    fn host_precompiles(&self) -> Vec<HostPrecompile>;
+}
+
+/// The default [`PrecompileRegistry`], which accelerates `ecrecover`, `ecPairing`, KZG point
+/// evaluation, and the BLS12-381 precompiles.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultPrecompileRegistry;
+
+impl PrecompileRegistry for DefaultPrecompileRegistry {
+    fn accelerated<H, O>(&self, spec: OpSpecId) -> Vec<AcceleratedPrecompile<H, O>>
+    where
+        H: HintWriterClient + Send + Sync,
+        O: PreimageOracleClient + Send + Sync,
+    {
+        match spec {
+            OpSpecId::BEDROCK | OpSpecId::REGOLITH | OpSpecId::CANYON => {
+                accelerated_bedrock::<H, O>()
+            }
+            OpSpecId::ECOTONE | OpSpecId::FJORD => accelerated_ecotone::<H, O>(),
+            OpSpecId::GRANITE | OpSpecId::HOLOCENE => accelerated_granite::<H, O>(),
+            OpSpecId::ISTHMUS | OpSpecId::INTEROP | OpSpecId::OSAKA => {
+                accelerated_isthmus::<H, O>()
+            }
+        }
+    }
+
+    fn host_precompiles(&self) -> Vec<HostPrecompile> {
+        vec![
+            HostPrecompile::new(ECRECOVER_ADDR, ec_recover_gas),
+            HostPrecompile::new(bn128::pair::ADDRESS, bn128_pair_gas),
+            HostPrecompile::new(KZG_POINT_EVAL_ADDR, kzg_point_eval_gas),
+            HostPrecompile::new(bls12_381_const::G1_ADD_ADDRESS, bls12_g1_add_gas),
+            HostPrecompile::new(bls12_381_const::G1_MSM_ADDRESS, bls12_g1_msm_gas),
+            HostPrecompile::new(bls12_381_const::G2_ADD_ADDRESS, bls12_g2_add_gas),
+            HostPrecompile::new(bls12_381_const::G2_MSM_ADDRESS, bls12_g2_msm_gas),
+            HostPrecompile::new(bls12_381_const::MAP_FP_TO_G1_ADDRESS, bls12_map_fp_gas),
+            HostPrecompile::new(bls12_381_const::MAP_FP2_TO_G2_ADDRESS, bls12_map_fp2_gas),
+            HostPrecompile::new(bls12_381_const::PAIRING_ADDRESS, bls12_pairing_gas),
+        ]
+    }
+}
+
+/// The accelerated precompiles for the bedrock spec.
+fn accelerated_bedrock<H, O>() -> Vec<AcceleratedPrecompile<H, O>>
+where
+    H: HintWriterClient + Send + Sync,
+    O: PreimageOracleClient + Send + Sync,
+{
+    vec![
+        AcceleratedPrecompile::new(ECRECOVER_ADDR, fpvm_ec_recover::<H, O>, ec_recover_gas),
+        AcceleratedPrecompile::new(bn128::pair::ADDRESS, fpvm_bn128_pair::<H, O>, bn128_pair_gas),
+    ]
+}
+
+/// The accelerated precompiles for the ecotone spec.
+fn accelerated_ecotone<H, O>() -> Vec<AcceleratedPrecompile<H, O>>
+where
+    H: HintWriterClient + Send + Sync,
+    O: PreimageOracleClient + Send + Sync,
+{
+    let mut base = accelerated_bedrock::<H, O>();
+    base.push(AcceleratedPrecompile::new(
+        KZG_POINT_EVAL_ADDR,
+        fpvm_kzg_point_eval::<H, O>,
+        kzg_point_eval_gas,
+    ));
+    base
+}
+
+/// The accelerated precompiles for the granite spec.
+fn accelerated_granite<H, O>() -> Vec<AcceleratedPrecompile<H, O>>
+where
+    H: HintWriterClient + Send + Sync,
+    O: PreimageOracleClient + Send + Sync,
+{
+    let mut base = accelerated_ecotone::<H, O>();
+    base.push(AcceleratedPrecompile::new(
+        bn128::pair::ADDRESS,
+        fpvm_bn128_pair_granite::<H, O>,
+        bn128_pair_granite_gas,
+    ));
+    base
+}
+
+/// The accelerated precompiles for the isthmus spec.
+fn accelerated_isthmus<H, O>() -> Vec<AcceleratedPrecompile<H, O>>
+where
+    H: HintWriterClient + Send + Sync,
+    O: PreimageOracleClient + Send + Sync,
+{
+    let mut base = accelerated_granite::<H, O>();
+    base.push(AcceleratedPrecompile::new(
+        bls12_381_const::G1_ADD_ADDRESS,
+        fpvm_bls12_g1_add::<H, O>,
+        bls12_g1_add_gas,
+    ));
+    base.push(AcceleratedPrecompile::new(
+        bls12_381_const::G1_MSM_ADDRESS,
+        fpvm_bls12_g1_msm::<H, O>,
+        bls12_g1_msm_gas,
+    ));
+    base.push(AcceleratedPrecompile::new(
+        bls12_381_const::G2_ADD_ADDRESS,
+        fpvm_bls12_g2_add::<H, O>,
+        bls12_g2_add_gas,
+    ));
+    base.push(AcceleratedPrecompile::new(
+        bls12_381_const::G2_MSM_ADDRESS,
+        fpvm_bls12_g2_msm::<H, O>,
+        bls12_g2_msm_gas,
+    ));
+    base.push(AcceleratedPrecompile::new(
+        bls12_381_const::MAP_FP_TO_G1_ADDRESS,
+        fpvm_bls12_map_fp::<H, O>,
+        bls12_map_fp_gas,
+    ));
+    base.push(AcceleratedPrecompile::new(
+        bls12_381_const::MAP_FP2_TO_G2_ADDRESS,
+        fpvm_bls12_map_fp2::<H, O>,
+        bls12_map_fp2_gas,
+    ));
+    base.push(AcceleratedPrecompile::new(
+        bls12_381_const::PAIRING_ADDRESS,
+        fpvm_bls12_pairing::<H, O>,
+        bls12_pairing_gas,
+    ));
+    base
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use kona_preimage::{HintWriter, NativeChannel, OracleReader};
+
+    type Entries =
+        Vec<AcceleratedPrecompile<HintWriter<NativeChannel>, OracleReader<NativeChannel>>>;
+
+    #[test]
+    fn test_host_precompiles_cover_accelerated() {
+        let host = DefaultPrecompileRegistry.host_precompiles();
+        let accelerated: Entries = DefaultPrecompileRegistry.accelerated(OpSpecId::ISTHMUS);
+
+        for entry in accelerated {
+            assert!(host.iter().any(|p| p.address == entry.address), "{}", entry.address);
+        }
+    }
+
+    #[test]
+    fn test_accelerated_granite_overrides_bn128_pair() {
+        let accelerated: Entries = DefaultPrecompileRegistry.accelerated(OpSpecId::GRANITE);
+        let pair = accelerated.iter().rev().find(|p| p.address == bn128::pair::ADDRESS).unwrap();
+
+        let oversized = [0u8; 112_688];
+        assert!(matches!(
+            pair.check_gas(&oversized, u64::MAX),
+            Err(PrecompileError::Bn128PairLength)
+        ));
+    }
+
+    #[test]
+    fn test_check_gas() {
+        let ecrecover = HostPrecompile::new(ECRECOVER_ADDR, ec_recover_gas);
+        assert_eq!(ecrecover.check_gas(&[], 3_000).unwrap(), 3_000);
+        assert!(matches!(ecrecover.check_gas(&[], 2_999), Err(PrecompileError::OutOfGas)));
+    }
+}
@@ -0,0 +1,89 @@
+//! Replays the recorded proof fixtures in `testdata` through the `kona-client` program, serving
+//! preimages offline, and checks the exit status of each run against the fixture's expected one.
+//!
+//! Fixtures are recorded with the `proof-fixture` example, which derives the expected exit
+//! status from the canonical output root of the claimed block rather than from the recorded run.
+//! Recording requires archival L1 and L2 nodes, so the test is skipped while `testdata` holds no
+//! fixture.
+
+use kona_host::{OfflineHostBackend, PreimageServer, fixture::ProofFixture};
+use kona_preimage::{BidirectionalChannel, HintReader, HintWriter, OracleReader, OracleServer};
+use std::{path::PathBuf, sync::Arc};
+use tokio::sync::RwLock;
+
+/// The file extension of recorded proof fixtures.
+const FIXTURE_EXTENSION: &str = "zlib";
+
+/// The hardforks that should each be covered by at least one fixture. Fixture names are prefixed
+/// with the hardfork that is active at the claimed block.
+const HARDFORKS: [&str; 5] = ["ecotone", "fjord", "granite", "holocene", "isthmus"];
+
+/// Returns the paths of all recorded fixtures, in a stable order.
+fn fixture_paths() -> Vec<PathBuf> {
+    let testdata = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("testdata");
+    let mut paths = std::fs::read_dir(testdata)
+        .expect("Failed to read testdata directory")
+        .map(|entry| entry.expect("Failed to read directory entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == FIXTURE_EXTENSION))
+        .collect::<Vec<_>>();
+    paths.sort();
+    paths
+}
+
+/// Replays a single fixture and asserts that the client program exits with the expected status.
+async fn replay_fixture(fixture: ProofFixture) {
+    let kv_store = Arc::new(RwLock::new(fixture.to_kv_store()));
+
+    let hint = BidirectionalChannel::new().unwrap();
+    let preimage = BidirectionalChannel::new().unwrap();
+
+    let server_task = tokio::task::spawn(
+        PreimageServer::new(
+            OracleServer::new(preimage.host),
+            HintReader::new(hint.host),
+            Arc::new(OfflineHostBackend::new(kv_store)),
+        )
+        .start(),
+    );
+    let client_task = tokio::task::spawn(kona_client::single::run(
+        OracleReader::new(preimage.client),
+        HintWriter::new(hint.client),
+    ));
+
+    let (server_result, client_result) = tokio::try_join!(server_task, client_task).unwrap();
+    server_result.unwrap_or_else(|e| panic!("Preimage server failed for {}: {e}", fixture.name));
+
+    assert_eq!(
+        client_result.is_err() as u8,
+        fixture.expected_status,
+        "Unexpected exit status for fixture {}",
+        fixture.name
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_replay_proof_fixtures() {
+    let paths = fixture_paths();
+    if paths.is_empty() {
+        eprintln!("No proof fixtures found in testdata, skipping");
+        return;
+    }
+
+    let fixtures = paths
+        .iter()
+        .map(|path| {
+            ProofFixture::load(path)
+                .unwrap_or_else(|e| panic!("Failed to load fixture {}: {e}", path.display()))
+        })
+        .collect::<Vec<_>>();
+
+    for hardfork in HARDFORKS {
+        if !fixtures.iter().any(|f| f.name.starts_with(&format!("{hardfork}-"))) {
+            eprintln!("No proof fixture covers the {hardfork} hardfork");
+        }
+    }
+
+    for fixture in fixtures {
+        replay_fixture(fixture).await;
+    }
+}
@@ -6,4 +6,7 @@ pub use offline::OfflineHostBackend;
 mod online;
 pub use online::{HintHandler, OnlineHostBackend, OnlineHostBackendCfg};
 
+mod prefetch;
+pub use prefetch::{DEFAULT_WITNESS_PREFETCH_CONCURRENCY, ExecutionWitnessPrefetcher};
+
 pub(crate) mod util;
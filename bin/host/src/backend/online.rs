@@ -31,11 +31,19 @@ pub trait HintHandler {
 
     /// Fetches data in response to a hint.
     async fn fetch_hint(
+        &self,
         hint: Hint<<Self::Cfg as OnlineHostBackendCfg>::HintType>,
         cfg: &Self::Cfg,
         providers: &<Self::Cfg as OnlineHostBackendCfg>::Providers,
         kv: SharedKeyValueStore,
     ) -> Result<()>;
+
+    /// Returns `true` if a [HintHandler::fetch_hint] error cannot be resolved by retrying the
+    /// hint, e.g. because no provider serves the hinted chain. The [OnlineHostBackend] fails the
+    /// preimage request on such errors, and retries the hint on any other error.
+    fn is_fatal(&self, _err: &anyhow::Error) -> bool {
+        false
+    }
 }
 
 /// The [OnlineHostBackend] is a [HintRouter] and [PreimageFetcher] that is used to fetch data from
@@ -58,8 +66,8 @@ where
     proactive_hints: HashSet<C::HintType>,
     /// The last hint that was received.
     last_hint: Arc<RwLock<Option<Hint<C::HintType>>>>,
-    /// Phantom marker for the [HintHandler].
-    _hint_handler: std::marker::PhantomData<H>,
+    /// The [HintHandler] that fetches data in response to hints.
+    hint_handler: H,
 }
 
 impl<C, H> OnlineHostBackend<C, H>
@@ -69,14 +77,14 @@ where
 {
     /// Creates a new [HintHandler] with the given configuration, key-value store, providers, and
     /// external configuration.
-    pub fn new(cfg: C, kv: SharedKeyValueStore, providers: C::Providers, _: H) -> Self {
+    pub fn new(cfg: C, kv: SharedKeyValueStore, providers: C::Providers, hint_handler: H) -> Self {
         Self {
             cfg,
             kv,
             providers,
             proactive_hints: HashSet::default(),
             last_hint: Arc::new(RwLock::new(None)),
-            _hint_handler: std::marker::PhantomData,
+            hint_handler,
         }
     }
 
@@ -102,7 +110,8 @@ where
             .map_err(|e| PreimageOracleError::HintParseFailed(e.to_string()))?;
         if self.proactive_hints.contains(&parsed_hint.ty) {
             debug!(target: "host_backend", "Proactive hint received; Immediately fetching {hint}");
-            H::fetch_hint(parsed_hint, &self.cfg, &self.providers, self.kv.clone())
+            self.hint_handler
+                .fetch_hint(parsed_hint, &self.cfg, &self.providers, self.kv.clone())
                 .await
                 .map_err(|e| PreimageOracleError::Other(e.to_string()))?;
         } else {
@@ -134,10 +143,16 @@ where
         // Use a loop to keep retrying the prefetch as long as the key is not found
         while preimage.is_none() {
             if let Some(hint) = self.last_hint.read().await.as_ref() {
-                let value =
-                    H::fetch_hint(hint.clone(), &self.cfg, &self.providers, self.kv.clone()).await;
+                let value = self
+                    .hint_handler
+                    .fetch_hint(hint.clone(), &self.cfg, &self.providers, self.kv.clone())
+                    .await;
 
                 if let Err(e) = value {
+                    if self.hint_handler.is_fatal(&e) {
+                        error!(target: "host_backend", "Failed to prefetch hint, not retrying: {e}");
+                        return Err(PreimageOracleError::Other(e.to_string()));
+                    }
                     error!(target: "host_backend", "Failed to prefetch hint: {e}");
                     continue;
                 }
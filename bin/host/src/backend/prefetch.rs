@@ -0,0 +1,270 @@
+//! Contains the [ExecutionWitnessPrefetcher], which bulk-loads execution witnesses into the
+//! key-value store ahead of the client program.
+
+use crate::SharedKeyValueStore;
+use alloy_consensus::Header;
+use alloy_eips::BlockNumberOrTag;
+use alloy_primitives::{B256, Bytes, keccak256};
+use alloy_provider::{Provider, RootProvider};
+use alloy_rlp::Decodable;
+use alloy_rpc_types::debug::ExecutionWitness;
+use alloy_transport::TransportError;
+use anyhow::Result;
+use kona_preimage::PreimageKey;
+use op_alloy_network::Optimism;
+use std::{ops::RangeInclusive, sync::Arc};
+use tokio::{sync::Semaphore, task::JoinSet};
+use tracing::{debug, info, warn};
+
+/// The JSON-RPC error code returned when a method does not exist or is not available.
+const METHOD_NOT_FOUND_CODE: i64 = -32601;
+
+/// The default number of `debug_executionWitness` requests that may be in flight at once.
+pub const DEFAULT_WITNESS_PREFETCH_CONCURRENCY: usize = 16;
+
+/// The [ExecutionWitnessPrefetcher] fetches `debug_executionWitness` for a range of L2 blocks
+/// with bounded concurrency, and loads the state, code, and key preimages of each witness into
+/// the key-value store in bulk.
+///
+/// Prefetching is best-effort. If the L2 execution client does not serve
+/// `debug_executionWitness`, the prefetcher stops, and the host falls back to answering
+/// `L2AccountProof` and `L2StateNode` hints individually with `eth_getProof` and `debug_dbGet`.
+#[derive(Clone)]
+#[allow(missing_debug_implementations)]
+pub struct ExecutionWitnessPrefetcher {
+    /// The L2 execution layer provider.
+    provider: RootProvider<Optimism>,
+    /// The key-value store that witness preimages are loaded into.
+    kv: SharedKeyValueStore,
+    /// The maximum number of witness requests that may be in flight at once.
+    concurrency: usize,
+}
+
+impl ExecutionWitnessPrefetcher {
+    /// Creates a new [ExecutionWitnessPrefetcher].
+    pub fn new(
+        provider: RootProvider<Optimism>,
+        kv: SharedKeyValueStore,
+        concurrency: usize,
+    ) -> Self {
+        Self { provider, kv, concurrency: concurrency.max(1) }
+    }
+
+    /// Prefetches the execution witnesses for all blocks after the agreed L2 head block, up to and
+    /// including `claimed_block_number`.
+    pub async fn prefetch_after(
+        &self,
+        agreed_l2_head_hash: B256,
+        claimed_block_number: u64,
+    ) -> Result<()> {
+        let raw_header: Bytes =
+            self.provider.client().request("debug_getRawHeader", &[agreed_l2_head_hash]).await?;
+        let agreed_header = Header::decode(&mut raw_header.as_ref())?;
+
+        self.prefetch(agreed_header.number + 1..=claimed_block_number).await
+    }
+
+    /// Prefetches the execution witnesses for all blocks in the given range.
+    ///
+    /// Blocks are requested in ascending order, so that the witnesses the client program needs
+    /// first are loaded first.
+    pub async fn prefetch(&self, blocks: RangeInclusive<u64>) -> Result<()> {
+        if blocks.is_empty() {
+            return Ok(());
+        }
+
+        info!(
+            target: "witness_prefetcher",
+            "Prefetching execution witnesses for L2 blocks #{}..=#{}",
+            blocks.start(),
+            blocks.end()
+        );
+
+        let semaphore = Arc::new(Semaphore::new(self.concurrency));
+        let mut tasks = JoinSet::new();
+        let mut total_preimages = 0usize;
+
+        for number in blocks {
+            // Wait for a free slot before dispatching the next request.
+            let permit = semaphore.clone().acquire_owned().await?;
+            let this = self.clone();
+            tasks.spawn(async move {
+                let result = this.load_witness(number).await;
+                drop(permit);
+                (number, result)
+            });
+
+            while let Some(joined) = tasks.try_join_next() {
+                match Self::handle_result(joined?) {
+                    Some(count) => total_preimages += count,
+                    None => {
+                        tasks.abort_all();
+                        return Ok(());
+                    }
+                }
+            }
+        }
+
+        while let Some(joined) = tasks.join_next().await {
+            match Self::handle_result(joined?) {
+                Some(count) => total_preimages += count,
+                None => {
+                    tasks.abort_all();
+                    return Ok(());
+                }
+            }
+        }
+
+        info!(
+            target: "witness_prefetcher",
+            "Finished prefetching execution witnesses; loaded {total_preimages} preimages"
+        );
+        Ok(())
+    }
+
+    /// Fetches the execution witness for a single block and loads it into the key-value store.
+    /// Returns the number of preimages that were written.
+    async fn load_witness(&self, number: u64) -> Result<usize> {
+        let witness: ExecutionWitness = self
+            .provider
+            .client()
+            .request("debug_executionWitness", [BlockNumberOrTag::Number(number)])
+            .await?;
+
+        let preimages = witness.state.into_iter().chain(witness.codes).chain(witness.keys);
+
+        // Hash outside of the lock, so that the client is not blocked on the bulk load.
+        let entries =
+            preimages.map(|preimage| (keccak256(preimage.as_ref()), preimage)).collect::<Vec<_>>();
+        let count = entries.len();
+
+        let mut kv_lock = self.kv.write().await;
+        for (hash, preimage) in entries {
+            kv_lock.set(PreimageKey::new_keccak256(*hash).into(), preimage.into())?;
+        }
+
+        Ok(count)
+    }
+
+    /// Handles the result of a single witness fetch. Returns `None` if prefetching should stop.
+    fn handle_result((number, result): (u64, Result<usize>)) -> Option<usize> {
+        match result {
+            Ok(count) => {
+                debug!(
+                    target: "witness_prefetcher",
+                    "Loaded {count} preimages from the execution witness of block #{number}"
+                );
+                Some(count)
+            }
+            Err(e) if Self::is_method_not_found(&e) => {
+                warn!(
+                    target: "witness_prefetcher",
+                    "`debug_executionWitness` is not available; falling back to `eth_getProof`"
+                );
+                None
+            }
+            Err(e) => {
+                // The block may not exist yet on the L2 node, or the request may have timed out.
+                // Any missing preimages will be fetched when the client hints for them.
+                warn!(
+                    target: "witness_prefetcher",
+                    "Failed to prefetch execution witness for block #{number}: {e}"
+                );
+                Some(0)
+            }
+        }
+    }
+
+    /// Returns `true` if the error indicates that the RPC method is not served by the endpoint.
+    fn is_method_not_found(err: &anyhow::Error) -> bool {
+        err.downcast_ref::<TransportError>()
+            .and_then(|e| e.as_error_resp())
+            .is_some_and(|payload| payload.code == METHOD_NOT_FOUND_CODE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MemoryKeyValueStore;
+    use alloy_rpc_client::RpcClient;
+    use alloy_transport::mock::{Asserter, MockTransport};
+    use tokio::sync::RwLock;
+
+    fn prefetcher(asserter: &Asserter) -> (ExecutionWitnessPrefetcher, SharedKeyValueStore) {
+        let client = RpcClient::new(MockTransport::new(asserter.clone()), false);
+        let kv: SharedKeyValueStore = Arc::new(RwLock::new(MemoryKeyValueStore::new()));
+        (ExecutionWitnessPrefetcher::new(RootProvider::new(client), kv.clone(), 1), kv)
+    }
+
+    fn witness(preimages: &[&'static [u8]]) -> ExecutionWitness {
+        let (state, rest) = preimages.split_at(preimages.len().min(1));
+        ExecutionWitness {
+            state: state.iter().copied().map(Bytes::from_static).collect(),
+            codes: rest.iter().copied().map(Bytes::from_static).collect(),
+            ..Default::default()
+        }
+    }
+
+    async fn stored(kv: &SharedKeyValueStore, preimage: &[u8]) -> Option<Vec<u8>> {
+        kv.read().await.get(PreimageKey::new_keccak256(*keccak256(preimage)).into())
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_loads_witness_preimages() {
+        let asserter = Asserter::new();
+        let (prefetcher, kv) = prefetcher(&asserter);
+        asserter.push_success(&witness(&[b"node", b"code"]));
+        asserter.push_success(&witness(&[b"key"]));
+
+        prefetcher.prefetch(1..=2).await.unwrap();
+
+        for preimage in [b"node".as_slice(), b"code", b"key"] {
+            assert_eq!(stored(&kv, preimage).await.as_deref(), Some(preimage));
+        }
+        assert!(asserter.read_q().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_after_agreed_head() {
+        let asserter = Asserter::new();
+        let (prefetcher, kv) = prefetcher(&asserter);
+        let agreed = Header { number: 5, ..Default::default() };
+        asserter.push_success(&Bytes::from(alloy_rlp::encode(&agreed)));
+        asserter.push_success(&witness(&[b"node"]));
+
+        prefetcher.prefetch_after(agreed.hash_slow(), 6).await.unwrap();
+
+        assert_eq!(stored(&kv, b"node").await.as_deref(), Some(b"node".as_slice()));
+        assert!(asserter.read_q().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_skips_failed_blocks() {
+        let asserter = Asserter::new();
+        let (prefetcher, kv) = prefetcher(&asserter);
+        asserter.push_failure_msg("block not found");
+        asserter.push_success(&witness(&[b"node"]));
+
+        prefetcher.prefetch(1..=2).await.unwrap();
+
+        assert_eq!(stored(&kv, b"node").await.as_deref(), Some(b"node".as_slice()));
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_stops_when_method_not_found() {
+        let asserter = Asserter::new();
+        let (prefetcher, kv) = prefetcher(&asserter);
+        asserter.push_failure(
+            serde_json::from_value(
+                serde_json::json!({ "code": METHOD_NOT_FOUND_CODE, "message": "method not found" }),
+            )
+            .unwrap(),
+        );
+        asserter.push_success(&witness(&[b"node"]));
+
+        prefetcher.prefetch(1..=2).await.unwrap();
+
+        assert!(stored(&kv, b"node").await.is_none());
+    }
+}
@@ -8,6 +8,7 @@ use anyhow::Result;
 use clap::{Parser, Subcommand};
 use kona_cli::{LogConfig, cli_styles, log::LogArgs};
 use serde::Serialize;
+use std::path::PathBuf;
 use tracing::info;
 use tracing_subscriber::EnvFilter;
 
@@ -16,7 +17,8 @@ kona-host is a CLI application that runs the Kona pre-image server and client pr
 can run in two modes: server mode and native mode. In server mode, the host runs the pre-image
 server and waits for the client program in the parent process to request pre-images. In native
 mode, the host runs the client program in a separate thread with the pre-image server in the
-primary thread.
+primary thread. In daemon mode, the host accepts proof jobs over a Unix socket and runs them
+concurrently in native mode, sharing one pre-image store between all jobs.
 ";
 
 /// The host binary CLI application arguments.
@@ -26,6 +28,11 @@ pub struct HostCli {
     /// Logging arguments.
     #[command(flatten)]
     pub log_args: LogArgs,
+    /// Path to a superchain-registry checkout or a directory of custom chain configs to merge over
+    /// the built-in superchain registry. The registry may only add new chains: the client program
+    /// loads the rollup configs of built-in chains from its own registry.
+    #[arg(long, global = true, env = "KONA_HOST_REGISTRY_PATH")]
+    pub registry_path: Option<PathBuf>,
     /// Host mode
     #[command(subcommand)]
     pub mode: HostMode,
@@ -41,13 +48,35 @@ pub enum HostMode {
     /// Run the host in super-chain (interop) mode.
     #[cfg(feature = "interop")]
     Super(kona_host::interop::InteropHost),
+    /// Run the host as a long-lived daemon, serving many single-chain proofs concurrently.
+    #[cfg(feature = "single")]
+    Daemon(kona_host::daemon::HostDaemon),
 }
 
 #[tokio::main(flavor = "multi_thread")]
 async fn main() -> Result<()> {
     let cfg = HostCli::parse();
+
+    // In daemon mode, the logs of each proof job are captured into the job registry.
+    #[cfg(feature = "single")]
+    let job_registry = match &cfg.mode {
+        HostMode::Daemon(daemon) => Some(daemon.job_registry()),
+        _ => None,
+    };
+    #[cfg(feature = "single")]
+    LogConfig::new(cfg.log_args).init_tracing_subscriber_with_layer(
+        None::<EnvFilter>,
+        job_registry.clone().map(kona_host::daemon::JobLogLayer::new),
+    )?;
+    #[cfg(not(feature = "single"))]
     LogConfig::new(cfg.log_args).init_tracing_subscriber(None::<EnvFilter>)?;
 
+    // Merge any custom registry before the registry is first accessed.
+    if let Some(path) = &cfg.registry_path {
+        kona_registry::set_custom_chains_path(path)?;
+        info!(target: "host", "Loaded superchain registry from {}", path.display());
+    }
+
     match cfg.mode {
         #[cfg(feature = "single")]
         HostMode::Single(cfg) => {
@@ -57,6 +86,11 @@ async fn main() -> Result<()> {
         HostMode::Super(cfg) => {
             cfg.start().await?;
         }
+        #[cfg(feature = "single")]
+        HostMode::Daemon(cfg) => {
+            let registry = job_registry.unwrap_or_else(|| cfg.job_registry());
+            cfg.start_with_registry(registry).await?;
+        }
     }
 
     info!(target: "host", "Exiting host program.");
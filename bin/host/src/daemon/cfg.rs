@@ -0,0 +1,377 @@
+//! This module contains all CLI-specific code for the batch server (daemon) entrypoint.
+
+use super::{
+    DEFAULT_MAX_FINISHED_JOBS, DEFAULT_MAX_JOB_LOGS, DaemonRequest, DaemonResponse, JobId,
+    JobRegistry, JobStatus, ProofJob,
+};
+use crate::{
+    DiskKeyValueStore, KeyValueStore, MemoryKeyValueStore, OnlineHostBackend, PreimageServer,
+    ShareableKeyValueStore, SharedKeyValueStore, SplitKeyValueStore,
+    single::{
+        SingleChainHintHandler, SingleChainHost, SingleChainLocalInputs, SingleChainProviders,
+    },
+};
+use alloy_provider::Provider;
+use clap::Parser;
+use kona_cli::cli_styles;
+use kona_preimage::{BidirectionalChannel, HintReader, HintWriter, OracleReader, OracleServer};
+use kona_proof::HintType;
+use serde::Serialize;
+use std::{
+    path::PathBuf,
+    sync::{Arc, RwLock as StdRwLock},
+};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{UnixListener, UnixStream},
+    sync::{RwLock, Semaphore},
+    task,
+};
+use tracing::{Instrument, error, info, info_span, warn};
+
+/// The default maximum number of proof jobs that the daemon runs at once.
+const DEFAULT_MAX_CONCURRENT_JOBS: usize = 4;
+
+/// The daemon's shared preimage store type.
+type DaemonKeyValueStore = ShareableKeyValueStore<dyn KeyValueStore + Send + Sync>;
+
+/// The batch server (daemon) CLI application arguments.
+///
+/// The daemon accepts single-chain [ProofJob]s over a local Unix socket, and runs each job's
+/// client program natively alongside its own preimage server. All jobs share the daemon's
+/// preimage key-value store and provider connections.
+#[derive(Default, Parser, Serialize, Clone, Debug)]
+#[command(styles = cli_styles())]
+pub struct HostDaemon {
+    /// Path of the Unix socket to accept proof jobs on.
+    #[arg(long, env)]
+    pub socket_path: PathBuf,
+    /// Address of L2 JSON-RPC endpoint to use (eth and debug namespace required).
+    #[arg(long, visible_alias = "l2", env)]
+    pub l2_node_address: String,
+    /// Address of L1 JSON-RPC endpoint to use (eth and debug namespace required)
+    #[arg(long, visible_alias = "l1", env)]
+    pub l1_node_address: String,
+    /// Address of the L1 Beacon API endpoint to use.
+    #[arg(long, visible_alias = "beacon", env)]
+    pub l1_beacon_address: String,
+    /// The Data Directory for preimage data storage. If not provided, preimages are kept in
+    /// memory.
+    #[arg(long, visible_alias = "db", env)]
+    pub data_dir: Option<PathBuf>,
+    /// The maximum number of proof jobs to run at once. Further jobs are queued.
+    #[arg(long, default_value_t = DEFAULT_MAX_CONCURRENT_JOBS, env)]
+    pub max_concurrent_jobs: usize,
+    /// The maximum number of finished jobs to keep the status and logs of. The oldest finished
+    /// jobs are evicted first.
+    #[arg(long, default_value_t = DEFAULT_MAX_FINISHED_JOBS, env)]
+    pub max_finished_jobs: usize,
+    /// The maximum number of log lines to keep per job. The oldest lines are dropped first.
+    #[arg(long, default_value_t = DEFAULT_MAX_JOB_LOGS, env)]
+    pub max_job_logs: usize,
+    /// Optionally enables the use of `debug_executePayload` to collect the execution witness from
+    /// the execution layer.
+    #[arg(long, env)]
+    pub enable_experimental_witness_endpoint: bool,
+}
+
+/// An error that can occur when running the host daemon.
+#[derive(Debug, thiserror::Error)]
+pub enum HostDaemonError {
+    /// An IO error.
+    #[error("IO error: {0}")]
+    IOError(#[from] std::io::Error),
+    /// An error from the single chain host.
+    #[error(transparent)]
+    SingleChainHostError(#[from] crate::single::SingleChainHostError),
+    /// The chain ID of the L2 node could not be fetched.
+    #[error("Failed to fetch the L2 chain ID: {0}")]
+    L2ChainId(#[from] alloy_transport::TransportError),
+}
+
+/// The state shared between all connections and jobs of the daemon.
+#[derive(Clone)]
+struct DaemonState {
+    /// The daemon configuration.
+    cfg: HostDaemon,
+    /// The preimage store shared by all jobs.
+    kv: DaemonKeyValueStore,
+    /// The providers shared by all jobs.
+    providers: SingleChainProviders,
+    /// The chain ID served by the L2 provider. Jobs for other chains are rejected.
+    l2_chain_id: u64,
+    /// The registry of submitted jobs.
+    registry: JobRegistry,
+    /// Bounds the number of jobs that run at once.
+    slots: Arc<Semaphore>,
+}
+
+impl HostDaemon {
+    /// Creates the [JobRegistry] that tracks the daemon's jobs.
+    ///
+    /// Install a [JobLogLayer](super::JobLogLayer) over the registry in the tracing subscriber to
+    /// capture the logs of each job, and pass the registry to [HostDaemon::start_with_registry].
+    pub fn job_registry(&self) -> JobRegistry {
+        JobRegistry::new(self.max_finished_jobs).with_max_logs(self.max_job_logs)
+    }
+
+    /// Starts the [HostDaemon], serving proof jobs until the process is terminated.
+    pub async fn start(self) -> Result<(), HostDaemonError> {
+        let registry = self.job_registry();
+        self.start_with_registry(registry).await
+    }
+
+    /// Starts the [HostDaemon] with the given [JobRegistry], serving proof jobs until the process
+    /// is terminated.
+    pub async fn start_with_registry(self, registry: JobRegistry) -> Result<(), HostDaemonError> {
+        let providers = self.template_host(None).create_providers().await?;
+        let l2_chain_id = providers.l2.get_chain_id().await?;
+        info!(target: "host_daemon", "Serving proof jobs for L2 chain {l2_chain_id}");
+
+        let state = DaemonState {
+            kv: self.create_key_value_store(),
+            providers,
+            l2_chain_id,
+            registry,
+            slots: Arc::new(Semaphore::new(self.max_concurrent_jobs.max(1))),
+            cfg: self.clone(),
+        };
+
+        // Remove a stale socket left behind by a previous daemon.
+        if self.socket_path.exists() {
+            std::fs::remove_file(&self.socket_path)?;
+        }
+        let listener = UnixListener::bind(&self.socket_path)?;
+        info!(target: "host_daemon", "Accepting proof jobs on {}", self.socket_path.display());
+
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let state = state.clone();
+            task::spawn(async move {
+                if let Err(e) = state.serve_connection(stream).await {
+                    warn!(target: "host_daemon", "Connection closed with error: {e}");
+                }
+            });
+        }
+    }
+
+    /// Creates the preimage key-value store shared by all jobs.
+    fn create_key_value_store(&self) -> DaemonKeyValueStore {
+        let inner: Arc<StdRwLock<dyn KeyValueStore + Send + Sync>> =
+            if let Some(ref data_dir) = self.data_dir {
+                Arc::new(StdRwLock::new(DiskKeyValueStore::new(data_dir.clone())))
+            } else {
+                Arc::new(StdRwLock::new(MemoryKeyValueStore::new()))
+            };
+        ShareableKeyValueStore::new(inner)
+    }
+
+    /// Returns a [SingleChainHost] configured with the daemon's endpoints and, if provided, the
+    /// boot info of a [ProofJob].
+    fn template_host(&self, job: Option<&ProofJob>) -> SingleChainHost {
+        let mut host = SingleChainHost {
+            l1_node_address: Some(self.l1_node_address.clone()),
+            l2_node_address: Some(self.l2_node_address.clone()),
+            l1_beacon_address: Some(self.l1_beacon_address.clone()),
+            native: true,
+            enable_experimental_witness_endpoint: self.enable_experimental_witness_endpoint,
+            ..Default::default()
+        };
+
+        if let Some(job) = job {
+            host.l1_head = job.l1_head;
+            host.agreed_l2_head_hash = job.agreed_l2_head_hash;
+            host.agreed_l2_output_root = job.agreed_l2_output_root;
+            host.claimed_l2_output_root = job.claimed_l2_output_root;
+            host.claimed_l2_block_number = job.claimed_l2_block_number;
+            host.l2_chain_id = job.l2_chain_id;
+            host.rollup_config_path = job.rollup_config_path.clone();
+        }
+
+        host
+    }
+}
+
+impl DaemonState {
+    /// Serves requests on a single connection until the peer closes it.
+    async fn serve_connection(&self, stream: UnixStream) -> std::io::Result<()> {
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+
+        while let Some(line) = lines.next_line().await? {
+            let response = match serde_json::from_str::<DaemonRequest>(&line) {
+                Ok(request) => self.handle_request(request).await,
+                Err(e) => DaemonResponse::error(format!("Invalid request: {e}")),
+            };
+
+            let mut out = serde_json::to_vec(&response).map_err(std::io::Error::other)?;
+            out.push(b'\n');
+            writer.write_all(&out).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Handles a single [DaemonRequest].
+    async fn handle_request(&self, request: DaemonRequest) -> DaemonResponse {
+        match request {
+            DaemonRequest::Submit(job) => {
+                if let Err(e) = job.validate() {
+                    return DaemonResponse::error(e);
+                }
+                if let Err(e) = self.check_chain(&job) {
+                    return DaemonResponse::error(e);
+                }
+
+                let id = self.registry.insert(job.clone());
+                let state = self.clone();
+                task::spawn(async move { state.run_job(id, job).await });
+
+                DaemonResponse::Submitted { id }
+            }
+            DaemonRequest::Status { id } => self.registry.get(id).map_or_else(
+                || DaemonResponse::error(format!("Unknown job {id}")),
+                DaemonResponse::Job,
+            ),
+            DaemonRequest::List => DaemonResponse::Jobs(self.registry.list()),
+        }
+    }
+
+    /// Checks that the rollup config of a [ProofJob] belongs to the chain served by the daemon's
+    /// L2 provider, so that the job is not proven against another chain's state.
+    fn check_chain(&self, job: &ProofJob) -> Result<(), String> {
+        let rollup_config =
+            self.cfg.template_host(Some(job)).read_rollup_config().map_err(|e| e.to_string())?;
+        let job_chain_id = rollup_config.l2_chain_id.id();
+        if job_chain_id != self.l2_chain_id {
+            return Err(format!(
+                "Job is for L2 chain {job_chain_id}, but the daemon serves L2 chain {}",
+                self.l2_chain_id
+            ));
+        }
+        Ok(())
+    }
+
+    /// Runs a [ProofJob] to completion once an execution slot is free, recording its status in
+    /// the [JobRegistry].
+    ///
+    /// The job runs within a span carrying its [JobId], so that its logs are captured by the
+    /// [JobLogLayer](super::JobLogLayer).
+    async fn run_job(self, id: JobId, job: ProofJob) {
+        let Ok(_slot) = self.slots.clone().acquire_owned().await else {
+            return;
+        };
+
+        let span = info_span!("proof_job", proof_job = id);
+        self.registry.set_status(id, JobStatus::Running);
+        let status = async {
+            info!(
+                target: "host_daemon",
+                "Running proof for claim {} at L2 block #{}",
+                job.claimed_l2_output_root,
+                job.claimed_l2_block_number
+            );
+            match self.execute_job(&job).await {
+                Ok(()) => {
+                    info!(target: "host_daemon", "Client program exited successfully");
+                    JobStatus::Succeeded
+                }
+                Err(e) => {
+                    error!(target: "host_daemon", "Proof job failed: {e}");
+                    JobStatus::Failed { error: e }
+                }
+            }
+        }
+        .instrument(span)
+        .await;
+        self.registry.set_status(id, status);
+    }
+
+    /// Runs the client program for a [ProofJob] natively, serving it preimages from the shared
+    /// key-value store over a dedicated pair of channels.
+    async fn execute_job(&self, job: &ProofJob) -> Result<(), String> {
+        let host = self.cfg.template_host(Some(job));
+
+        // Local inputs are specific to the job, all other preimages are shared between jobs.
+        let kv: SharedKeyValueStore = Arc::new(RwLock::new(SplitKeyValueStore::new(
+            SingleChainLocalInputs::new(host.clone()),
+            self.kv.clone(),
+        )));
+
+        let hint = BidirectionalChannel::new().map_err(|e| e.to_string())?;
+        let preimage = BidirectionalChannel::new().map_err(|e| e.to_string())?;
+
+        let backend =
+            OnlineHostBackend::new(host, kv, self.providers.clone(), SingleChainHintHandler::new())
+                .with_proactive_hint(HintType::L2PayloadWitness);
+        let server_task = task::spawn(
+            PreimageServer::new(
+                OracleServer::new(preimage.host),
+                HintReader::new(hint.host),
+                Arc::new(backend),
+            )
+            .start()
+            .in_current_span(),
+        );
+        let client_task = task::spawn(
+            kona_client::single::run(
+                OracleReader::new(preimage.client),
+                HintWriter::new(hint.client),
+            )
+            .in_current_span(),
+        );
+
+        let (server_result, client_result) =
+            tokio::try_join!(server_task, client_task).map_err(|e| e.to_string())?;
+        server_result.map_err(|e| e.to_string())?;
+        client_result.map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::HostDaemon;
+    use clap::Parser;
+
+    #[test]
+    fn test_flags() {
+        let endpoints = [
+            "daemon",
+            "--l1-node-address",
+            "dummy",
+            "--l2-node-address",
+            "dummy",
+            "--l1-beacon-address",
+            "dummy",
+        ];
+
+        let cases = [
+            // valid
+            (["--socket-path", "/tmp/kona-host.sock"].as_slice(), true),
+            (
+                ["--socket-path", "/tmp/kona-host.sock", "--max-concurrent-jobs", "8"].as_slice(),
+                true,
+            ),
+            (["--socket-path", "/tmp/kona-host.sock", "--data-dir", "dummy"].as_slice(), true),
+            (
+                ["--socket-path", "/tmp/kona-host.sock", "--max-finished-jobs", "16"].as_slice(),
+                true,
+            ),
+            // invalid
+            ([].as_slice(), false),
+            (
+                ["--socket-path", "/tmp/kona-host.sock", "--max-concurrent-jobs", "x"].as_slice(),
+                false,
+            ),
+        ];
+
+        for (args_ext, valid) in cases.into_iter() {
+            let args = endpoints.iter().chain(args_ext.iter()).cloned().collect::<Vec<_>>();
+
+            let parsed = HostDaemon::try_parse_from(args);
+            assert_eq!(parsed.is_ok(), valid);
+        }
+
+        assert!(HostDaemon::try_parse_from(["daemon", "--socket-path", "dummy"]).is_err());
+    }
+}
@@ -0,0 +1,271 @@
+//! Contains the proof job types and the [JobRegistry] that tracks them.
+
+use alloy_primitives::B256;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    path::PathBuf,
+    sync::{
+        Arc, PoisonError, RwLock,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+/// The unique identifier of a [ProofJob] within a [HostDaemon].
+///
+/// [HostDaemon]: super::HostDaemon
+pub type JobId = u64;
+
+/// A single-chain proof job submitted to the [HostDaemon]. Carries the boot info of the proof and
+/// the source of the chain's rollup config.
+///
+/// [HostDaemon]: super::HostDaemon
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofJob {
+    /// Hash of the L1 head block. Derivation stops after this block is processed.
+    pub l1_head: B256,
+    /// Hash of the agreed upon safe L2 block committed to by `agreed_l2_output_root`.
+    pub agreed_l2_head_hash: B256,
+    /// Agreed safe L2 Output Root to start derivation from.
+    pub agreed_l2_output_root: B256,
+    /// Claimed L2 output root at block # `claimed_l2_block_number` to validate.
+    pub claimed_l2_output_root: B256,
+    /// Number of the L2 block that the claimed output root commits to.
+    pub claimed_l2_block_number: u64,
+    /// The L2 chain ID of a chain in the superchain registry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l2_chain_id: Option<u64>,
+    /// Path to a rollup config on the daemon's file system.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rollup_config_path: Option<PathBuf>,
+}
+
+impl ProofJob {
+    /// Checks that the job specifies exactly one source for the rollup config.
+    pub const fn validate(&self) -> Result<(), &'static str> {
+        match (&self.l2_chain_id, &self.rollup_config_path) {
+            (Some(_), None) | (None, Some(_)) => Ok(()),
+            (Some(_), Some(_)) => Err("Only one of `l2ChainId` and `rollupConfigPath` may be set"),
+            (None, None) => Err("One of `l2ChainId` or `rollupConfigPath` must be set"),
+        }
+    }
+}
+
+/// The status of a [ProofJob].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    /// The job is waiting for a free execution slot.
+    Queued,
+    /// The job's client program and preimage server are running.
+    Running,
+    /// The client program exited successfully; the claim is valid.
+    Succeeded,
+    /// The client program or preimage server failed.
+    Failed {
+        /// The error that caused the job to fail.
+        error: String,
+    },
+}
+
+impl JobStatus {
+    /// Returns `true` if the job has finished, successfully or not.
+    pub const fn is_finished(&self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed { .. })
+    }
+}
+
+/// The record of a [ProofJob] kept by the [JobRegistry].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobRecord {
+    /// The job's identifier.
+    pub id: JobId,
+    /// The submitted job.
+    pub job: ProofJob,
+    /// The job's current status.
+    pub status: JobStatus,
+    /// The most recent log lines emitted over the lifetime of the job, oldest first.
+    pub logs: VecDeque<String>,
+}
+
+/// The default number of finished jobs that the [JobRegistry] retains.
+pub const DEFAULT_MAX_FINISHED_JOBS: usize = 1024;
+
+/// The default number of log lines that the [JobRegistry] retains per job.
+pub const DEFAULT_MAX_JOB_LOGS: usize = 1024;
+
+/// The [JobRegistry] tracks the status and logs of every [ProofJob] submitted to the daemon.
+///
+/// Once more than `max_finished` jobs have finished, the records of the oldest finished jobs are
+/// evicted. Only the last `max_logs` log lines of each job are retained.
+#[derive(Debug, Clone)]
+pub struct JobRegistry {
+    /// The next job identifier to hand out.
+    next_id: Arc<AtomicU64>,
+    /// The records of all retained jobs, keyed by their identifier.
+    jobs: Arc<RwLock<BTreeMap<JobId, JobRecord>>>,
+    /// The maximum number of finished jobs to retain.
+    max_finished: usize,
+    /// The maximum number of log lines to retain per job.
+    max_logs: usize,
+}
+
+impl Default for JobRegistry {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_FINISHED_JOBS)
+    }
+}
+
+impl JobRegistry {
+    /// Creates a new [JobRegistry] that retains at most `max_finished` finished jobs.
+    pub fn new(max_finished: usize) -> Self {
+        Self {
+            next_id: Default::default(),
+            jobs: Default::default(),
+            max_finished,
+            max_logs: DEFAULT_MAX_JOB_LOGS,
+        }
+    }
+
+    /// Sets the maximum number of log lines retained per job.
+    pub const fn with_max_logs(mut self, max_logs: usize) -> Self {
+        self.max_logs = max_logs;
+        self
+    }
+
+    /// Registers a new [ProofJob] in the [JobStatus::Queued] state and returns its identifier.
+    pub fn insert(&self, job: ProofJob) -> JobId {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let record = JobRecord { id, job, status: JobStatus::Queued, logs: VecDeque::new() };
+        self.write().insert(id, record);
+        id
+    }
+
+    /// Updates the status of a job. Finishing a job may evict the oldest finished jobs.
+    pub fn set_status(&self, id: JobId, status: JobStatus) {
+        let finished = status.is_finished();
+        let mut jobs = self.write();
+        if let Some(record) = jobs.get_mut(&id) {
+            record.status = status;
+        }
+        if !finished {
+            return;
+        }
+
+        let finished_ids =
+            jobs.values().filter(|r| r.status.is_finished()).map(|r| r.id).collect::<Vec<_>>();
+        let excess = finished_ids.len().saturating_sub(self.max_finished);
+        for id in &finished_ids[..excess] {
+            jobs.remove(id);
+        }
+    }
+
+    /// Appends a log line to a job's record, dropping its oldest line once it holds more than
+    /// `max_logs` lines.
+    ///
+    /// Log lines are usually captured from the job's [tracing] events by the
+    /// [JobLogLayer](super::JobLogLayer).
+    pub fn push_log(&self, id: JobId, line: impl Into<String>) {
+        let Some(record) = self.write().get_mut(&id) else {
+            return;
+        };
+        if record.logs.len() >= self.max_logs {
+            record.logs.pop_front();
+        }
+        if self.max_logs > 0 {
+            record.logs.push_back(line.into());
+        }
+    }
+
+    /// Returns the record of a job, if it exists.
+    pub fn get(&self, id: JobId) -> Option<JobRecord> {
+        self.jobs.read().unwrap_or_else(PoisonError::into_inner).get(&id).cloned()
+    }
+
+    /// Returns the records of all jobs, ordered by identifier.
+    pub fn list(&self) -> Vec<JobRecord> {
+        self.jobs.read().unwrap_or_else(PoisonError::into_inner).values().cloned().collect()
+    }
+
+    /// Returns a write guard over the job records.
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, BTreeMap<JobId, JobRecord>> {
+        self.jobs.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn job() -> ProofJob {
+        ProofJob {
+            l1_head: B256::ZERO,
+            agreed_l2_head_hash: B256::ZERO,
+            agreed_l2_output_root: B256::ZERO,
+            claimed_l2_output_root: B256::ZERO,
+            claimed_l2_block_number: 0,
+            l2_chain_id: Some(10),
+            rollup_config_path: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_job() {
+        assert!(job().validate().is_ok());
+        assert!(ProofJob { l2_chain_id: None, ..job() }.validate().is_err());
+        assert!(
+            ProofJob { rollup_config_path: Some("rollup.json".into()), ..job() }
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_registry_tracks_jobs() {
+        let registry = JobRegistry::default();
+        let a = registry.insert(job());
+        let b = registry.insert(job());
+        assert_ne!(a, b);
+
+        registry.set_status(a, JobStatus::Running);
+        registry.push_log(a, "started");
+        registry.set_status(b, JobStatus::Failed { error: "boom".to_string() });
+
+        let record = registry.get(a).unwrap();
+        assert_eq!(record.status, JobStatus::Running);
+        assert_eq!(record.logs, vec!["started".to_string()]);
+        assert!(registry.get(b).unwrap().status.is_finished());
+        assert_eq!(registry.list().len(), 2);
+        assert!(registry.get(b + 1).is_none());
+    }
+
+    #[test]
+    fn test_registry_evicts_finished_jobs() {
+        let registry = JobRegistry::new(2);
+        let ids = (0..4).map(|_| registry.insert(job())).collect::<Vec<_>>();
+
+        registry.set_status(ids[1], JobStatus::Running);
+        for id in [ids[0], ids[2], ids[3]] {
+            registry.set_status(id, JobStatus::Succeeded);
+        }
+
+        // The oldest finished job is evicted, running jobs are kept.
+        assert!(registry.get(ids[0]).is_none());
+        let retained = registry.list().into_iter().map(|r| r.id).collect::<Vec<_>>();
+        assert_eq!(retained, vec![ids[1], ids[2], ids[3]]);
+    }
+
+    #[test]
+    fn test_registry_bounds_logs() {
+        let registry = JobRegistry::default().with_max_logs(2);
+        let id = registry.insert(job());
+        for line in ["a", "b", "c"] {
+            registry.push_log(id, line);
+        }
+
+        // Only the most recent lines are kept.
+        assert_eq!(registry.get(id).unwrap().logs, vec!["b".to_string(), "c".to_string()]);
+    }
+}
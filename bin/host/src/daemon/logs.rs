@@ -0,0 +1,136 @@
+//! Contains the [JobLogLayer], which captures the [tracing] events of each proof job into its
+//! [JobRecord](super::JobRecord).
+
+use super::{JobId, JobRegistry};
+use std::fmt::{self, Write};
+use tracing::{
+    Event, Subscriber,
+    field::{Field, Visit},
+    span,
+};
+use tracing_subscriber::{Layer, layer::Context, registry::LookupSpan};
+
+/// The name of the span field that carries the [JobId] of a proof job.
+pub const JOB_SPAN_FIELD: &str = "proof_job";
+
+/// A [tracing_subscriber] [Layer] that appends every event emitted within a proof job's span to
+/// the job's logs in the [JobRegistry].
+///
+/// A span belongs to a job if it, or one of its parents, records the [JOB_SPAN_FIELD] field.
+#[derive(Debug, Clone)]
+pub struct JobLogLayer {
+    /// The registry that job logs are appended to.
+    registry: JobRegistry,
+}
+
+impl JobLogLayer {
+    /// Creates a new [JobLogLayer] that appends job logs to the given [JobRegistry].
+    pub const fn new(registry: JobRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+/// The [JobId] of a span, stored in the span's extensions.
+#[derive(Debug, Clone, Copy)]
+struct JobSpan(JobId);
+
+impl<S> Layer<S> for JobLogLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        let mut visitor = JobIdVisitor(None);
+        attrs.record(&mut visitor);
+        if let (Some(job), Some(span)) = (visitor.0, ctx.span(id)) {
+            span.extensions_mut().insert(JobSpan(job));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let Some(job) = ctx.event_scope(event).and_then(|scope| {
+            scope.into_iter().find_map(|span| span.extensions().get::<JobSpan>().map(|j| j.0))
+        }) else {
+            return;
+        };
+
+        let metadata = event.metadata();
+        let mut line = format!("{} {}:", metadata.level(), metadata.target());
+        event.record(&mut LineVisitor(&mut line));
+        self.registry.push_log(job, line);
+    }
+}
+
+/// Extracts the [JobId] from the [JOB_SPAN_FIELD] field of a span.
+struct JobIdVisitor(Option<JobId>);
+
+impl Visit for JobIdVisitor {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == JOB_SPAN_FIELD {
+            self.0 = Some(value);
+        }
+    }
+
+    fn record_debug(&mut self, _: &Field, _: &dyn fmt::Debug) {}
+}
+
+/// Formats the fields of an event into a single log line.
+struct LineVisitor<'a>(&'a mut String);
+
+impl Visit for LineVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            let _ = write!(self.0, " {value}");
+        } else {
+            let _ = write!(self.0, " {}={value}", field.name());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.0, " {value:?}");
+        } else {
+            let _ = write!(self.0, " {}={value:?}", field.name());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::daemon::ProofJob;
+    use alloy_primitives::B256;
+    use tracing::{info, info_span, warn};
+    use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+
+    #[test]
+    fn test_captures_job_events() {
+        let registry = JobRegistry::default();
+        let job = ProofJob {
+            l1_head: B256::ZERO,
+            agreed_l2_head_hash: B256::ZERO,
+            agreed_l2_output_root: B256::ZERO,
+            claimed_l2_output_root: B256::ZERO,
+            claimed_l2_block_number: 0,
+            l2_chain_id: Some(10),
+            rollup_config_path: None,
+        };
+        let a = registry.insert(job.clone());
+        let b = registry.insert(job);
+
+        let subscriber = tracing_subscriber::registry().with(JobLogLayer::new(registry.clone()));
+        let guard = subscriber.set_default();
+        info!(target: "host", "outside of any job");
+        info_span!("proof_job", proof_job = a).in_scope(|| {
+            info!(target: "host", "started");
+            info_span!("server").in_scope(|| warn!(target: "server", hint = "l1-block", "slow"));
+        });
+        info_span!("proof_job", proof_job = b).in_scope(|| info!(target: "host", "other"));
+        drop(guard);
+
+        assert_eq!(
+            registry.get(a).unwrap().logs,
+            vec!["INFO host: started".to_string(), "WARN server: slow hint=l1-block".to_string()]
+        );
+        assert_eq!(registry.get(b).unwrap().logs, vec!["INFO host: other".to_string()]);
+    }
+}
@@ -0,0 +1,17 @@
+//! This module contains the batch server (daemon) mode for the host, which serves many
+//! single-chain proofs concurrently from one long-lived process.
+
+mod cfg;
+pub use cfg::{HostDaemon, HostDaemonError};
+
+mod job;
+pub use job::{
+    DEFAULT_MAX_FINISHED_JOBS, DEFAULT_MAX_JOB_LOGS, JobId, JobRecord, JobRegistry, JobStatus,
+    ProofJob,
+};
+
+mod logs;
+pub use logs::{JOB_SPAN_FIELD, JobLogLayer};
+
+mod rpc;
+pub use rpc::{DaemonRequest, DaemonResponse};
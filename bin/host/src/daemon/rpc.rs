@@ -0,0 +1,107 @@
+//! Contains the wire protocol spoken over the [HostDaemon]'s Unix socket.
+//!
+//! Each request and response is a single line of JSON.
+//!
+//! [HostDaemon]: super::HostDaemon
+
+use super::{JobId, JobRecord, ProofJob};
+use serde::{Deserialize, Serialize};
+
+/// A request sent to the [HostDaemon].
+///
+/// [HostDaemon]: super::HostDaemon
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "method", content = "params", rename_all = "snake_case")]
+pub enum DaemonRequest {
+    /// Submits a new [ProofJob].
+    Submit(ProofJob),
+    /// Requests the record of a single job.
+    Status {
+        /// The job's identifier.
+        id: JobId,
+    },
+    /// Requests the records of all jobs.
+    List,
+}
+
+/// A response sent by the [HostDaemon].
+///
+/// [HostDaemon]: super::HostDaemon
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "result", content = "data", rename_all = "snake_case")]
+pub enum DaemonResponse {
+    /// The job was accepted and queued.
+    Submitted {
+        /// The identifier assigned to the job.
+        id: JobId,
+    },
+    /// The record of a single job.
+    Job(JobRecord),
+    /// The records of all jobs.
+    Jobs(Vec<JobRecord>),
+    /// The request could not be served.
+    Error {
+        /// A description of the error.
+        message: String,
+    },
+}
+
+impl DaemonResponse {
+    /// Creates a [DaemonResponse::Error] from the given message.
+    pub fn error(message: impl ToString) -> Self {
+        Self::Error { message: message.to_string() }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::daemon::JobStatus;
+    use alloy_primitives::B256;
+
+    #[test]
+    fn test_deserialize_requests() {
+        let submit = r#"{"method":"submit","params":{"l1Head":"0x0000000000000000000000000000000000000000000000000000000000000000","agreedL2HeadHash":"0x0000000000000000000000000000000000000000000000000000000000000000","agreedL2OutputRoot":"0x0000000000000000000000000000000000000000000000000000000000000000","claimedL2OutputRoot":"0x0000000000000000000000000000000000000000000000000000000000000000","claimedL2BlockNumber":5,"l2ChainId":10}}"#;
+        let DaemonRequest::Submit(job) = serde_json::from_str(submit).unwrap() else {
+            panic!("expected submit request");
+        };
+        assert_eq!(job.claimed_l2_block_number, 5);
+        assert_eq!(job.l2_chain_id, Some(10));
+        assert_eq!(job.rollup_config_path, None);
+
+        let status: DaemonRequest =
+            serde_json::from_str(r#"{"method":"status","params":{"id":3}}"#).unwrap();
+        assert_eq!(status, DaemonRequest::Status { id: 3 });
+
+        let list: DaemonRequest = serde_json::from_str(r#"{"method":"list"}"#).unwrap();
+        assert_eq!(list, DaemonRequest::List);
+    }
+
+    #[test]
+    fn test_response_roundtrip() {
+        let record = JobRecord {
+            id: 1,
+            job: ProofJob {
+                l1_head: B256::ZERO,
+                agreed_l2_head_hash: B256::ZERO,
+                agreed_l2_output_root: B256::ZERO,
+                claimed_l2_output_root: B256::ZERO,
+                claimed_l2_block_number: 0,
+                l2_chain_id: None,
+                rollup_config_path: Some("rollup.json".into()),
+            },
+            status: JobStatus::Failed { error: "invalid claim".to_string() },
+            logs: vec!["started".to_string()].into(),
+        };
+
+        for response in [
+            DaemonResponse::Submitted { id: 1 },
+            DaemonResponse::Job(record.clone()),
+            DaemonResponse::Jobs(vec![record]),
+            DaemonResponse::error("unknown job"),
+        ] {
+            let ser = serde_json::to_string(&response).unwrap();
+            assert_eq!(serde_json::from_str::<DaemonResponse>(&ser).unwrap(), response);
+        }
+    }
+}
@@ -6,7 +6,7 @@ use alloy_transport_http::Http;
 use reqwest::Client;
 
 mod precompiles;
-pub(crate) use precompiles::execute;
+pub use precompiles::execute;
 
 /// Returns an HTTP provider for the given URL.
 pub fn http_provider<N: Network>(url: &str) -> RootProvider<N> {
@@ -2,33 +2,97 @@
 
 use alloy_primitives::{Address, Bytes};
 use anyhow::{Result, anyhow};
-use revm::precompile::{self, PrecompileWithAddress};
-
-/// List of precompiles that are accelerated by the host program.
-pub(crate) const ACCELERATED_PRECOMPILES: &[PrecompileWithAddress] = &[
-    precompile::secp256k1::ECRECOVER,          // ecRecover
-    precompile::bn128::pair::ISTANBUL,         // ecPairing
-    precompile::bls12_381::g1_add::PRECOMPILE, // BLS12-381 G1 Point Addition
-    precompile::bls12_381::g1_msm::PRECOMPILE, /* BLS12-381 G1 Point Multi-scalar
-                                                * Multiplication */
-    precompile::bls12_381::g2_add::PRECOMPILE, // BLS12-381 G2 Point Addition
-    precompile::bls12_381::g2_msm::PRECOMPILE, // BLS12-381 G2 Point Multi-scalar Multiplication
-    precompile::bls12_381::map_fp2_to_g2::PRECOMPILE, // BLS12-381 FP2 to G2 Point Mapping
-    precompile::bls12_381::map_fp_to_g1::PRECOMPILE, // BLS12-381 FP to G1 Point Mapping
-    precompile::bls12_381::pairing::PRECOMPILE, // BLS12-381 pairing
-    precompile::kzg_point_evaluation::POINT_EVALUATION, // KZG point evaluation
-];
-
-/// Executes an accelerated precompile on [revm].
-pub(crate) fn execute<T: Into<Bytes>>(address: Address, input: T, gas: u64) -> Result<Vec<u8>> {
-    if let Some(precompile) =
-        ACCELERATED_PRECOMPILES.iter().find(|precompile| precompile.0 == address)
-    {
-        let output = precompile.precompile()(&input.into(), gas)
-            .map_err(|e| anyhow!("Failed precompile execution: {e}"))?;
-
-        Ok(output.bytes.into())
-    } else {
+use kona_client::fpvm_evm::PrecompileRegistry;
+use revm::precompile::Precompiles;
+
+/// Executes a precompile accelerated by the given [PrecompileRegistry], after checking the call
+/// against the precompile's gas schedule.
+///
+/// Precompiles without a native function in the registry are executed on [revm].
+pub fn execute<R, T>(registry: &R, address: Address, input: T, gas: u64) -> Result<Vec<u8>>
+where
+    R: PrecompileRegistry,
+    T: Into<Bytes>,
+{
+    let Some(entry) = registry.host_precompiles().into_iter().find(|p| p.address == address) else {
         anyhow::bail!("Precompile not accelerated");
+    };
+
+    let input = input.into();
+    entry.check_gas(&input, gas).map_err(|e| anyhow!("Failed precompile gas check: {e}"))?;
+
+    let precompile = entry
+        .precompile
+        .or_else(|| Precompiles::latest().get(&address).copied())
+        .ok_or_else(|| anyhow!("Precompile not implemented"))?;
+    let output =
+        precompile(&input, gas).map_err(|e| anyhow!("Failed precompile execution: {e}"))?;
+
+    Ok(output.bytes.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloy_primitives::hex;
+    use kona_client::fpvm_evm::{DefaultPrecompileRegistry, HostPrecompile};
+    use revm::precompile::{PrecompileError, PrecompileOutput, PrecompileResult, u64_to_address};
+
+    const ECRECOVER_INPUT: [u8; 128] = hex!(
+        "456e9aea5e197a1f1af7a3e85a3212fa4049a3ba34c2289b4c860fc0b0c64ef3000000000000000000000000000000000000000000000000000000000000001c9242685bf161793cc25603c231bc2f568eb630ea16aa137d2664ac80388256084f8ae3bd7535248d0bd448298cc2e2071e56992d0774dc340c368ae950852ada"
+    );
+
+    /// A registry that only accelerates a custom precompile.
+    #[derive(Debug, Clone)]
+    struct CustomRegistry;
+
+    impl PrecompileRegistry for CustomRegistry {
+        fn accelerated<H, O>(
+            &self,
+            _: op_revm::OpSpecId,
+        ) -> Vec<kona_client::fpvm_evm::AcceleratedPrecompile<H, O>> {
+            Vec::new()
+        }
+
+        fn host_precompiles(&self) -> Vec<HostPrecompile> {
+            vec![HostPrecompile::new(u64_to_address(0xff), |_| Ok(100)).with_precompile(echo)]
+        }
+    }
+
+    fn echo(input: &[u8], _: u64) -> PrecompileResult {
+        Ok(PrecompileOutput::new(100, input.to_vec().into()))
+    }
+
+    #[test]
+    fn test_execute_accelerated() {
+        let output =
+            execute(&DefaultPrecompileRegistry, u64_to_address(1), ECRECOVER_INPUT, 3_000).unwrap();
+        assert_eq!(
+            output,
+            hex!("0000000000000000000000007156526fbd7a3c72969b54f64e42c10fbb768c8a")
+        );
+    }
+
+    #[test]
+    fn test_execute_insufficient_gas() {
+        let err = execute(&DefaultPrecompileRegistry, u64_to_address(1), ECRECOVER_INPUT, 2_999)
+            .unwrap_err();
+        assert!(err.to_string().contains(&PrecompileError::OutOfGas.to_string()));
+    }
+
+    #[test]
+    fn test_execute_not_accelerated() {
+        // SHA-256 is not accelerated by the FPVM.
+        assert!(
+            execute(&DefaultPrecompileRegistry, u64_to_address(2), [0u8; 32], u64::MAX).is_err()
+        );
+    }
+
+    #[test]
+    fn test_execute_custom_registry() {
+        let output = execute(&CustomRegistry, u64_to_address(0xff), [0xde, 0xad], 100).unwrap();
+        assert_eq!(output, vec![0xde, 0xad]);
+
+        assert!(execute(&CustomRegistry, u64_to_address(1), ECRECOVER_INPUT, 3_000).is_err());
     }
 }
@@ -0,0 +1,8 @@
+//! This module contains the proof fixture recorder, which captures the boot info and every
+//! preimage served during an online single-chain proof so that it can be replayed offline.
+
+mod proof;
+pub use proof::{ProofFixture, ProofFixtureError};
+
+mod recorder;
+pub use recorder::{RecordFixtureError, RecordingHostBackend, record_single_chain_fixture};
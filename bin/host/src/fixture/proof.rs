@@ -0,0 +1,135 @@
+//! Contains the [ProofFixture] format.
+
+use crate::{KeyValueStore, MemoryKeyValueStore};
+use alloy_primitives::{B256, Bytes};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, path::Path};
+
+/// The zlib compression level used when encoding fixtures.
+const FIXTURE_COMPRESSION_LEVEL: u8 = 9;
+
+/// An error that can occur when encoding or decoding a [ProofFixture].
+#[derive(Debug, thiserror::Error)]
+pub enum ProofFixtureError {
+    /// An IO error.
+    #[error("IO error: {0}")]
+    IOError(#[from] std::io::Error),
+    /// A JSON (de)serialization error.
+    #[error("Failed (de)serializing fixture: {0}")]
+    SerdeError(#[from] serde_json::Error),
+    /// The fixture could not be decompressed.
+    #[error("Failed decompressing fixture: {0}")]
+    DecompressionError(String),
+}
+
+/// A deterministic, self-contained record of a single-chain proof.
+///
+/// A [ProofFixture] holds the boot info of the proof, the exit status that the client program
+/// produced when the fixture was recorded, and every preimage that the host served to the client.
+/// Replaying it through an [OfflineHostBackend] requires no network access.
+///
+/// Fixtures are stored as zlib-compressed JSON.
+///
+/// [OfflineHostBackend]: crate::OfflineHostBackend
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofFixture {
+    /// A human readable name for the fixture, i.e. the chain and hardfork that it covers.
+    pub name: String,
+    /// Hash of the L1 head block.
+    pub l1_head: B256,
+    /// Hash of the agreed upon safe L2 block.
+    pub agreed_l2_head_hash: B256,
+    /// Agreed safe L2 Output Root to start derivation from.
+    pub agreed_l2_output_root: B256,
+    /// Claimed L2 output root to validate.
+    pub claimed_l2_output_root: B256,
+    /// Number of the L2 block that the claimed output root commits to.
+    pub claimed_l2_block_number: u64,
+    /// The L2 chain ID, if the rollup config was sourced from the superchain registry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l2_chain_id: Option<u64>,
+    /// The exit status of the client program when the fixture was recorded.
+    pub expected_status: u8,
+    /// Every preimage served to the client program, keyed by its [PreimageKey].
+    ///
+    /// [PreimageKey]: kona_preimage::PreimageKey
+    pub preimages: BTreeMap<B256, Bytes>,
+}
+
+impl ProofFixture {
+    /// Encodes the fixture as zlib-compressed JSON.
+    pub fn encode(&self) -> Result<Vec<u8>, ProofFixtureError> {
+        let json = serde_json::to_vec(self)?;
+        Ok(miniz_oxide::deflate::compress_to_vec_zlib(&json, FIXTURE_COMPRESSION_LEVEL))
+    }
+
+    /// Decodes a fixture from zlib-compressed JSON.
+    pub fn decode(data: &[u8]) -> Result<Self, ProofFixtureError> {
+        let json = miniz_oxide::inflate::decompress_to_vec_zlib(data)
+            .map_err(|e| ProofFixtureError::DecompressionError(e.to_string()))?;
+        Ok(serde_json::from_slice(&json)?)
+    }
+
+    /// Reads and decodes a fixture from the file system.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ProofFixtureError> {
+        Self::decode(&std::fs::read(path)?)
+    }
+
+    /// Encodes and writes the fixture to the file system.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ProofFixtureError> {
+        Ok(std::fs::write(path, self.encode()?)?)
+    }
+
+    /// Returns a [MemoryKeyValueStore] containing every preimage in the fixture.
+    pub fn to_kv_store(&self) -> MemoryKeyValueStore {
+        let mut kv = MemoryKeyValueStore::new();
+        for (key, value) in self.preimages.iter() {
+            // Setting a value in the in-memory store is infallible.
+            let _ = kv.set(*key, value.to_vec());
+        }
+        kv
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use kona_preimage::PreimageKey;
+    use kona_proof::boot::L1_HEAD_KEY;
+
+    #[test]
+    fn test_fixture_roundtrip() {
+        let l1_head = B256::repeat_byte(0xaa);
+        let local_key: B256 = PreimageKey::new_local(L1_HEAD_KEY.to()).into();
+        let fixture = ProofFixture {
+            name: "holocene-test".to_string(),
+            l1_head,
+            agreed_l2_head_hash: B256::repeat_byte(0x01),
+            agreed_l2_output_root: B256::repeat_byte(0x02),
+            claimed_l2_output_root: B256::repeat_byte(0x03),
+            claimed_l2_block_number: 42,
+            l2_chain_id: Some(10),
+            expected_status: 0,
+            preimages: BTreeMap::from([
+                (local_key, Bytes::copy_from_slice(l1_head.as_slice())),
+                (B256::repeat_byte(0xff), Bytes::from_static(&[0xde, 0xad])),
+            ]),
+        };
+
+        let decoded = ProofFixture::decode(&fixture.encode().unwrap()).unwrap();
+        assert_eq!(decoded, fixture);
+
+        let kv = decoded.to_kv_store();
+        assert_eq!(kv.get(local_key), Some(l1_head.to_vec()));
+        assert_eq!(kv.get(B256::repeat_byte(0xff)), Some(vec![0xde, 0xad]));
+    }
+
+    #[test]
+    fn test_decode_invalid_fixture() {
+        assert!(matches!(
+            ProofFixture::decode(&[0x00, 0x01]),
+            Err(ProofFixtureError::DecompressionError(_))
+        ));
+    }
+}
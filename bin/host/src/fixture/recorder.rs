@@ -0,0 +1,184 @@
+//! Contains the [RecordingHostBackend] and the single-chain fixture recorder.
+
+use super::ProofFixture;
+use crate::{
+    OnlineHostBackend, PreimageServer,
+    single::{SingleChainHintHandler, SingleChainHost, SingleChainHostError},
+};
+use alloy_primitives::{B256, Bytes};
+use alloy_provider::{Provider, RootProvider};
+use alloy_transport::TransportError;
+use async_trait::async_trait;
+use kona_preimage::{
+    BidirectionalChannel, HintReader, HintRouter, HintWriter, OracleReader, OracleServer,
+    PreimageFetcher, PreimageKey, errors::PreimageOracleResult,
+};
+use kona_proof::HintType;
+use kona_protocol::{OutputRoot, Predeploys};
+use op_alloy_network::Optimism;
+use std::{collections::BTreeMap, sync::Arc};
+use tokio::{sync::Mutex, task};
+use tracing::info;
+
+/// An error that can occur when recording a [ProofFixture].
+#[derive(Debug, thiserror::Error)]
+pub enum RecordFixtureError {
+    /// An error from the single chain host.
+    #[error(transparent)]
+    Host(#[from] SingleChainHostError),
+    /// An error fetching the canonical output root from the L2 execution layer.
+    #[error("Failed to fetch the canonical output root: {0}")]
+    Transport(#[from] TransportError),
+    /// The claimed L2 block does not exist on the L2 execution layer.
+    #[error("L2 block #{0} not found")]
+    BlockNotFound(u64),
+    /// The client program's exit status disagrees with the canonical chain.
+    #[error(
+        "Client program exited with status {actual}, but the canonical chain expects {expected}"
+    )]
+    StatusMismatch {
+        /// The exit status expected from the canonical output root.
+        expected: u8,
+        /// The exit status of the client program.
+        actual: u8,
+    },
+}
+
+/// A wrapper around a preimage server backend that records every preimage it serves.
+#[derive(Debug)]
+pub struct RecordingHostBackend<B> {
+    /// The wrapped backend.
+    inner: B,
+    /// The preimages that have been served, keyed by their [PreimageKey].
+    served: Arc<Mutex<BTreeMap<B256, Bytes>>>,
+}
+
+impl<B> RecordingHostBackend<B> {
+    /// Creates a new [RecordingHostBackend] around the given backend.
+    pub fn new(inner: B) -> Self {
+        Self { inner, served: Arc::new(Mutex::new(BTreeMap::new())) }
+    }
+
+    /// Returns a handle to the preimages that have been served so far.
+    pub fn served(&self) -> Arc<Mutex<BTreeMap<B256, Bytes>>> {
+        self.served.clone()
+    }
+}
+
+#[async_trait]
+impl<B> PreimageFetcher for RecordingHostBackend<B>
+where
+    B: PreimageFetcher + Send + Sync,
+{
+    async fn get_preimage(&self, key: PreimageKey) -> PreimageOracleResult<Vec<u8>> {
+        let preimage = self.inner.get_preimage(key).await?;
+        self.served.lock().await.insert(key.into(), Bytes::copy_from_slice(&preimage));
+        Ok(preimage)
+    }
+}
+
+#[async_trait]
+impl<B> HintRouter for RecordingHostBackend<B>
+where
+    B: HintRouter + Send + Sync,
+{
+    async fn route_hint(&self, hint: String) -> PreimageOracleResult<()> {
+        self.inner.route_hint(hint).await
+    }
+}
+
+/// Runs the single-chain client program natively against an online [SingleChainHost], and
+/// records the boot info, the expected exit status, and every served preimage as a
+/// [ProofFixture].
+///
+/// The expected exit status does not come from the client program. It is `0` if the claimed
+/// output root matches the canonical output root of the claimed block on the L2 execution layer,
+/// and `1` otherwise. Recording fails if the client program disagrees, so that a fixture never
+/// enshrines a faulty run.
+pub async fn record_single_chain_fixture(
+    host: &SingleChainHost,
+    name: impl Into<String>,
+) -> Result<ProofFixture, RecordFixtureError> {
+    if host.is_offline() {
+        return Err(
+            SingleChainHostError::Other("Fixtures can only be recorded in online mode").into()
+        );
+    }
+
+    let kv_store = host.create_key_value_store()?;
+    let providers = host.create_providers().await?;
+    let canonical_output_root =
+        canonical_output_root(&providers.l2, host.claimed_l2_block_number).await?;
+    let expected_status = (canonical_output_root != host.claimed_l2_output_root) as u8;
+    let backend = RecordingHostBackend::new(
+        OnlineHostBackend::new(host.clone(), kv_store, providers, SingleChainHintHandler::new())
+            .with_proactive_hint(HintType::L2PayloadWitness),
+    );
+    let served = backend.served();
+
+    let hint = BidirectionalChannel::new().map_err(SingleChainHostError::from)?;
+    let preimage = BidirectionalChannel::new().map_err(SingleChainHostError::from)?;
+
+    let server_task = task::spawn(
+        PreimageServer::new(
+            OracleServer::new(preimage.host),
+            HintReader::new(hint.host),
+            Arc::new(backend),
+        )
+        .start(),
+    );
+    let client_task = task::spawn(kona_client::single::run(
+        OracleReader::new(preimage.client),
+        HintWriter::new(hint.client),
+    ));
+
+    let (server_result, client_result) =
+        tokio::try_join!(server_task, client_task).map_err(SingleChainHostError::from)?;
+    server_result.map_err(SingleChainHostError::from)?;
+
+    let actual = client_result.is_err() as u8;
+    if actual != expected_status {
+        return Err(RecordFixtureError::StatusMismatch { expected: expected_status, actual });
+    }
+
+    let preimages = std::mem::take(&mut *served.lock().await);
+    info!(
+        target: "fixture_recorder",
+        "Recorded {} preimages; client program exited with the expected status {expected_status}",
+        preimages.len()
+    );
+
+    Ok(ProofFixture {
+        name: name.into(),
+        l1_head: host.l1_head,
+        agreed_l2_head_hash: host.agreed_l2_head_hash,
+        agreed_l2_output_root: host.agreed_l2_output_root,
+        claimed_l2_output_root: host.claimed_l2_output_root,
+        claimed_l2_block_number: host.claimed_l2_block_number,
+        l2_chain_id: host.l2_chain_id,
+        expected_status,
+        preimages,
+    })
+}
+
+/// Computes the canonical output root of the given L2 block from the L2 execution layer.
+async fn canonical_output_root(
+    l2: &RootProvider<Optimism>,
+    number: u64,
+) -> Result<B256, RecordFixtureError> {
+    let block = l2
+        .get_block_by_number(number.into())
+        .await?
+        .ok_or(RecordFixtureError::BlockNotFound(number))?;
+    let message_passer = l2
+        .get_proof(Predeploys::L2_TO_L1_MESSAGE_PASSER, Default::default())
+        .block_id(block.header.hash.into())
+        .await?;
+
+    Ok(OutputRoot::from_parts(
+        block.header.state_root,
+        message_passer.storage_hash,
+        block.header.hash,
+    )
+    .hash())
+}
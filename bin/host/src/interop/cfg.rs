@@ -1,6 +1,9 @@
 //! This module contains all CLI-specific code for the interop entrypoint.
 
-use super::{InteropHintHandler, InteropLocalInputs};
+use super::{
+    InteropHintHandler, InteropLocalInputs, MissingChainData,
+    preflight::{check_chain_data, pre_state_output_roots},
+};
 use crate::{
     DiskKeyValueStore, MemoryKeyValueStore, OfflineHostBackend, OnlineHostBackend,
     OnlineHostBackendCfg, PreimageServer, SharedKeyValueStore, SplitKeyValueStore,
@@ -8,22 +11,36 @@ use crate::{
 };
 use alloy_primitives::{B256, Bytes};
 use alloy_provider::{Provider, RootProvider};
+use alloy_rlp::Decodable;
 use clap::Parser;
 use kona_cli::cli_styles;
+use kona_client::fpvm_evm::{DefaultPrecompileRegistry, PrecompileRegistry};
 use kona_genesis::RollupConfig;
+use kona_interop::DependencySet;
 use kona_preimage::{
     BidirectionalChannel, Channel, HintReader, HintWriter, OracleReader, OracleServer,
 };
-use kona_proof_interop::HintType;
+use kona_proof_interop::{HintType, PreState};
 use kona_providers_alloy::{OnlineBeaconClient, OnlineBlobProvider};
+use kona_registry::ROLLUP_CONFIGS;
 use kona_std_fpvm::{FileChannel, FileDescriptor};
 use op_alloy_network::Optimism;
 use serde::Serialize;
-use std::{collections::HashMap, path::PathBuf, str::FromStr, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
 use tokio::{
     sync::RwLock,
-    task::{self, JoinHandle},
+    task::{self, JoinHandle, JoinSet},
 };
+use tracing::warn;
+
+/// The time to wait for an L2 node to respond to the pre-flight probe.
+const L2_PROBE_TIMEOUT: Duration = Duration::from_secs(10);
 
 /// The interop host application.
 #[derive(Default, Parser, Serialize, Clone, Debug)]
@@ -92,6 +109,14 @@ pub struct InteropHost {
     /// look up the configs in the superchain registry.
     #[arg(long, alias = "rollup-cfgs", value_delimiter = ',', env)]
     pub rollup_config_paths: Option<Vec<PathBuf>>,
+    /// Path to the dependency set file used by the supervisor. If provided, rollup configs for
+    /// chains in the dependency set that are not passed via `--rollup-config-paths` are resolved
+    /// from the superchain registry.
+    ///
+    /// L2 node addresses may be provided for a subset of the chains in the dependency set. The
+    /// remaining chains are served offline from the data directory.
+    #[arg(long, visible_alias = "depset", env)]
+    pub dependency_set: Option<PathBuf>,
 }
 
 /// An error that can occur when handling interop hosts
@@ -115,6 +140,9 @@ pub enum InteropHostError {
     /// An error when no provider found for chain ID.
     #[error("No provider found for chain ID: {0}")]
     RootProviderError(u64),
+    /// Per-chain data required for the proof is missing.
+    #[error("Missing per-chain data: {}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "))]
+    MissingChainData(Vec<MissingChainData>),
     /// Any other error.
     #[error("Error: {0}")]
     Other(&'static str),
@@ -142,10 +170,27 @@ impl InteropHost {
     ) -> Result<JoinHandle<Result<(), InteropHostError>>, InteropHostError>
     where
         C: Channel + Send + Sync + 'static,
+    {
+        self.start_server_with_registry(hint, preimage, DefaultPrecompileRegistry).await
+    }
+
+    /// Starts the preimage server like [InteropHost::start_server], serving `L1Precompile` hints
+    /// with the precompiles of the given [PrecompileRegistry].
+    pub async fn start_server_with_registry<C, R>(
+        &self,
+        hint: C,
+        preimage: C,
+        registry: R,
+    ) -> Result<JoinHandle<Result<(), InteropHostError>>, InteropHostError>
+    where
+        C: Channel + Send + Sync + 'static,
+        R: PrecompileRegistry,
     {
         let kv_store = self.create_key_value_store()?;
 
         let task_handle = if self.is_offline() {
+            self.check_chain_data(&HashSet::default(), &[], &kv_store).await?;
+
             task::spawn(async {
                 PreimageServer::new(
                     OracleServer::new(preimage),
@@ -157,12 +202,15 @@ impl InteropHost {
                 .map_err(InteropHostError::from)
             })
         } else {
-            let providers = self.create_providers().await?;
+            let (providers, unreachable) = self.create_providers().await?;
+            let online_chains = providers.l2s.keys().copied().collect();
+            self.check_chain_data(&online_chains, &unreachable, &kv_store).await?;
+
             let backend = OnlineHostBackend::new(
                 self.clone(),
                 kv_store.clone(),
                 providers,
-                InteropHintHandler,
+                InteropHintHandler::new().with_registry(registry),
             )
             .with_proactive_hint(HintType::L2BlockData);
 
@@ -199,7 +247,9 @@ impl InteropHost {
         std::process::exit(client_result.is_err() as i32)
     }
 
-    /// Returns `true` if the host is running in offline mode.
+    /// Returns `true` if the host is running in offline mode. The host is also able to serve a
+    /// mix of online and offline chains in online mode, if only some L2 node addresses are
+    /// provided alongside a data directory.
     pub const fn is_offline(&self) -> bool {
         self.l1_node_address.is_none() &&
             self.l2_node_addresses.is_none() &&
@@ -209,23 +259,120 @@ impl InteropHost {
 
     /// Reads the [RollupConfig]s from the file system and returns a map of L2 chain ID ->
     /// [RollupConfig]s.
+    ///
+    /// The configs of chains in the dependency set or the agreed pre-state that were not passed on
+    /// the command line are resolved from the superchain registry.
     pub fn read_rollup_configs(&self) -> Result<HashMap<u64, RollupConfig>, InteropHostError> {
-        let rollup_config_paths = self.rollup_config_paths.as_ref().ok_or_else(|| {
-            InteropHostError::Other(
-                "No rollup config paths provided. Please provide a path to the rollup configs.",
-            )
-        })?;
+        self.read_rollup_configs_with(self.read_dependency_set()?.as_ref())
+    }
+
+    /// Reads the [RollupConfig]s like [InteropHost::read_rollup_configs], using an already loaded
+    /// [DependencySet].
+    pub fn read_rollup_configs_with(
+        &self,
+        dependency_set: Option<&DependencySet>,
+    ) -> Result<HashMap<u64, RollupConfig>, InteropHostError> {
+        let mut rollup_configs = self.rollup_config_paths.iter().flatten().try_fold(
+            HashMap::default(),
+            |mut acc, path| {
+                // Read the serialized config from the file system.
+                let ser_config = std::fs::read_to_string(path)?;
+
+                // Deserialize the config and return it.
+                let cfg: RollupConfig = serde_json::from_str(&ser_config)?;
+
+                acc.insert(cfg.l2_chain_id.id(), cfg);
+                Ok::<_, InteropHostError>(acc)
+            },
+        )?;
+
+        let mut chain_ids = dependency_set
+            .map(|dependency_set| dependency_set.dependencies.keys().copied().collect::<Vec<_>>())
+            .unwrap_or_default();
+        if let Ok(pre_state) = self.decode_agreed_pre_state() {
+            chain_ids.extend(pre_state_output_roots(&pre_state).iter().map(|root| root.chain_id));
+        }
+
+        for chain_id in chain_ids {
+            if rollup_configs.contains_key(&chain_id) {
+                continue;
+            }
+            if let Some(cfg) = ROLLUP_CONFIGS.get(&chain_id) {
+                rollup_configs.insert(chain_id, cfg.clone());
+            }
+        }
 
-        rollup_config_paths.iter().try_fold(HashMap::default(), |mut acc, path| {
-            // Read the serialized config from the file system.
-            let ser_config = std::fs::read_to_string(path)?;
+        Ok(rollup_configs)
+    }
 
-            // Deserialize the config and return it.
-            let cfg: RollupConfig = serde_json::from_str(&ser_config)?;
+    /// Reads the [DependencySet] from the file system, if a path was provided.
+    pub fn read_dependency_set(&self) -> Result<Option<DependencySet>, InteropHostError> {
+        let Some(path) = self.dependency_set.as_ref() else {
+            return Ok(None);
+        };
 
-            acc.insert(cfg.l2_chain_id.id(), cfg);
-            Ok(acc)
-        })
+        let ser_dependency_set = std::fs::read_to_string(path)?;
+        Ok(Some(serde_json::from_str(&ser_dependency_set)?))
+    }
+
+    /// Decodes the agreed L2 [PreState].
+    fn decode_agreed_pre_state(&self) -> Result<PreState, InteropHostError> {
+        PreState::decode(&mut self.agreed_l2_pre_state.as_ref())
+            .map_err(|_| InteropHostError::Other("Failed to decode the agreed L2 pre-state"))
+    }
+
+    /// Checks that a rollup config and a data source are available for every chain that takes
+    /// part in the proof, and for the L1 head if no L1 node is configured, before the proof
+    /// starts. `online_chains` holds the chain IDs of the L2 nodes that responded to the
+    /// pre-flight probe, and `unreachable` the addresses of those that did not.
+    async fn check_chain_data(
+        &self,
+        online_chains: &HashSet<u64>,
+        unreachable: &[String],
+        kv_store: &SharedKeyValueStore,
+    ) -> Result<(), InteropHostError> {
+        let Ok(pre_state) = self.decode_agreed_pre_state() else {
+            // The client program short-circuits on invalid pre-states, without loading any
+            // per-chain data.
+            warn!(target: "interop_host", "Agreed L2 pre-state is not a valid pre-state");
+            return Ok(());
+        };
+        let dependency_set = self.read_dependency_set()?;
+        let rollup_configs = self.read_rollup_configs_with(dependency_set.as_ref())?;
+
+        if let Some(ref dependency_set) = dependency_set {
+            for chain_id in online_chains {
+                if !dependency_set.dependencies.contains_key(chain_id) {
+                    warn!(
+                        target: "interop_host",
+                        "L2 node address provided for chain {chain_id}, which is not in the dependency set"
+                    );
+                }
+            }
+        }
+
+        let offline_l1_head = self.l1_node_address.is_none().then_some(self.l1_head);
+        let kv_lock = kv_store.read().await;
+        let missing = check_chain_data(
+            &pre_state,
+            dependency_set.as_ref(),
+            &rollup_configs,
+            online_chains,
+            offline_l1_head,
+            &*kv_lock,
+        );
+
+        if missing.is_empty() {
+            return Ok(());
+        }
+        if !unreachable.is_empty() {
+            warn!(
+                target: "interop_host",
+                "Chains without a data source may be served by the unreachable L2 nodes: {}",
+                unreachable.join(", ")
+            );
+        }
+        Err(InteropHostError::MissingChainData(missing))
     }
 
     /// Creates the key-value store for the host backend.
@@ -246,7 +393,11 @@ impl InteropHost {
     }
 
     /// Creates the providers required for the preimage server backend.
-    async fn create_providers(&self) -> Result<InteropProviders, InteropHostError> {
+    ///
+    /// Every L2 node is probed for its chain ID. Nodes that do not respond are left out of the
+    /// providers, and their addresses are returned alongside them, so that their chains are
+    /// served from the data directory if possible.
+    async fn create_providers(&self) -> Result<(InteropProviders, Vec<String>), InteropHostError> {
         let l1_provider = http_provider(
             self.l1_node_address.as_ref().ok_or(InteropHostError::Other("Provider must be set"))?,
         );
@@ -263,17 +414,54 @@ impl InteropHost {
             .l2_node_addresses
             .as_ref()
             .ok_or(InteropHostError::Other("L2 node addresses must be set"))?;
-        let mut l2_providers = HashMap::default();
-        for l2_node_address in l2_node_addresses {
-            let l2_provider = http_provider::<Optimism>(l2_node_address);
-            let chain_id = l2_provider.get_chain_id().await?;
-            l2_providers.insert(chain_id, l2_provider);
-        }
+        let (l2_providers, unreachable) = probe_l2_providers(l2_node_addresses).await;
 
-        Ok(InteropProviders { l1: l1_provider, blobs: blob_provider, l2s: l2_providers })
+        Ok((
+            InteropProviders { l1: l1_provider, blobs: blob_provider, l2s: l2_providers },
+            unreachable,
+        ))
     }
 }
 
+/// Probes the given L2 nodes concurrently for their chain IDs. Returns the providers of the nodes
+/// that responded, keyed by chain ID, and the addresses of the nodes that did not.
+async fn probe_l2_providers(
+    addresses: &[String],
+) -> (HashMap<u64, RootProvider<Optimism>>, Vec<String>) {
+    let mut probes = JoinSet::new();
+    for address in addresses.iter().cloned() {
+        probes.spawn(async move {
+            let provider = http_provider::<Optimism>(&address);
+            let chain_id = tokio::time::timeout(L2_PROBE_TIMEOUT, provider.get_chain_id()).await;
+            (address, provider, chain_id)
+        });
+    }
+
+    let mut providers = HashMap::default();
+    let mut unreachable = Vec::new();
+    while let Some(probe) = probes.join_next().await {
+        let Ok((address, provider, chain_id)) = probe else {
+            continue;
+        };
+        match chain_id {
+            Ok(Ok(chain_id)) => {
+                providers.insert(chain_id, provider);
+            }
+            Ok(Err(e)) => {
+                warn!(target: "interop_host", "L2 node {address} is unreachable: {e}");
+                unreachable.push(address);
+            }
+            Err(_) => {
+                warn!(target: "interop_host", "L2 node {address} did not respond in time");
+                unreachable.push(address);
+            }
+        }
+    }
+    unreachable.sort();
+
+    (providers, unreachable)
+}
+
 impl OnlineHostBackendCfg for InteropHost {
     type HintType = HintType;
     type Providers = InteropProviders;
@@ -300,7 +488,12 @@ impl InteropProviders {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::b256;
+    use crate::KeyValueStore;
+    use alloy_primitives::{b256, keccak256};
+    use kona_interop::{ChainDependency, OutputRootWithChain, SuperRoot};
+    use kona_preimage::PreimageKey;
+    use kona_protocol::OutputRoot;
+    use tempfile::NamedTempFile;
 
     #[test]
     fn test_parse_interop_host_cli() {
@@ -357,4 +550,130 @@ mod tests {
         assert_eq!(host.claimed_l2_timestamp, 0);
         assert!(host.native);
     }
+
+    #[test]
+    fn test_parse_interop_dependency_set() {
+        let hash = b256!("ffd7db0f9d5cdeb49c4c9eba649d4dc6d852d64671e65488e57f58584992ac68");
+        let host = InteropHost::parse_from([
+            "interop-host",
+            "--l1-head",
+            &hash.to_string(),
+            "--l2-pre-state",
+            "ff",
+            "--claimed-l2-post-state",
+            &hash.to_string(),
+            "--claimed-l2-timestamp",
+            "0",
+            "--native",
+            "--l2-node-addresses",
+            "http://localhost:8545",
+            "--l1-node-address",
+            "http://localhost:8546",
+            "--l1-beacon-address",
+            "http://localhost:8547",
+            "--data-dir",
+            "dummy",
+            "--dependency-set",
+            "depset.json",
+        ]);
+        assert_eq!(host.dependency_set, Some(PathBuf::from("depset.json")));
+        assert_eq!(host.data_dir, Some(PathBuf::from("dummy")));
+        assert!(host.read_dependency_set().is_err());
+    }
+
+    /// Returns the encoded output root of the agreed L2 block of the given chain.
+    fn output_root(chain_id: u64) -> Vec<u8> {
+        let block_hash = B256::repeat_byte(chain_id as u8);
+        OutputRoot::from_parts(B256::ZERO, B256::ZERO, block_hash).encode().to_vec()
+    }
+
+    /// Returns an [InteropHost] whose agreed pre-state is a super root over the given chains, with
+    /// the given dependency set written to a temporary file that lives as long as the returned
+    /// [NamedTempFile].
+    fn preflight_host(chain_ids: &[u64], dependencies: &[u64]) -> (InteropHost, NamedTempFile) {
+        let pre_state = PreState::SuperRoot(SuperRoot::new(
+            0,
+            chain_ids
+                .iter()
+                .map(|id| OutputRootWithChain::new(*id, keccak256(output_root(*id))))
+                .collect(),
+        ));
+        let dependency_set = DependencySet {
+            dependencies: dependencies.iter().map(|id| (*id, ChainDependency {})).collect(),
+            override_message_expiry_window: None,
+        };
+        let file = NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), serde_json::to_string(&dependency_set).unwrap()).unwrap();
+
+        let host = InteropHost {
+            agreed_l2_pre_state: alloy_rlp::encode(&pre_state).into(),
+            dependency_set: Some(file.path().to_path_buf()),
+            data_dir: Some(PathBuf::from("dummy")),
+            ..Default::default()
+        };
+        (host, file)
+    }
+
+    /// Returns a key-value store holding the L1 head header, and the agreed output roots and L2
+    /// block headers of the given chains.
+    fn kv_store(host: &InteropHost, preimages_for: &[u64]) -> SharedKeyValueStore {
+        let mut kv = MemoryKeyValueStore::new();
+        kv.set(PreimageKey::new_keccak256(*host.l1_head).into(), vec![0xff]).unwrap();
+        for id in preimages_for {
+            let output_root = output_root(*id);
+            let key = PreimageKey::new_keccak256(*keccak256(&output_root));
+            kv.set(key.into(), output_root).unwrap();
+            let key = PreimageKey::new_keccak256(*B256::repeat_byte(*id as u8));
+            kv.set(key.into(), vec![0xff]).unwrap();
+        }
+        Arc::new(RwLock::new(kv))
+    }
+
+    #[tokio::test]
+    async fn test_preflight_offline_host() {
+        let (host, _file) = preflight_host(&[10, 8453], &[10, 8453]);
+        assert!(host.is_offline());
+
+        let kv = kv_store(&host, &[10, 8453]);
+        host.check_chain_data(&HashSet::default(), &[], &kv).await.unwrap();
+
+        let kv = kv_store(&host, &[10]);
+        let err = host.check_chain_data(&HashSet::default(), &[], &kv).await.unwrap_err();
+        assert!(matches!(
+            err,
+            InteropHostError::MissingChainData(missing)
+                if missing == vec![MissingChainData::DataSource(8453)]
+        ));
+
+        // The L1 head must be available offline as well.
+        let kv = Arc::new(RwLock::new(MemoryKeyValueStore::new())) as SharedKeyValueStore;
+        let err = host.check_chain_data(&HashSet::from([10, 8453]), &[], &kv).await.unwrap_err();
+        assert!(matches!(
+            err,
+            InteropHostError::MissingChainData(missing)
+                if missing == vec![MissingChainData::L1Head(host.l1_head)]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_preflight_missing_dependency() {
+        let (host, _file) = preflight_host(&[10, 8453], &[10]);
+
+        let kv = kv_store(&host, &[]);
+        let err = host.check_chain_data(&HashSet::from([10, 8453]), &[], &kv).await.unwrap_err();
+        assert!(matches!(
+            err,
+            InteropHostError::MissingChainData(missing)
+                if missing == vec![MissingChainData::NotInDependencySet(8453)]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_probe_excludes_unreachable_l2_nodes() {
+        let unreachable = "http://127.0.0.1:1".to_string();
+        let (providers, failed) = probe_l2_providers(std::slice::from_ref(&unreachable)).await;
+
+        assert!(providers.is_empty());
+        assert_eq!(failed, vec![unreachable]);
+    }
 }
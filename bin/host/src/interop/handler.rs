@@ -1,6 +1,6 @@
 //! [HintHandler] for the [InteropHost].
 
-use super::InteropHost;
+use super::{InteropHost, InteropHostError};
 use crate::{
     HintHandler, OnlineHostBackend, OnlineHostBackendCfg, PreimageServer, SharedKeyValueStore,
     backend::util::store_ordered_trie,
@@ -18,6 +18,7 @@ use alloy_rpc_types::Block;
 use anyhow::{Result, anyhow, ensure};
 use ark_ff::{BigInteger, PrimeField};
 use async_trait::async_trait;
+use kona_client::fpvm_evm::{DefaultPrecompileRegistry, PrecompileRegistry};
 use kona_derive::EthereumDataSource;
 use kona_driver::Driver;
 use kona_executor::TrieDBProvider;
@@ -40,14 +41,52 @@ use tokio::task;
 use tracing::{Instrument, debug, info, info_span, warn};
 
 /// The [HintHandler] for the [InteropHost].
-#[derive(Debug, Clone, Copy)]
-pub struct InteropHintHandler;
+///
+/// `L1Precompile` hints are served with the host precompiles of a [PrecompileRegistry], which
+/// must match the registry that the client program accelerates.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InteropHintHandler<R = DefaultPrecompileRegistry> {
+    /// The registry of the precompiles that the host executes on behalf of the client program.
+    registry: R,
+}
+
+impl InteropHintHandler {
+    /// Creates a new [InteropHintHandler] that serves the precompiles in the
+    /// [DefaultPrecompileRegistry].
+    pub const fn new() -> Self {
+        Self { registry: DefaultPrecompileRegistry }
+    }
+}
+
+impl<R> InteropHintHandler<R> {
+    /// Replaces the [PrecompileRegistry] that `L1Precompile` hints are served with.
+    pub fn with_registry<R2: PrecompileRegistry>(self, registry: R2) -> InteropHintHandler<R2> {
+        InteropHintHandler { registry }
+    }
+
+    /// Returns a reference to the inner [PrecompileRegistry].
+    pub const fn registry(&self) -> &R {
+        &self.registry
+    }
+}
 
 #[async_trait]
-impl HintHandler for InteropHintHandler {
+impl<R: PrecompileRegistry> HintHandler for InteropHintHandler<R> {
     type Cfg = InteropHost;
 
+    /// Hints for chains without an L2 provider are served from the data directory in mixed
+    /// online and offline mode, so they cannot be fetched by retrying.
+    fn is_fatal(&self, err: &anyhow::Error) -> bool {
+        err.chain().any(|e| {
+            matches!(
+                e.downcast_ref::<InteropHostError>(),
+                Some(InteropHostError::RootProviderError(_))
+            )
+        })
+    }
+
     async fn fetch_hint(
+        &self,
         hint: Hint<<Self::Cfg as OnlineHostBackendCfg>::HintType>,
         cfg: &Self::Cfg,
         providers: &<Self::Cfg as OnlineHostBackendCfg>::Providers,
@@ -163,7 +202,7 @@ impl HintHandler for InteropHintHandler {
                 let input = hint.data[28..].to_vec();
                 let input_hash = keccak256(hint.data.as_ref());
 
-                let result = crate::eth::execute(address, input, gas).map_or_else(
+                let result = crate::eth::execute(&self.registry, address, input, gas).map_or_else(
                     |_| vec![0u8; 1],
                     |raw_res| {
                         let mut res = Vec::with_capacity(1 + raw_res.len());
@@ -461,8 +500,12 @@ impl HintHandler for InteropHintHandler {
                 // store them in the key-value store.
                 let hint = BidirectionalChannel::new()?;
                 let preimage = BidirectionalChannel::new()?;
-                let backend =
-                    OnlineHostBackend::new(cfg.clone(), kv.clone(), providers.clone(), Self);
+                let backend = OnlineHostBackend::new(
+                    cfg.clone(),
+                    kv.clone(),
+                    providers.clone(),
+                    self.clone(),
+                );
                 let server_task = task::spawn(
                     PreimageServer::new(
                         OracleServer::new(preimage.host),
@@ -8,3 +8,6 @@ pub use local_kv::InteropLocalInputs;
 
 mod handler;
 pub use handler::InteropHintHandler;
+
+mod preflight;
+pub use preflight::MissingChainData;
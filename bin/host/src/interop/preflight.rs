@@ -0,0 +1,270 @@
+//! Contains the pre-flight checks that the [InteropHost] runs before serving a proof, so that
+//! missing per-chain data is reported up front rather than mid-run.
+//!
+//! [InteropHost]: super::InteropHost
+
+use crate::KeyValueStore;
+use alloy_primitives::B256;
+use kona_genesis::RollupConfig;
+use kona_interop::{DependencySet, OutputRootWithChain};
+use kona_preimage::PreimageKey;
+use kona_proof_interop::PreState;
+use kona_protocol::VersionedOutputRoot;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Per-chain data that is required for an interop proof, but is not available to the host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum MissingChainData {
+    /// The chain is part of the agreed pre-state, but not of the dependency set.
+    #[error("chain {0} is part of the agreed pre-state, but not of the dependency set")]
+    NotInDependencySet(u64),
+    /// No rollup config was provided for the chain, and it is not in the superchain registry.
+    #[error("no rollup config provided for chain {0}, and it is not in the superchain registry")]
+    RollupConfig(u64),
+    /// No reachable L2 node serves the chain, and the data directory does not contain its agreed
+    /// output root.
+    #[error("no reachable L2 node for chain {0}, and no offline data found for it")]
+    DataSource(u64),
+    /// The agreed output root of an offline chain in the data directory cannot be decoded.
+    #[error("the offline agreed output root of chain {0} cannot be decoded")]
+    InvalidOutputRoot(u64),
+    /// The data directory does not contain the header of the agreed L2 block of an offline chain.
+    #[error("no offline data found for the agreed L2 block header of chain {0}")]
+    AgreedHeader(u64),
+    /// No L1 node is configured, and the data directory does not contain the L1 head header.
+    #[error("no L1 node configured, and no offline data found for the L1 head {0}")]
+    L1Head(B256),
+}
+
+/// Checks that the host has a rollup config and a data source for every chain that takes part in
+/// the proof, returning every missing piece of data.
+///
+/// A chain takes part in the proof if it is in the agreed pre-state. If a dependency set is
+/// provided, every such chain must also be in the dependency set. A chain has a data source if an
+/// L2 node for it responded to the pre-flight probe, or if the key-value store already holds the
+/// data the client program starts the chain from: the preimage of its agreed output root, and the
+/// header of the agreed L2 block that the output root commits to.
+///
+/// `offline_l1_head` is the L1 head, if no L1 node is configured. The key-value store must then
+/// hold its header as well.
+pub(crate) fn check_chain_data<KV>(
+    pre_state: &PreState,
+    dependency_set: Option<&DependencySet>,
+    rollup_configs: &HashMap<u64, RollupConfig>,
+    online_chains: &HashSet<u64>,
+    offline_l1_head: Option<B256>,
+    kv: &KV,
+) -> Vec<MissingChainData>
+where
+    KV: KeyValueStore + ?Sized,
+{
+    let output_roots = pre_state_output_roots(pre_state);
+    let mut missing = Vec::new();
+
+    if let Some(l1_head) = offline_l1_head {
+        if !has_preimage(kv, l1_head) {
+            missing.push(MissingChainData::L1Head(l1_head));
+        }
+    }
+
+    if let Some(dependency_set) = dependency_set {
+        missing.extend(
+            output_roots
+                .iter()
+                .filter(|root| !dependency_set.dependencies.contains_key(&root.chain_id))
+                .map(|root| MissingChainData::NotInDependencySet(root.chain_id)),
+        );
+    }
+
+    let chain_ids = output_roots.iter().map(|root| root.chain_id).collect::<BTreeSet<_>>();
+    for chain_id in chain_ids {
+        if !rollup_configs.contains_key(&chain_id) {
+            missing.push(MissingChainData::RollupConfig(chain_id));
+        }
+
+        if online_chains.contains(&chain_id) {
+            continue;
+        }
+
+        let Some(root) = output_roots.iter().find(|root| root.chain_id == chain_id) else {
+            continue;
+        };
+        let Some(output_root) = kv.get(PreimageKey::new_keccak256(*root.output_root).into()) else {
+            missing.push(MissingChainData::DataSource(chain_id));
+            continue;
+        };
+        let Ok(output_root) = VersionedOutputRoot::decode(&output_root) else {
+            missing.push(MissingChainData::InvalidOutputRoot(chain_id));
+            continue;
+        };
+        if !has_preimage(kv, output_root.block_hash()) {
+            missing.push(MissingChainData::AgreedHeader(chain_id));
+        }
+    }
+
+    missing
+}
+
+/// Returns the output roots of the super root that the [PreState] builds on.
+pub(crate) fn pre_state_output_roots(pre_state: &PreState) -> &[OutputRootWithChain] {
+    match pre_state {
+        PreState::SuperRoot(super_root) => &super_root.output_roots,
+        PreState::TransitionState(transition_state) => &transition_state.pre_state.output_roots,
+    }
+}
+
+/// Returns `true` if the key-value store holds the keccak256 preimage of `hash`.
+fn has_preimage<KV>(kv: &KV, hash: B256) -> bool
+where
+    KV: KeyValueStore + ?Sized,
+{
+    kv.get(PreimageKey::new_keccak256(*hash).into()).is_some()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::MemoryKeyValueStore;
+    use alloy_primitives::keccak256;
+    use kona_interop::{ChainDependency, SuperRoot};
+    use kona_protocol::OutputRoot;
+
+    /// Returns the encoded output root of the agreed L2 block of the given chain.
+    fn output_root(chain_id: u64) -> Vec<u8> {
+        let block_hash = B256::repeat_byte(chain_id as u8);
+        OutputRoot::from_parts(B256::ZERO, B256::ZERO, block_hash).encode().to_vec()
+    }
+
+    fn pre_state(chain_ids: &[u64]) -> PreState {
+        PreState::SuperRoot(SuperRoot::new(
+            0,
+            chain_ids
+                .iter()
+                .map(|id| OutputRootWithChain::new(*id, keccak256(output_root(*id))))
+                .collect(),
+        ))
+    }
+
+    /// Stores the agreed output root of the chain, and the agreed L2 block header if `header`.
+    fn store_chain(kv: &mut MemoryKeyValueStore, chain_id: u64, header: bool) {
+        let output_root = output_root(chain_id);
+        kv.set(PreimageKey::new_keccak256(*keccak256(&output_root)).into(), output_root).unwrap();
+        if header {
+            let block_hash = B256::repeat_byte(chain_id as u8);
+            kv.set(PreimageKey::new_keccak256(*block_hash).into(), vec![0xff]).unwrap();
+        }
+    }
+
+    fn dependency_set(chain_ids: &[u64]) -> DependencySet {
+        DependencySet {
+            dependencies: chain_ids.iter().map(|id| (*id, ChainDependency {})).collect(),
+            override_message_expiry_window: None,
+        }
+    }
+
+    fn rollup_configs(chain_ids: &[u64]) -> HashMap<u64, RollupConfig> {
+        chain_ids.iter().map(|id| (*id, RollupConfig::default())).collect()
+    }
+
+    #[test]
+    fn test_all_chains_online() {
+        let missing = check_chain_data(
+            &pre_state(&[1, 2, 3]),
+            Some(&dependency_set(&[1, 2, 3])),
+            &rollup_configs(&[1, 2, 3]),
+            &HashSet::from([1, 2, 3]),
+            None,
+            &MemoryKeyValueStore::new(),
+        );
+        assert!(missing.is_empty());
+    }
+
+    #[test]
+    fn test_mixed_online_and_offline_chains() {
+        let mut kv = MemoryKeyValueStore::new();
+        store_chain(&mut kv, 3, true);
+        store_chain(&mut kv, 4, false);
+
+        let missing = check_chain_data(
+            &pre_state(&[1, 2, 3, 4]),
+            Some(&dependency_set(&[1, 2, 3, 4])),
+            &rollup_configs(&[1, 2, 3, 4]),
+            &HashSet::from([1]),
+            None,
+            &kv,
+        );
+        assert_eq!(
+            missing,
+            vec![MissingChainData::DataSource(2), MissingChainData::AgreedHeader(4)]
+        );
+    }
+
+    #[test]
+    fn test_offline_l1_head() {
+        let l1_head = B256::repeat_byte(0xaa);
+        let mut kv = MemoryKeyValueStore::new();
+        store_chain(&mut kv, 1, true);
+
+        let check = |kv: &MemoryKeyValueStore| {
+            check_chain_data(
+                &pre_state(&[1]),
+                None,
+                &rollup_configs(&[1]),
+                &HashSet::new(),
+                Some(l1_head),
+                kv,
+            )
+        };
+        assert_eq!(check(&kv), vec![MissingChainData::L1Head(l1_head)]);
+
+        kv.set(PreimageKey::new_keccak256(*l1_head).into(), vec![0xff]).unwrap();
+        assert!(check(&kv).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_offline_output_root() {
+        let pre_state = PreState::SuperRoot(SuperRoot::new(
+            0,
+            vec![OutputRootWithChain::new(1, keccak256([0xff]))],
+        ));
+        let mut kv = MemoryKeyValueStore::new();
+        kv.set(PreimageKey::new_keccak256(*keccak256([0xff])).into(), vec![0xff]).unwrap();
+
+        let missing =
+            check_chain_data(&pre_state, None, &rollup_configs(&[1]), &HashSet::new(), None, &kv);
+        assert_eq!(missing, vec![MissingChainData::InvalidOutputRoot(1)]);
+    }
+
+    #[test]
+    fn test_reports_all_missing_data() {
+        let missing = check_chain_data(
+            &pre_state(&[1, 2, 4]),
+            Some(&dependency_set(&[1, 2, 3])),
+            &rollup_configs(&[1, 2]),
+            &HashSet::from([1, 2]),
+            None,
+            &MemoryKeyValueStore::new(),
+        );
+        assert_eq!(
+            missing,
+            vec![
+                MissingChainData::NotInDependencySet(4),
+                MissingChainData::RollupConfig(4),
+                MissingChainData::DataSource(4),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_without_dependency_set() {
+        let missing = check_chain_data(
+            &pre_state(&[1, 2]),
+            None,
+            &rollup_configs(&[1]),
+            &HashSet::from([1, 2]),
+            None,
+            &MemoryKeyValueStore::new(),
+        );
+        assert_eq!(missing, vec![MissingChainData::RollupConfig(2)]);
+    }
+}
@@ -0,0 +1,78 @@
+//! Contains a concrete implementation of the [KeyValueStore] trait that shares one underlying
+//! [KeyValueStore] between many owners.
+
+use super::KeyValueStore;
+use alloy_primitives::B256;
+use anyhow::{Result, anyhow};
+use std::sync::{Arc, RwLock};
+
+/// A cloneable [KeyValueStore] handle that shares one underlying [KeyValueStore] between many
+/// owners, such as the preimage servers of concurrently running proof jobs.
+///
+/// Unlike [SharedKeyValueStore], this type implements [KeyValueStore] itself, so that it can be
+/// composed with job-specific stores, i.e. in a [SplitKeyValueStore].
+///
+/// [SharedKeyValueStore]: crate::SharedKeyValueStore
+/// [SplitKeyValueStore]: crate::SplitKeyValueStore
+#[derive(Debug)]
+pub struct ShareableKeyValueStore<KV>
+where
+    KV: KeyValueStore + ?Sized,
+{
+    inner: Arc<RwLock<KV>>,
+}
+
+impl<KV> ShareableKeyValueStore<KV>
+where
+    KV: KeyValueStore + ?Sized,
+{
+    /// Create a new [ShareableKeyValueStore] around the given [KeyValueStore].
+    pub const fn new(inner: Arc<RwLock<KV>>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<KV> Clone for ShareableKeyValueStore<KV>
+where
+    KV: KeyValueStore + ?Sized,
+{
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone() }
+    }
+}
+
+impl<KV> KeyValueStore for ShareableKeyValueStore<KV>
+where
+    KV: KeyValueStore + ?Sized,
+{
+    fn get(&self, key: B256) -> Option<Vec<u8>> {
+        self.inner.read().ok()?.get(key)
+    }
+
+    fn set(&mut self, key: B256, value: Vec<u8>) -> Result<()> {
+        self.inner
+            .write()
+            .map_err(|e| anyhow!("Shared key-value store lock poisoned: {e}"))?
+            .set(key, value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ShareableKeyValueStore;
+    use crate::kv::{KeyValueStore, MemoryKeyValueStore};
+    use alloy_primitives::B256;
+    use std::sync::{Arc, RwLock};
+
+    #[test]
+    fn test_shareable_kv_store_shares_writes() {
+        let inner = Arc::new(RwLock::new(MemoryKeyValueStore::new()));
+        let mut a = ShareableKeyValueStore::new(inner.clone());
+        let b = a.clone();
+
+        a.set(B256::ZERO, vec![0xbe, 0xef]).unwrap();
+
+        assert_eq!(b.get(B256::ZERO), Some(vec![0xbe, 0xef]));
+        assert_eq!(inner.read().unwrap().get(B256::ZERO), Some(vec![0xbe, 0xef]));
+    }
+}
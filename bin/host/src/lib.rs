@@ -6,16 +6,26 @@ pub use server::{PreimageServer, PreimageServerError};
 
 mod kv;
 pub use kv::{
-    DiskKeyValueStore, KeyValueStore, MemoryKeyValueStore, SharedKeyValueStore, SplitKeyValueStore,
+    DiskKeyValueStore, KeyValueStore, MemoryKeyValueStore, ShareableKeyValueStore,
+    SharedKeyValueStore, SplitKeyValueStore,
 };
 
 mod backend;
-pub use backend::{HintHandler, OfflineHostBackend, OnlineHostBackend, OnlineHostBackendCfg};
+pub use backend::{
+    DEFAULT_WITNESS_PREFETCH_CONCURRENCY, ExecutionWitnessPrefetcher, HintHandler,
+    OfflineHostBackend, OnlineHostBackend, OnlineHostBackendCfg,
+};
 
 pub mod eth;
 
 #[cfg(feature = "single")]
 pub mod single;
 
+#[cfg(feature = "single")]
+pub mod daemon;
+
+#[cfg(feature = "single")]
+pub mod fixture;
+
 #[cfg(feature = "interop")]
 pub mod interop;
@@ -5,7 +5,7 @@ use kona_preimage::{
 };
 use std::sync::Arc;
 use tokio::spawn;
-use tracing::{error, info};
+use tracing::{Instrument, error, info};
 
 /// The [PreimageServer] is responsible for waiting for incoming preimage requests and
 /// serving them to the client.
@@ -47,9 +47,14 @@ where
 
     /// Starts the [PreimageServer] and waits for incoming requests.
     pub async fn start(self) -> Result<(), PreimageServerError> {
-        // Create the futures for the oracle server and hint router.
-        let server = spawn(Self::start_oracle_server(self.oracle_server, self.backend.clone()));
-        let hint_router = spawn(Self::start_hint_router(self.hint_reader, self.backend.clone()));
+        // Create the futures for the oracle server and hint router. Both run within the caller's
+        // span, so that their events are attributed to it.
+        let server = spawn(
+            Self::start_oracle_server(self.oracle_server, self.backend.clone()).in_current_span(),
+        );
+        let hint_router = spawn(
+            Self::start_hint_router(self.hint_reader, self.backend.clone()).in_current_span(),
+        );
 
         // Race the two futures to completion, returning the result of the first one to finish.
         tokio::select! {
@@ -2,20 +2,23 @@
 
 use super::{SingleChainHintHandler, SingleChainLocalInputs};
 use crate::{
-    DiskKeyValueStore, MemoryKeyValueStore, OfflineHostBackend, OnlineHostBackend,
-    OnlineHostBackendCfg, PreimageServer, SharedKeyValueStore, SplitKeyValueStore,
-    eth::http_provider, server::PreimageServerError,
+    DEFAULT_WITNESS_PREFETCH_CONCURRENCY, DiskKeyValueStore, ExecutionWitnessPrefetcher,
+    MemoryKeyValueStore, OfflineHostBackend, OnlineHostBackend, OnlineHostBackendCfg,
+    PreimageServer, SharedKeyValueStore, SplitKeyValueStore, eth::http_provider,
+    server::PreimageServerError,
 };
 use alloy_primitives::B256;
 use alloy_provider::RootProvider;
 use clap::Parser;
 use kona_cli::cli_styles;
+use kona_client::fpvm_evm::{DefaultPrecompileRegistry, PrecompileRegistry};
 use kona_genesis::RollupConfig;
 use kona_preimage::{
     BidirectionalChannel, Channel, HintReader, HintWriter, OracleReader, OracleServer,
 };
 use kona_proof::HintType;
 use kona_providers_alloy::{OnlineBeaconClient, OnlineBlobProvider};
+use kona_registry::ROLLUP_CONFIGS;
 use kona_std_fpvm::{FileChannel, FileDescriptor};
 use op_alloy_network::Optimism;
 use serde::Serialize;
@@ -24,6 +27,7 @@ use tokio::{
     sync::RwLock,
     task::{self, JoinHandle},
 };
+use tracing::warn;
 
 /// The host binary CLI application arguments.
 #[derive(Default, Parser, Serialize, Clone, Debug)]
@@ -110,6 +114,15 @@ pub struct SingleChainHost {
     /// the execution layer.
     #[arg(long, env)]
     pub enable_experimental_witness_endpoint: bool,
+    /// Prefetches `debug_executionWitness` for every block between the agreed L2 head and the
+    /// claimed L2 block, loading the witnesses into the key-value store ahead of the client
+    /// program. Falls back to `eth_getProof` if the endpoint is unavailable.
+    #[arg(long, env)]
+    pub prefetch_execution_witnesses: bool,
+    /// The maximum number of `debug_executionWitness` requests that may be in flight at once when
+    /// `--prefetch-execution-witnesses` is enabled.
+    #[arg(long, default_value_t = DEFAULT_WITNESS_PREFETCH_CONCURRENCY, env)]
+    pub witness_prefetch_concurrency: usize,
 }
 
 /// An error that can occur when handling single chain hosts
@@ -154,6 +167,21 @@ impl SingleChainHost {
     ) -> Result<JoinHandle<Result<(), SingleChainHostError>>, SingleChainHostError>
     where
         C: Channel + Send + Sync + 'static,
+    {
+        self.start_server_with_registry(hint, preimage, DefaultPrecompileRegistry).await
+    }
+
+    /// Starts the preimage server like [SingleChainHost::start_server], serving `L1Precompile`
+    /// hints with the precompiles of the given [PrecompileRegistry].
+    pub async fn start_server_with_registry<C, R>(
+        &self,
+        hint: C,
+        preimage: C,
+        registry: R,
+    ) -> Result<JoinHandle<Result<(), SingleChainHostError>>, SingleChainHostError>
+    where
+        C: Channel + Send + Sync + 'static,
+        R: PrecompileRegistry,
     {
         let kv_store = self.create_key_value_store()?;
 
@@ -170,11 +198,33 @@ impl SingleChainHost {
             })
         } else {
             let providers = self.create_providers().await?;
+
+            if self.prefetch_execution_witnesses {
+                let prefetcher = ExecutionWitnessPrefetcher::new(
+                    providers.l2.clone(),
+                    kv_store.clone(),
+                    self.witness_prefetch_concurrency,
+                );
+                let (agreed_l2_head_hash, claimed_l2_block_number) =
+                    (self.agreed_l2_head_hash, self.claimed_l2_block_number);
+
+                // The prefetcher runs alongside the preimage server. Any preimages that it fails
+                // to load are fetched on demand when the client hints for them.
+                task::spawn(async move {
+                    if let Err(e) = prefetcher
+                        .prefetch_after(agreed_l2_head_hash, claimed_l2_block_number)
+                        .await
+                    {
+                        warn!(target: "host", "Execution witness prefetching failed: {e}");
+                    }
+                });
+            }
+
             let backend = OnlineHostBackend::new(
                 self.clone(),
                 kv_store.clone(),
                 providers,
-                SingleChainHintHandler,
+                SingleChainHintHandler::new().with_registry(registry),
             )
             .with_proactive_hint(HintType::L2PayloadWitness);
 
@@ -220,12 +270,19 @@ impl SingleChainHost {
     }
 
     /// Reads the [RollupConfig] from the file system and returns it as a string.
+    ///
+    /// If no rollup config path was provided, the config is resolved from the superchain registry
+    /// by the L2 chain ID. This allows custom chains added with `--registry-path` to be served to
+    /// the client program. `--registry-path` may not redefine built-in chains, since the client
+    /// program loads their configs from its own registry instead.
     pub fn read_rollup_config(&self) -> Result<RollupConfig, SingleChainHostError> {
-        let path = self.rollup_config_path.as_ref().ok_or_else(|| {
-            SingleChainHostError::Other(
-                "No rollup config path provided. Please provide a path to the rollup config.",
-            )
-        })?;
+        let Some(path) = self.rollup_config_path.as_ref() else {
+            return self.l2_chain_id.and_then(|id| ROLLUP_CONFIGS.get(&id).cloned()).ok_or(
+                SingleChainHostError::Other(
+                    "No rollup config path provided. Please provide a path to the rollup config.",
+                ),
+            );
+        };
 
         // Read the serialized config from the file system.
         let ser_config = std::fs::read_to_string(path)?;
@@ -346,6 +403,24 @@ mod test {
                 .as_slice(),
                 true,
             ),
+            (
+                [
+                    "--l1-node-address",
+                    "dummy",
+                    "--l2-node-address",
+                    "dummy",
+                    "--l1-beacon-address",
+                    "dummy",
+                    "--server",
+                    "--l2-chain-id",
+                    "0",
+                    "--prefetch-execution-witnesses",
+                    "--witness-prefetch-concurrency",
+                    "32",
+                ]
+                .as_slice(),
+                true,
+            ),
             // invalid
             (["--server", "--native", "--l2-chain-id", "0"].as_slice(), false),
             (["--l2-chain-id", "0", "--rollup-config-path", "dummy", "--server"].as_slice(), false),
@@ -357,6 +432,19 @@ mod test {
             (["--l2-node-address", "dummy", "--server", "--l2-chain-id", "0"].as_slice(), false),
             (["--l1-beacon-address", "dummy", "--server", "--l2-chain-id", "0"].as_slice(), false),
             ([].as_slice(), false),
+            (
+                [
+                    "--server",
+                    "--l2-chain-id",
+                    "0",
+                    "--data-dir",
+                    "dummy",
+                    "--witness-prefetch-concurrency",
+                    "not-a-number",
+                ]
+                .as_slice(),
+                false,
+            ),
         ];
 
         for (args_ext, valid) in cases.into_iter() {
@@ -366,4 +454,34 @@ mod test {
             assert_eq!(parsed.is_ok(), valid);
         }
     }
+
+    #[test]
+    fn test_read_rollup_config_from_registry() {
+        let zero_hash_str = &B256::ZERO.to_string();
+        let host = SingleChainHost::try_parse_from([
+            "single",
+            "--l1-head",
+            zero_hash_str,
+            "--l2-head",
+            zero_hash_str,
+            "--l2-output-root",
+            zero_hash_str,
+            "--l2-claim",
+            zero_hash_str,
+            "--l2-block-number",
+            "0",
+            "--server",
+            "--l2-chain-id",
+            "10",
+            "--data-dir",
+            "dummy",
+        ])
+        .unwrap();
+
+        let rollup_config = host.read_rollup_config().unwrap();
+        assert_eq!(&rollup_config, kona_registry::ROLLUP_CONFIGS.get(&10).unwrap());
+
+        let host = SingleChainHost { l2_chain_id: Some(999999), ..host };
+        assert!(host.read_rollup_config().is_err());
+    }
 }
@@ -16,6 +16,7 @@ use alloy_rpc_types::{Block, debug::ExecutionWitness};
 use anyhow::{Result, anyhow, ensure};
 use ark_ff::{BigInteger, PrimeField};
 use async_trait::async_trait;
+use kona_client::fpvm_evm::{DefaultPrecompileRegistry, PrecompileRegistry};
 use kona_preimage::{PreimageKey, PreimageKeyType};
 use kona_proof::{Hint, HintType, l1::ROOTS_OF_UNITY};
 use kona_protocol::{BlockInfo, OutputRoot, Predeploys};
@@ -23,14 +24,41 @@ use op_alloy_rpc_types_engine::OpPayloadAttributes;
 use tracing::warn;
 
 /// The [HintHandler] for the [SingleChainHost].
-#[derive(Debug, Clone, Copy)]
-pub struct SingleChainHintHandler;
+///
+/// `L1Precompile` hints are served with the host precompiles of a [PrecompileRegistry], which
+/// must match the registry that the client program accelerates.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SingleChainHintHandler<R = DefaultPrecompileRegistry> {
+    /// The registry of the precompiles that the host executes on behalf of the client program.
+    registry: R,
+}
+
+impl SingleChainHintHandler {
+    /// Creates a new [SingleChainHintHandler] that serves the precompiles in the
+    /// [DefaultPrecompileRegistry].
+    pub const fn new() -> Self {
+        Self { registry: DefaultPrecompileRegistry }
+    }
+}
+
+impl<R> SingleChainHintHandler<R> {
+    /// Replaces the [PrecompileRegistry] that `L1Precompile` hints are served with.
+    pub fn with_registry<R2: PrecompileRegistry>(self, registry: R2) -> SingleChainHintHandler<R2> {
+        SingleChainHintHandler { registry }
+    }
+
+    /// Returns a reference to the inner [PrecompileRegistry].
+    pub const fn registry(&self) -> &R {
+        &self.registry
+    }
+}
 
 #[async_trait]
-impl HintHandler for SingleChainHintHandler {
+impl<R: PrecompileRegistry> HintHandler for SingleChainHintHandler<R> {
     type Cfg = SingleChainHost;
 
     async fn fetch_hint(
+        &self,
         hint: Hint<<Self::Cfg as OnlineHostBackendCfg>::HintType>,
         cfg: &Self::Cfg,
         providers: &<Self::Cfg as OnlineHostBackendCfg>::Providers,
@@ -146,7 +174,7 @@ impl HintHandler for SingleChainHintHandler {
                 let input = hint.data[28..].to_vec();
                 let input_hash = keccak256(hint.data.as_ref());
 
-                let result = crate::eth::execute(address, input, gas).map_or_else(
+                let result = crate::eth::execute(&self.registry, address, input, gas).map_or_else(
                     |_| vec![0u8; 1],
                     |raw_res| {
                         let mut res = Vec::with_capacity(1 + raw_res.len());
@@ -377,3 +405,92 @@ impl HintHandler for SingleChainHintHandler {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{MemoryKeyValueStore, eth::http_provider, single::SingleChainProviders};
+    use kona_client::fpvm_evm::{AcceleratedPrecompile, HostPrecompile};
+    use kona_preimage::{HintWriterClient, PreimageOracleClient};
+    use kona_providers_alloy::{OnlineBeaconClient, OnlineBlobProvider};
+    use op_revm::OpSpecId;
+    use revm::precompile::{PrecompileOutput, PrecompileResult, u64_to_address};
+    use std::sync::Arc;
+    use tokio::sync::RwLock;
+
+    /// A registry that only serves a custom echo precompile on the host.
+    #[derive(Debug, Clone)]
+    struct EchoRegistry;
+
+    impl PrecompileRegistry for EchoRegistry {
+        fn accelerated<H, O>(&self, _: OpSpecId) -> Vec<AcceleratedPrecompile<H, O>>
+        where
+            H: HintWriterClient + Send + Sync,
+            O: PreimageOracleClient + Send + Sync,
+        {
+            Vec::new()
+        }
+
+        fn host_precompiles(&self) -> Vec<HostPrecompile> {
+            vec![HostPrecompile::new(u64_to_address(0xff), |_| Ok(100)).with_precompile(echo)]
+        }
+    }
+
+    fn echo(input: &[u8], _: u64) -> PrecompileResult {
+        Ok(PrecompileOutput::new(100, input.to_vec().into()))
+    }
+
+    /// Serves an `L1Precompile` hint with the given handler, returning the stored result.
+    async fn precompile_result<R: PrecompileRegistry>(
+        handler: SingleChainHintHandler<R>,
+        address: Address,
+        input: &[u8],
+    ) -> Vec<u8> {
+        // The providers are never queried for precompile hints.
+        let providers = SingleChainProviders {
+            l1: http_provider("http://127.0.0.1:1"),
+            blobs: OnlineBlobProvider {
+                beacon_client: OnlineBeaconClient::new_http("http://127.0.0.1:1".to_string()),
+                genesis_time: 0,
+                slot_interval: 12,
+            },
+            l2: http_provider("http://127.0.0.1:1"),
+        };
+        let kv: SharedKeyValueStore = Arc::new(RwLock::new(MemoryKeyValueStore::new()));
+
+        let mut data = address.to_vec();
+        data.extend_from_slice(&100u64.to_be_bytes());
+        data.extend_from_slice(input);
+        let input_hash = keccak256(&data);
+
+        handler
+            .fetch_hint(
+                Hint::new(HintType::L1Precompile, data),
+                &SingleChainHost::default(),
+                &providers,
+                kv.clone(),
+            )
+            .await
+            .unwrap();
+
+        let kv_lock = kv.read().await;
+        kv_lock.get(PreimageKey::new(*input_hash, PreimageKeyType::Precompile).into()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_precompile_hint_uses_registry() {
+        let custom = u64_to_address(0xff);
+
+        let result = precompile_result(
+            SingleChainHintHandler::new().with_registry(EchoRegistry),
+            custom,
+            &[0xde, 0xad],
+        )
+        .await;
+        assert_eq!(result, vec![0x01, 0xde, 0xad]);
+
+        // The default registry does not serve the custom precompile.
+        let result = precompile_result(SingleChainHintHandler::new(), custom, &[0xde, 0xad]).await;
+        assert_eq!(result, vec![0x00]);
+    }
+}
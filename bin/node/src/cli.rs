@@ -1,7 +1,9 @@
 //! Contains the node CLI.
 
 use crate::{
-    commands::{BootstoreCommand, InfoCommand, NetCommand, NodeCommand, RegistryCommand},
+    commands::{
+        BootstoreCommand, DebugCommand, InfoCommand, NetCommand, NodeCommand, RegistryCommand,
+    },
     flags::{GlobalArgs, init_unified_metrics},
     version,
 };
@@ -27,6 +29,9 @@ pub enum Commands {
     Bootstore(BootstoreCommand),
     /// Get info about op chain.
     Info(InfoCommand),
+    /// Tools to inspect rollup data.
+    #[command(alias = "d")]
+    Debug(DebugCommand),
 }
 
 /// The node CLI.
@@ -51,6 +56,9 @@ pub struct Cli {
 impl Cli {
     /// Runs the CLI.
     pub fn run(self) -> Result<()> {
+        // Merge any custom registry before the registry is first accessed.
+        self.global.init_registry()?;
+
         // Initialize unified metrics
         init_unified_metrics(&self.global.metrics)?;
 
@@ -61,6 +69,7 @@ impl Cli {
             Commands::Registry(ref registry) => registry.init_logs(&self.global)?,
             Commands::Bootstore(ref bootstore) => bootstore.init_logs(&self.global)?,
             Commands::Info(ref info) => info.init_logs(&self.global)?,
+            Commands::Debug(ref debug) => debug.init_logs(&self.global)?,
         }
 
         // Allow subcommands to initialize cli metrics.
@@ -78,6 +87,7 @@ impl Cli {
             Commands::Registry(registry) => registry.run(&self.global),
             Commands::Bootstore(bootstore) => bootstore.run(&self.global),
             Commands::Info(info) => info.run(&self.global),
+            Commands::Debug(debug) => Self::run_until_ctrl_c(debug.run(&self.global)),
         }
     }
 
@@ -114,6 +124,8 @@ mod tests {
     #[case::bootstore_subcommand_long(Commands::Bootstore(Default::default()), "boot")]
     #[case::bootstore_subcommand_long2(Commands::Bootstore(Default::default()), "store")]
     #[case::info_subcommand(Commands::Info(Default::default()), "info")]
+    #[case::debug_subcommand_long(Commands::Debug(Default::default()), "debug")]
+    #[case::debug_subcommand_short(Commands::Debug(Default::default()), "d")]
     fn test_parse_cli(#[case] subcommand: Commands, #[case] subcommand_alias: &str) {
         let args = vec!["kona-node", subcommand_alias, "--help"];
         let cli = Cli::parse_from(args);
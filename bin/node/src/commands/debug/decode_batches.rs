@@ -0,0 +1,797 @@
+//! `debug decode-batches` Subcommand
+
+use crate::flags::GlobalArgs;
+use alloy_eips::eip4844::{BYTES_PER_BLOB, Blob};
+use alloy_primitives::{Address, B256, Bytes, FixedBytes, hex};
+use alloy_rlp::Decodable;
+use anyhow::{Context, bail};
+use clap::{Parser, ValueEnum};
+use kona_derive::{
+    BlobData, ChainProvider, DataAvailabilityProvider, EthereumDataSource, PipelineError,
+    PipelineErrorKind,
+};
+use kona_genesis::{
+    MAX_RLP_BYTES_PER_CHANNEL_BEDROCK, MAX_RLP_BYTES_PER_CHANNEL_FJORD, RollupConfig,
+};
+use kona_protocol::{
+    Batch, BatchDecodingError, BatchReader, BlockInfo, Channel, ChannelId, Frame, SingleBatch,
+    SpanBatch,
+};
+use kona_providers_alloy::{AlloyChainProvider, OnlineBeaconClient, OnlineBlobProvider};
+use kona_registry::scr_rollup_config_by_alloy_ident;
+use op_alloy_consensus::OpTxType;
+use serde::Serialize;
+use std::{
+    collections::HashMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+use tracing::{debug, info};
+use url::Url;
+
+/// The cache size of the L1 chain provider used to fetch batcher transactions.
+const L1_PROVIDER_CACHE_SIZE: usize = 256;
+
+/// The output format of the `decode-batches` subcommand.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text.
+    #[default]
+    Text,
+    /// A single JSON document.
+    Json,
+}
+
+/// The `debug decode-batches` Subcommand
+///
+/// Reassembles the channels posted by the batcher, decompresses them and prints every batch
+/// they contain along with the result of the stateless validity checks against the rollup
+/// config. Batcher data is either fetched from an L1 block range or read from files.
+///
+/// # Usage
+///
+/// ```sh
+/// # Decode the batches posted in an L1 block range.
+/// kona-node debug decode-batches --l1-eth-rpc http://localhost:8545 \
+///                                --l1-beacon http://localhost:5052 \
+///                                --start 21000000 --end 21000010
+///
+/// # Decode raw batcher transaction calldata and blobs.
+/// kona-node debug decode-batches --calldata tx.hex --blob blob.bin --format json
+/// ```
+#[derive(Parser, Default, PartialEq, Debug, Clone)]
+#[command(about = "Decodes the channels and batches posted by the batcher")]
+pub struct DecodeBatchesCommand {
+    /// URL of the L1 execution client RPC API used to fetch batcher transactions.
+    #[arg(long, visible_alias = "l1", requires = "l1_beacon", requires = "start")]
+    pub l1_eth_rpc: Option<Url>,
+    /// URL of the L1 beacon API used to fetch blobs.
+    #[arg(long, visible_alias = "l1.beacon", requires = "l1_eth_rpc")]
+    pub l1_beacon: Option<Url>,
+    /// The first L1 block of the range to decode.
+    #[arg(long, requires = "l1_eth_rpc")]
+    pub start: Option<u64>,
+    /// The last L1 block of the range to decode, inclusive. Defaults to the start block.
+    #[arg(long, requires = "start")]
+    pub end: Option<u64>,
+    /// Files containing the calldata of batcher transactions, hex encoded or raw.
+    #[arg(long, value_delimiter = ',', conflicts_with = "l1_eth_rpc")]
+    pub calldata: Vec<PathBuf>,
+    /// Files containing blobs posted by the batcher, hex encoded or raw.
+    #[arg(long = "blob", value_delimiter = ',', conflicts_with = "l1_eth_rpc")]
+    pub blobs: Vec<PathBuf>,
+    /// Path to a custom L2 rollup configuration file
+    /// (overrides the default rollup configuration from the registry)
+    #[arg(long, visible_alias = "rollup-cfg")]
+    pub l2_config_file: Option<PathBuf>,
+    /// The batcher address to filter L1 transactions by.
+    /// Defaults to the batcher address of the genesis system config.
+    #[arg(long)]
+    pub batcher_address: Option<Address>,
+    /// The output format.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+}
+
+impl DecodeBatchesCommand {
+    /// Runs the subcommand.
+    pub async fn run(self, args: &GlobalArgs) -> anyhow::Result<()> {
+        let cfg = self.rollup_config(args)?;
+        let mut decoder = BatchDecoder::new(&cfg);
+
+        match (self.l1_eth_rpc.clone(), self.l1_beacon.clone(), self.start) {
+            (Some(l1_eth_rpc), Some(l1_beacon), Some(start)) => {
+                self.decode_l1_range(&mut decoder, l1_eth_rpc, l1_beacon, start).await?
+            }
+            _ => self.decode_files(&mut decoder)?,
+        }
+
+        let report = decoder.finish();
+        match self.format {
+            OutputFormat::Text => print!("{report}"),
+            OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+        }
+        Ok(())
+    }
+
+    /// Returns the L2 rollup config, either from a file or the superchain registry.
+    fn rollup_config(&self, args: &GlobalArgs) -> anyhow::Result<RollupConfig> {
+        let cfg = match &self.l2_config_file {
+            Some(path) => {
+                debug!(target: "debug", "Loading l2 config from file: {:?}", path);
+                let file = fs::File::open(path).context("Failed to open l2 config file")?;
+                serde_json::from_reader(file).context("Failed to parse l2 config")?
+            }
+            None => scr_rollup_config_by_alloy_ident(&args.l2_chain_id)
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Failed to find l2 config for chain ID {}", args.l2_chain_id)
+                })?
+                .clone(),
+        };
+        Ok(args.apply_overrides(cfg))
+    }
+
+    /// Feeds the calldata and blob files to the decoder.
+    fn decode_files(&self, decoder: &mut BatchDecoder<'_>) -> anyhow::Result<()> {
+        if self.calldata.is_empty() && self.blobs.is_empty() {
+            bail!("Either an L1 block range or calldata / blob files must be provided");
+        }
+
+        for path in &self.calldata {
+            let data = read_hex_or_raw(path)?;
+            decoder.ingest(None, &data);
+        }
+        for path in &self.blobs {
+            let raw = read_hex_or_raw(path)?;
+            let blob = Blob::try_from(raw.as_slice()).map_err(|_| {
+                anyhow::anyhow!(
+                    "{} is {} bytes long, expected a {BYTES_PER_BLOB} byte blob",
+                    path.display(),
+                    raw.len()
+                )
+            })?;
+            let data = BlobData::decode_blob(&blob)
+                .map_err(|e| anyhow::anyhow!("Failed to decode blob {}: {e}", path.display()))?;
+            decoder.ingest(None, &data);
+        }
+        Ok(())
+    }
+
+    /// Fetches the batcher data posted in the L1 block range and feeds it to the decoder.
+    async fn decode_l1_range(
+        &self,
+        decoder: &mut BatchDecoder<'_>,
+        l1_eth_rpc: Url,
+        l1_beacon: Url,
+        start: u64,
+    ) -> anyhow::Result<()> {
+        let end = self.end.unwrap_or(start);
+        if end < start {
+            bail!("The end block {end} is before the start block {start}");
+        }
+        let cfg = decoder.cfg;
+        let batcher_address = self
+            .batcher_address
+            .or_else(|| cfg.genesis.system_config.as_ref().map(|c| c.batcher_address))
+            .ok_or_else(|| anyhow::anyhow!("No batcher address configured"))?;
+
+        let mut provider = AlloyChainProvider::new_http(l1_eth_rpc, L1_PROVIDER_CACHE_SIZE);
+        let blobs = OnlineBlobProvider::init(OnlineBeaconClient::new_http(l1_beacon.into())).await;
+        let mut source = EthereumDataSource::new_from_parts(provider.clone(), blobs, cfg);
+
+        info!(target: "debug", "Decoding batches posted by {batcher_address} in L1 blocks {start}..={end}");
+        for number in start..=end {
+            let block = provider
+                .block_info_by_number(number)
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to fetch L1 block {number}: {e}"))?;
+            loop {
+                match source.next(&block, batcher_address).await {
+                    Ok(data) => decoder.ingest(Some(block), &data),
+                    Err(PipelineErrorKind::Temporary(PipelineError::Eof)) => break,
+                    Err(e) => bail!("Failed to fetch batcher data in L1 block {number}: {e}"),
+                }
+            }
+            source.clear();
+        }
+
+        decoder.check_origins(&mut provider).await
+    }
+}
+
+/// Reads a file that contains either hex encoded or raw bytes.
+fn read_hex_or_raw(path: &Path) -> anyhow::Result<Vec<u8>> {
+    let raw = fs::read(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let decoded = std::str::from_utf8(&raw).ok().and_then(|s| hex::decode(s.trim()).ok());
+    Ok(decoded.unwrap_or(raw))
+}
+
+/// A channel that is still missing frames.
+#[derive(Debug)]
+struct PendingChannel {
+    /// The channel being reassembled.
+    channel: Channel,
+    /// The L1 block that the first frame was included in, if known.
+    open_block: Option<u64>,
+    /// The L1 block that the latest frame was included in, if known.
+    close_block: Option<BlockInfo>,
+}
+
+/// Reassembles channels from batcher data and decodes the batches they contain.
+///
+/// Frames are buffered per [`ChannelId`] in the order they are ingested. Once a channel has
+/// all of its frames, it is decompressed and every batch is decoded and checked against the
+/// [`RollupConfig`].
+#[derive(Debug)]
+pub struct BatchDecoder<'a> {
+    /// The rollup config.
+    cfg: &'a RollupConfig,
+    /// Channels that are missing frames.
+    pending: HashMap<ChannelId, PendingChannel>,
+    /// The report being built.
+    report: DecodeReport,
+}
+
+impl<'a> BatchDecoder<'a> {
+    /// Creates a new [`BatchDecoder`].
+    pub fn new(cfg: &'a RollupConfig) -> Self {
+        Self { cfg, pending: HashMap::new(), report: DecodeReport::default() }
+    }
+
+    /// Ingests the data of a single batcher transaction or blob.
+    ///
+    /// `inclusion` is the L1 block that the data was included in, if known.
+    pub fn ingest(&mut self, inclusion: Option<BlockInfo>, data: &[u8]) {
+        let frames = match Frame::parse_frames(data) {
+            Ok(frames) => frames,
+            Err(e) => {
+                let block = inclusion.map(|b| format!(" in L1 block {}", b.number));
+                self.report
+                    .errors
+                    .push(format!("Invalid batcher data{}: {e}", block.unwrap_or_default()));
+                return;
+            }
+        };
+
+        for frame in frames {
+            let id = frame.id;
+            let pending = self.pending.entry(id).or_insert_with(|| PendingChannel {
+                channel: Channel::new(id, inclusion.unwrap_or_default()),
+                open_block: inclusion.map(|b| b.number),
+                close_block: None,
+            });
+            if let Err(e) = pending.channel.add_frame(frame, inclusion.unwrap_or_default()) {
+                self.report.errors.push(format!("Channel {}: {e}", hex::encode_prefixed(id)));
+                continue;
+            }
+            pending.close_block = inclusion;
+
+            if pending.channel.is_ready() {
+                let Some(pending) = self.pending.remove(&id) else { continue };
+                let channel = self.decode_channel(pending);
+                self.report.channels.push(channel);
+            }
+        }
+    }
+
+    /// Decompresses a complete channel and decodes its batches.
+    fn decode_channel(&self, pending: PendingChannel) -> ChannelReport {
+        let PendingChannel { channel, open_block, close_block } = pending;
+        let mut report = ChannelReport {
+            id: hex::encode_prefixed(channel.id()),
+            open_block,
+            close_block: close_block.map(|b| b.number),
+            frames: channel.len(),
+            compression: None,
+            batches: Vec::new(),
+            error: None,
+        };
+
+        // The channel timeout is only enforced when the inclusion blocks are known.
+        if let (Some(open), Some(close)) = (open_block, close_block) {
+            let timeout = self.cfg.channel_timeout(close.timestamp);
+            if open + timeout < close.number {
+                report.error = Some(format!("Channel timed out after {timeout} L1 blocks"));
+            }
+        }
+
+        let Some(data) = channel.frame_data() else {
+            report.error = Some("Channel is missing frame data".to_string());
+            return report;
+        };
+        let fjord = close_block.is_some_and(|b| self.cfg.is_fjord_active(b.timestamp));
+        let max_rlp_bytes = if fjord || close_block.is_none() {
+            MAX_RLP_BYTES_PER_CHANNEL_FJORD
+        } else {
+            MAX_RLP_BYTES_PER_CHANNEL_BEDROCK
+        };
+        let mut reader = BatchReader::new(data.to_vec(), max_rlp_bytes as usize);
+        if let Err(e) = reader.decompress() {
+            report.error = Some(e.to_string());
+            return report;
+        }
+        report.compression = Some(if reader.brotli_used { "brotli" } else { "zlib" });
+
+        let mut buf = reader.decompressed.as_slice();
+        while !buf.is_empty() {
+            let batch = Bytes::decode(&mut buf)
+                .map_err(BatchDecodingError::AlloyRlpError)
+                .and_then(|data| Batch::decode(&mut data.as_ref(), self.cfg));
+            match batch {
+                Ok(batch) => {
+                    report.batches.push(self.batch_report(&batch, close_block, reader.brotli_used))
+                }
+                Err(e) => {
+                    report.error = Some(format!("Failed to decode batch: {e}"));
+                    break;
+                }
+            }
+        }
+        report
+    }
+
+    /// Builds the report for a single batch, running the checks that only need the
+    /// [`RollupConfig`] and the inclusion block.
+    fn batch_report(
+        &self,
+        batch: &Batch,
+        inclusion: Option<BlockInfo>,
+        brotli_used: bool,
+    ) -> BatchReport {
+        let mut report = match batch {
+            Batch::Single(single) => BatchReport::from(single),
+            Batch::Span(span) => BatchReport::from(span),
+        };
+        let cfg = self.cfg;
+        let timestamp = batch.timestamp();
+
+        if brotli_used && !cfg.is_fjord_active(timestamp) {
+            report.flag("Brotli compression used before Fjord".to_string());
+        }
+        if matches!(batch, Batch::Span(_)) && !cfg.is_delta_active(timestamp) {
+            report.flag("Span batch used before Delta".to_string());
+        }
+
+        // Filter out batches that were included too late.
+        let epoch = match batch {
+            Batch::Single(single) => single.epoch_num,
+            Batch::Span(span) => span.starting_epoch_num(),
+        };
+        if let Some(inclusion) = inclusion.filter(|b| epoch + cfg.seq_window_size < b.number) {
+            report.flag(format!(
+                "Included in L1 block {} past the sequencing window of L1 origin {epoch}",
+                inclusion.number
+            ));
+        }
+
+        let mut issues = Vec::new();
+        for (block, transactions) in report.blocks.iter().zip(batch_transactions(batch)) {
+            let ts = block.timestamp;
+            if ts < cfg.genesis.l2_time {
+                issues.push(format!("Block {ts} precedes the L2 genesis"));
+            } else if (ts - cfg.genesis.l2_time).checked_rem(cfg.block_time).is_some_and(|r| r != 0)
+            {
+                issues.push(format!(
+                    "Block {ts} is not aligned to the {}s block time",
+                    cfg.block_time
+                ));
+            }
+            if cfg.is_first_interop_block(ts) && !transactions.is_empty() {
+                issues.push(format!(
+                    "Block {ts} includes user transactions in the interop transition block"
+                ));
+            }
+            for tx in transactions {
+                match tx.first() {
+                    None => issues.push(format!("Block {ts} includes an empty transaction")),
+                    Some(&ty) if ty == OpTxType::Deposit as u8 => {
+                        issues.push(format!("Block {ts} includes a deposit transaction"))
+                    }
+                    Some(&ty) if ty == OpTxType::Eip7702 as u8 && !cfg.is_isthmus_active(ts) => {
+                        issues.push(format!(
+                            "Block {ts} includes an EIP-7702 transaction before Isthmus"
+                        ))
+                    }
+                    _ => {}
+                }
+            }
+        }
+        issues.into_iter().for_each(|issue| report.flag(issue));
+        report
+    }
+
+    /// Checks the decoded batches against their L1 origins.
+    ///
+    /// This validates the L1 origin hashes, that no L2 block is older than its L1 origin and
+    /// that non-empty blocks respect the max sequencer drift.
+    pub async fn check_origins<P>(&mut self, provider: &mut P) -> anyhow::Result<()>
+    where
+        P: ChainProvider + Send,
+    {
+        let mut origins = HashMap::<u64, BlockInfo>::new();
+        for batch in self.report.channels.iter_mut().flat_map(|c| c.batches.iter_mut()) {
+            let mut issues = Vec::new();
+            for (i, block) in batch.blocks.iter().enumerate() {
+                let origin = match origins.get(&block.l1_origin) {
+                    Some(origin) => *origin,
+                    None => {
+                        let origin =
+                            provider.block_info_by_number(block.l1_origin).await.map_err(|e| {
+                                anyhow::anyhow!("Failed to fetch L1 block {}: {e}", block.l1_origin)
+                            })?;
+                        *origins.entry(block.l1_origin).or_insert(origin)
+                    }
+                };
+
+                if block.l1_origin_hash.is_some_and(|hash| hash != origin.hash) {
+                    issues.push(format!("Block {} has an invalid L1 origin hash", block.timestamp));
+                }
+                let is_last = i + 1 == batch.blocks.len();
+                if is_last &&
+                    batch.l1_origin_check.is_some_and(|check| origin.hash[..20] != check[..])
+                {
+                    issues.push("Span batch L1 origin check does not match".to_string());
+                }
+                if block.timestamp < origin.timestamp {
+                    issues.push(format!(
+                        "Block {} is older than its L1 origin {}",
+                        block.timestamp, origin.number
+                    ));
+                }
+                let max =
+                    origin.timestamp.saturating_add(self.cfg.max_sequencer_drift(origin.timestamp));
+                if block.timestamp > max && block.transactions > 0 {
+                    issues.push(format!(
+                        "Block {} exceeds the max sequencer drift of L1 origin {}",
+                        block.timestamp, origin.number
+                    ));
+                }
+            }
+            issues.into_iter().for_each(|issue| batch.flag(issue));
+        }
+        Ok(())
+    }
+
+    /// Consumes the decoder, returning the report and recording channels that are missing frames.
+    pub fn finish(mut self) -> DecodeReport {
+        let mut incomplete = self
+            .pending
+            .into_values()
+            .map(|p| IncompleteChannel {
+                id: hex::encode_prefixed(p.channel.id()),
+                open_block: p.open_block,
+                frames: p.channel.len(),
+            })
+            .collect::<Vec<_>>();
+        incomplete.sort_by_key(|c| c.open_block);
+        self.report.incomplete_channels = incomplete;
+        self.report
+    }
+}
+
+/// Returns the transactions of every block in the batch.
+fn batch_transactions(batch: &Batch) -> Vec<&[Bytes]> {
+    match batch {
+        Batch::Single(single) => vec![single.transactions.as_slice()],
+        Batch::Span(span) => span.batches.iter().map(|b| b.transactions.as_slice()).collect(),
+    }
+}
+
+/// The result of decoding batcher data.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct DecodeReport {
+    /// Channels that were fully reassembled, in the order they were completed.
+    pub channels: Vec<ChannelReport>,
+    /// Channels that are missing frames.
+    pub incomplete_channels: Vec<IncompleteChannel>,
+    /// Batcher data that could not be parsed into frames.
+    pub errors: Vec<String>,
+}
+
+/// A fully reassembled channel.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChannelReport {
+    /// The channel ID.
+    pub id: String,
+    /// The L1 block that the first frame was included in, if known.
+    pub open_block: Option<u64>,
+    /// The L1 block that the last frame was included in, if known.
+    pub close_block: Option<u64>,
+    /// The number of frames in the channel.
+    pub frames: usize,
+    /// The compression algorithm of the channel.
+    pub compression: Option<&'static str>,
+    /// The batches decoded from the channel.
+    pub batches: Vec<BatchReport>,
+    /// The error that stopped the channel from being decoded, if any.
+    pub error: Option<String>,
+}
+
+/// A decoded batch.
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchReport {
+    /// The batch type.
+    pub kind: &'static str,
+    /// The L2 blocks in the batch.
+    pub blocks: Vec<BlockReport>,
+    /// The first 20 bytes of the L1 origin hash of the last block, for span batches.
+    pub l1_origin_check: Option<FixedBytes<20>>,
+    /// Whether the batch passed every check.
+    pub valid: bool,
+    /// The checks that the batch failed.
+    pub issues: Vec<String>,
+}
+
+impl BatchReport {
+    /// Records a failed check.
+    fn flag(&mut self, issue: String) {
+        self.valid = false;
+        self.issues.push(issue);
+    }
+}
+
+impl From<&SingleBatch> for BatchReport {
+    fn from(batch: &SingleBatch) -> Self {
+        Self {
+            kind: "single",
+            blocks: vec![BlockReport {
+                timestamp: batch.timestamp,
+                l1_origin: batch.epoch_num,
+                l1_origin_hash: Some(batch.epoch_hash),
+                transactions: batch.transactions.len(),
+            }],
+            l1_origin_check: None,
+            valid: true,
+            issues: Vec::new(),
+        }
+    }
+}
+
+impl From<&SpanBatch> for BatchReport {
+    fn from(batch: &SpanBatch) -> Self {
+        Self {
+            kind: "span",
+            blocks: batch
+                .batches
+                .iter()
+                .map(|b| BlockReport {
+                    timestamp: b.timestamp,
+                    l1_origin: b.epoch_num,
+                    l1_origin_hash: None,
+                    transactions: b.transactions.len(),
+                })
+                .collect(),
+            l1_origin_check: Some(batch.l1_origin_check),
+            valid: true,
+            issues: Vec::new(),
+        }
+    }
+}
+
+/// An L2 block within a decoded batch.
+#[derive(Debug, Clone, Serialize)]
+pub struct BlockReport {
+    /// The L2 block timestamp.
+    pub timestamp: u64,
+    /// The L1 origin block number.
+    pub l1_origin: u64,
+    /// The L1 origin block hash, only known for single batches.
+    pub l1_origin_hash: Option<B256>,
+    /// The number of transactions in the block.
+    pub transactions: usize,
+}
+
+/// A channel that is missing frames.
+#[derive(Debug, Clone, Serialize)]
+pub struct IncompleteChannel {
+    /// The channel ID.
+    pub id: String,
+    /// The L1 block that the first frame was included in, if known.
+    pub open_block: Option<u64>,
+    /// The number of frames received.
+    pub frames: usize,
+}
+
+/// Formats an optional L1 block number.
+fn block_number(number: Option<u64>) -> String {
+    number.map_or_else(|| "?".to_string(), |n| n.to_string())
+}
+
+impl fmt::Display for DecodeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for channel in &self.channels {
+            writeln!(
+                f,
+                "Channel {} (L1 blocks {}..={}, {} frame(s), {})",
+                channel.id,
+                block_number(channel.open_block),
+                block_number(channel.close_block),
+                channel.frames,
+                channel.compression.unwrap_or("unknown compression"),
+            )?;
+            for batch in &channel.batches {
+                let status = if batch.valid { "valid" } else { "invalid" };
+                writeln!(f, "  {} batch, {} block(s), {status}", batch.kind, batch.blocks.len())?;
+                for issue in &batch.issues {
+                    writeln!(f, "    ! {issue}")?;
+                }
+                for block in &batch.blocks {
+                    write!(f, "    timestamp {} | L1 origin {}", block.timestamp, block.l1_origin)?;
+                    if let Some(hash) = block.l1_origin_hash {
+                        write!(f, " ({hash})")?;
+                    }
+                    writeln!(f, " | {} tx(s)", block.transactions)?;
+                }
+            }
+            if let Some(error) = &channel.error {
+                writeln!(f, "  error: {error}")?;
+            }
+        }
+        for channel in &self.incomplete_channels {
+            writeln!(
+                f,
+                "Incomplete channel {} (opened in L1 block {}, {} frame(s))",
+                channel.id,
+                block_number(channel.open_block),
+                channel.frames
+            )?;
+        }
+        for error in &self.errors {
+            writeln!(f, "Error: {error}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_rlp::Encodable;
+    use kona_genesis::{ChainGenesis, HardForkConfig};
+    use kona_protocol::DERIVATION_VERSION_0;
+    use miniz_oxide::deflate::compress_to_vec_zlib;
+
+    fn test_config() -> RollupConfig {
+        RollupConfig {
+            block_time: 2,
+            seq_window_size: 100,
+            genesis: ChainGenesis { l2_time: 10, ..Default::default() },
+            hardforks: HardForkConfig { delta_time: Some(0), ..Default::default() },
+            ..Default::default()
+        }
+    }
+
+    fn single_batch(timestamp: u64, transactions: Vec<Bytes>) -> Batch {
+        Batch::Single(SingleBatch { epoch_num: 1, timestamp, transactions, ..Default::default() })
+    }
+
+    /// Compresses the batches into channel data.
+    fn channel_data(batches: &[Batch]) -> Vec<u8> {
+        let mut rlp = Vec::new();
+        for batch in batches {
+            let mut encoded = Vec::new();
+            batch.encode(&mut encoded).unwrap();
+            Bytes::from(encoded).encode(&mut rlp);
+        }
+        compress_to_vec_zlib(&rlp, 6)
+    }
+
+    /// Splits the channel data into `count` frames, each wrapped in its own batcher transaction.
+    fn batcher_txs(id: ChannelId, data: &[u8], count: usize) -> Vec<Vec<u8>> {
+        let chunks = data.chunks(data.len().div_ceil(count)).collect::<Vec<_>>();
+        chunks
+            .iter()
+            .enumerate()
+            .map(|(i, chunk)| {
+                let frame = Frame::new(id, i as u16, chunk.to_vec(), i + 1 == chunks.len());
+                [vec![DERIVATION_VERSION_0], frame.encode()].concat()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_decode_single_batch() {
+        let cfg = test_config();
+        let data = channel_data(&[single_batch(12, vec![Bytes::from(vec![0x02, 0x01])])]);
+        let mut decoder = BatchDecoder::new(&cfg);
+        for tx in batcher_txs([1; 16], &data, 1) {
+            decoder.ingest(None, &tx);
+        }
+        let report = decoder.finish();
+
+        assert_eq!(report.channels.len(), 1);
+        let channel = &report.channels[0];
+        assert_eq!(channel.compression, Some("zlib"));
+        assert!(channel.error.is_none());
+        assert_eq!(channel.batches.len(), 1);
+        assert!(channel.batches[0].valid, "{:?}", channel.batches[0].issues);
+        assert_eq!(channel.batches[0].blocks[0].timestamp, 12);
+        assert_eq!(channel.batches[0].blocks[0].transactions, 1);
+        assert!(report.incomplete_channels.is_empty());
+        assert!(report.errors.is_empty());
+    }
+
+    #[test]
+    fn test_decode_channel_across_transactions() {
+        let cfg = test_config();
+        let data = channel_data(&[single_batch(12, vec![]), single_batch(14, vec![])]);
+        let txs = batcher_txs([2; 16], &data, 3);
+        let mut decoder = BatchDecoder::new(&cfg);
+
+        // Ingest the frames out of order.
+        decoder.ingest(None, &txs[2]);
+        decoder.ingest(None, &txs[0]);
+        assert!(decoder.report.channels.is_empty());
+        decoder.ingest(None, &txs[1]);
+
+        let report = decoder.finish();
+        assert_eq!(report.channels.len(), 1);
+        assert_eq!(report.channels[0].frames, 3);
+        assert_eq!(report.channels[0].batches.len(), 2);
+    }
+
+    #[test]
+    fn test_decode_flags_invalid_batches() {
+        let cfg = test_config();
+        let deposit = Bytes::from(vec![OpTxType::Deposit as u8]);
+        let data = channel_data(&[single_batch(13, vec![deposit, Bytes::new()])]);
+        let mut decoder = BatchDecoder::new(&cfg);
+        let inclusion = BlockInfo { number: 200, ..Default::default() };
+        for tx in batcher_txs([3; 16], &data, 1) {
+            decoder.ingest(Some(inclusion), &tx);
+        }
+        let report = decoder.finish();
+
+        let batch = &report.channels[0].batches[0];
+        assert!(!batch.valid);
+        assert_eq!(
+            batch.issues,
+            vec![
+                "Included in L1 block 200 past the sequencing window of L1 origin 1".to_string(),
+                "Block 13 is not aligned to the 2s block time".to_string(),
+                "Block 13 includes a deposit transaction".to_string(),
+                "Block 13 includes an empty transaction".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_reports_incomplete_channels_and_errors() {
+        let cfg = test_config();
+        let data = channel_data(&[single_batch(12, vec![])]);
+        let txs = batcher_txs([4; 16], &data, 2);
+        let mut decoder = BatchDecoder::new(&cfg);
+        decoder.ingest(None, &txs[0]);
+        decoder.ingest(None, &[0x01, 0x02]);
+        let report = decoder.finish();
+
+        assert!(report.channels.is_empty());
+        assert_eq!(report.incomplete_channels.len(), 1);
+        assert_eq!(report.incomplete_channels[0].frames, 1);
+        assert_eq!(report.errors, vec!["Invalid batcher data: Unsupported derivation version"]);
+    }
+
+    #[test]
+    fn test_decode_batches_cli_requires_input_source() {
+        let cmd = DecodeBatchesCommand::try_parse_from(["decode-batches", "--start", "1"]);
+        assert!(cmd.is_err());
+        let cmd = DecodeBatchesCommand::try_parse_from([
+            "decode-batches",
+            "--l1-eth-rpc",
+            "http://localhost:8545",
+            "--calldata",
+            "tx.hex",
+        ]);
+        assert!(cmd.is_err());
+        let cmd = DecodeBatchesCommand::try_parse_from([
+            "decode-batches",
+            "--calldata",
+            "a.hex,b.hex",
+            "--format",
+            "json",
+        ])
+        .unwrap();
+        assert_eq!(cmd.calldata, vec![PathBuf::from("a.hex"), PathBuf::from("b.hex")]);
+        assert_eq!(cmd.format, OutputFormat::Json);
+    }
+}
@@ -0,0 +1,57 @@
+//! Debug Subcommand
+
+use crate::flags::GlobalArgs;
+use clap::{Parser, Subcommand};
+use kona_cli::LogConfig;
+
+mod decode_batches;
+pub use decode_batches::{
+    BatchDecoder, BatchReport, BlockReport, ChannelReport, DecodeBatchesCommand, DecodeReport,
+    IncompleteChannel, OutputFormat,
+};
+
+/// The `debug` Subcommand
+///
+/// The `debug` subcommand groups offline and online tools used to inspect rollup data.
+///
+/// # Usage
+///
+/// ```sh
+/// kona-node debug <SUBCOMMAND> [FLAGS] [OPTIONS]
+/// ```
+#[derive(Parser, Default, PartialEq, Debug, Clone)]
+#[command(about = "Tools to inspect rollup data")]
+pub struct DebugCommand {
+    /// The debug tool to run.
+    #[command(subcommand)]
+    pub subcommand: DebugSubcommand,
+}
+
+/// Subcommands for the `debug` command.
+#[derive(Debug, PartialEq, Clone, Subcommand)]
+pub enum DebugSubcommand {
+    /// Decodes the channels and batches posted by the batcher.
+    #[command(alias = "batches")]
+    DecodeBatches(DecodeBatchesCommand),
+}
+
+impl Default for DebugSubcommand {
+    fn default() -> Self {
+        Self::DecodeBatches(Default::default())
+    }
+}
+
+impl DebugCommand {
+    /// Initializes the logging system based on global arguments.
+    pub fn init_logs(&self, args: &GlobalArgs) -> anyhow::Result<()> {
+        LogConfig::new(args.log_args.clone()).init_tracing_subscriber(None)?;
+        Ok(())
+    }
+
+    /// Runs the subcommand.
+    pub async fn run(self, args: &GlobalArgs) -> anyhow::Result<()> {
+        match self.subcommand {
+            DebugSubcommand::DecodeBatches(cmd) => cmd.run(args).await,
+        }
+    }
+}
@@ -1,11 +1,14 @@
 //! Info Subcommand
 
 use crate::flags::GlobalArgs;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use kona_cli::LogConfig;
 use kona_registry::{OPCHAINS, ROLLUP_CONFIGS};
 use tracing::info;
 
+mod validate_config;
+pub use validate_config::ValidateConfigCommand;
+
 /// The `info` Subcommand
 ///
 /// The `info` subcommand is used to run the information stack for the `kona-node`.
@@ -13,12 +16,24 @@ use tracing::info;
 /// # Usage
 ///
 /// ```sh
-/// kona-node info
+/// kona-node info [SUBCOMMAND]
 /// ```
 
 #[derive(Parser, Default, PartialEq, Debug, Clone)]
 #[command(about = "Runs the information stack for the kona-node.")]
-pub struct InfoCommand;
+pub struct InfoCommand {
+    /// An optional info tool to run. Prints the chain info if not provided.
+    #[command(subcommand)]
+    pub subcommand: Option<InfoSubcommand>,
+}
+
+/// Subcommands for the `info` command.
+#[derive(Debug, PartialEq, Clone, Subcommand)]
+pub enum InfoSubcommand {
+    /// Validates a rollup config and diffs it against the superchain registry.
+    #[command(alias = "validate")]
+    ValidateConfig(ValidateConfigCommand),
+}
 
 impl InfoCommand {
     /// Initializes the logging system based on global arguments.
@@ -29,6 +44,10 @@ impl InfoCommand {
 
     /// Runs the information stack for the kona-node.
     pub fn run(&self, args: &GlobalArgs) -> anyhow::Result<()> {
+        if let Some(InfoSubcommand::ValidateConfig(cmd)) = &self.subcommand {
+            return cmd.run(args);
+        }
+
         info!(target: "node_info", "Running info command");
 
         let op_chain_config = OPCHAINS.get(&args.l2_chain_id.id()).expect("No Chain config found");
@@ -0,0 +1,118 @@
+//! The `validate-config` info subcommand.
+
+use crate::flags::GlobalArgs;
+use anyhow::Context;
+use clap::Parser;
+use kona_genesis::RollupConfig;
+use kona_registry::{ROLLUP_CONFIGS, scr_rollup_config_by_alloy_ident};
+use std::{fs, path::PathBuf};
+use tracing::debug;
+
+/// The `validate-config` subcommand.
+///
+/// Validates a rollup config, checking hardfork ordering, the fields required by each scheduled
+/// hardfork and consistency with the genesis system config. If the superchain registry contains a
+/// config for the same chain ID, every field that differs from it is listed.
+///
+/// # Usage
+///
+/// ```sh
+/// kona-node info validate-config [--l2-config-file <PATH>]
+/// ```
+#[derive(Parser, Default, PartialEq, Debug, Clone)]
+#[command(about = "Validates a rollup config and diffs it against the superchain registry")]
+pub struct ValidateConfigCommand {
+    /// Path to a custom L2 rollup configuration file to validate.
+    /// Defaults to the rollup configuration of the chain from the registry.
+    #[arg(long, visible_alias = "rollup-cfg")]
+    pub l2_config_file: Option<PathBuf>,
+}
+
+impl ValidateConfigCommand {
+    /// Runs the subcommand.
+    pub fn run(&self, args: &GlobalArgs) -> anyhow::Result<()> {
+        let cfg = self.rollup_config(args)?;
+        let chain_id = cfg.l2_chain_id;
+
+        match ROLLUP_CONFIGS.get(&chain_id.id()) {
+            Some(registry_cfg) => {
+                let diffs = cfg.diff(registry_cfg);
+                if diffs.is_empty() {
+                    println!("Rollup config for chain {chain_id} matches the superchain registry");
+                } else {
+                    println!(
+                        "Rollup config for chain {chain_id} differs from the superchain registry:"
+                    );
+                    for diff in diffs {
+                        println!("  {diff}");
+                    }
+                }
+            }
+            None => println!("Chain {chain_id} is not in the superchain registry, skipping diff"),
+        }
+
+        cfg.validate().with_context(|| format!("Invalid rollup config for chain {chain_id}"))?;
+        println!("Rollup config for chain {chain_id} is valid");
+        Ok(())
+    }
+
+    /// Returns the L2 rollup config, either from a file or the superchain registry.
+    fn rollup_config(&self, args: &GlobalArgs) -> anyhow::Result<RollupConfig> {
+        let cfg = match &self.l2_config_file {
+            Some(path) => {
+                debug!(target: "info", "Loading l2 config from file: {:?}", path);
+                let file = fs::File::open(path).context("Failed to open l2 config file")?;
+                serde_json::from_reader(file).context("Failed to parse l2 config")?
+            }
+            None => scr_rollup_config_by_alloy_ident(&args.l2_chain_id)
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Failed to find l2 config for chain ID {}", args.l2_chain_id)
+                })?
+                .clone(),
+        };
+        Ok(args.apply_overrides(cfg))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{InfoCommand, InfoSubcommand};
+
+    #[test]
+    fn test_parse_validate_config() {
+        let cmd = InfoCommand::try_parse_from([
+            "info",
+            "validate-config",
+            "--l2-config-file",
+            "cfg.json",
+        ])
+        .unwrap();
+        assert_eq!(
+            cmd.subcommand,
+            Some(InfoSubcommand::ValidateConfig(ValidateConfigCommand {
+                l2_config_file: Some(PathBuf::from("cfg.json")),
+            }))
+        );
+        assert_eq!(InfoCommand::try_parse_from(["info"]).unwrap().subcommand, None);
+    }
+
+    #[test]
+    fn test_validate_registry_config() {
+        let args = GlobalArgs { l2_chain_id: 10.into(), ..Default::default() };
+        ValidateConfigCommand::default().run(&args).unwrap();
+    }
+
+    #[test]
+    fn test_validate_invalid_config_file() {
+        let mut cfg = ROLLUP_CONFIGS.get(&10).cloned().unwrap();
+        cfg.hardforks.delta_time = cfg.hardforks.canyon_time.map(|t| t - 1);
+
+        let file = tempfile::NamedTempFile::new().unwrap();
+        serde_json::to_writer(file.as_file(), &cfg).unwrap();
+
+        let cmd = ValidateConfigCommand { l2_config_file: Some(file.path().to_path_buf()) };
+        let err = cmd.run(&GlobalArgs::default()).unwrap_err();
+        assert!(err.root_cause().to_string().contains("Delta activates"));
+    }
+}
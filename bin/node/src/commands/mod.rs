@@ -1,7 +1,7 @@
 //! Contains subcommands for the kona node.
 
 mod info;
-pub use info::InfoCommand;
+pub use info::{InfoCommand, InfoSubcommand, ValidateConfigCommand};
 
 mod node;
 pub use node::NodeCommand;
@@ -14,3 +14,9 @@ pub use net::NetCommand;
 
 mod registry;
 pub use registry::RegistryCommand;
+
+mod debug;
+pub use debug::{
+    BatchDecoder, BatchReport, BlockReport, ChannelReport, DebugCommand, DebugSubcommand,
+    DecodeBatchesCommand, DecodeReport, IncompleteChannel, OutputFormat,
+};
@@ -10,8 +10,10 @@ use backon::{ExponentialBuilder, Retryable};
 use clap::Parser;
 use kona_cli::{LogConfig, metrics_args::MetricsArgs};
 use kona_genesis::RollupConfig;
-use kona_node_service::{NodeMode, RollupNode, RollupNodeService};
+use kona_node_service::{InteropMode, NodeMode, RollupNode, RollupNodeService};
+use kona_protocol::DecompressorRegistry;
 use kona_registry::scr_rollup_config_by_alloy_ident;
+use kona_rpc::{DepositIndex, RpcBuilder, SystemConfigHistory};
 use op_alloy_provider::ext::engine::OpEngineApi;
 use serde_json::from_reader;
 use std::{fs::File, path::PathBuf, sync::Arc};
@@ -96,6 +98,30 @@ pub struct NodeCommand {
     /// (overrides the default rollup configuration from the registry)
     #[arg(long, visible_alias = "rollup-cfg", env = "KONA_NODE_ROLLUP_CONFIG")]
     pub l2_config_file: Option<PathBuf>,
+    /// The data directory to persist the system config history to. The history records every
+    /// system config update applied by the derivation pipeline, and is only kept in memory if
+    /// unset. The node refuses to start if the persisted history cannot be read.
+    #[arg(long = "system-config-history.datadir", env = "KONA_NODE_SYSTEM_CONFIG_HISTORY_DATADIR")]
+    pub system_config_history_datadir: Option<PathBuf>,
+    /// Index the deposit transactions derived from `OptimismPortal` events, served by the
+    /// `optimism_depositsByL1Tx` and `optimism_depositByL1Log` RPC methods.
+    #[arg(long = "deposit-index", default_value = "false", env = "KONA_NODE_DEPOSIT_INDEX")]
+    pub deposit_index: bool,
+    /// The L2 timestamp at which the derivation pipeline starts accepting zstd compressed
+    /// channels. Zstd is not part of the OP Stack protocol and is only meant for chains that
+    /// enable it through a custom hardfork. Such chains cannot be proven with `kona-client`.
+    #[cfg(feature = "zstd")]
+    #[arg(long = "zstd.activation-time", env = "KONA_NODE_ZSTD_ACTIVATION_TIME")]
+    pub zstd_activation_time: Option<u64>,
+    /// The channel version byte of zstd compressed channels.
+    #[cfg(feature = "zstd")]
+    #[arg(
+        long = "zstd.channel-version",
+        default_value_t = kona_protocol::ZstdDecompressor::DEFAULT_CHANNEL_VERSION,
+        requires = "zstd_activation_time",
+        env = "KONA_NODE_ZSTD_CHANNEL_VERSION"
+    )]
+    pub zstd_channel_version: u8,
     /// P2P CLI arguments.
     #[command(flatten)]
     pub p2p_flags: P2PArgs,
@@ -115,6 +141,12 @@ impl Default for NodeCommand {
             l2_engine_rpc: Url::parse("http://localhost:8551").unwrap(),
             l2_engine_jwt_secret: None,
             l2_config_file: None,
+            system_config_history_datadir: None,
+            deposit_index: false,
+            #[cfg(feature = "zstd")]
+            zstd_activation_time: None,
+            #[cfg(feature = "zstd")]
+            zstd_channel_version: kona_protocol::ZstdDecompressor::DEFAULT_CHANNEL_VERSION,
             node_mode: NodeMode::Validator,
             p2p_flags: P2PArgs::default(),
             rpc_flags: RpcArgs::default(),
@@ -249,7 +281,23 @@ impl NodeCommand {
 
         self.p2p_flags.check_ports()?;
         let p2p_config = self.p2p_flags.config(&cfg, args, Some(self.l1_eth_rpc.clone())).await?;
-        let rpc_config = self.rpc_flags.into();
+        let managed_rpc = self.rpc_flags.managed_config()?;
+        let interop_mode =
+            if managed_rpc.is_some() { InteropMode::Indexed } else { InteropMode::Polled };
+        let rpc_config = Option::<RpcBuilder>::from(self.rpc_flags)
+            .map(|rpc_config| RpcBuilder { managed: managed_rpc, ..rpc_config });
+        let system_config_history = self
+            .system_config_history_datadir
+            .map(|datadir| {
+                SystemConfigHistory::from_file(SystemConfigHistory::path(
+                    cfg.l2_chain_id.id(),
+                    &datadir,
+                ))
+            })
+            .transpose()?
+            .unwrap_or_default();
+        let decompressors = self.decompressors();
+        let sequencer_config = self.sequencer_flags.config(&cfg)?;
 
         info!(
             target: "rollup_node",
@@ -268,7 +316,11 @@ impl NodeCommand {
             .with_l2_engine_rpc_url(self.l2_engine_rpc)
             .with_p2p_config(p2p_config)
             .with_rpc_config(rpc_config)
-            .with_sequencer_config(self.sequencer_flags.config())
+            .with_interop_mode(interop_mode)
+            .with_sequencer_config(sequencer_config)
+            .with_system_config_history(system_config_history)
+            .with_deposit_index(self.deposit_index.then(DepositIndex::default))
+            .with_decompressors(decompressors)
             .build()
             .start()
             .await
@@ -280,6 +332,27 @@ impl NodeCommand {
         Ok(())
     }
 
+    /// Returns the [`DecompressorRegistry`] that the derivation pipeline decompresses channels
+    /// with, holding the zstd decompressor if it is enabled.
+    #[cfg(feature = "zstd")]
+    pub fn decompressors(&self) -> DecompressorRegistry {
+        let decompressors = DecompressorRegistry::new();
+        match self.zstd_activation_time {
+            Some(activation_time) => decompressors.with(kona_protocol::ZstdDecompressor::new(
+                self.zstd_channel_version,
+                Some(activation_time),
+            )),
+            None => decompressors,
+        }
+    }
+
+    /// Returns the [`DecompressorRegistry`] that the derivation pipeline decompresses channels
+    /// with.
+    #[cfg(not(feature = "zstd"))]
+    pub const fn decompressors(&self) -> DecompressorRegistry {
+        DecompressorRegistry::new()
+    }
+
     /// Get the L2 rollup config, either from a file or the superchain registry.
     pub fn get_l2_config(&self, args: &GlobalArgs) -> Result<RollupConfig> {
         match &self.l2_config_file {
@@ -367,6 +440,54 @@ mod tests {
     fn test_node_cli_defaults() {
         let args = NodeCommand::parse_from(["node"].iter().chain(default_flags().iter()).copied());
         assert_eq!(args.node_mode, NodeMode::Validator);
+        assert_eq!(args.system_config_history_datadir, None);
+        assert!(!args.deposit_index);
+    }
+
+    #[test]
+    fn test_node_cli_deposit_index() {
+        let args = NodeCommand::parse_from(
+            ["node", "--deposit-index"].iter().chain(default_flags().iter()).copied(),
+        );
+        assert!(args.deposit_index);
+    }
+
+    #[test]
+    fn test_node_cli_system_config_history() {
+        let args = NodeCommand::parse_from(
+            ["node", "--system-config-history.datadir", "/tmp/kona"]
+                .iter()
+                .chain(default_flags().iter())
+                .copied(),
+        );
+        assert_eq!(args.system_config_history_datadir, Some(PathBuf::from("/tmp/kona")));
+    }
+
+    #[test]
+    fn test_node_cli_default_decompressors() {
+        let args = NodeCommand::parse_from(["node"].iter().chain(default_flags().iter()).copied());
+        assert!(args.decompressors().get(2).is_none());
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_node_cli_zstd() {
+        let args = NodeCommand::parse_from(
+            ["node", "--zstd.activation-time", "100", "--zstd.channel-version", "3"]
+                .iter()
+                .chain(default_flags().iter())
+                .copied(),
+        );
+        let decompressors = args.decompressors();
+        let zstd = decompressors.get(3).unwrap();
+        assert!(!zstd.is_active(&RollupConfig::default(), 99));
+        assert!(zstd.is_active(&RollupConfig::default(), 100));
+
+        let err = NodeCommand::try_parse_from(
+            ["node", "--zstd.channel-version", "3"].iter().chain(default_flags().iter()).copied(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("--zstd.activation-time"));
     }
 
     #[test]
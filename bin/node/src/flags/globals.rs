@@ -5,6 +5,7 @@ use clap::Parser;
 use kona_cli::{log::LogArgs, metrics_args::MetricsArgs};
 use kona_genesis::RollupConfig;
 use kona_registry::OPCHAINS;
+use std::path::PathBuf;
 
 /// Global arguments for the CLI.
 #[derive(Parser, Default, Clone, Debug)]
@@ -23,6 +24,15 @@ pub struct GlobalArgs {
         help = "The L2 chain ID to use"
     )]
     pub l2_chain_id: alloy_chains::Chain,
+    /// Path to a superchain-registry checkout or a directory of custom chain configs to merge
+    /// over the built-in superchain registry.
+    #[arg(
+        long = "registry-path",
+        global = true,
+        env = "KONA_NODE_REGISTRY_PATH",
+        help = "Path to a superchain-registry checkout or a directory of custom chain configs"
+    )]
+    pub registry_path: Option<PathBuf>,
     /// Embed the override flags globally to provide override values adjacent to the configs.
     #[command(flatten)]
     pub override_args: super::OverrideArgs,
@@ -32,6 +42,18 @@ pub struct GlobalArgs {
 }
 
 impl GlobalArgs {
+    /// Merges the registry at `--registry-path`, if any, over the built-in superchain registry.
+    ///
+    /// Must be called before the registry is first accessed.
+    pub fn init_registry(&self) -> anyhow::Result<()> {
+        if let Some(path) = &self.registry_path {
+            kona_registry::set_registry_path(path).map_err(|e| {
+                anyhow::anyhow!("Failed to load superchain registry from {}: {e}", path.display())
+            })?;
+        }
+        Ok(())
+    }
+
     /// Applies the specified overrides to the given rollup config.
     ///
     /// Transforms the rollup config and returns the updated config with the overrides applied.
@@ -114,6 +136,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_registry_path() {
+        let args = GlobalArgs::try_parse_from(["test", "--registry-path", "/registry"]).unwrap();
+        assert_eq!(args.registry_path, Some(PathBuf::from("/registry")));
+        assert!(GlobalArgs::try_parse_from(["test"]).unwrap().registry_path.is_none());
+    }
+
     #[test]
     fn test_l2_chain_id_default() {
         // Test that the default value is chain ID 10 (Optimism)
@@ -12,7 +12,7 @@ use clap::Parser;
 use discv5::{Enr, enr::k256};
 use kona_genesis::RollupConfig;
 use kona_node_service::NetworkConfig;
-use kona_p2p::{GaterConfig, LocalNode};
+use kona_p2p::{ConnectionGater, GaterConfig, LocalNode};
 use kona_peers::{PeerMonitoring, PeerScoreLevel};
 use kona_sources::RuntimeLoader;
 use libp2p::identity::Keypair;
@@ -150,6 +150,14 @@ pub struct P2PArgs {
     #[arg(long = "p2p.ban.duration", default_value = "60", env = "KONA_NODE_P2P_BAN_DURATION")]
     pub ban_duration: u64,
 
+    /// An optional path to a JSON file overriding the peer scoring thresholds.
+    ///
+    /// The file can set the ban threshold (`banThreshold`), the ban duration in minutes
+    /// (`banDuration`) and the block topics score thresholds (`topics`). It is applied on startup
+    /// and can be reloaded at runtime with the `opp2p_reloadScoringConfig` RPC method.
+    #[arg(long = "p2p.scoring.config", env = "KONA_NODE_P2P_SCORING_CONFIG")]
+    pub scoring_config: Option<PathBuf>,
+
     /// The interval in seconds to find peers using the discovery service.
     /// Defaults to 5 seconds.
     #[arg(
@@ -177,6 +185,23 @@ pub struct P2PArgs {
     #[arg(long = "p2p.bootnodes", value_delimiter = ',', env = "KONA_NODE_P2P_BOOTNODES")]
     pub bootnodes: Vec<Enr>,
 
+    /// An optional list of static peers to always stay connected to.
+    ///
+    /// Static peers are multiaddresses that include the peer id (`/p2p/<peer id>`). They are
+    /// dialed on startup, redialed with a backoff when disconnected, and are trusted and
+    /// registered as explicit gossipsub peers. Explicit peering must be configured on both sides:
+    /// each node must list the other as a static peer.
+    #[arg(long = "p2p.static", value_delimiter = ',', env = "KONA_NODE_P2P_STATIC")]
+    pub static_peers: Vec<libp2p::Multiaddr>,
+
+    /// An optional list of trusted peer ids.
+    ///
+    /// Trusted peers are exempt from the redial limits, from score based bans and from ip address
+    /// and subnet blocks, and are never disconnected by the connection gater. Unlike static peers,
+    /// they are not registered as explicit gossipsub peers.
+    #[arg(long = "p2p.trusted", value_delimiter = ',', env = "KONA_NODE_P2P_TRUSTED")]
+    pub trusted_peers: Vec<libp2p::PeerId>,
+
     /// Optionally enable topic scoring.
     ///
     /// Topic scoring is a mechanism to score peers based on their behavior in the gossip network.
@@ -400,6 +425,13 @@ impl P2PArgs {
         let mut gossip_address = libp2p::Multiaddr::from(self.listen_ip);
         gossip_address.push(libp2p::multiaddr::Protocol::Tcp(self.listen_tcp_port));
 
+        // Static peers must include a peer id to be trusted and redialed.
+        if let Some(addr) =
+            self.static_peers.iter().find(|a| ConnectionGater::peer_id_from_addr(a).is_none())
+        {
+            anyhow::bail!("Static peer {addr} is missing a peer id (/p2p/<peer id>)");
+        }
+
         // The unsafe block signer obtained from the chain config.
         let chain_unsafe_block_signer = self.unsafe_block_signer(config, args, l1_rpc).await?;
 
@@ -413,6 +445,7 @@ impl P2PArgs {
             unsafe_block_signer: chain_unsafe_block_signer,
             gossip_config,
             scoring: self.scoring,
+            scoring_config: self.scoring_config,
             monitor_peers,
             bootstore: self.bootstore,
             topic_scoring: self.topic_scoring,
@@ -421,6 +454,8 @@ impl P2PArgs {
                 dial_period: Duration::from_secs(60 * self.redial_period),
             },
             bootnodes: self.bootnodes,
+            static_peers: self.static_peers,
+            trusted_peers: self.trusted_peers,
             rollup_config: config.clone(),
             gossip_signer: self.signer.config(args)?,
         })
@@ -512,6 +547,26 @@ mod tests {
         assert_eq!(args.p2p.discovery_randomize, None);
     }
 
+    #[test]
+    fn test_p2p_args_static_and_trusted_peers() {
+        let peer = "16Uiu2HAmKVVub7edwZ3RKDnqMpZVsusYW9TKRgbwpH54nvDWLE4x";
+        let static_peer = format!("/ip4/10.0.0.1/tcp/9222/p2p/{peer}");
+        let args = MockCommand::parse_from([
+            "test",
+            "--p2p.static",
+            &format!("{static_peer},/ip4/10.0.0.2/tcp/9222"),
+            "--p2p.trusted",
+            peer,
+        ]);
+        assert_eq!(args.p2p.static_peers.len(), 2);
+        assert_eq!(args.p2p.static_peers[0], static_peer.parse().unwrap());
+        assert_eq!(args.p2p.trusted_peers, vec![peer.parse().unwrap()]);
+
+        let args = MockCommand::parse_from(["test"]);
+        assert!(args.p2p.static_peers.is_empty());
+        assert!(args.p2p.trusted_peers.is_empty());
+    }
+
     #[test]
     fn test_p2p_args_no_discovery() {
         let args = MockCommand::parse_from(["test", "--p2p.no-discovery"]);
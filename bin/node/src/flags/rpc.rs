@@ -2,8 +2,9 @@
 //!
 //! Flags for configuring the RPC server.
 
+use alloy_rpc_types_engine::JwtSecret;
 use clap::Parser;
-use kona_rpc::RpcBuilder;
+use kona_rpc::{ManagedRpcConfig, RpcBuilder};
 use std::{
     net::{IpAddr, SocketAddr},
     path::PathBuf,
@@ -28,7 +29,7 @@ pub struct RpcArgs {
     #[arg(long = "rpc.enable-admin", env = "KONA_NODE_RPC_ENABLE_ADMIN")]
     pub enable_admin: bool,
     /// File path used to persist state changes made via the admin API so they persist across
-    /// restarts. Disabled if not set.
+    /// restarts. Disabled if not set. The node refuses to start if the file cannot be read.
     #[arg(long = "rpc.admin-state", env = "KONA_NODE_RPC_ADMIN_STATE")]
     pub admin_persistence: Option<PathBuf>,
     /// Enables websocket rpc server to track block production
@@ -37,6 +38,24 @@ pub struct RpcArgs {
     /// Enables development RPC endpoints for engine state introspection
     #[arg(long = "rpc.dev-enabled", default_value = "false", env = "KONA_NODE_RPC_DEV_ENABLED")]
     pub dev_enabled: bool,
+    /// Runs the node in managed mode, driven by an interop supervisor through the managed-mode
+    /// RPC.
+    #[arg(long = "interop.managed", default_value = "false", env = "KONA_NODE_INTEROP_MANAGED")]
+    pub interop_managed: bool,
+    /// Managed-mode RPC listening address.
+    #[arg(
+        long = "interop.rpc.addr",
+        default_value = "127.0.0.1",
+        env = "KONA_NODE_INTEROP_RPC_ADDR"
+    )]
+    pub interop_listen_addr: IpAddr,
+    /// Managed-mode RPC listening port.
+    #[arg(long = "interop.rpc.port", default_value = "9645", env = "KONA_NODE_INTEROP_RPC_PORT")]
+    pub interop_listen_port: u16,
+    /// Path to the hex-encoded JWT secret used to authenticate the supervisor on the managed-mode
+    /// RPC. Required in managed mode.
+    #[arg(long = "interop.jwt-secret", env = "KONA_NODE_INTEROP_JWT_SECRET")]
+    pub interop_jwt_secret: Option<PathBuf>,
 }
 
 impl Default for RpcArgs {
@@ -47,6 +66,32 @@ impl Default for RpcArgs {
     }
 }
 
+impl RpcArgs {
+    /// Returns the [`ManagedRpcConfig`] if the node runs in managed mode, reading the JWT secret
+    /// of the supervisor from its file.
+    pub fn managed_config(&self) -> anyhow::Result<Option<ManagedRpcConfig>> {
+        if !self.interop_managed {
+            return Ok(None);
+        }
+        if self.rpc_disabled {
+            anyhow::bail!("Managed mode requires the RPC server, which is disabled");
+        }
+        let path = self
+            .interop_jwt_secret
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Managed mode requires --interop.jwt-secret"))?;
+        let secret = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read interop JWT secret: {e}"))?;
+        let jwt_secret = JwtSecret::from_hex(secret.trim())
+            .map_err(|e| anyhow::anyhow!("Invalid interop JWT secret: {e}"))?;
+
+        Ok(Some(ManagedRpcConfig {
+            socket: SocketAddr::new(self.interop_listen_addr, self.interop_listen_port),
+            jwt_secret,
+        }))
+    }
+}
+
 impl From<RpcArgs> for Option<RpcBuilder> {
     fn from(args: RpcArgs) -> Self {
         if args.rpc_disabled {
@@ -59,6 +104,7 @@ impl From<RpcArgs> for Option<RpcBuilder> {
             admin_persistence: args.admin_persistence,
             ws_enabled: args.ws_enabled,
             dev_enabled: args.dev_enabled,
+            managed: None,
         })
     }
 }
@@ -77,6 +123,10 @@ mod tests {
     #[case::disable_rpc_alias(&["--rpc.port", "8743"], |args: &mut RpcArgs| { args.listen_port = 8743; })]
     #[case::disable_rpc(&["--rpc.enable-admin"], |args: &mut RpcArgs| { args.enable_admin = true; })]
     #[case::disable_rpc(&["--rpc.admin-state", "/"], |args: &mut RpcArgs| { args.admin_persistence = Some(PathBuf::from("/")); })]
+    #[case::interop_managed(&["--interop.managed"], |args: &mut RpcArgs| { args.interop_managed = true; })]
+    #[case::interop_rpc_addr(&["--interop.rpc.addr", "1.1.1.1"], |args: &mut RpcArgs| { args.interop_listen_addr = IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1)); })]
+    #[case::interop_rpc_port(&["--interop.rpc.port", "8743"], |args: &mut RpcArgs| { args.interop_listen_port = 8743; })]
+    #[case::interop_jwt_secret(&["--interop.jwt-secret", "/jwt.hex"], |args: &mut RpcArgs| { args.interop_jwt_secret = Some(PathBuf::from("/jwt.hex")); })]
     fn test_parse_rpc_args(#[case] args: &[&str], #[case] mutate: impl Fn(&mut RpcArgs)) {
         let args = [&["kona-node"], args].concat();
         let cli = RpcArgs::parse_from(args);
@@ -84,4 +134,24 @@ mod tests {
         mutate(&mut expected);
         assert_eq!(cli, expected);
     }
+
+    #[test]
+    fn test_managed_config() {
+        assert!(RpcArgs::default().managed_config().unwrap().is_none());
+
+        let args = RpcArgs { interop_managed: true, ..Default::default() };
+        assert!(args.managed_config().is_err());
+
+        let args = RpcArgs { interop_managed: true, rpc_disabled: true, ..Default::default() };
+        assert!(args.managed_config().is_err());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("jwt.hex");
+        std::fs::write(&path, alloy_primitives::hex::encode([1u8; 32])).unwrap();
+        let args =
+            RpcArgs { interop_managed: true, interop_jwt_secret: Some(path), ..Default::default() };
+        let config = args.managed_config().unwrap().unwrap();
+        assert_eq!(config.socket, SocketAddr::from(([127, 0, 0, 1], 9645)));
+        assert_eq!(config.jwt_secret.as_bytes(), &[1u8; 32]);
+    }
 }
@@ -4,9 +4,14 @@
 //!
 //! [op-node]: https://github.com/ethereum-optimism/optimism/blob/develop/op-node/flags/flags.go#L233-L265
 
-use clap::Parser;
-use kona_node_service::SequencerConfig;
-use std::{num::ParseIntError, time::Duration};
+use anyhow::{Result, bail};
+use clap::{Parser, ValueEnum};
+use kona_genesis::RollupConfig;
+use kona_node_service::{L1OriginPolicyKind, SequencerConfig};
+use std::{
+    num::ParseIntError,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 use url::Url;
 
 /// Sequencer CLI Flags
@@ -30,6 +35,35 @@ pub struct SequencerArgs {
     )]
     pub max_safe_lag: u64,
 
+    /// Number of L2 blocks between the L2 safe and unsafe heads above which the sequencer is
+    /// throttled, building empty blocks at half the block cadence until the safe head catches up.
+    /// Disabled if 0.
+    #[arg(
+        long = "sequencer.throttle-safe-lag",
+        default_value = "0",
+        env = "KONA_NODE_SEQUENCER_THROTTLE_SAFE_LAG"
+    )]
+    pub throttle_safe_lag: u64,
+
+    /// The policy deciding when the sequencer advances to the next L1 origin.
+    #[arg(
+        long = "sequencer.origin-policy",
+        value_enum,
+        default_value_t = OriginPolicy::FastestAllowed,
+        env = "KONA_NODE_SEQUENCER_ORIGIN_POLICY"
+    )]
+    pub origin_policy: OriginPolicy,
+
+    /// Minimum age, in seconds, of the next L1 origin relative to the next L2 block. Required by,
+    /// and only accepted with, the `fixed-lag` origin policy. Must be below the max sequencer
+    /// drift, past which the sequencer advances the L1 origin regardless of the policy.
+    #[arg(
+        long = "sequencer.origin-lag",
+        required_if_eq("origin_policy", "fixed-lag"),
+        env = "KONA_NODE_SEQUENCER_ORIGIN_LAG"
+    )]
+    pub origin_lag: Option<u64>,
+
     /// Number of L1 blocks to keep distance from the L1 head as a sequencer for picking an L1
     /// origin.
     #[arg(long = "sequencer.l1-confs", default_value = "4", env = "KONA_NODE_SEQUENCER_L1_CONFS")]
@@ -57,6 +91,18 @@ pub struct SequencerArgs {
     pub conductor_rpc_timeout: Duration,
 }
 
+/// The L1 origin selection policy of the sequencer.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OriginPolicy {
+    /// Advance to the next L1 origin as soon as it is allowed to.
+    #[default]
+    FastestAllowed,
+    /// Advance to the next L1 origin once it is half of the sequencer drift window old.
+    Conservative,
+    /// Advance to the next L1 origin once it is at least `--sequencer.origin-lag` seconds old.
+    FixedLag,
+}
+
 impl Default for SequencerArgs {
     fn default() -> Self {
         // Construct default values using the clap parser.
@@ -67,12 +113,80 @@ impl Default for SequencerArgs {
 
 impl SequencerArgs {
     /// Creates a [`SequencerConfig`] from the [`SequencerArgs`].
-    pub fn config(&self) -> SequencerConfig {
-        SequencerConfig {
+    ///
+    /// Fails if `--sequencer.origin-lag` is set without the `fixed-lag` origin policy, or is not
+    /// below the max sequencer drift of the given [`RollupConfig`] at the current time.
+    pub fn config(&self, cfg: &RollupConfig) -> Result<SequencerConfig> {
+        let origin_policy = match (self.origin_policy, self.origin_lag) {
+            (OriginPolicy::FixedLag, Some(lag)) => {
+                let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+                let max_drift = cfg.max_sequencer_drift(now);
+                if lag >= max_drift {
+                    bail!(
+                        "--sequencer.origin-lag ({lag}s) must be below the max sequencer drift ({max_drift}s)"
+                    );
+                }
+                L1OriginPolicyKind::FixedLag(lag)
+            }
+            (OriginPolicy::FixedLag, None) => {
+                bail!("--sequencer.origin-policy fixed-lag requires --sequencer.origin-lag")
+            }
+            (_, Some(_)) => {
+                bail!("--sequencer.origin-lag requires --sequencer.origin-policy fixed-lag")
+            }
+            (OriginPolicy::FastestAllowed, None) => L1OriginPolicyKind::FastestAllowed,
+            (OriginPolicy::Conservative, None) => L1OriginPolicyKind::Conservative,
+        };
+
+        Ok(SequencerConfig {
             sequencer_stopped: self.stopped,
             sequencer_recovery_mode: self.recover,
             conductor_rpc_url: self.conductor_rpc.clone(),
             l1_conf_delay: self.l1_confs,
-        }
+            admin_persistence: None,
+            origin_policy,
+            max_safe_lag: self.max_safe_lag,
+            throttle_safe_lag: self.throttle_safe_lag,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(args: &[&str]) -> Result<SequencerConfig> {
+        let cfg = RollupConfig { max_sequencer_drift: 600, ..Default::default() };
+        SequencerArgs::try_parse_from(["sequencer"].iter().chain(args))?.config(&cfg)
+    }
+
+    #[test]
+    fn test_origin_policy_fixed_lag() {
+        let config =
+            config(&["--sequencer.origin-policy", "fixed-lag", "--sequencer.origin-lag", "300"])
+                .unwrap();
+        assert_eq!(config.origin_policy, L1OriginPolicyKind::FixedLag(300));
+    }
+
+    #[test]
+    fn test_origin_lag_requires_fixed_lag_policy() {
+        assert!(config(&["--sequencer.origin-lag", "300"]).is_err());
+        assert!(
+            config(&["--sequencer.origin-policy", "conservative", "--sequencer.origin-lag", "300"])
+                .is_err()
+        );
+        assert!(config(&["--sequencer.origin-policy", "fixed-lag"]).is_err());
+    }
+
+    #[test]
+    fn test_origin_lag_below_max_sequencer_drift() {
+        assert!(
+            config(&["--sequencer.origin-policy", "fixed-lag", "--sequencer.origin-lag", "600"])
+                .is_err()
+        );
+        assert!(
+            config(&["--sequencer.origin-policy", "fixed-lag", "--sequencer.origin-lag", "599"])
+                .is_ok()
+        );
     }
 }
@@ -1,18 +1,23 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
 use alloy_primitives::{Address, B256};
 use alloy_signer::{Signer, k256::ecdsa};
 use alloy_signer_local::PrivateKeySigner;
 use clap::{Parser, arg};
-use kona_sources::{BlockSigner, ClientCert, RemoteSigner};
+use kona_sources::{
+    BlockSigner, ClientCert, KeystorePassword, KeystoreSigner, RemoteSigner, RemoteSignerGroup,
+};
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use std::str::FromStr;
 use url::Url;
 
 use crate::flags::GlobalArgs;
 
+/// The environment variable the keystore password is read from, if no password file is provided.
+const KEYSTORE_PASSWORD_ENV: &str = "KONA_NODE_P2P_SEQUENCER_KEYSTORE_PASSWORD";
+
 /// Signer CLI Flags
-#[derive(Debug, Clone, Parser, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Parser, PartialEq, Eq)]
 pub struct SignerArgs {
     /// An optional flag to specify a local private key for the sequencer to sign unsafe blocks.
     #[arg(
@@ -21,22 +26,66 @@ pub struct SignerArgs {
         conflicts_with = "endpoint"
     )]
     pub sequencer_key: Option<B256>,
-    /// The URL of the remote signer endpoint. If not provided, remote signer will be disabled.
+    /// An optional path to an encrypted JSON keystore holding the key for the sequencer to sign
+    /// unsafe blocks. This is mutually exclusive with `p2p.sequencer.key` and
+    /// `p2p.signer.endpoint`.
+    #[arg(
+        long = "p2p.sequencer.keystore",
+        env = "KONA_NODE_P2P_SEQUENCER_KEYSTORE",
+        conflicts_with_all = ["sequencer_key", "endpoint"]
+    )]
+    pub keystore: Option<PathBuf>,
+    /// An optional path to the file holding the password of `p2p.sequencer.keystore`. If not
+    /// provided, the password is read from the `KONA_NODE_P2P_SEQUENCER_KEYSTORE_PASSWORD`
+    /// environment variable.
+    #[arg(
+        long = "p2p.sequencer.keystore.password-file",
+        env = "KONA_NODE_P2P_SEQUENCER_KEYSTORE_PASSWORD_FILE",
+        requires = "keystore"
+    )]
+    pub keystore_password_file: Option<PathBuf>,
+    /// The URLs of the remote signer endpoints. If not provided, remote signer will be disabled.
     /// This is mutually exclusive with `p2p.sequencer.key`.
     /// This is required if any of the other signer flags are provided.
+    ///
+    /// When several endpoints are provided, in order of preference, the node fails over between
+    /// them. When using env vars, split with commas.
     #[arg(
         long = "p2p.signer.endpoint",
         env = "KONA_NODE_P2P_SIGNER_ENDPOINT",
+        value_delimiter = ',',
         requires = "address"
     )]
-    pub endpoint: Option<Url>,
+    pub endpoint: Vec<Url>,
     /// The address to sign transactions for. Required if `signer.endpoint` is provided.
+    ///
+    /// Either a single address for all the endpoints, or one address per endpoint. Blocks are
+    /// signed by the endpoints of the current unsafe block signer address, so that the node
+    /// follows signer rotations. When using env vars, split with commas.
     #[arg(
         long = "p2p.signer.address",
         env = "KONA_NODE_P2P_SIGNER_ADDRESS",
+        value_delimiter = ',',
         requires = "endpoint"
     )]
-    pub address: Option<Address>,
+    pub address: Vec<Address>,
+    /// The interval between two health probes of the remote signers, in seconds.
+    #[arg(
+        long = "p2p.signer.health-interval",
+        env = "KONA_NODE_P2P_SIGNER_HEALTH_INTERVAL",
+        default_value = "10",
+        value_parser = |arg: &str| -> Result<Duration, std::num::ParseIntError> {Ok(Duration::from_secs(arg.parse()?))}
+    )]
+    pub health_interval: Duration,
+    /// The time a remote signer has to answer a health probe or a signing request before the node
+    /// fails over to the next one, in milliseconds.
+    #[arg(
+        long = "p2p.signer.timeout",
+        env = "KONA_NODE_P2P_SIGNER_TIMEOUT",
+        default_value = "1000",
+        value_parser = |arg: &str| -> Result<Duration, std::num::ParseIntError> {Ok(Duration::from_millis(arg.parse()?))}
+    )]
+    pub timeout: Duration,
     /// Headers to pass to the remote signer. Format `key=value`. Value can contain any character
     /// allowed in a HTTP header. When using env vars, split with commas. When using flags one
     /// key value pair per flag.
@@ -65,18 +114,39 @@ pub struct SignerArgs {
     pub key: Option<PathBuf>,
 }
 
+impl Default for SignerArgs {
+    fn default() -> Self {
+        // Construct default values using the clap parser.
+        // This works since none of the cli flags are required.
+        Self::parse_from::<[_; 0], &str>([])
+    }
+}
+
 /// Errors that can occur when parsing the signer arguments.
 #[derive(Debug, thiserror::Error)]
 pub enum SignerArgsParseError {
     /// The local sequencer key and remote signer cannot be specified at the same time.
     #[error("A local sequencer key and a remote signer cannot be specified at the same time.")]
     LocalAndRemoteSigner,
+    /// A keystore cannot be specified along with a local sequencer key or a remote signer.
+    #[error("A keystore cannot be specified along with a local sequencer key or a remote signer.")]
+    KeystoreAndOtherSigner,
     /// The sequencer key is invalid.
     #[error("The sequencer key is invalid.")]
     SequencerKeyInvalid(#[from] ecdsa::Error),
     /// The address is required if `signer.endpoint` is provided.
     #[error("The address is required if `signer.endpoint` is provided.")]
     AddressRequired,
+    /// The number of addresses does not match the number of endpoints.
+    #[error(
+        "Expected a single address or one address per endpoint, got {addresses} for {endpoints} endpoints."
+    )]
+    AddressCountMismatch {
+        /// The number of addresses.
+        addresses: usize,
+        /// The number of endpoints.
+        endpoints: usize,
+    },
     /// The header is invalid.
     #[error("The header is invalid.")]
     InvalidHeader,
@@ -94,6 +164,14 @@ pub enum SignerArgsParseError {
 impl SignerArgs {
     /// Creates a [`BlockSigner`] from the [`SignerArgs`].
     pub fn config(self, args: &GlobalArgs) -> Result<Option<BlockSigner>, SignerArgsParseError> {
+        let keystore = self.config_keystore(args);
+        if keystore.is_some() && (self.sequencer_key.is_some() || !self.endpoint.is_empty()) {
+            return Err(SignerArgsParseError::KeystoreAndOtherSigner);
+        }
+        if let Some(keystore) = keystore {
+            return Ok(Some(keystore.into()));
+        }
+
         // The sequencer signer obtained from the CLI arguments.
         let gossip_signer: Option<BlockSigner> = match (self.sequencer_key, self.config_remote()?) {
             (Some(_), Some(_)) => return Err(SignerArgsParseError::LocalAndRemoteSigner),
@@ -110,14 +188,32 @@ impl SignerArgs {
         Ok(gossip_signer)
     }
 
-    /// Creates a [`RemoteSigner`] from the [`SignerArgs`].
-    fn config_remote(self) -> Result<Option<RemoteSigner>, SignerArgsParseError> {
-        let Some(endpoint) = self.endpoint else {
-            return Ok(None);
+    /// Creates a [`KeystoreSigner`] from the [`SignerArgs`].
+    fn config_keystore(&self, args: &GlobalArgs) -> Option<KeystoreSigner> {
+        let keystore = self.keystore.clone()?;
+        let password = match &self.keystore_password_file {
+            Some(file) => KeystorePassword::File(file.clone()),
+            None => KeystorePassword::Env(KEYSTORE_PASSWORD_ENV.to_string()),
         };
+        Some(KeystoreSigner::new(keystore, password).with_chain_id(Some(args.l2_chain_id.into())))
+    }
+
+    /// Creates a [`RemoteSignerGroup`] from the [`SignerArgs`].
+    fn config_remote(self) -> Result<Option<RemoteSignerGroup>, SignerArgsParseError> {
+        if self.endpoint.is_empty() {
+            return Ok(None);
+        }
 
-        let Some(address) = self.address else {
-            return Err(SignerArgsParseError::AddressRequired);
+        let addresses = match self.address.as_slice() {
+            [] => return Err(SignerArgsParseError::AddressRequired),
+            [address] => vec![*address; self.endpoint.len()],
+            addresses if addresses.len() == self.endpoint.len() => addresses.to_vec(),
+            addresses => {
+                return Err(SignerArgsParseError::AddressCountMismatch {
+                    addresses: addresses.len(),
+                    endpoints: self.endpoint.len(),
+                });
+            }
         };
 
         let headers = self
@@ -140,12 +236,115 @@ impl SignerArgs {
             })
             .transpose()?;
 
-        Ok(Some(RemoteSigner {
-            address,
-            endpoint,
-            ca_cert: self.ca_cert.clone(),
-            client_cert,
-            headers,
-        }))
+        let signers = self
+            .endpoint
+            .iter()
+            .zip(addresses)
+            .map(|(endpoint, address)| RemoteSigner {
+                address,
+                endpoint: endpoint.clone(),
+                ca_cert: self.ca_cert.clone(),
+                client_cert: client_cert.clone(),
+                headers: headers.clone(),
+            })
+            .collect();
+
+        Ok(Some(
+            RemoteSignerGroup::new(signers)
+                .with_health_check_interval(self.health_interval)
+                .with_request_timeout(self.timeout),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_remote_single_address() {
+        let args = SignerArgs::parse_from([
+            "kona-node",
+            "--p2p.signer.endpoint",
+            "http://signer-1:8080,http://signer-2:8080",
+            "--p2p.signer.address",
+            "0x0000000000000000000000000000000000000001",
+        ]);
+        let group = args.config_remote().unwrap().unwrap();
+        assert_eq!(group.signers.len(), 2);
+        assert!(group.signers.iter().all(|s| s.address == Address::with_last_byte(1)));
+        assert_eq!(group.health_check_interval, Duration::from_secs(10));
+        assert_eq!(group.request_timeout, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_config_remote_address_per_endpoint() {
+        let args = SignerArgs::parse_from([
+            "kona-node",
+            "--p2p.signer.endpoint",
+            "http://signer-1:8080",
+            "--p2p.signer.endpoint",
+            "http://signer-2:8080",
+            "--p2p.signer.address",
+            "0x0000000000000000000000000000000000000001",
+            "--p2p.signer.address",
+            "0x0000000000000000000000000000000000000002",
+            "--p2p.signer.health-interval",
+            "5",
+            "--p2p.signer.timeout",
+            "500",
+        ]);
+        let group = args.config_remote().unwrap().unwrap();
+        let addresses: Vec<_> = group.signers.iter().map(|s| s.address).collect();
+        assert_eq!(addresses, [Address::with_last_byte(1), Address::with_last_byte(2)]);
+        assert_eq!(group.health_check_interval, Duration::from_secs(5));
+        assert_eq!(group.request_timeout, Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_config_keystore() {
+        let global = GlobalArgs { l2_chain_id: 10.into(), ..Default::default() };
+        let args = SignerArgs::parse_from(["kona-node", "--p2p.sequencer.keystore", "/key.json"]);
+        let signer = args.config_keystore(&global).unwrap();
+        assert_eq!(signer.keystore, PathBuf::from("/key.json"));
+        assert_eq!(signer.password, KeystorePassword::Env(KEYSTORE_PASSWORD_ENV.to_string()));
+        assert_eq!(signer.chain_id, Some(10));
+
+        let args = SignerArgs::parse_from([
+            "kona-node",
+            "--p2p.sequencer.keystore",
+            "/key.json",
+            "--p2p.sequencer.keystore.password-file",
+            "/password.txt",
+        ]);
+        let signer = args.config_keystore(&global).unwrap();
+        assert_eq!(signer.password, KeystorePassword::File(PathBuf::from("/password.txt")));
+
+        assert!(SignerArgs::default().config_keystore(&global).is_none());
+        assert!(
+            SignerArgs::try_parse_from([
+                "kona-node",
+                "--p2p.sequencer.keystore",
+                "/key.json",
+                "--p2p.signer.endpoint",
+                "http://signer:8080",
+                "--p2p.signer.address",
+                "0x0000000000000000000000000000000000000001",
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_config_remote_address_count_mismatch() {
+        let args = SignerArgs {
+            endpoint: vec!["http://signer-1:8080".parse().unwrap()],
+            address: vec![Address::with_last_byte(1), Address::with_last_byte(2)],
+            ..Default::default()
+        };
+        assert!(matches!(
+            args.config_remote(),
+            Err(SignerArgsParseError::AddressCountMismatch { addresses: 2, endpoints: 1 })
+        ));
     }
 }
@@ -29,6 +29,7 @@ impl Cli {
     pub fn run(self) -> Result<()> {
         self.metrics.init_metrics()?;
         self.init_logs(&self.global)?;
+        self.supervisor.init_registry()?;
 
         Self::run_until_ctrl_c(async move {
             let config = self.supervisor.init_config().await?;
@@ -6,6 +6,7 @@ use glob::glob;
 use kona_genesis::RollupConfig;
 use kona_interop::DependencySet;
 use kona_protocol::BlockInfo;
+use kona_registry::ROLLUP_CONFIGS;
 use kona_supervisor_core::{
     config::{Config, RollupConfigSet},
     syncnode::ClientConfig,
@@ -54,6 +55,12 @@ pub struct SupervisorArgs {
     #[arg(long = "rollup-config-paths", env = "ROLLUP_CONFIG_PATHS")]
     pub rollup_config_paths: PathBuf,
 
+    /// Path to a superchain-registry checkout or a directory of custom chain configs to merge
+    /// over the built-in superchain registry. The rollup configs of chains in the dependency set
+    /// that are not loaded from `--rollup-config-paths` are resolved from the registry.
+    #[arg(long = "registry-path", env = "REGISTRY_PATH")]
+    pub registry_path: Option<PathBuf>,
+
     /// IP address for the Supervisor RPC server to listen on.
     #[arg(long = "rpc.addr", env = "RPC_ADDR", default_value = "0.0.0.0")]
     pub rpc_address: IpAddr,
@@ -64,6 +71,18 @@ pub struct SupervisorArgs {
 }
 
 impl SupervisorArgs {
+    /// Merges the registry at `--registry-path`, if any, over the built-in superchain registry.
+    ///
+    /// Must be called before the registry is first accessed.
+    pub fn init_registry(&self) -> Result<()> {
+        if let Some(path) = &self.registry_path {
+            kona_registry::set_registry_path(path).with_context(|| {
+                format!("Failed to load superchain registry from '{}'", path.display())
+            })?;
+        }
+        Ok(())
+    }
+
     async fn read_json_file<T: DeserializeOwned>(path: &Path) -> Result<T> {
         let mut file = File::open(path)
             .await
@@ -100,8 +119,25 @@ impl SupervisorArgs {
         Ok(rollup_configs)
     }
 
-    /// Initialise and return the rollup config set.
-    pub async fn init_rollup_config_set(&self) -> Result<RollupConfigSet> {
+    /// Returns the rollup configs of the chains in the [`DependencySet`] that are missing from
+    /// `loaded`, resolved from the superchain registry.
+    fn registry_rollup_configs(
+        dependency_set: &DependencySet,
+        loaded: &[RollupConfig],
+    ) -> Vec<RollupConfig> {
+        dependency_set
+            .dependencies
+            .keys()
+            .filter(|chain_id| !loaded.iter().any(|cfg| cfg.l2_chain_id.id() == **chain_id))
+            .filter_map(|chain_id| ROLLUP_CONFIGS.get(chain_id).cloned())
+            .collect()
+    }
+
+    /// Initialise and return the rollup config set for the chains in the [`DependencySet`].
+    pub async fn init_rollup_config_set(
+        &self,
+        dependency_set: &DependencySet,
+    ) -> Result<RollupConfigSet> {
         let l1_url = self
             .l1_rpc
             .parse()
@@ -110,8 +146,9 @@ impl SupervisorArgs {
 
         let mut rollup_config_set = RollupConfigSet::default();
 
-        // Use the helper to get all configs
-        let rollup_configs = self.get_rollup_configs().await?;
+        // Use the helper to get all configs, falling back to the registry for missing chains.
+        let mut rollup_configs = self.get_rollup_configs().await?;
+        rollup_configs.extend(Self::registry_rollup_configs(dependency_set, &rollup_configs));
 
         for rollup_config in rollup_configs {
             let chain_id = rollup_config.l2_chain_id;
@@ -168,7 +205,7 @@ impl SupervisorArgs {
     /// initialise and return the Supervisor [`Config`].
     pub async fn init_config(&self) -> Result<Config> {
         let dependency_set = self.init_dependency_set().await?;
-        let rollup_config_set = self.init_rollup_config_set().await?;
+        let rollup_config_set = self.init_rollup_config_set(&dependency_set).await?;
 
         let rpc_addr = SocketAddr::new(self.rpc_address, self.rpc_port);
         let managed_nodes_config = self.init_managed_nodes_config()?;
@@ -253,6 +290,8 @@ mod tests {
             "/path/to/deps.json",
             "--rollup-config-paths",
             "/configs/rollup-*.json",
+            "--registry-path",
+            "/registry",
             "--rpc.addr",
             "192.168.1.100",
             "--rpc.port",
@@ -269,6 +308,7 @@ mod tests {
         );
         assert_eq!(cli.supervisor.dependency_set, PathBuf::from("/path/to/deps.json"));
         assert_eq!(cli.supervisor.rollup_config_paths, PathBuf::from("/configs/rollup-*.json"));
+        assert_eq!(cli.supervisor.registry_path, Some(PathBuf::from("/registry")));
         assert_eq!(cli.supervisor.rpc_address, IpAddr::V4(Ipv4Addr::new(192, 168, 1, 100)));
         assert_eq!(cli.supervisor.rpc_port, 9001);
     }
@@ -303,6 +343,7 @@ mod tests {
             datadir_sync_endpoint: None,
             dependency_set: temp_file.path().to_path_buf(),
             rollup_config_paths: PathBuf::from("dummy/rollup_config_*.json"),
+            registry_path: None,
             rpc_address: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
             rpc_port: 8545,
         };
@@ -334,6 +375,7 @@ mod tests {
             datadir_sync_endpoint: None,
             dependency_set: PathBuf::from("/path/to/non_existent_file.json"),
             rollup_config_paths: PathBuf::from("dummy/rollup_config_*.json"),
+            registry_path: None,
             rpc_address: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
             rpc_port: 8545,
         };
@@ -359,6 +401,7 @@ mod tests {
             datadir_sync_endpoint: None,
             dependency_set: temp_file.path().to_path_buf(),
             rollup_config_paths: PathBuf::from("dummy/rollup_config_*.json"),
+            registry_path: None,
             rpc_address: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
             rpc_port: 8545,
         };
@@ -432,6 +475,7 @@ mod tests {
             datadir_sync_endpoint: None,
             dependency_set: PathBuf::from("dummy.json"),
             rollup_config_paths: dir.path().join("rollup-*.json"),
+            registry_path: None,
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
         };
@@ -453,6 +497,7 @@ mod tests {
             datadir_sync_endpoint: None,
             dependency_set: PathBuf::from("dummy.json"),
             rollup_config_paths: dir.path().join("rollup-*.json"),
+            registry_path: None,
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
         };
@@ -477,6 +522,7 @@ mod tests {
             datadir_sync_endpoint: None,
             dependency_set: PathBuf::from("dummy.json"),
             rollup_config_paths: dir.path().join("rollup-*.json"),
+            registry_path: None,
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
         };
@@ -496,6 +542,7 @@ mod tests {
             datadir_sync_endpoint: None,
             dependency_set: PathBuf::from("dummy.json"),
             rollup_config_paths: PathBuf::from(""),
+            registry_path: None,
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
         };
@@ -515,6 +562,7 @@ mod tests {
             datadir_sync_endpoint: None,
             dependency_set: PathBuf::from("dummy.json"),
             rollup_config_paths: PathBuf::from("dummy/rollup_config_*.json"),
+            registry_path: None,
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
         };
@@ -604,6 +652,7 @@ mod tests {
             datadir_sync_endpoint: None,
             dependency_set: dep_file.path().to_path_buf(),
             rollup_config_paths: rollup_dir.path().join("rollup-*.json"),
+            registry_path: None,
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
         };
@@ -618,4 +667,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_registry_rollup_configs() {
+        let mut dependencies = HashMap::default();
+        dependencies.insert(10, ChainDependency {});
+        dependencies.insert(8453, ChainDependency {});
+        dependencies.insert(999999, ChainDependency {});
+        let dependency_set = DependencySet { dependencies, override_message_expiry_window: None };
+
+        let loaded = vec![ROLLUP_CONFIGS.get(&10).cloned().unwrap()];
+        let configs = SupervisorArgs::registry_rollup_configs(&dependency_set, &loaded);
+        assert_eq!(configs.len(), 1);
+        assert_eq!(configs[0].l2_chain_id.id(), 8453);
+    }
 }
@@ -40,10 +40,10 @@ extern crate tracing;
 
 mod task_queue;
 pub use task_queue::{
-    BuildTask, BuildTaskError, ConsolidateTask, ConsolidateTaskError, Engine, EngineBuildError,
-    EngineResetError, EngineTask, EngineTaskError, EngineTaskErrorSeverity, EngineTaskErrors,
-    EngineTaskExt, FinalizeTask, FinalizeTaskError, InsertTask, InsertTaskError, SynchronizeTask,
-    SynchronizeTaskError,
+    BuildTask, BuildTaskError, ConsolidateTask, ConsolidateTaskError, CrossUpdate, CrossUpdateTask,
+    CrossUpdateTaskError, Engine, EngineBuildError, EngineResetError, EngineTask, EngineTaskError,
+    EngineTaskErrorSeverity, EngineTaskErrors, EngineTaskExt, FinalizeTask, FinalizeTaskError,
+    InsertTask, InsertTaskError, SynchronizeTask, SynchronizeTaskError,
 };
 
 mod attributes;
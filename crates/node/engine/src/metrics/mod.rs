@@ -59,6 +59,8 @@ impl Metrics {
     pub const BUILD_TASK_LABEL: &str = "build";
     /// Finalize task label.
     pub const FINALIZE_TASK_LABEL: &str = "finalize";
+    /// Cross-safety update task label.
+    pub const CROSS_UPDATE_TASK_LABEL: &str = "cross-update";
 
     /// Identifier for the histogram that tracks engine method call time.
     pub const ENGINE_METHOD_REQUEST_DURATION: &str = "kona_node_engine_method_request_duration";
@@ -117,11 +119,13 @@ impl Metrics {
         kona_macros::set!(counter, Self::ENGINE_TASK_SUCCESS, Self::CONSOLIDATE_TASK_LABEL, 0);
         kona_macros::set!(counter, Self::ENGINE_TASK_SUCCESS, Self::BUILD_TASK_LABEL, 0);
         kona_macros::set!(counter, Self::ENGINE_TASK_SUCCESS, Self::FINALIZE_TASK_LABEL, 0);
+        kona_macros::set!(counter, Self::ENGINE_TASK_SUCCESS, Self::CROSS_UPDATE_TASK_LABEL, 0);
 
         kona_macros::set!(counter, Self::ENGINE_TASK_FAILURE, Self::INSERT_TASK_LABEL, 0);
         kona_macros::set!(counter, Self::ENGINE_TASK_FAILURE, Self::CONSOLIDATE_TASK_LABEL, 0);
         kona_macros::set!(counter, Self::ENGINE_TASK_FAILURE, Self::BUILD_TASK_LABEL, 0);
         kona_macros::set!(counter, Self::ENGINE_TASK_FAILURE, Self::FINALIZE_TASK_LABEL, 0);
+        kona_macros::set!(counter, Self::ENGINE_TASK_FAILURE, Self::CROSS_UPDATE_TASK_LABEL, 0);
 
         // Engine reset count
         kona_macros::set!(counter, Self::ENGINE_RESET_COUNT, 0);
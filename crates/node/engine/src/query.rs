@@ -7,10 +7,12 @@
 use std::sync::Arc;
 
 use alloy_eips::BlockNumberOrTag;
+use alloy_primitives::B256;
 use alloy_provider::Provider;
 use alloy_transport::{RpcError, TransportErrorKind};
 use kona_genesis::RollupConfig;
-use kona_protocol::{L2BlockInfo, OutputRoot, Predeploys};
+use kona_protocol::{BlockInfo, L2BlockInfo, OutputRoot, Predeploys};
+use op_alloy_consensus::OpReceiptEnvelope;
 use tokio::sync::oneshot::Sender;
 
 use crate::{EngineClient, EngineClientError, EngineState};
@@ -38,6 +40,27 @@ pub enum EngineQueries {
         /// Response channel for (block_info, output_root, engine_state).
         sender: Sender<(L2BlockInfo, OutputRoot, EngineState)>,
     },
+    /// Request the [`L2BlockInfo`] of a specific L2 block, if it exists.
+    L2BlockRef {
+        /// The block number or tag to retrieve.
+        block: BlockNumberOrTag,
+        /// Response channel for the block info.
+        sender: Sender<Option<L2BlockInfo>>,
+    },
+    /// Request the [`BlockInfo`] of a specific L1 block, if it exists.
+    L1BlockRef {
+        /// The block number or tag to retrieve.
+        block: BlockNumberOrTag,
+        /// Response channel for the block info.
+        sender: Sender<Option<BlockInfo>>,
+    },
+    /// Request the receipts of a specific L2 block, if it exists.
+    Receipts {
+        /// The hash of the block to retrieve the receipts for.
+        block_hash: B256,
+        /// Response channel for the receipts.
+        sender: Sender<Option<Vec<OpReceiptEnvelope>>>,
+    },
     /// Subscribe to engine state updates via a watch channel receiver.
     StateReceiver(Sender<tokio::sync::watch::Receiver<EngineState>>),
     /// Development API: Subscribe to task queue length updates.
@@ -126,6 +149,28 @@ impl EngineQueries {
                     .send((output_block_info, output_response_v0, state))
                     .map_err(|_| EngineQueriesError::OutputChannelClosed)
             }
+            Self::L2BlockRef { block, sender } => {
+                let block_info = client.l2_block_info_by_label(block).await?;
+                sender.send(block_info).map_err(|_| EngineQueriesError::OutputChannelClosed)
+            }
+            Self::L1BlockRef { block, sender } => {
+                let block_info = client
+                    .l1_provider()
+                    .get_block(block.into())
+                    .await?
+                    .map(|block| block.into_consensus().into());
+                sender.send(block_info).map_err(|_| EngineQueriesError::OutputChannelClosed)
+            }
+            Self::Receipts { block_hash, sender } => {
+                let receipts = client.l2_engine().get_block_receipts(block_hash.into()).await?;
+                let receipts = receipts.map(|receipts| {
+                    receipts
+                        .into_iter()
+                        .map(|receipt| receipt.inner.inner.map_logs(|log| log.inner))
+                        .collect()
+                });
+                sender.send(receipts).map_err(|_| EngineQueriesError::OutputChannelClosed)
+            }
             Self::StateReceiver(subscription) => subscription
                 .send(state_recv.clone())
                 .map_err(|_| EngineQueriesError::OutputChannelClosed),
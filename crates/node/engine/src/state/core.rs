@@ -158,6 +158,12 @@ pub struct EngineState {
     /// because engine may forgot backupUnsafeHead or backupUnsafeHead is not part
     /// of the chain.
     pub need_fcu_call_backup_unsafe_reorg: bool,
+
+    /// Whether the cross-verified heads are managed by an interop supervisor.
+    ///
+    /// When set, inserted and derived blocks only advance the unsafe and local-safe heads. The
+    /// cross-unsafe, safe and finalized heads are advanced by [`crate::CrossUpdateTask`]s instead.
+    pub managed: bool,
 }
 
 impl EngineState {
@@ -169,7 +175,7 @@ impl EngineState {
     ///
     /// [Consolidation]: https://specs.optimism.io/protocol/derivation.html#l1-consolidation-payload-attributes-matching
     pub fn needs_consolidation(&self) -> bool {
-        self.sync_state.safe_head() != self.sync_state.unsafe_head()
+        self.sync_state.local_safe_head() != self.sync_state.unsafe_head()
     }
 }
 
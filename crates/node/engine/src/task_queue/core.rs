@@ -81,16 +81,12 @@ impl Engine {
         client: Arc<EngineClient>,
         config: Arc<RollupConfig>,
     ) -> Result<(L2BlockInfo, BlockInfo, SystemConfig), EngineResetError> {
-        // Clear any outstanding tasks to prepare for the reset.
-        self.clear();
-
         let start =
             find_starting_forkchoice(&config, client.l1_provider(), client.l2_engine()).await?;
 
-        // Retry to synchronize the engine until we succeeds or a critical error occurs.
-        while let Err(err) = SynchronizeTask::new(
-            client.clone(),
-            config.clone(),
+        self.reset_to(
+            client,
+            config,
             EngineSyncStateUpdate {
                 unsafe_head: Some(start.un_safe),
                 cross_unsafe_head: Some(start.un_safe),
@@ -99,8 +95,28 @@ impl Engine {
                 finalized_head: Some(start.finalized),
             },
         )
-        .execute(&mut self.state)
         .await
+    }
+
+    /// Resets the engine to the heads of the given [`EngineSyncStateUpdate`], keeping the current
+    /// heads that are not set. A forkchoice update is dispatched in order to reorg the execution
+    /// layer.
+    ///
+    /// Returns the new local-safe head, along with the L1 block to restart derivation from and the
+    /// [`SystemConfig`] at the local-safe head.
+    pub async fn reset_to(
+        &mut self,
+        client: Arc<EngineClient>,
+        config: Arc<RollupConfig>,
+        heads: EngineSyncStateUpdate,
+    ) -> Result<(L2BlockInfo, BlockInfo, SystemConfig), EngineResetError> {
+        // Clear any outstanding tasks to prepare for the reset.
+        self.clear();
+
+        // Retry to synchronize the engine until we succeeds or a critical error occurs.
+        while let Err(err) = SynchronizeTask::new(client.clone(), config.clone(), heads)
+            .execute(&mut self.state)
+            .await
         {
             match err.severity() {
                 EngineTaskErrorSeverity::Temporary |
@@ -115,11 +131,9 @@ impl Engine {
         }
 
         // Find the new safe head's L1 origin and SystemConfig.
-        let origin_block = start
-            .safe
-            .l1_origin
-            .number
-            .saturating_sub(config.channel_timeout(start.safe.block_info.timestamp));
+        let safe = self.state.sync_state.local_safe_head();
+        let origin_block =
+            safe.l1_origin.number.saturating_sub(config.channel_timeout(safe.block_info.timestamp));
         let l1_origin_info: BlockInfo = client
             .l1_provider()
             .get_block(origin_block.into())
@@ -130,7 +144,7 @@ impl Engine {
             .into();
         let l2_safe_block = client
             .l2_engine()
-            .get_block(start.safe.block_info.hash.into())
+            .get_block(safe.block_info.hash.into())
             .full()
             .await
             .map_err(SyncStartError::RpcError)?
@@ -141,7 +155,7 @@ impl Engine {
 
         kona_macros::inc!(counter, Metrics::ENGINE_RESET_COUNT);
 
-        Ok((start.safe, l1_origin_info, system_config))
+        Ok((safe, l1_origin_info, system_config))
     }
 
     /// Clears the task queue.
@@ -23,7 +23,8 @@ use tokio::sync::mpsc;
 /// 1. **Automatic Forkchoice Updates**: Performs initial `engine_forkchoiceUpdated` call with
 ///    payload attributes to initiate block building on the execution layer
 /// 2. **Payload Construction**: Retrieves the built payload using `engine_getPayload`
-/// 3. **Block Import**: Imports the payload using [`InsertTask`] for canonicalization
+/// 3. **Block Import**: Imports the payload using [`InsertTask`] for canonicalization, unless the
+///    import is left to the receiver of the payload, see [`BuildTask::with_import`]
 ///
 /// ## Forkchoice Integration
 ///
@@ -53,6 +54,9 @@ pub struct BuildTask {
     /// An optional channel to send the built [`OpExecutionPayloadEnvelope`] to, after the block
     /// has been built, imported, and canonicalized.
     pub payload_tx: Option<mpsc::Sender<OpExecutionPayloadEnvelope>>,
+    /// Whether to import the built payload. If `false`, the payload is only sent to the
+    /// `payload_tx` channel, and its receiver is responsible for importing it.
+    pub import: bool,
 }
 
 impl BuildTask {
@@ -64,7 +68,16 @@ impl BuildTask {
         is_attributes_derived: bool,
         payload_tx: Option<mpsc::Sender<OpExecutionPayloadEnvelope>>,
     ) -> Self {
-        Self { engine, cfg, attributes, is_attributes_derived, payload_tx }
+        Self { engine, cfg, attributes, is_attributes_derived, payload_tx, import: true }
+    }
+
+    /// Sets whether to import the built payload.
+    ///
+    /// The sequencer leaves the import to itself, so that the payload is committed to the
+    /// conductor before it becomes the unsafe head.
+    pub const fn with_import(mut self, import: bool) -> Self {
+        self.import = import;
+        self
     }
 
     /// Starts the block building process by sending an initial `engine_forkchoiceUpdate` call with
@@ -276,6 +289,22 @@ impl EngineTaskExt for BuildTask {
         )
         .map_err(BuildTaskError::FromBlock)?;
 
+        // Hand the sealed payload over without importing it, if requested.
+        if !self.import {
+            if let Some(tx) = &self.payload_tx {
+                tx.send(new_payload).await.map_err(BuildTaskError::MpscSend)?;
+            }
+
+            info!(
+                target: "engine_builder",
+                l2_number = new_block_ref.block_info.number,
+                l2_time = new_block_ref.block_info.timestamp,
+                fcu_duration = ?fcu_duration,
+                "Built new unsafe block, leaving the import to the receiver"
+            );
+            return Ok(());
+        }
+
         // Insert the new block into the engine.
         match InsertTask::new(
             Arc::clone(&self.engine),
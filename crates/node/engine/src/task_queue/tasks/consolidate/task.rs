@@ -93,7 +93,7 @@ impl ConsolidateTask {
 
                     // Apply a transient update to the safe head.
                     state.sync_state = state.sync_state.apply_update(EngineSyncStateUpdate {
-                        safe_head: Some(block_info),
+                        safe_head: (!state.managed).then_some(block_info),
                         local_safe_head: Some(block_info),
                         ..Default::default()
                     });
@@ -116,7 +116,7 @@ impl ConsolidateTask {
                         Arc::clone(&self.client),
                         self.cfg.clone(),
                         EngineSyncStateUpdate {
-                            safe_head: Some(block_info),
+                            safe_head: (!state.managed).then_some(block_info),
                             local_safe_head: Some(block_info),
                             ..Default::default()
                         },
@@ -170,7 +170,7 @@ impl EngineTaskExt for ConsolidateTask {
 
     async fn execute(&self, state: &mut EngineState) -> Result<(), ConsolidateTaskError> {
         // Skip to building the payload attributes if consolidation is not needed.
-        if state.sync_state.safe_head().block_info.number <
+        if state.sync_state.local_safe_head().block_info.number <
             state.sync_state.unsafe_head().block_info.number
         {
             self.consolidate(state).await
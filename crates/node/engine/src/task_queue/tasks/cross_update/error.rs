@@ -0,0 +1,56 @@
+//! Contains error types for the [crate::CrossUpdateTask].
+
+use crate::{
+    EngineTaskError, SynchronizeTaskError, task_queue::tasks::task::EngineTaskErrorSeverity,
+};
+use alloy_primitives::B256;
+use alloy_transport::{RpcError, TransportErrorKind};
+use kona_protocol::FromBlockError;
+use thiserror::Error;
+
+/// An error that occurs when running the [crate::CrossUpdateTask].
+#[derive(Debug, Error)]
+pub enum CrossUpdateTaskError {
+    /// The block is ahead of the head it is promoted from.
+    #[error("Block {0} is ahead of the {1} head {2}")]
+    AheadOfHead(u64, &'static str, u64),
+    /// The block to promote was not found.
+    #[error("The block to promote was not found: Number {0}")]
+    BlockNotFound(u64),
+    /// The block to promote is not part of the canonical chain.
+    #[error("Block {number} hash mismatch: expected {expected}, got {actual}")]
+    BlockHashMismatch {
+        /// The number of the block.
+        number: u64,
+        /// The hash of the block to promote.
+        expected: B256,
+        /// The hash of the canonical block at the same height.
+        actual: B256,
+    },
+    /// An error occurred while transforming the RPC block into [`L2BlockInfo`].
+    ///
+    /// [`L2BlockInfo`]: kona_protocol::L2BlockInfo
+    #[error(transparent)]
+    FromBlock(#[from] FromBlockError),
+    /// A temporary RPC failure.
+    #[error(transparent)]
+    TransportError(#[from] RpcError<TransportErrorKind>),
+    /// The forkchoice update call to promote the block failed.
+    #[error(transparent)]
+    ForkchoiceUpdateFailed(#[from] SynchronizeTaskError),
+}
+
+impl EngineTaskError for CrossUpdateTaskError {
+    fn severity(&self) -> EngineTaskErrorSeverity {
+        match self {
+            // The supervisor's view of the chain differs from the engine's, the engine must be
+            // reset to re-synchronize with it.
+            Self::AheadOfHead(..) => EngineTaskErrorSeverity::Reset,
+            Self::BlockNotFound(_) => EngineTaskErrorSeverity::Reset,
+            Self::BlockHashMismatch { .. } => EngineTaskErrorSeverity::Reset,
+            Self::FromBlock(_) => EngineTaskErrorSeverity::Critical,
+            Self::TransportError(_) => EngineTaskErrorSeverity::Temporary,
+            Self::ForkchoiceUpdateFailed(inner) => inner.severity(),
+        }
+    }
+}
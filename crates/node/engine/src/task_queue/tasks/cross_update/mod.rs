@@ -0,0 +1,7 @@
+//! Task and its associated types for applying cross-safety updates to the engine state.
+
+mod task;
+pub use task::{CrossUpdate, CrossUpdateTask};
+
+mod error;
+pub use error::CrossUpdateTaskError;
@@ -0,0 +1,126 @@
+//! A task for applying cross-safety updates to the engine state.
+
+use crate::{
+    CrossUpdateTaskError, EngineClient, EngineState, EngineTaskExt, SynchronizeTask,
+    state::EngineSyncStateUpdate,
+};
+use alloy_eips::BlockNumHash;
+use alloy_provider::Provider;
+use async_trait::async_trait;
+use kona_genesis::RollupConfig;
+use kona_protocol::L2BlockInfo;
+use std::sync::Arc;
+
+/// A cross-safety update, decided by an interop supervisor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossUpdate {
+    /// Promotes an unsafe block to cross-unsafe.
+    CrossUnsafe(BlockNumHash),
+    /// Promotes a local-safe block to safe.
+    CrossSafe(BlockNumHash),
+    /// Promotes a safe block to finalized.
+    Finalized(BlockNumHash),
+}
+
+impl CrossUpdate {
+    /// Returns the block that is promoted.
+    pub const fn block(&self) -> BlockNumHash {
+        match self {
+            Self::CrossUnsafe(block) | Self::CrossSafe(block) | Self::Finalized(block) => *block,
+        }
+    }
+}
+
+/// The [`CrossUpdateTask`] fetches the [`L2BlockInfo`] of the promoted block, checks that it is
+/// canonical and that the head it is promoted from already includes it, then dispatches a
+/// forkchoice update with the new head.
+#[derive(Debug, Clone)]
+pub struct CrossUpdateTask {
+    /// The engine client.
+    pub client: Arc<EngineClient>,
+    /// The rollup config.
+    pub cfg: Arc<RollupConfig>,
+    /// The update to apply.
+    pub update: CrossUpdate,
+}
+
+impl CrossUpdateTask {
+    /// Creates a new [`CrossUpdateTask`].
+    pub const fn new(
+        client: Arc<EngineClient>,
+        cfg: Arc<RollupConfig>,
+        update: CrossUpdate,
+    ) -> Self {
+        Self { client, cfg, update }
+    }
+}
+
+#[async_trait]
+impl EngineTaskExt for CrossUpdateTask {
+    type Output = ();
+
+    type Error = CrossUpdateTaskError;
+
+    async fn execute(&self, state: &mut EngineState) -> Result<(), CrossUpdateTaskError> {
+        let block = self.update.block();
+
+        // Sanity check that the block is included in the head it is promoted from.
+        let (label, head) = match self.update {
+            CrossUpdate::CrossUnsafe(_) => ("unsafe", state.sync_state.unsafe_head()),
+            CrossUpdate::CrossSafe(_) => ("local-safe", state.sync_state.local_safe_head()),
+            CrossUpdate::Finalized(_) => ("safe", state.sync_state.safe_head()),
+        };
+        if head.block_info.number < block.number {
+            return Err(CrossUpdateTaskError::AheadOfHead(
+                block.number,
+                label,
+                head.block_info.number,
+            ));
+        }
+
+        let consensus_block = self
+            .client
+            .l2_engine()
+            .get_block(block.number.into())
+            .full()
+            .await
+            .map_err(CrossUpdateTaskError::TransportError)?
+            .ok_or(CrossUpdateTaskError::BlockNotFound(block.number))?
+            .into_consensus();
+        let block_info =
+            L2BlockInfo::from_block_and_genesis(&consensus_block, &self.client.cfg().genesis)
+                .map_err(CrossUpdateTaskError::FromBlock)?;
+        if block_info.block_info.hash != block.hash {
+            return Err(CrossUpdateTaskError::BlockHashMismatch {
+                number: block.number,
+                expected: block.hash,
+                actual: block_info.block_info.hash,
+            });
+        }
+
+        let state_update = match self.update {
+            CrossUpdate::CrossUnsafe(_) => {
+                EngineSyncStateUpdate { cross_unsafe_head: Some(block_info), ..Default::default() }
+            }
+            CrossUpdate::CrossSafe(_) => {
+                EngineSyncStateUpdate { safe_head: Some(block_info), ..Default::default() }
+            }
+            CrossUpdate::Finalized(_) => {
+                EngineSyncStateUpdate { finalized_head: Some(block_info), ..Default::default() }
+            }
+        };
+        SynchronizeTask::new(self.client.clone(), self.cfg.clone(), state_update)
+            .execute(state)
+            .await?;
+
+        info!(
+            target: "engine",
+            hash = %block_info.block_info.hash,
+            number = block_info.block_info.number,
+            update = ?self.update,
+            "Applied cross-safety update"
+        );
+
+        Ok(())
+    }
+}
@@ -128,10 +128,10 @@ impl EngineTaskExt for InsertTask {
             Arc::clone(&self.client),
             self.rollup_config.clone(),
             EngineSyncStateUpdate {
-                cross_unsafe_head: Some(new_unsafe_ref),
+                cross_unsafe_head: (!state.managed).then_some(new_unsafe_ref),
                 unsafe_head: Some(new_unsafe_ref),
                 local_safe_head: self.is_payload_safe.then_some(new_unsafe_ref),
-                safe_head: self.is_payload_safe.then_some(new_unsafe_ref),
+                safe_head: (self.is_payload_safe && !state.managed).then_some(new_unsafe_ref),
                 ..Default::default()
             },
         )
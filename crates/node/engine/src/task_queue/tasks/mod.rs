@@ -19,3 +19,6 @@ pub use consolidate::{ConsolidateTask, ConsolidateTaskError};
 
 mod finalize;
 pub use finalize::{FinalizeTask, FinalizeTaskError};
+
+mod cross_update;
+pub use cross_update::{CrossUpdate, CrossUpdateTask, CrossUpdateTaskError};
@@ -2,9 +2,10 @@
 //!
 //! [`Engine`]: crate::Engine
 
-use super::{BuildTask, ConsolidateTask, FinalizeTask, InsertTask};
+use super::{BuildTask, ConsolidateTask, CrossUpdateTask, FinalizeTask, InsertTask};
 use crate::{
-    BuildTaskError, ConsolidateTaskError, EngineState, FinalizeTaskError, InsertTaskError,
+    BuildTaskError, ConsolidateTaskError, CrossUpdateTaskError, EngineState, FinalizeTaskError,
+    InsertTaskError,
 };
 use async_trait::async_trait;
 use derive_more::Display;
@@ -67,6 +68,9 @@ pub enum EngineTaskErrors {
     /// An error that occurred while finalizing an L2 block.
     #[error(transparent)]
     Finalize(#[from] FinalizeTaskError),
+    /// An error that occurred while applying a cross-safety update.
+    #[error(transparent)]
+    CrossUpdate(#[from] CrossUpdateTaskError),
 }
 
 impl EngineTaskError for EngineTaskErrors {
@@ -76,6 +80,7 @@ impl EngineTaskError for EngineTaskErrors {
             Self::Build(inner) => inner.severity(),
             Self::Consolidate(inner) => inner.severity(),
             Self::Finalize(inner) => inner.severity(),
+            Self::CrossUpdate(inner) => inner.severity(),
         }
     }
 }
@@ -94,6 +99,8 @@ pub enum EngineTask {
     Consolidate(ConsolidateTask),
     /// Finalizes an L2 block
     Finalize(FinalizeTask),
+    /// Applies a cross-safety update decided by an interop supervisor.
+    CrossUpdate(CrossUpdateTask),
 }
 
 impl EngineTask {
@@ -104,6 +111,7 @@ impl EngineTask {
             Self::Build(task) => task.execute(state).await?,
             Self::Consolidate(task) => task.execute(state).await?,
             Self::Finalize(task) => task.execute(state).await?,
+            Self::CrossUpdate(task) => task.execute(state).await?,
         };
 
         Ok(())
@@ -115,6 +123,7 @@ impl EngineTask {
             Self::Consolidate(_) => crate::Metrics::CONSOLIDATE_TASK_LABEL,
             Self::Build(_) => crate::Metrics::BUILD_TASK_LABEL,
             Self::Finalize(_) => crate::Metrics::FINALIZE_TASK_LABEL,
+            Self::CrossUpdate(_) => crate::Metrics::CROSS_UPDATE_TASK_LABEL,
         }
     }
 }
@@ -126,7 +135,8 @@ impl PartialEq for EngineTask {
             (Self::Insert(_), Self::Insert(_)) |
                 (Self::Build(_), Self::Build(_)) |
                 (Self::Consolidate(_), Self::Consolidate(_)) |
-                (Self::Finalize(_), Self::Finalize(_))
+                (Self::Finalize(_), Self::Finalize(_)) |
+                (Self::CrossUpdate(_), Self::CrossUpdate(_))
         )
     }
 }
@@ -141,7 +151,7 @@ impl PartialOrd for EngineTask {
 
 impl Ord for EngineTask {
     fn cmp(&self, other: &Self) -> Ordering {
-        // Order (descending): BuildBlock -> InsertUnsafe -> Consolidate -> Finalize
+        // Order (descending): BuildBlock -> InsertUnsafe -> Consolidate -> CrossUpdate -> Finalize
         //
         // https://specs.optimism.io/protocol/derivation.html#forkchoice-synchronization
         //
@@ -151,6 +161,8 @@ impl Ord for EngineTask {
         //   gossip is imported promptly.
         // - Consolidate tasks are prioritized over Finalize tasks, as they advance the safe chain
         //   via derivation.
+        // - CrossUpdate tasks are prioritized over Finalize tasks, as cross-safety updates must be
+        //   applied before the blocks they promote can be finalized.
         // - Finalize tasks have the lowest priority, as they only update finalized status.
         match (self, other) {
             // Same variant cases
@@ -158,6 +170,7 @@ impl Ord for EngineTask {
             (Self::Consolidate(_), Self::Consolidate(_)) => Ordering::Equal,
             (Self::Build(_), Self::Build(_)) => Ordering::Equal,
             (Self::Finalize(_), Self::Finalize(_)) => Ordering::Equal,
+            (Self::CrossUpdate(_), Self::CrossUpdate(_)) => Ordering::Equal,
 
             // BuildBlock tasks are prioritized over InsertUnsafe and Consolidate tasks
             (Self::Build(_), _) => Ordering::Greater,
@@ -167,9 +180,13 @@ impl Ord for EngineTask {
             (Self::Insert(_), _) => Ordering::Greater,
             (_, Self::Insert(_)) => Ordering::Less,
 
-            // Consolidate tasks are prioritized over Finalize tasks
+            // Consolidate tasks are prioritized over CrossUpdate and Finalize tasks
             (Self::Consolidate(_), _) => Ordering::Greater,
             (_, Self::Consolidate(_)) => Ordering::Less,
+
+            // CrossUpdate tasks are prioritized over Finalize tasks
+            (Self::CrossUpdate(_), _) => Ordering::Greater,
+            (_, Self::CrossUpdate(_)) => Ordering::Less,
         }
     }
 }
@@ -3,7 +3,7 @@
 use backon::{ExponentialBuilder, RetryableWithContext};
 use derive_more::Debug;
 use discv5::{Config, Discv5, Enr, enr::NodeId};
-use kona_peers::{BootNode, BootNodes, BootStore, EnrValidation, enr_to_multiaddr};
+use kona_peers::{BootNode, BootNodes, BootStore, EnrValidation, PeerStore, enr_to_multiaddr};
 use libp2p::Multiaddr;
 use std::path::PathBuf;
 use tokio::{
@@ -11,7 +11,7 @@ use tokio::{
     time::{Duration, sleep},
 };
 
-use crate::{Discv5Builder, Discv5Handler, HandlerRequest, LocalNode};
+use crate::{ConnectionGater, Discv5Builder, Discv5Handler, HandlerRequest, LocalNode};
 
 /// The [`Discv5Driver`] drives the discovery service.
 ///
@@ -34,6 +34,8 @@ pub struct Discv5Driver {
     pub disc: Discv5,
     /// The [`BootStore`].
     pub store: BootStore,
+    /// The [`PeerStore`] read on startup, used to forward the peers with the best history first.
+    pub peer_history: PeerStore,
     /// Bootnodes used to bootstrap the discovery service.
     pub bootnodes: Vec<Enr>,
     /// The chain ID of the network.
@@ -68,12 +70,14 @@ impl Discv5Driver {
         bootstore: Option<PathBuf>,
         bootnodes: Vec<Enr>,
     ) -> Self {
-        let store = BootStore::from_chain_id(chain_id, bootstore, bootnodes.clone());
+        let store = BootStore::from_chain_id(chain_id, bootstore.clone(), bootnodes.clone());
+        let peer_history = PeerStore::from_chain_id(chain_id, bootstore);
         Self {
             disc,
             chain_id,
             bootnodes,
             store,
+            peer_history,
             interval,
             forward: true,
             remove_interval: None,
@@ -223,17 +227,42 @@ impl Discv5Driver {
     }
 
     /// Sends ENRs from the boot store to the enr receiver.
+    ///
+    /// ENRs are sent by decreasing reputation in the [`PeerStore`], and ENRs of banned or blocked
+    /// peers are skipped.
     pub async fn forward(&mut self, enr_sender: tokio::sync::mpsc::Sender<Enr>) {
         if !self.forward {
             return;
         }
-        for enr in self.store.valid_peers_with_chain_id(self.chain_id) {
-            if let Err(e) = enr_sender.send(enr.clone()).await {
+        for enr in self.preferred_enrs() {
+            if let Err(e) = enr_sender.send(enr).await {
                 debug!(target: "discovery", "Failed to forward enr: {:?}", e);
             }
         }
     }
 
+    /// Returns the valid ENRs of the boot store ordered by decreasing reputation in the
+    /// [`PeerStore`], without the ENRs of banned or blocked peers.
+    fn preferred_enrs(&self) -> Vec<Enr> {
+        let now = PeerStore::timestamp();
+        let mut enrs = Vec::new();
+        for enr in self.store.valid_peers_with_chain_id(self.chain_id) {
+            let peer_id =
+                enr_to_multiaddr(enr).and_then(|a| ConnectionGater::peer_id_from_addr(&a));
+            let Some(peer_id) = peer_id else {
+                enrs.push((PeerStore::NEUTRAL_REPUTATION, enr.clone()));
+                continue;
+            };
+            if !self.peer_history.is_dialable(&peer_id, now) {
+                trace!(target: "discovery", ?peer_id, "Skipping ENR of banned or blocked peer");
+                continue;
+            }
+            enrs.push((self.peer_history.reputation(&peer_id), enr.clone()));
+        }
+        enrs.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+        enrs.into_iter().map(|(_, enr)| enr).collect()
+    }
+
     /// Spawns a new [`Discv5`] discovery service in a new tokio task.
     ///
     /// Returns a [`Discv5Handler`] to communicate with the spawned task.
@@ -2,16 +2,20 @@
 
 use alloy_primitives::Address;
 use kona_genesis::RollupConfig;
-use kona_peers::{PeerMonitoring, PeerScoreLevel};
+use kona_peers::{PeerMonitoring, PeerScoreLevel, PeerStore};
 use libp2p::{
-    Multiaddr, StreamProtocol, SwarmBuilder, gossipsub::Config, identity::Keypair,
+    Multiaddr, PeerId, StreamProtocol, SwarmBuilder, gossipsub::Config, identity::Keypair,
     noise::Config as NoiseConfig, tcp::Config as TcpConfig, yamux::Config as YamuxConfig,
 };
-use std::time::Duration;
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
 use tokio::sync::watch::{self};
 
 use crate::{
-    Behaviour, BlockHandler, GossipDriver, GossipDriverBuilderError, gossip::gater::GaterConfig,
+    Behaviour, BlockHandler, GossipDriver, GossipDriverBuilderError, PeerScoreTracker,
+    gossip::gater::GaterConfig,
 };
 
 /// A builder for the [`GossipDriver`].
@@ -38,6 +42,14 @@ pub struct GossipDriverBuilder {
     gater_config: Option<GaterConfig>,
     /// Topic scoring. Disabled by default.
     topic_scoring: bool,
+    /// An optional path to the peer [`crate::ScoringConfig`] file.
+    scoring_config: Option<PathBuf>,
+    /// An optional path to the [`PeerStore`] file.
+    peerstore: Option<PathBuf>,
+    /// The static peers, which are always dialed.
+    static_peers: Vec<Multiaddr>,
+    /// The trusted peers.
+    trusted_peers: Vec<PeerId>,
 }
 
 impl GossipDriverBuilder {
@@ -59,6 +71,10 @@ impl GossipDriverBuilder {
             gater_config: None,
             rollup_config,
             topic_scoring: false,
+            scoring_config: None,
+            peerstore: None,
+            static_peers: Vec::new(),
+            trusted_peers: Vec::new(),
         }
     }
 
@@ -94,6 +110,37 @@ impl GossipDriverBuilder {
         self
     }
 
+    /// Sets the path to the peer [`crate::ScoringConfig`] file.
+    ///
+    /// The file is applied when the [`GossipDriver`] is built, and can be reloaded at runtime.
+    pub fn with_scoring_config(mut self, scoring_config: Option<PathBuf>) -> Self {
+        self.scoring_config = scoring_config;
+        self
+    }
+
+    /// Sets the path to the [`PeerStore`] file, enabling the persistent peer history.
+    ///
+    /// The blocked, protected and banned peers of the store are restored when the
+    /// [`GossipDriver`] is built.
+    pub fn with_peerstore(mut self, peerstore: Option<PathBuf>) -> Self {
+        self.peerstore = peerstore;
+        self
+    }
+
+    /// Sets the static peers, which are trusted, always dialed and explicit gossipsub peers.
+    ///
+    /// Each [`Multiaddr`] must contain the peer id of the peer.
+    pub fn with_static_peers(mut self, static_peers: Vec<Multiaddr>) -> Self {
+        self.static_peers = static_peers;
+        self
+    }
+
+    /// Sets the trusted peers, which are exempt from dial limits, bans and address blocks.
+    pub fn with_trusted_peers(mut self, trusted_peers: Vec<PeerId>) -> Self {
+        self.trusted_peers = trusted_peers;
+        self
+    }
+
     /// Sets the unsafe block signer [`Address`].
     pub const fn with_unsafe_block_signer_receiver(mut self, signer: Address) -> Self {
         self.signer = signer;
@@ -167,6 +214,7 @@ impl GossipDriverBuilder {
         let mut behaviour = Behaviour::new(keypair.public(), config, &[Box::new(handler.clone())])?;
 
         // If peer scoring is configured, set it on the behaviour.
+        let mut peer_scores = PeerScoreTracker::default();
         match self.scoring {
             None => info!(target: "scoring", "Peer scoring not enabled"),
             Some(PeerScoreLevel::Off) => {
@@ -177,8 +225,12 @@ impl GossipDriverBuilder {
                 let params = level
                     .to_params(handler.topics(), self.topic_scoring, block_time)
                     .unwrap_or_default();
-                match behaviour.gossipsub.with_peer_score(params, PeerScoreLevel::thresholds()) {
-                    Ok(_) => debug!(target: "scoring", "Peer scoring enabled successfully"),
+                let thresholds = PeerScoreLevel::thresholds();
+                match behaviour.gossipsub.with_peer_score(params.clone(), thresholds.clone()) {
+                    Ok(_) => {
+                        debug!(target: "scoring", "Peer scoring enabled successfully");
+                        peer_scores = PeerScoreTracker::new(params, thresholds);
+                    }
                     Err(e) => warn!(target: "scoring", "Peer scoring failed: {}", e),
                 }
             }
@@ -215,6 +267,30 @@ impl GossipDriverBuilder {
         let gater_config = self.gater_config.take().unwrap_or_default();
         let gate = crate::ConnectionGater::new(gater_config);
 
-        Ok((GossipDriver::new(swarm, addr, handler, sync_handler, sync_protocol, gate), signer_tx))
+        let mut driver = GossipDriver::new(swarm, addr, handler, sync_handler, sync_protocol, gate);
+        driver.peer_monitoring = self.peer_monitoring;
+        driver.peer_scores = peer_scores;
+        for peer_id in self.trusted_peers {
+            driver.trust_peer(peer_id);
+        }
+        for addr in self.static_peers {
+            // Static peers are dialed once the driver is started.
+            match driver.static_peers.insert(addr.clone(), Instant::now()) {
+                Some(peer_id) => driver.grant_static(peer_id),
+                None => warn!(target: "gossip", ?addr, "Ignoring static peer without a peer id"),
+            }
+        }
+        if let Some(peerstore) = self.peerstore {
+            driver.peer_history = Some(PeerStore::from_file(peerstore).with_writer());
+            driver.restore_peer_history();
+        }
+        if let Some(scoring_config) = self.scoring_config {
+            driver.scoring_config = Some(scoring_config);
+            driver
+                .reload_scoring_config()
+                .map_err(|e| GossipDriverBuilderError::ScoringConfig(e.to_string()))?;
+        }
+
+        Ok((driver, signer_tx))
     }
 }
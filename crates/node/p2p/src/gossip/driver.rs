@@ -5,17 +5,19 @@ use derive_more::Debug;
 use discv5::Enr;
 use futures::{AsyncReadExt, AsyncWriteExt, stream::StreamExt};
 use kona_genesis::RollupConfig;
-use kona_peers::{EnrValidation, PeerMonitoring, enr_to_multiaddr};
+use kona_peers::{EnrValidation, PeerMonitoring, PeerStore, enr_to_multiaddr};
 use libp2p::{
     Multiaddr, PeerId, Swarm, TransportError,
     gossipsub::{IdentTopic, MessageId},
+    multiaddr::Protocol,
     swarm::SwarmEvent,
 };
 use libp2p_identity::Keypair;
 use libp2p_stream::IncomingStreams;
 use op_alloy_rpc_types_engine::OpNetworkPayloadEnvelope;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
+    path::PathBuf,
     sync::Arc,
     time::{Duration, Instant},
 };
@@ -23,9 +25,12 @@ use tokio::sync::Mutex;
 
 use crate::{
     Behaviour, BlockHandler, ConnectionGate, ConnectionGater, Event, GossipDriverBuilder, Handler,
-    PublishError,
+    PeerScoreTracker, PublishError, ScoringConfig, ScoringConfigError, StaticPeers,
 };
 
+/// The maximum number of peers from the [`PeerStore`] dialed when the [`GossipDriver`] starts.
+const MAX_PREFERRED_DIALS: usize = 32;
+
 /// A driver for a [`Swarm`] instance.
 ///
 /// Connects the swarm to the given [`Multiaddr`]
@@ -55,6 +60,19 @@ pub struct GossipDriver<G: ConnectionGate> {
     /// If set, the gossip layer will monitor peer scores and ban peers that are below a given
     /// threshold.
     pub peer_monitoring: Option<PeerMonitoring>,
+    /// Tracks the components of the peer scores.
+    pub peer_scores: PeerScoreTracker,
+    /// An optional path to the peer [`ScoringConfig`] file.
+    pub scoring_config: Option<PathBuf>,
+    /// The persistent history of peers, if enabled.
+    pub peer_history: Option<PeerStore>,
+    /// The static peers, which are always dialed and redialed with a backoff.
+    pub static_peers: StaticPeers,
+    /// The peers trusted through the config or the `opp2p_addTrustedPeer` RPC.
+    ///
+    /// Static peers are trusted while they are static, so that removing a static peer only
+    /// untrusts it if it is not in this set.
+    pub trusted_peers: HashSet<PeerId>,
     /// Tracks connection start time for peers
     pub peer_connection_start: HashMap<PeerId, Instant>,
     /// The connection gate.
@@ -92,6 +110,11 @@ where
             handler,
             peerstore: Default::default(),
             peer_monitoring: None,
+            peer_scores: Default::default(),
+            scoring_config: None,
+            peer_history: None,
+            static_peers: Default::default(),
+            trusted_peers: Default::default(),
             peer_connection_start: Default::default(),
             sync_handler,
             sync_protocol: Some(sync_protocol),
@@ -208,6 +231,210 @@ where
         Ok(())
     }
 
+    /// Reloads the peer [`ScoringConfig`] file and applies it, returning the applied config.
+    pub fn reload_scoring_config(&mut self) -> Result<ScoringConfig, ScoringConfigError> {
+        let path = self.scoring_config.as_ref().ok_or(ScoringConfigError::NotConfigured)?;
+        let config = ScoringConfig::load(path)?;
+        self.apply_scoring_config(&config)?;
+        Ok(config)
+    }
+
+    /// Applies the [`ScoringConfig`] to the peer monitoring and to the block topics scores.
+    ///
+    /// Nothing is applied if the topic score overrides are invalid.
+    pub fn apply_scoring_config(
+        &mut self,
+        config: &ScoringConfig,
+    ) -> Result<(), ScoringConfigError> {
+        if let Some(overrides) = &config.topics {
+            let mut updates = Vec::new();
+            for topic in [
+                &self.handler.blocks_v1_topic,
+                &self.handler.blocks_v2_topic,
+                &self.handler.blocks_v3_topic,
+                &self.handler.blocks_v4_topic,
+            ] {
+                let Some(params) = self.peer_scores.topic_params(&topic.hash()) else {
+                    continue;
+                };
+                let params = overrides.apply(params.clone());
+                params.validate().map_err(ScoringConfigError::InvalidTopicParams)?;
+                updates.push((topic.clone(), params));
+            }
+            if updates.is_empty() {
+                return Err(ScoringConfigError::TopicScoringDisabled);
+            }
+            for (topic, params) in updates {
+                self.peer_scores.set_topic_params(topic.hash(), params.clone());
+                if let Err(err) =
+                    self.swarm.behaviour_mut().gossipsub.set_topic_params(topic, params)
+                {
+                    warn!(target: "scoring", err, "Failed to set the topic score parameters");
+                }
+            }
+        }
+
+        self.peer_monitoring = config.peer_monitoring(self.peer_monitoring.take());
+        info!(target: "scoring", ?config, "Applied peer scoring config");
+        Ok(())
+    }
+
+    /// Restores the blocked, protected and banned peers of the [`PeerStore`] into the connection
+    /// gate.
+    pub fn restore_peer_history(&mut self) {
+        let Some(history) = &self.peer_history else {
+            return;
+        };
+        let now = PeerStore::timestamp();
+        for peer_id in history.blocked_peers() {
+            self.connection_gate.block_peer(peer_id);
+            self.swarm.behaviour_mut().gossipsub.blacklist_peer(peer_id);
+        }
+        for peer_id in history.protected_peers() {
+            self.connection_gate.protect_peer(*peer_id);
+        }
+        for (peer_id, until) in history.banned_peers(now) {
+            self.connection_gate.ban_peer(peer_id, Duration::from_secs(until - now));
+        }
+        info!(target: "gossip", peers = history.len(), "Restored peer history");
+    }
+
+    /// Dials the peers of the [`PeerStore`] with the best history, on their last-seen address.
+    pub fn dial_preferred_peers(&mut self) {
+        let Some(history) = &self.peer_history else {
+            return;
+        };
+        let addrs = history
+            .preferred_peers(PeerStore::timestamp())
+            .into_iter()
+            .filter_map(|(peer_id, record)| {
+                let mut addr = record.addresses.front()?.clone();
+                if ConnectionGater::peer_id_from_addr(&addr).is_none() {
+                    addr.push(Protocol::P2p(peer_id));
+                }
+                Some(addr)
+            })
+            .take(MAX_PREFERRED_DIALS)
+            .collect::<Vec<_>>();
+        debug!(target: "gossip", count = addrs.len(), "Dialing preferred peers");
+        for addr in addrs {
+            self.dial_multiaddr(addr);
+        }
+    }
+
+    /// Adds a static peer, which is trusted and dialed until it is removed.
+    ///
+    /// Static peers are registered as explicit gossipsub peers, see [`Self::grant_static`].
+    ///
+    /// Returns `false` if the [`Multiaddr`] does not contain a peer id.
+    pub fn add_static_peer(&mut self, addr: Multiaddr) -> bool {
+        let Some(peer_id) = self.static_peers.insert(addr, Instant::now()) else {
+            return false;
+        };
+        self.grant_static(peer_id);
+        if self.swarm.is_connected(&peer_id) {
+            self.static_peers.connected(&peer_id);
+        }
+        self.dial_static_peers();
+        true
+    }
+
+    /// Removes a static peer. The peer is no longer redialed, and is no longer trusted unless it
+    /// was trusted on its own.
+    ///
+    /// Returns `false` if the peer is not a static peer.
+    pub fn remove_static_peer(&mut self, peer_id: &PeerId) -> bool {
+        if self.static_peers.remove(peer_id).is_none() {
+            return false;
+        }
+        self.swarm.behaviour_mut().gossipsub.remove_explicit_peer(peer_id);
+        if !self.trusted_peers.contains(peer_id) {
+            self.revoke_trust(*peer_id);
+        }
+        true
+    }
+
+    /// Trusts a peer, until it is untrusted with [`Self::untrust_peer`].
+    ///
+    /// Trusted peers are exempt from the connection gater limits and from score based bans. Unlike
+    /// static peers, they are not registered as explicit gossipsub peers.
+    pub fn trust_peer(&mut self, peer_id: PeerId) {
+        self.trusted_peers.insert(peer_id);
+        self.grant_trust(peer_id);
+    }
+
+    /// Untrusts a peer. Static peers stay trusted until they are removed.
+    pub fn untrust_peer(&mut self, peer_id: &PeerId) {
+        self.trusted_peers.remove(peer_id);
+        if !self.static_peers.contains(peer_id) {
+            self.revoke_trust(*peer_id);
+        }
+    }
+
+    /// Trusts a static peer, and registers it as an explicit gossipsub peer so that messages are
+    /// always forwarded to it, regardless of the mesh.
+    ///
+    /// Explicit peering must be configured on both sides: a peer that does not have the node as
+    /// an explicit peer treats its messages as regular mesh traffic, and may prune it.
+    pub(crate) fn grant_static(&mut self, peer_id: PeerId) {
+        self.grant_trust(peer_id);
+        self.swarm.behaviour_mut().gossipsub.add_explicit_peer(&peer_id);
+    }
+
+    /// Trusts the peer in the connection gate, which exempts it from the gater limits and from
+    /// score based bans.
+    fn grant_trust(&mut self, peer_id: PeerId) {
+        self.connection_gate.trust_peer(peer_id);
+    }
+
+    /// Reverts [`Self::grant_trust`].
+    fn revoke_trust(&mut self, peer_id: PeerId) {
+        self.connection_gate.untrust_peer(peer_id);
+    }
+
+    /// Dials the static peers that are due for a dial.
+    pub fn dial_static_peers(&mut self) {
+        for addr in self.static_peers.due(Instant::now()) {
+            debug!(target: "gossip", peer=?addr, "Dialing static peer");
+            self.dial_multiaddr(addr);
+        }
+    }
+
+    /// Records the scores of the connected peers in the [`PeerStore`] and hands it over to its
+    /// background writer.
+    pub fn sync_peer_history(&mut self) {
+        let Some(history) = &mut self.peer_history else {
+            return;
+        };
+        let now = PeerStore::timestamp();
+        let gossipsub = &self.swarm.behaviour().gossipsub;
+        for peer_id in self.swarm.connected_peers() {
+            if let Some(score) = gossipsub.peer_score(peer_id) {
+                history.record_score(*peer_id, score, now);
+            }
+        }
+        history.sync();
+    }
+
+    /// Samples the gossipsub meshes into the [`PeerScoreTracker`], and decays its counters.
+    ///
+    /// Gossipsub emits no event when a peer is grafted or pruned, so the meshes are sampled on
+    /// every gossipsub event and every heartbeat, when gossipsub rebalances them.
+    pub fn refresh_peer_scores(&mut self) {
+        self.peer_scores.refresh(&self.swarm.behaviour().gossipsub, Instant::now());
+    }
+
+    /// Waits until the [`PeerStore`] synced so far is written to disk, without blocking the
+    /// runtime.
+    pub async fn flush_peer_history(&self) {
+        let Some(history) = self.peer_history.clone() else {
+            return;
+        };
+        if let Err(e) = tokio::task::spawn_blocking(move || history.flush()).await {
+            warn!(target: "gossip", "Failed to flush the peer history: {:?}", e);
+        }
+    }
+
     /// Returns the local peer id.
     pub fn local_peer_id(&self) -> &libp2p::PeerId {
         self.swarm.local_peer_id()
@@ -348,6 +575,8 @@ where
         &mut self,
         event: libp2p::gossipsub::Event,
     ) -> Option<OpNetworkPayloadEnvelope> {
+        // Peers are grafted and pruned as control messages are received, sample the meshes.
+        self.refresh_peer_scores();
         match event {
             libp2p::gossipsub::Event::Message {
                 propagation_source: src,
@@ -357,7 +586,9 @@ where
                 trace!(target: "gossip", "Received message with topic: {}", message.topic);
                 kona_macros::inc!(gauge, crate::Metrics::GOSSIP_EVENT, "type" => "message", "topic" => message.topic.to_string());
                 if self.handler.topics().contains(&message.topic) {
+                    let topic = message.topic.clone();
                     let (status, payload) = self.handler.handle(message);
+                    self.peer_scores.record_message(src, &topic, &status);
                     _ = self
                         .swarm
                         .behaviour_mut()
@@ -392,7 +623,23 @@ where
             SwarmEvent::Behaviour(behavior_event) => {
                 return self.handle_gossip_event(behavior_event)
             }
-            SwarmEvent::ConnectionEstablished { peer_id, .. } => {
+            SwarmEvent::ConnectionEstablished { peer_id, endpoint, connection_id, .. } => {
+                // Outbound connections are gated when dialing, inbound ones once established.
+                if !endpoint.is_dialer() {
+                    let addr = endpoint.get_remote_address();
+                    if let Err(e) = self.connection_gate.can_accept(&peer_id, addr) {
+                        debug!(target: "gossip", ?peer_id, ?addr, "Rejecting inbound connection: {}", e);
+                        kona_macros::inc!(
+                            gauge,
+                            crate::Metrics::GOSSIPSUB_CONNECTION,
+                            "type" => "rejected",
+                            "peer" => peer_id.to_string(),
+                        );
+                        self.swarm.close_connection(connection_id);
+                        return None;
+                    }
+                }
+
                 let peer_count = self.swarm.connected_peers().count();
                 info!(target: "gossip", "Connection established: {:?} | Peer Count: {}", peer_id, peer_count);
                 kona_macros::inc!(
@@ -404,14 +651,27 @@ where
                 kona_macros::set!(gauge, crate::Metrics::GOSSIP_PEER_COUNT, peer_count as f64);
 
                 self.peer_connection_start.insert(peer_id, Instant::now());
+                self.peer_scores.connected(peer_id, endpoint.get_remote_address());
+                self.static_peers.connected(&peer_id);
+
+                // Only outbound connections are made on an address the peer can be dialed on.
+                if let Some(history) = &mut self.peer_history {
+                    if endpoint.is_dialer() {
+                        let addr = endpoint.get_remote_address().clone();
+                        history.record_connected(peer_id, addr, PeerStore::timestamp());
+                    }
+                }
             }
-            SwarmEvent::OutgoingConnectionError { peer_id: _peer_id, error, .. } => {
+            SwarmEvent::OutgoingConnectionError { peer_id, error, .. } => {
                 debug!(target: "gossip", "Outgoing connection error: {:?}", error);
+                if let (Some(history), Some(peer_id)) = (&mut self.peer_history, peer_id) {
+                    history.record_dial_failure(peer_id);
+                }
                 kona_macros::inc!(
                     gauge,
                     crate::Metrics::GOSSIPSUB_CONNECTION,
                     "type" => "outgoing_error",
-                    "peer" => _peer_id.map(|p| p.to_string()).unwrap_or_default()
+                    "peer" => peer_id.map(|p| p.to_string()).unwrap_or_default()
                 );
             }
             SwarmEvent::IncomingConnectionError {
@@ -425,7 +685,7 @@ where
                     "connection_id" => _connection_id.to_string()
                 );
             }
-            SwarmEvent::ConnectionClosed { peer_id, cause, .. } => {
+            SwarmEvent::ConnectionClosed { peer_id, cause, num_established, .. } => {
                 let peer_count = self.swarm.connected_peers().count();
                 warn!(target: "gossip", ?peer_id, ?cause, peer_count, "Connection closed");
                 kona_macros::inc!(
@@ -457,6 +717,17 @@ where
                     );
                 }
 
+                if num_established == 0 {
+                    self.peer_scores.disconnected(&peer_id, Instant::now());
+                    self.static_peers.disconnected(&peer_id, Instant::now());
+                    if let Some(history) = &mut self.peer_history {
+                        let gossipsub = &self.swarm.behaviour().gossipsub;
+                        if let Some(score) = gossipsub.peer_score(&peer_id) {
+                            history.record_score(peer_id, score, PeerStore::timestamp());
+                        }
+                    }
+                }
+
                 let pings = Arc::clone(&self.ping);
                 tokio::spawn(async move {
                     pings.lock().await.remove(&peer_id);
@@ -66,6 +66,10 @@ pub enum GossipDriverBuilderError {
     /// The sync request/response protocol has already been accepted.
     #[error("sync request/response protocol already accepted")]
     SyncReqRespAlreadyAccepted,
+    /// The peer scoring config could not be loaded or applied.
+    #[error("invalid peer scoring config: {0}")]
+    #[from(skip)]
+    ScoringConfig(String),
 }
 
 /// An error type representing reasons why a peer cannot be dialed.
@@ -95,6 +99,12 @@ pub enum DialError {
         /// The PeerId of the peer that is on the blocklist
         peer_id: PeerId,
     },
+    /// Peer is banned.
+    #[error("Peer is banned: {peer_id}")]
+    PeerBanned {
+        /// The PeerId of the peer whose ban has not expired yet
+        peer_id: PeerId,
+    },
     /// Failed to extract IP address from Multiaddr.
     #[error("Failed to extract IP address from Multiaddr: {addr}")]
     InvalidIpAddress {
@@ -3,7 +3,7 @@
 use crate::{Connectedness, DialError};
 use ipnet::IpNet;
 use libp2p::{Multiaddr, PeerId};
-use std::net::IpAddr;
+use std::{net::IpAddr, time::Duration};
 
 /// Connection Gate
 ///
@@ -15,6 +15,10 @@ pub trait ConnectionGate {
     /// Returns Ok(()) if the peer can be dialed, or Err(DialError) with the reason why not.
     fn can_dial(&mut self, peer_id: &Multiaddr) -> Result<(), DialError>;
 
+    /// Checks if an inbound connection from a peer on the given remote address is allowed.
+    /// Returns Ok(()) if the connection can be kept, or Err(DialError) with the reason why not.
+    fn can_accept(&mut self, peer_id: &PeerId, addr: &Multiaddr) -> Result<(), DialError>;
+
     /// Returns the [`Connectedness`] for a given peer id.
     fn connectedness(&self, peer_id: &PeerId) -> Connectedness;
 
@@ -42,6 +46,9 @@ pub trait ConnectionGate {
     /// Lists the blocked peers.
     fn list_blocked_peers(&self) -> Vec<PeerId>;
 
+    /// Bans a given peer id from being dialed for the given duration.
+    fn ban_peer(&mut self, peer_id: &PeerId, duration: Duration);
+
     /// Blocks a given ip address from connecting to the gossip swarm.
     fn block_addr(&mut self, ip: IpAddr);
 
@@ -68,4 +75,13 @@ pub trait ConnectionGate {
 
     /// Lists all protected peers.
     fn list_protected_peers(&self) -> Vec<PeerId>;
+
+    /// Trusts a peer, exempting it from dial limits, bans, address blocks and disconnection.
+    fn trust_peer(&mut self, peer_id: PeerId);
+
+    /// Untrusts a peer.
+    fn untrust_peer(&mut self, peer_id: PeerId);
+
+    /// Lists all trusted peers.
+    fn list_trusted_peers(&self) -> Vec<PeerId>;
 }
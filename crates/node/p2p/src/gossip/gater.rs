@@ -75,8 +75,15 @@ pub struct ConnectionGater {
     ///
     /// Protecting a peer prevents the peer from any redial thresholds or peer scoring.
     pub protected_peers: HashSet<PeerId>,
+    /// A set of trusted peers.
+    ///
+    /// Trusted peers are exempt from redial thresholds, bans, address and subnet blocks, and
+    /// cannot be disconnected. Explicitly blocked peers are still not dialed.
+    pub trusted_peers: HashSet<PeerId>,
     /// A set of blocked peer ids.
     pub blocked_peers: HashSet<PeerId>,
+    /// A mapping from banned peer ids to the end of their ban.
+    pub banned_peers: HashMap<PeerId, Instant>,
     /// A set of blocked ip addresses that cannot be dialed.
     pub blocked_addrs: HashSet<IpAddr>,
     /// A set of blocked subnets that cannot be connected to.
@@ -92,7 +99,9 @@ impl ConnectionGater {
             dialed_peers: HashMap::new(),
             connectedness: HashMap::new(),
             protected_peers: HashSet::new(),
+            trusted_peers: HashSet::new(),
             blocked_peers: HashSet::new(),
+            banned_peers: HashMap::new(),
             blocked_addrs: HashSet::new(),
             blocked_subnets: HashSet::new(),
         }
@@ -142,6 +151,18 @@ impl ConnectionGater {
         })
     }
 
+    /// Returns if the peer is banned, lifting its ban if it has expired.
+    fn ban_active(&mut self, peer_id: &PeerId) -> bool {
+        let Some(until) = self.banned_peers.get(peer_id) else {
+            return false;
+        };
+        if *until > Instant::now() {
+            return true;
+        }
+        self.banned_peers.remove(peer_id);
+        false
+    }
+
     /// Checks if a given [`IpAddr`] is within any of the `blocked_subnets`.
     pub fn check_ip_in_blocked_subnets(&self, ip_addr: &IpAddr) -> bool {
         for subnet in &self.blocked_subnets {
@@ -169,8 +190,9 @@ impl ConnectionGate for ConnectionGater {
             return Err(DialError::AlreadyDialing { peer_id });
         }
 
-        // If the peer is protected, do not apply thresholds.
-        let protected = self.protected_peers.contains(&peer_id);
+        // If the peer is protected or trusted, do not apply thresholds.
+        let trusted = self.trusted_peers.contains(&peer_id);
+        let protected = trusted || self.protected_peers.contains(&peer_id);
 
         // If the peer is not protected, its dial threshold is reached and dial period is not
         // expired, do not dial.
@@ -188,6 +210,18 @@ impl ConnectionGate for ConnectionGater {
             return Err(DialError::PeerBlocked { peer_id });
         }
 
+        // Trusted peers are not subject to bans and address blocks.
+        if trusted {
+            return Ok(());
+        }
+
+        // If the peer is banned, do not dial until the ban expires.
+        if self.ban_active(&peer_id) {
+            debug!(target: "gossip", peer=?addr, "Peer is banned, not dialing");
+            kona_macros::inc!(gauge, crate::Metrics::DIAL_PEER_ERROR, "type" => "banned_peer", "peer" => peer_id.to_string());
+            return Err(DialError::PeerBanned { peer_id });
+        }
+
         // There must be a reachable IP Address in the Multiaddr protocol stack.
         let ip_addr = Self::ip_from_addr(addr).ok_or_else(|| {
             warn!(target: "p2p", peer=?addr, "Failed to extract IpAddr from Multiaddr");
@@ -212,6 +246,33 @@ impl ConnectionGate for ConnectionGater {
         Ok(())
     }
 
+    fn can_accept(&mut self, peer_id: &PeerId, addr: &Multiaddr) -> Result<(), DialError> {
+        if self.blocked_peers.contains(peer_id) {
+            return Err(DialError::PeerBlocked { peer_id: *peer_id });
+        }
+
+        // Trusted peers are not subject to bans and address blocks.
+        if self.trusted_peers.contains(peer_id) {
+            return Ok(());
+        }
+
+        if self.ban_active(peer_id) {
+            return Err(DialError::PeerBanned { peer_id: *peer_id });
+        }
+
+        // Inbound connections are accepted on any transport, so the address may not carry an IP.
+        let Some(ip) = Self::ip_from_addr(addr) else {
+            return Ok(());
+        };
+        if self.blocked_addrs.contains(&ip) {
+            return Err(DialError::AddressBlocked { ip });
+        }
+        if self.check_ip_in_blocked_subnets(&ip) {
+            return Err(DialError::SubnetBlocked { ip });
+        }
+        Ok(())
+    }
+
     fn connectedness(&self, peer_id: &PeerId) -> Connectedness {
         self.connectedness.get(peer_id).cloned().unwrap_or(Connectedness::NotConnected)
     }
@@ -255,8 +316,8 @@ impl ConnectionGate for ConnectionGater {
             // If we cannot extract the PeerId, disconnection is allowed.
             return true;
         };
-        // If the peer is protected, do not disconnect.
-        if !self.protected_peers.contains(&peer_id) {
+        // If the peer is protected or trusted, do not disconnect.
+        if !self.protected_peers.contains(&peer_id) && !self.trusted_peers.contains(&peer_id) {
             return true;
         }
         // Peer is protected, cannot disconnect.
@@ -279,6 +340,12 @@ impl ConnectionGate for ConnectionGater {
         self.blocked_peers.iter().copied().collect()
     }
 
+    fn ban_peer(&mut self, peer_id: &PeerId, duration: Duration) {
+        self.banned_peers.insert(*peer_id, Instant::now() + duration);
+        debug!(target: "gossip", peer=?peer_id, ?duration, "Banned peer");
+        self.connectedness.insert(*peer_id, Connectedness::CannotConnect);
+    }
+
     fn block_addr(&mut self, ip: IpAddr) {
         self.blocked_addrs.insert(ip);
         debug!(target: "gossip", ?ip, "Blocked ip address");
@@ -316,6 +383,20 @@ impl ConnectionGate for ConnectionGater {
         self.protected_peers.remove(&peer_id);
         debug!(target: "gossip", peer=?peer_id, "Unprotected peer");
     }
+
+    fn trust_peer(&mut self, peer_id: PeerId) {
+        self.trusted_peers.insert(peer_id);
+        debug!(target: "gossip", peer=?peer_id, "Trusted peer");
+    }
+
+    fn untrust_peer(&mut self, peer_id: PeerId) {
+        self.trusted_peers.remove(&peer_id);
+        debug!(target: "gossip", peer=?peer_id, "Untrusted peer");
+    }
+
+    fn list_trusted_peers(&self) -> Vec<PeerId> {
+        self.trusted_peers.iter().copied().collect()
+    }
 }
 
 #[test]
@@ -369,3 +450,81 @@ fn test_dial_error_handling() {
     let result = gater.can_dial(&valid_addr);
     assert!(matches!(result, Err(DialError::AlreadyDialing { .. })));
 }
+
+#[test]
+fn test_banned_peer() {
+    use crate::{ConnectionGate, DialError};
+    use std::str::FromStr;
+
+    let mut gater = ConnectionGater::new(GaterConfig::default());
+    let addr = Multiaddr::from_str(
+        "/ip4/127.0.0.1/tcp/8080/p2p/12D3KooWEyoppNCUx8Yx66oV9fJnriXwCcXwDDUA2kj6vnc6iDEp",
+    )
+    .unwrap();
+    let peer_id = ConnectionGater::peer_id_from_addr(&addr).unwrap();
+
+    // A banned peer cannot be dialed.
+    gater.ban_peer(&peer_id, Duration::from_secs(60));
+    assert!(matches!(gater.can_dial(&addr), Err(DialError::PeerBanned { .. })));
+
+    // An expired ban is lifted.
+    gater.ban_peer(&peer_id, Duration::ZERO);
+    assert!(gater.can_dial(&addr).is_ok());
+    assert!(gater.banned_peers.is_empty());
+}
+
+#[test]
+fn test_trusted_peer() {
+    use crate::{ConnectionGate, DialError};
+    use std::str::FromStr;
+
+    let mut gater =
+        ConnectionGater::new(GaterConfig { peer_redialing: Some(1), ..Default::default() });
+    let addr = Multiaddr::from_str(
+        "/ip4/10.0.0.1/tcp/8080/p2p/12D3KooWEyoppNCUx8Yx66oV9fJnriXwCcXwDDUA2kj6vnc6iDEp",
+    )
+    .unwrap();
+    let peer_id = ConnectionGater::peer_id_from_addr(&addr).unwrap();
+
+    gater.dialed(&addr);
+    gater.ban_peer(&peer_id, Duration::from_secs(60));
+    gater.block_subnet("10.0.0.0/8".parse().unwrap());
+    assert!(gater.can_dial(&addr).is_err());
+    assert!(gater.can_disconnect(&addr));
+
+    // Trusted peers bypass dial thresholds, bans and address blocks, and cannot be disconnected.
+    gater.trust_peer(peer_id);
+    assert!(gater.can_dial(&addr).is_ok());
+    assert!(!gater.can_disconnect(&addr));
+
+    // Blocking a trusted peer still prevents it from being dialed.
+    gater.block_peer(&peer_id);
+    assert!(matches!(gater.can_dial(&addr), Err(DialError::PeerBlocked { .. })));
+}
+
+#[test]
+fn test_can_accept() {
+    use crate::{ConnectionGate, DialError};
+    use std::str::FromStr;
+
+    let mut gater = ConnectionGater::new(GaterConfig::default());
+    let addr = Multiaddr::from_str("/ip4/10.0.0.1/tcp/8080").unwrap();
+    let peer_id = PeerId::random();
+    assert!(gater.can_accept(&peer_id, &addr).is_ok());
+
+    // Banned peers are rejected until their ban expires.
+    gater.ban_peer(&peer_id, Duration::from_secs(60));
+    assert!(matches!(gater.can_accept(&peer_id, &addr), Err(DialError::PeerBanned { .. })));
+    gater.ban_peer(&peer_id, Duration::ZERO);
+    assert!(gater.can_accept(&peer_id, &addr).is_ok());
+
+    // Blocked subnets are rejected, unless the peer is trusted.
+    gater.block_subnet("10.0.0.0/8".parse().unwrap());
+    assert!(matches!(gater.can_accept(&peer_id, &addr), Err(DialError::SubnetBlocked { .. })));
+    gater.trust_peer(peer_id);
+    assert!(gater.can_accept(&peer_id, &addr).is_ok());
+
+    // Blocked peers are always rejected.
+    gater.block_peer(&peer_id);
+    assert!(matches!(gater.can_accept(&peer_id, &addr), Err(DialError::PeerBlocked { .. })));
+}
@@ -72,6 +72,15 @@ pub use handler::{BlockHandler, Handler};
 mod driver;
 pub use driver::GossipDriver;
 
+mod score_config;
+pub use score_config::{ScoringConfig, ScoringConfigError, TopicScoringConfig};
+
+mod score_tracker;
+pub use score_tracker::PeerScoreTracker;
+
+mod static_peers;
+pub use static_peers::StaticPeers;
+
 mod block_validity;
 pub use block_validity::BlockInvalidError;
 
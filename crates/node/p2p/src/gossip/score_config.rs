@@ -0,0 +1,168 @@
+//! A peer scoring configuration file that can be reloaded at runtime.
+
+use kona_peers::PeerMonitoring;
+use libp2p::gossipsub::TopicScoreParams;
+use std::{path::Path, time::Duration};
+use thiserror::Error;
+
+/// The ban duration used when peer banning is enabled by a [`ScoringConfig`].
+const DEFAULT_BAN_DURATION: Duration = Duration::from_secs(60 * 60);
+
+/// Peer scoring thresholds, loaded from a JSON file.
+///
+/// Every field is optional, and only the fields that are set override the running configuration.
+/// The file is applied when the [`crate::GossipDriver`] is built, and every time it is reloaded
+/// through the `opp2p_reloadScoringConfig` RPC.
+///
+/// The gossip, publish and graylist thresholds of gossipsub are fixed once the gossipsub behaviour
+/// is built, and cannot be changed at runtime: `rust-libp2p` offers no way to update them.
+///
+/// ```json
+/// {
+///   "banThreshold": -80,
+///   "banDuration": 30,
+///   "topics": { "meshMessageDeliveriesThreshold": 2 }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ScoringConfig {
+    /// The score under which peers are banned.
+    /// Setting it enables peer banning if it was disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ban_threshold: Option<f64>,
+    /// The duration of a peer's ban, in minutes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ban_duration: Option<u64>,
+    /// Overrides of the score parameters of the block topics.
+    /// Only applies if topic scoring is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub topics: Option<TopicScoringConfig>,
+}
+
+/// Overrides of the [`TopicScoreParams`] of the block topics.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct TopicScoringConfig {
+    /// The cap of the time in mesh component (P1), in time quanta.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_in_mesh_cap: Option<f64>,
+    /// The cap of the first message deliveries counter (P2).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_message_deliveries_cap: Option<f64>,
+    /// The number of mesh message deliveries under which mesh peers are penalized (P3).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mesh_message_deliveries_threshold: Option<f64>,
+    /// The cap of the mesh message deliveries counter (P3).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mesh_message_deliveries_cap: Option<f64>,
+}
+
+impl TopicScoringConfig {
+    /// Applies the overrides to the given [`TopicScoreParams`].
+    pub fn apply(&self, mut params: TopicScoreParams) -> TopicScoreParams {
+        if let Some(cap) = self.time_in_mesh_cap {
+            params.time_in_mesh_cap = cap;
+        }
+        if let Some(cap) = self.first_message_deliveries_cap {
+            params.first_message_deliveries_cap = cap;
+        }
+        if let Some(threshold) = self.mesh_message_deliveries_threshold {
+            params.mesh_message_deliveries_threshold = threshold;
+        }
+        if let Some(cap) = self.mesh_message_deliveries_cap {
+            params.mesh_message_deliveries_cap = cap;
+        }
+        params
+    }
+}
+
+/// An error raised when loading or applying a [`ScoringConfig`].
+#[derive(Debug, Error)]
+pub enum ScoringConfigError {
+    /// No scoring config file is configured.
+    #[error("No peer scoring config file is configured")]
+    NotConfigured,
+    /// Failed to read the scoring config file.
+    #[error("Failed to read the peer scoring config: {0}")]
+    Read(#[from] std::io::Error),
+    /// Failed to parse the scoring config file.
+    #[error("Failed to parse the peer scoring config: {0}")]
+    Parse(#[from] serde_json::Error),
+    /// The topic score overrides are set but topic scoring is disabled.
+    #[error("Topic score overrides require topic scoring to be enabled")]
+    TopicScoringDisabled,
+    /// The topic score overrides result in invalid topic score parameters.
+    #[error("Invalid topic score parameters: {0}")]
+    InvalidTopicParams(&'static str),
+}
+
+impl ScoringConfig {
+    /// Loads the [`ScoringConfig`] from the JSON file at the given path.
+    pub fn load(path: &Path) -> Result<Self, ScoringConfigError> {
+        let contents = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// Returns the [`PeerMonitoring`] resulting from applying the ban overrides to `current`.
+    pub fn peer_monitoring(&self, current: Option<PeerMonitoring>) -> Option<PeerMonitoring> {
+        let mut monitoring = match (current, self.ban_threshold) {
+            (Some(monitoring), _) => monitoring,
+            (None, Some(ban_threshold)) => {
+                PeerMonitoring { ban_threshold, ban_duration: DEFAULT_BAN_DURATION }
+            }
+            (None, None) => return None,
+        };
+        if let Some(ban_threshold) = self.ban_threshold {
+            monitoring.ban_threshold = ban_threshold;
+        }
+        if let Some(minutes) = self.ban_duration {
+            monitoring.ban_duration = Duration::from_secs(60 * minutes);
+        }
+        Some(monitoring)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_peers::PeerScoreLevel;
+
+    #[test]
+    fn test_load_scoring_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("scoring.json");
+        std::fs::write(
+            &path,
+            r#"{"banThreshold": -80, "topics": {"meshMessageDeliveriesThreshold": 2}}"#,
+        )
+        .unwrap();
+
+        let config = ScoringConfig::load(&path).unwrap();
+        assert_eq!(config.ban_threshold, Some(-80.0));
+        assert_eq!(config.ban_duration, None);
+
+        let params = config.topics.unwrap().apply(PeerScoreLevel::topic_score_params(2));
+        assert_eq!(params.mesh_message_deliveries_threshold, 2.0);
+
+        std::fs::write(&path, r#"{"gossipThreshold": -10}"#).unwrap();
+        assert!(matches!(ScoringConfig::load(&path), Err(ScoringConfigError::Parse(_))));
+    }
+
+    #[test]
+    fn test_peer_monitoring_overrides() {
+        let current =
+            PeerMonitoring { ban_threshold: -100.0, ban_duration: Duration::from_secs(60) };
+
+        let config = ScoringConfig { ban_duration: Some(30), ..Default::default() };
+        let monitoring = config.peer_monitoring(Some(current.clone())).unwrap();
+        assert_eq!(monitoring.ban_threshold, -100.0);
+        assert_eq!(monitoring.ban_duration, Duration::from_secs(30 * 60));
+        assert!(config.peer_monitoring(None).is_none());
+
+        let config = ScoringConfig { ban_threshold: Some(-50.0), ..Default::default() };
+        let monitoring = config.peer_monitoring(None).unwrap();
+        assert_eq!(monitoring.ban_threshold, -50.0);
+        assert_eq!(monitoring.ban_duration, DEFAULT_BAN_DURATION);
+    }
+}
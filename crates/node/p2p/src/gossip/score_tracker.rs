@@ -0,0 +1,491 @@
+//! Mirrors the gossipsub peer score to break it down into its components.
+
+use libp2p::{
+    Multiaddr, PeerId,
+    gossipsub::{
+        MessageAcceptance, PeerScoreParams, PeerScoreThresholds, TopicHash, TopicScoreParams,
+    },
+    multiaddr::Protocol,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    net::IpAddr,
+    time::Instant,
+};
+
+use crate::{PeerScoreBreakdown, TopicScoreBreakdown};
+
+/// Tracks the inputs of the gossipsub peer score, to break the score of a peer down into its
+/// components.
+///
+/// `rust-libp2p` only exposes the total score of a peer, so the tracker mirrors the score function
+/// of gossipsub from what the [`crate::GossipDriver`] observes:
+/// - the mesh membership of the peers. Gossipsub emits no event when a peer is grafted or pruned,
+///   so the meshes are sampled on every gossipsub event, every heartbeat and score inspection. Mesh
+///   changes are picked up at most one heartbeat late.
+/// - the validation result of the messages, credited to their propagation source.
+/// - the IP addresses of the connections to the peers.
+///
+/// Only the peers seen connecting are tracked, so that every tracked peer is forgotten once it
+/// has been disconnected for longer than the score retention period.
+#[derive(Debug, Default, Clone)]
+pub struct PeerScoreTracker {
+    /// The score parameters, if peer scoring is enabled.
+    params: Option<PeerScoreParams>,
+    /// The score thresholds, if peer scoring is enabled.
+    thresholds: Option<PeerScoreThresholds>,
+    /// The tracked peers.
+    peers: HashMap<PeerId, TrackedPeer>,
+    /// The last time the counters were decayed.
+    last_decay: Option<Instant>,
+}
+
+/// The score inputs of a peer.
+#[derive(Debug, Default, Clone)]
+struct TrackedPeer {
+    /// The IP addresses of the connections to the peer.
+    ips: HashSet<IpAddr>,
+    /// The time at which the peer disconnected, if it is not connected.
+    disconnected_at: Option<Instant>,
+    /// The score inputs of the peer in each topic.
+    topics: HashMap<TopicHash, TrackedTopic>,
+}
+
+/// The score inputs of a peer in a topic.
+#[derive(Debug, Default, Clone)]
+struct TrackedTopic {
+    /// The time at which the peer was grafted, if it is in the mesh.
+    mesh_since: Option<Instant>,
+    /// The first message deliveries counter.
+    first_message_deliveries: f64,
+    /// The mesh message deliveries counter.
+    mesh_message_deliveries: f64,
+    /// The mesh failure penalty counter.
+    mesh_failure_penalty: f64,
+    /// The invalid message deliveries counter.
+    invalid_message_deliveries: f64,
+}
+
+impl TrackedTopic {
+    /// Returns the mesh message delivery deficit of the peer, if the mesh message delivery
+    /// penalty is active.
+    fn mesh_deficit(&self, params: &TopicScoreParams, now: Instant) -> Option<f64> {
+        let mesh_time = now.saturating_duration_since(self.mesh_since?);
+        (mesh_time > params.mesh_message_deliveries_activation &&
+            self.mesh_message_deliveries < params.mesh_message_deliveries_threshold)
+            .then(|| params.mesh_message_deliveries_threshold - self.mesh_message_deliveries)
+    }
+
+    /// Removes the peer from the mesh, applying the mesh failure penalty if it has a delivery
+    /// deficit.
+    fn prune(&mut self, params: &TopicScoreParams, now: Instant) {
+        if let Some(deficit) = self.mesh_deficit(params, now) {
+            self.mesh_failure_penalty += deficit * deficit;
+        }
+        self.mesh_since = None;
+    }
+}
+
+/// Decays a counter, resetting it to zero once it is below `decay_to_zero`.
+fn decay(counter: &mut f64, factor: f64, decay_to_zero: f64) {
+    *counter *= factor;
+    if *counter < decay_to_zero {
+        *counter = 0.0;
+    }
+}
+
+impl PeerScoreTracker {
+    /// Creates a new [`PeerScoreTracker`] for the given score parameters and thresholds.
+    pub fn new(params: PeerScoreParams, thresholds: PeerScoreThresholds) -> Self {
+        Self { params: Some(params), thresholds: Some(thresholds), ..Default::default() }
+    }
+
+    /// Returns whether peer scoring is enabled.
+    pub const fn is_enabled(&self) -> bool {
+        self.params.is_some()
+    }
+
+    /// Returns the score thresholds, if peer scoring is enabled.
+    pub const fn thresholds(&self) -> Option<&PeerScoreThresholds> {
+        self.thresholds.as_ref()
+    }
+
+    /// Returns the score parameters of the given topic, if topic scoring is enabled.
+    pub fn topic_params(&self, topic: &TopicHash) -> Option<&TopicScoreParams> {
+        self.params.as_ref()?.topics.get(topic)
+    }
+
+    /// Sets the score parameters of the given topic.
+    pub fn set_topic_params(&mut self, topic: TopicHash, params: TopicScoreParams) {
+        if let Some(peer_params) = self.params.as_mut() {
+            peer_params.topics.insert(topic, params);
+        }
+    }
+
+    /// Returns the ids of the tracked peers.
+    pub fn peers(&self) -> impl Iterator<Item = &PeerId> {
+        self.peers.keys()
+    }
+
+    /// Records a connection to the peer at the given address.
+    pub fn connected(&mut self, peer: PeerId, addr: &Multiaddr) {
+        if self.params.is_none() {
+            return;
+        }
+        let tracked = self.peers.entry(peer).or_default();
+        tracked.disconnected_at = None;
+        let ip = addr.iter().find_map(|protocol| match protocol {
+            Protocol::Ip4(ip) => Some(IpAddr::V4(ip)),
+            Protocol::Ip6(ip) => Some(IpAddr::V6(ip)),
+            _ => None,
+        });
+        tracked.ips.extend(ip);
+    }
+
+    /// Records that all the connections to the peer are closed.
+    pub fn disconnected(&mut self, peer: &PeerId, now: Instant) {
+        let Some(params) = self.params.as_ref() else {
+            return;
+        };
+        let Some(tracked) = self.peers.get_mut(peer) else {
+            return;
+        };
+        tracked.ips.clear();
+        tracked.disconnected_at = Some(now);
+        for (topic, stats) in tracked.topics.iter_mut() {
+            if let Some(topic_params) = params.topics.get(topic) {
+                stats.prune(topic_params, now);
+            }
+        }
+    }
+
+    /// Records the validation result of a message in the given topic, first delivered by `peer`.
+    ///
+    /// Messages from peers that are not tracked are ignored.
+    pub fn record_message(
+        &mut self,
+        peer: PeerId,
+        topic: &TopicHash,
+        acceptance: &MessageAcceptance,
+    ) {
+        let Some(params) = self.topic_params(topic) else {
+            return;
+        };
+        let (first_cap, mesh_cap) =
+            (params.first_message_deliveries_cap, params.mesh_message_deliveries_cap);
+        let Some(tracked) = self.peers.get_mut(&peer) else {
+            return;
+        };
+        let stats = tracked.topics.entry(topic.clone()).or_default();
+        match acceptance {
+            MessageAcceptance::Accept => {
+                stats.first_message_deliveries =
+                    (stats.first_message_deliveries + 1.0).min(first_cap);
+                if stats.mesh_since.is_some() {
+                    stats.mesh_message_deliveries =
+                        (stats.mesh_message_deliveries + 1.0).min(mesh_cap);
+                }
+            }
+            MessageAcceptance::Reject => stats.invalid_message_deliveries += 1.0,
+            MessageAcceptance::Ignore => {}
+        }
+    }
+
+    /// Synchronizes the tracked mesh membership of a topic with the current mesh `peers`.
+    ///
+    /// Mesh peers that are not tracked are ignored.
+    pub fn sync_mesh<'a>(
+        &mut self,
+        topic: &TopicHash,
+        peers: impl IntoIterator<Item = &'a PeerId>,
+        now: Instant,
+    ) {
+        let Some(params) = self.params.as_ref().and_then(|p| p.topics.get(topic)) else {
+            return;
+        };
+        let mesh = peers.into_iter().collect::<HashSet<_>>();
+        for peer in &mesh {
+            let Some(tracked) = self.peers.get_mut(*peer) else {
+                continue;
+            };
+            let stats = tracked.topics.entry(topic.clone()).or_default();
+            if stats.mesh_since.is_none() {
+                stats.mesh_since = Some(now);
+            }
+        }
+        for (peer, tracked) in self.peers.iter_mut() {
+            if mesh.contains(peer) {
+                continue;
+            }
+            if let Some(stats) = tracked.topics.get_mut(topic) {
+                if stats.mesh_since.is_some() {
+                    stats.prune(params, now);
+                }
+            }
+        }
+    }
+
+    /// Refreshes the tracker from the current state of the gossipsub behaviour.
+    ///
+    /// Decays the counters and synchronizes the mesh membership of the scored topics.
+    pub fn refresh(&mut self, gossipsub: &libp2p::gossipsub::Behaviour, now: Instant) {
+        self.decay(now);
+        let topics = self
+            .params
+            .as_ref()
+            .map(|params| params.topics.keys().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+        for topic in topics {
+            self.sync_mesh(&topic, gossipsub.mesh_peers(&topic), now);
+        }
+    }
+
+    /// Decays the counters once for every decay interval elapsed since the last decay, and
+    /// forgets the peers that have been disconnected for longer than the score retention period.
+    pub fn decay(&mut self, now: Instant) {
+        let Some(params) = self.params.as_ref() else {
+            return;
+        };
+        let Some(last_decay) = self.last_decay else {
+            self.last_decay = Some(now);
+            return;
+        };
+        let elapsed = now.saturating_duration_since(last_decay);
+        let intervals = (elapsed.as_secs_f64() / params.decay_interval.as_secs_f64()) as i32;
+        if intervals == 0 {
+            return;
+        }
+        self.last_decay = Some(last_decay + params.decay_interval * intervals as u32);
+
+        self.peers.retain(|_, tracked| {
+            tracked
+                .disconnected_at
+                .is_none_or(|at| now.saturating_duration_since(at) < params.retain_score)
+        });
+        let decay_to_zero = params.decay_to_zero;
+        for tracked in self.peers.values_mut() {
+            for (topic, stats) in tracked.topics.iter_mut() {
+                let Some(p) = params.topics.get(topic) else {
+                    continue;
+                };
+                let first = p.first_message_deliveries_decay.powi(intervals);
+                decay(&mut stats.first_message_deliveries, first, decay_to_zero);
+                let mesh = p.mesh_message_deliveries_decay.powi(intervals);
+                decay(&mut stats.mesh_message_deliveries, mesh, decay_to_zero);
+                let failure = p.mesh_failure_penalty_decay.powi(intervals);
+                decay(&mut stats.mesh_failure_penalty, failure, decay_to_zero);
+                let invalid = p.invalid_message_deliveries_decay.powi(intervals);
+                decay(&mut stats.invalid_message_deliveries, invalid, decay_to_zero);
+            }
+        }
+    }
+
+    /// Estimates the breakdown of the `total` gossipsub score of the peer into its components.
+    ///
+    /// The behavioural penalty (P7) counter is private to gossipsub, so it is not estimated. The
+    /// part of `total` not explained by the estimated components is reported as
+    /// [`PeerScoreBreakdown::unexplained`]. The ban state is left to the caller.
+    pub fn breakdown(&self, peer: &PeerId, total: f64, now: Instant) -> PeerScoreBreakdown {
+        let mut breakdown = PeerScoreBreakdown { total, ..Default::default() };
+        let (Some(params), Some(tracked)) = (self.params.as_ref(), self.peers.get(peer)) else {
+            breakdown.unexplained = total;
+            return breakdown;
+        };
+        breakdown.connected = tracked.disconnected_at.is_none();
+
+        let mut topics_score = 0.0;
+        for (topic, stats) in &tracked.topics {
+            let Some(p) = params.topics.get(topic) else {
+                continue;
+            };
+            let weight = p.topic_weight;
+            let time_in_mesh = stats.mesh_since.map_or(0.0, |since| {
+                let quanta = now.saturating_duration_since(since).as_secs_f64() /
+                    p.time_in_mesh_quantum.as_secs_f64();
+                quanta.min(p.time_in_mesh_cap) * p.time_in_mesh_weight * weight
+            });
+            let deficit = stats.mesh_deficit(p, now).unwrap_or_default();
+            let invalid = stats.invalid_message_deliveries;
+            let topic_breakdown = TopicScoreBreakdown {
+                in_mesh: stats.mesh_since.is_some(),
+                time_in_mesh,
+                first_message_deliveries: stats.first_message_deliveries *
+                    p.first_message_deliveries_weight *
+                    weight,
+                mesh_message_deliveries: deficit *
+                    deficit *
+                    p.mesh_message_deliveries_weight *
+                    weight,
+                mesh_failure_penalty: stats.mesh_failure_penalty *
+                    p.mesh_failure_penalty_weight *
+                    weight,
+                invalid_message_deliveries: invalid *
+                    invalid *
+                    p.invalid_message_deliveries_weight *
+                    weight,
+            };
+            topics_score += topic_breakdown.time_in_mesh +
+                topic_breakdown.first_message_deliveries +
+                topic_breakdown.mesh_message_deliveries +
+                topic_breakdown.mesh_failure_penalty +
+                topic_breakdown.invalid_message_deliveries;
+            breakdown.topics.insert(topic.to_string(), topic_breakdown);
+        }
+        if params.topic_score_cap > 0.0 {
+            topics_score = topics_score.min(params.topic_score_cap);
+        }
+
+        // Application-specific scores are not assigned by the node.
+        breakdown.app_specific = 0.0;
+
+        for ip in &tracked.ips {
+            if params.ip_colocation_factor_whitelist.contains(ip) {
+                continue;
+            }
+            let peers_in_ip = self.peers.values().filter(|other| other.ips.contains(ip)).count();
+            let surplus = peers_in_ip as f64 - params.ip_colocation_factor_threshold;
+            if surplus > 0.0 {
+                breakdown.ip_colocation_factor +=
+                    surplus * surplus * params.ip_colocation_factor_weight;
+            }
+        }
+
+        breakdown.unexplained =
+            total - topics_score - breakdown.app_specific - breakdown.ip_colocation_factor;
+        breakdown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_peers::PeerScoreLevel;
+    use std::time::Duration;
+
+    const BLOCK_TIME: u64 = 2;
+
+    fn tracker(topic: &TopicHash) -> PeerScoreTracker {
+        let params =
+            PeerScoreLevel::Light.to_params(vec![topic.clone()], true, BLOCK_TIME).unwrap();
+        PeerScoreTracker::new(params, PeerScoreLevel::thresholds())
+    }
+
+    #[test]
+    fn test_message_deliveries_and_decay() {
+        let topic = TopicHash::from_raw("blocks");
+        let mut tracker = tracker(&topic);
+        let params = tracker.topic_params(&topic).unwrap().clone();
+        let peer = PeerId::random();
+        let now = Instant::now();
+        tracker.decay(now);
+        tracker.connected(peer, &"/ip4/10.0.0.1/tcp/9222".parse().unwrap());
+
+        tracker.record_message(peer, &topic, &MessageAcceptance::Accept);
+        tracker.record_message(peer, &topic, &MessageAcceptance::Reject);
+        tracker.record_message(peer, &topic, &MessageAcceptance::Ignore);
+
+        let breakdown = tracker.breakdown(&peer, 0.0, now);
+        let topic_breakdown = breakdown.topics[&topic.to_string()];
+        assert!(!topic_breakdown.in_mesh);
+        assert_eq!(
+            topic_breakdown.first_message_deliveries,
+            params.first_message_deliveries_weight * params.topic_weight
+        );
+        assert_eq!(
+            topic_breakdown.invalid_message_deliveries,
+            params.invalid_message_deliveries_weight * params.topic_weight
+        );
+
+        // The counters decay once per decay interval.
+        tracker.decay(now + Duration::from_secs(BLOCK_TIME));
+        let decayed = tracker.breakdown(&peer, 0.0, now).topics[&topic.to_string()];
+        let expected =
+            topic_breakdown.first_message_deliveries * params.first_message_deliveries_decay;
+        assert!((decayed.first_message_deliveries - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mesh_delivery_deficit() {
+        let topic = TopicHash::from_raw("blocks");
+        let mut tracker = tracker(&topic);
+        let params = tracker.topic_params(&topic).unwrap().clone();
+        let peer = PeerId::random();
+        let now = Instant::now();
+        tracker.connected(peer, &"/ip4/10.0.0.1/tcp/9222".parse().unwrap());
+
+        tracker.sync_mesh(&topic, [&peer], now);
+        tracker.record_message(peer, &topic, &MessageAcceptance::Accept);
+
+        // The deficit is not penalized before the activation window.
+        let breakdown = tracker.breakdown(&peer, 0.0, now);
+        let topic_breakdown = breakdown.topics[&topic.to_string()];
+        assert!(topic_breakdown.in_mesh);
+        assert_eq!(topic_breakdown.mesh_message_deliveries, 0.0);
+
+        let later = now + params.mesh_message_deliveries_activation + Duration::from_secs(1);
+        let deficit = params.mesh_message_deliveries_threshold - 1.0;
+        let topic_breakdown = tracker.breakdown(&peer, 0.0, later).topics[&topic.to_string()];
+        assert_eq!(
+            topic_breakdown.mesh_message_deliveries,
+            deficit * deficit * params.mesh_message_deliveries_weight * params.topic_weight
+        );
+        assert!(topic_breakdown.time_in_mesh > 0.0);
+
+        // Pruning the peer turns the deficit into a mesh failure penalty.
+        tracker.sync_mesh(&topic, [], later);
+        let topic_breakdown = tracker.breakdown(&peer, 0.0, later).topics[&topic.to_string()];
+        assert!(!topic_breakdown.in_mesh);
+        assert_eq!(topic_breakdown.mesh_message_deliveries, 0.0);
+        assert_eq!(
+            topic_breakdown.mesh_failure_penalty,
+            deficit * deficit * params.mesh_failure_penalty_weight * params.topic_weight
+        );
+    }
+
+    #[test]
+    fn test_ip_colocation_and_unexplained() {
+        let topic = TopicHash::from_raw("blocks");
+        let mut tracker = tracker(&topic);
+        let addr: Multiaddr = "/ip4/10.0.0.1/tcp/9222".parse().unwrap();
+        let peers = (0..12).map(|_| PeerId::random()).collect::<Vec<_>>();
+        for peer in &peers {
+            tracker.connected(*peer, &addr);
+        }
+
+        // 12 peers share the IP, 2 above the colocation threshold of 10.
+        let breakdown = tracker.breakdown(&peers[0], -200.0, Instant::now());
+        assert!(breakdown.connected);
+        assert_eq!(breakdown.ip_colocation_factor, 4.0 * -35.0);
+        assert_eq!(breakdown.unexplained, -200.0 + 140.0);
+
+        tracker.disconnected(&peers[1], Instant::now());
+        let breakdown = tracker.breakdown(&peers[1], 0.0, Instant::now());
+        assert!(!breakdown.connected);
+        assert_eq!(breakdown.ip_colocation_factor, 0.0);
+    }
+
+    #[test]
+    fn test_untracked_peers_are_ignored_and_disconnected_peers_evicted() {
+        let topic = TopicHash::from_raw("blocks");
+        let mut tracker = tracker(&topic);
+        let retain_score = tracker.params.as_ref().unwrap().retain_score;
+        let now = Instant::now();
+        tracker.decay(now);
+
+        // Peers never seen connecting are not tracked, so they can't outlive the retention period.
+        let unknown = PeerId::random();
+        tracker.record_message(unknown, &topic, &MessageAcceptance::Reject);
+        tracker.sync_mesh(&topic, [&unknown], now);
+        assert_eq!(tracker.peers().count(), 0);
+        assert_eq!(tracker.breakdown(&unknown, -5.0, now).unexplained, -5.0);
+
+        let peer = PeerId::random();
+        tracker.connected(peer, &"/ip4/10.0.0.1/tcp/9222".parse().unwrap());
+        tracker.record_message(peer, &topic, &MessageAcceptance::Reject);
+        tracker.disconnected(&peer, now);
+        assert_eq!(tracker.peers().collect::<Vec<_>>(), [&peer]);
+
+        tracker.decay(now + retain_score + Duration::from_secs(BLOCK_TIME));
+        assert_eq!(tracker.peers().count(), 0);
+    }
+}
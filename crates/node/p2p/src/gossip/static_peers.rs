@@ -0,0 +1,164 @@
+//! Static peers that are always dialed by the gossip driver.
+
+use libp2p::{Multiaddr, PeerId};
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use crate::ConnectionGater;
+
+/// The delay before the first redial of a static peer.
+const INITIAL_REDIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// The maximum delay between two redials of a static peer.
+const MAX_REDIAL_BACKOFF: Duration = Duration::from_secs(60);
+
+/// The dial state of a static peer.
+#[derive(Debug, Clone)]
+struct StaticPeer {
+    /// The address to dial the peer on.
+    addr: Multiaddr,
+    /// The number of redials scheduled since the peer was last connected.
+    failures: u32,
+    /// When the peer should be dialed next. `None` while the peer is connected.
+    next_dial: Option<Instant>,
+}
+
+/// The set of static peers.
+///
+/// Static peers are dialed when the gossip driver starts, and redialed with an exponential
+/// backoff whenever they disconnect or cannot be reached.
+#[derive(Debug, Clone, Default)]
+pub struct StaticPeers {
+    /// The static peers, by [`PeerId`].
+    peers: HashMap<PeerId, StaticPeer>,
+}
+
+impl StaticPeers {
+    /// Adds a static peer, scheduling an immediate dial. Returns the [`PeerId`] of the peer, or
+    /// `None` if the [`Multiaddr`] does not contain one.
+    pub fn insert(&mut self, addr: Multiaddr, now: Instant) -> Option<PeerId> {
+        let peer_id = ConnectionGater::peer_id_from_addr(&addr)?;
+        self.peers.insert(peer_id, StaticPeer { addr, failures: 0, next_dial: Some(now) });
+        Some(peer_id)
+    }
+
+    /// Removes a static peer, returning its [`Multiaddr`].
+    pub fn remove(&mut self, peer_id: &PeerId) -> Option<Multiaddr> {
+        self.peers.remove(peer_id).map(|peer| peer.addr)
+    }
+
+    /// Returns if the peer is a static peer.
+    pub fn contains(&self, peer_id: &PeerId) -> bool {
+        self.peers.contains_key(peer_id)
+    }
+
+    /// Returns the addresses of the static peers.
+    pub fn addrs(&self) -> impl Iterator<Item = &Multiaddr> {
+        self.peers.values().map(|peer| &peer.addr)
+    }
+
+    /// Returns the number of static peers.
+    pub fn len(&self) -> usize {
+        self.peers.len()
+    }
+
+    /// Returns if there are no static peers.
+    pub fn is_empty(&self) -> bool {
+        self.peers.is_empty()
+    }
+
+    /// Marks a static peer as connected, resetting its backoff.
+    pub fn connected(&mut self, peer_id: &PeerId) {
+        if let Some(peer) = self.peers.get_mut(peer_id) {
+            peer.failures = 0;
+            peer.next_dial = None;
+        }
+    }
+
+    /// Schedules a redial of a static peer after it disconnected.
+    pub fn disconnected(&mut self, peer_id: &PeerId, now: Instant) {
+        if let Some(peer) = self.peers.get_mut(peer_id) {
+            if peer.next_dial.is_none() {
+                peer.schedule(now);
+            }
+        }
+    }
+
+    /// Returns the addresses of the static peers due for a dial.
+    ///
+    /// The next dial of these peers is scheduled right away, so that they are redialed if this
+    /// dial fails. The schedule is cleared once the peer is [connected](Self::connected).
+    pub fn due(&mut self, now: Instant) -> Vec<Multiaddr> {
+        self.peers
+            .values_mut()
+            .filter(|peer| peer.next_dial.is_some_and(|at| at <= now))
+            .map(|peer| {
+                peer.schedule(now);
+                peer.addr.clone()
+            })
+            .collect()
+    }
+}
+
+impl StaticPeer {
+    /// Schedules the next dial of the peer, doubling the backoff with every consecutive failure
+    /// up to `MAX_REDIAL_BACKOFF`.
+    fn schedule(&mut self, now: Instant) {
+        let backoff = INITIAL_REDIAL_BACKOFF
+            .saturating_mul(2u32.saturating_pow(self.failures))
+            .min(MAX_REDIAL_BACKOFF);
+        self.failures = self.failures.saturating_add(1);
+        self.next_dial = Some(now + backoff);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDR: &str =
+        "/ip4/10.0.0.1/tcp/9222/p2p/12D3KooWEyoppNCUx8Yx66oV9fJnriXwCcXwDDUA2kj6vnc6iDEp";
+
+    #[test]
+    fn test_static_peer_redial_backoff() {
+        let now = Instant::now();
+        let secs = |n| now + Duration::from_secs(n);
+        let addr: Multiaddr = ADDR.parse().unwrap();
+        let mut peers = StaticPeers::default();
+
+        let peer_id = peers.insert(addr.clone(), now).unwrap();
+        assert!(peers.contains(&peer_id));
+        assert_eq!(peers.due(now), vec![addr.clone()]);
+
+        // Failed dials are retried with a backoff doubling every time.
+        assert!(peers.due(now).is_empty());
+        assert_eq!(peers.due(secs(1)), vec![addr.clone()]);
+        assert!(peers.due(secs(2)).is_empty());
+        assert_eq!(peers.due(secs(3)), vec![addr.clone()]);
+
+        // The backoff is capped.
+        let mut at = secs(3);
+        for _ in 0..16 {
+            at += MAX_REDIAL_BACKOFF;
+            assert_eq!(peers.due(at), vec![addr.clone()]);
+        }
+
+        // A connection clears the schedule and resets the backoff.
+        peers.connected(&peer_id);
+        assert!(peers.due(at + MAX_REDIAL_BACKOFF).is_empty());
+        peers.disconnected(&peer_id, now);
+        assert_eq!(peers.due(secs(1)), vec![addr]);
+
+        assert!(peers.remove(&peer_id).is_some());
+        assert!(peers.is_empty());
+    }
+
+    #[test]
+    fn test_static_peer_requires_peer_id() {
+        let mut peers = StaticPeers::default();
+        assert!(peers.insert("/ip4/10.0.0.1/tcp/9222".parse().unwrap(), Instant::now()).is_none());
+        assert_eq!(peers.addrs().count(), 0);
+    }
+}
@@ -15,8 +15,9 @@ pub use metrics::Metrics;
 
 mod rpc;
 pub use rpc::{
-    Connectedness, Direction, GossipScores, P2pRpcRequest, PeerCount, PeerDump, PeerInfo,
-    PeerScores, PeerStats, ReqRespScores, TopicScores,
+    Connectedness, Direction, GossipScores, P2pRpcRequest, PeerBanState, PeerCount, PeerDump,
+    PeerInfo, PeerScoreBreakdown, PeerScoreDump, PeerScores, PeerStats, ReqRespScores,
+    ScoreThresholds, TopicScoreBreakdown, TopicScores,
 };
 
 mod gossip;
@@ -26,7 +27,8 @@ pub use gossip::{
     Event, GLOBAL_VALIDATE_THROTTLE, GOSSIP_HEARTBEAT, GaterConfig, GossipDriver,
     GossipDriverBuilder, GossipDriverBuilderError, Handler, HandlerEncodeError, MAX_GOSSIP_SIZE,
     MAX_OUTBOUND_QUEUE, MAX_VALIDATE_QUEUE, MIN_GOSSIP_SIZE, PEER_SCORE_INSPECT_FREQUENCY,
-    PublishError, SEEN_MESSAGES_TTL, default_config, default_config_builder,
+    PeerScoreTracker, PublishError, SEEN_MESSAGES_TTL, ScoringConfig, ScoringConfigError,
+    StaticPeers, TopicScoringConfig, default_config, default_config_builder,
 };
 
 mod discv5;
@@ -129,6 +129,14 @@ impl Metrics {
         kona_macros::set!(gauge, Self::RPC_CALLS, "method", "opp2p_unprotectPeer", 0);
         kona_macros::set!(gauge, Self::RPC_CALLS, "method", "opp2p_connectPeer", 0);
         kona_macros::set!(gauge, Self::RPC_CALLS, "method", "opp2p_disconnectPeer", 0);
+        kona_macros::set!(gauge, Self::RPC_CALLS, "method", "opp2p_peerScores", 0);
+        kona_macros::set!(gauge, Self::RPC_CALLS, "method", "opp2p_reloadScoringConfig", 0);
+        kona_macros::set!(gauge, Self::RPC_CALLS, "method", "opp2p_addStaticPeer", 0);
+        kona_macros::set!(gauge, Self::RPC_CALLS, "method", "opp2p_removeStaticPeer", 0);
+        kona_macros::set!(gauge, Self::RPC_CALLS, "method", "opp2p_listStaticPeers", 0);
+        kona_macros::set!(gauge, Self::RPC_CALLS, "method", "opp2p_addTrustedPeer", 0);
+        kona_macros::set!(gauge, Self::RPC_CALLS, "method", "opp2p_removeTrustedPeer", 0);
+        kona_macros::set!(gauge, Self::RPC_CALLS, "method", "opp2p_listTrustedPeers", 0);
 
         // Gossip Events
         kona_macros::set!(gauge, Self::GOSSIP_EVENT, "type", "message", 0);
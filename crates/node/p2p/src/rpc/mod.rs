@@ -17,6 +17,8 @@
 //! - [`GossipScores`]: GossipSub-specific scoring metrics
 //! - [`TopicScores`]: Per-topic scoring information
 //! - [`ReqRespScores`]: Request-response protocol scoring
+//! - [`PeerScoreDump`]: Estimated breakdown of the gossipsub score of every peer, with its ban
+//!   state
 //!
 //! ### Connection Management
 //! - [`Connectedness`]: Peer connection state enumeration
@@ -28,6 +30,7 @@
 //! - Node identity and status queries
 //! - Peer listing and statistics
 //! - Connection management (block/unblock peers)
+//! - Peer score inspection and runtime reloading of the ban threshold and topic score caps
 //! - Network address filtering
 //! - Discovery table inspection
 //!
@@ -48,6 +51,7 @@ pub use request::P2pRpcRequest;
 
 mod types;
 pub use types::{
-    Connectedness, Direction, GossipScores, PeerCount, PeerDump, PeerInfo, PeerScores, PeerStats,
-    ReqRespScores, TopicScores,
+    Connectedness, Direction, GossipScores, PeerBanState, PeerCount, PeerDump, PeerInfo,
+    PeerScoreBreakdown, PeerScoreDump, PeerScores, PeerStats, ReqRespScores, ScoreThresholds,
+    TopicScoreBreakdown, TopicScores,
 };
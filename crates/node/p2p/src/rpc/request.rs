@@ -5,9 +5,10 @@ use std::{
     net::IpAddr,
     num::TryFromIntError,
     sync::Arc,
+    time::Instant,
 };
 
-use crate::{Discv5Handler, GossipDriver, GossipScores};
+use crate::{Discv5Handler, GossipDriver, GossipScores, ScoringConfig};
 use alloy_primitives::map::foldhash::fast::RandomState;
 use discv5::{
     enr::{NodeId, k256::ecdsa},
@@ -20,7 +21,10 @@ use tokio::sync::oneshot::Sender;
 
 use super::{
     PeerDump, PeerStats,
-    types::{Connectedness, Direction, PeerInfo, PeerScores},
+    types::{
+        Connectedness, Direction, PeerBanState, PeerInfo, PeerScoreDump, PeerScores,
+        ScoreThresholds,
+    },
 };
 use crate::ConnectionGate;
 
@@ -100,6 +104,32 @@ pub enum P2pRpcRequest {
     },
     /// Request to list all blocked Subnets.
     ListBlockedSubnets(Sender<Vec<IpNet>>),
+    /// Adds a static peer, which is trusted and always dialed.
+    AddStaticPeer {
+        /// The [`Multiaddr`] of the peer, including its peer id.
+        address: Multiaddr,
+        /// The reason the peer could not be added, if any.
+        out: Sender<Result<(), String>>,
+    },
+    /// Removes a static peer.
+    RemoveStaticPeer {
+        /// The id of the peer.
+        peer_id: PeerId,
+    },
+    /// Request to list the addresses of all static peers.
+    ListStaticPeers(Sender<Vec<Multiaddr>>),
+    /// Trusts a peer, exempting it from dial limits, bans and address blocks.
+    AddTrustedPeer {
+        /// The id of the peer.
+        peer_id: PeerId,
+    },
+    /// Untrusts a peer.
+    RemoveTrustedPeer {
+        /// The id of the peer.
+        peer_id: PeerId,
+    },
+    /// Request to list all trusted peers.
+    ListTrustedPeers(Sender<Vec<PeerId>>),
     /// Returns the current peer stats for both the
     /// - Discovery Service ([`crate::Discv5Driver`])
     /// - Gossip Service ([`crate::GossipDriver`])
@@ -107,6 +137,14 @@ pub enum P2pRpcRequest {
     /// This information can be used to briefly monitor the current state of the p2p network for a
     /// given peer.
     PeerStats(Sender<PeerStats>),
+    /// Returns a [`PeerScoreDump`] breaking down the gossipsub score of the peers, along with
+    /// their ban state.
+    PeerScores(Sender<PeerScoreDump>),
+    /// Reloads the peer [`ScoringConfig`] file, returning the applied config or the reason it
+    /// could not be applied.
+    ///
+    /// The gossip, publish and graylist thresholds cannot be changed at runtime.
+    ReloadScoringConfig(Sender<Result<ScoringConfig, String>>),
 }
 
 impl P2pRpcRequest {
@@ -119,6 +157,8 @@ impl P2pRpcRequest {
             Self::Peers { out, connected } => Self::handle_peers(out, connected, gossip, disc),
             Self::DisconnectPeer { peer_id } => Self::disconnect_peer(peer_id, gossip),
             Self::PeerStats(s) => Self::handle_peer_stats(s, gossip, disc),
+            Self::PeerScores(s) => Self::handle_peer_scores(s, gossip),
+            Self::ReloadScoringConfig(s) => Self::reload_scoring_config(s, gossip),
             Self::ConnectPeer { address } => Self::connect_peer(address, gossip),
             Self::BlockPeer { id } => Self::block_peer(id, gossip),
             Self::UnblockPeer { id } => Self::unblock_peer(id, gossip),
@@ -131,15 +171,121 @@ impl P2pRpcRequest {
             Self::BlockSubnet { address } => Self::block_subnet(address, gossip),
             Self::UnblockSubnet { address } => Self::unblock_subnet(address, gossip),
             Self::ListBlockedSubnets(s) => Self::list_blocked_subnets(s, gossip),
+            Self::AddStaticPeer { address, out } => Self::add_static_peer(address, out, gossip),
+            Self::RemoveStaticPeer { peer_id } => Self::remove_static_peer(peer_id, gossip),
+            Self::ListStaticPeers(s) => Self::list_static_peers(s, gossip),
+            Self::AddTrustedPeer { peer_id } => Self::add_trusted_peer(peer_id, gossip),
+            Self::RemoveTrustedPeer { peer_id } => Self::remove_trusted_peer(peer_id, gossip),
+            Self::ListTrustedPeers(s) => Self::list_trusted_peers(s, gossip),
+        }
+    }
+
+    fn handle_peer_scores<G: ConnectionGate>(
+        sender: Sender<PeerScoreDump>,
+        gossip: &mut GossipDriver<G>,
+    ) {
+        let now = Instant::now();
+        gossip.peer_scores.refresh(&gossip.swarm.behaviour().gossipsub, now);
+
+        let blocked_peers = gossip.connection_gate.list_blocked_peers();
+        let ban_threshold = gossip.peer_monitoring.as_ref().map(|m| m.ban_threshold);
+        let thresholds = gossip.peer_scores.thresholds().map(ScoreThresholds::from);
+
+        let peers = gossip
+            .peer_scores
+            .peers()
+            .filter_map(|peer_id| {
+                // The score is only available if peer scoring is enabled.
+                let total = gossip.swarm.behaviour().gossipsub.peer_score(peer_id)?;
+                let mut breakdown = gossip.peer_scores.breakdown(peer_id, total, now);
+                breakdown.ban_state = PeerBanState {
+                    blocked: blocked_peers.contains(peer_id),
+                    below_ban_threshold: ban_threshold.is_some_and(|t| total < t),
+                    graylisted: thresholds.is_some_and(|t| total < t.graylist_threshold),
+                    below_publish_threshold: thresholds
+                        .is_some_and(|t| total < t.publish_threshold),
+                    below_gossip_threshold: thresholds.is_some_and(|t| total < t.gossip_threshold),
+                };
+                Some((peer_id.to_string(), breakdown))
+            })
+            .collect();
+
+        if let Err(e) = sender.send(PeerScoreDump { thresholds, ban_threshold, peers }) {
+            warn!(target: "p2p::rpc", "Failed to send peer scores through response channel: {:?}", e);
+        }
+    }
+
+    fn reload_scoring_config<G: ConnectionGate>(
+        sender: Sender<Result<ScoringConfig, String>>,
+        gossip: &mut GossipDriver<G>,
+    ) {
+        let result = gossip.reload_scoring_config().map_err(|e| e.to_string());
+        if let Err(e) = &result {
+            warn!(target: "p2p::rpc", "Failed to reload the peer scoring config: {e}");
+        }
+        if sender.send(result).is_err() {
+            warn!(target: "p2p::rpc", "Failed to send the scoring config through response channel");
         }
     }
 
     fn protect_peer<G: ConnectionGate>(id: PeerId, gossip: &mut GossipDriver<G>) {
         gossip.connection_gate.protect_peer(id);
+        if let Some(history) = &mut gossip.peer_history {
+            history.set_protected(id, true);
+            history.sync();
+        }
     }
 
     fn unprotect_peer<G: ConnectionGate>(id: PeerId, gossip: &mut GossipDriver<G>) {
         gossip.connection_gate.unprotect_peer(id);
+        if let Some(history) = &mut gossip.peer_history {
+            history.set_protected(id, false);
+            history.sync();
+        }
+    }
+
+    fn add_static_peer<G: ConnectionGate>(
+        address: Multiaddr,
+        out: Sender<Result<(), String>>,
+        gossip: &mut GossipDriver<G>,
+    ) {
+        let result = if gossip.add_static_peer(address.clone()) {
+            Ok(())
+        } else {
+            warn!(target: "p2p::rpc", ?address, "Static peer address is missing a peer id");
+            Err(format!("Static peer address is missing a peer id: {address}"))
+        };
+        if out.send(result).is_err() {
+            warn!(target: "p2p::rpc", "Failed to send the static peer result through response channel");
+        }
+    }
+
+    fn remove_static_peer<G: ConnectionGate>(id: PeerId, gossip: &mut GossipDriver<G>) {
+        if !gossip.remove_static_peer(&id) {
+            debug!(target: "p2p::rpc", peer = ?id, "Peer is not a static peer");
+        }
+    }
+
+    fn list_static_peers<G: ConnectionGate>(s: Sender<Vec<Multiaddr>>, gossip: &GossipDriver<G>) {
+        let static_peers = gossip.static_peers.addrs().cloned().collect();
+        if let Err(e) = s.send(static_peers) {
+            warn!(target: "p2p::rpc", "Failed to send static peers through response channel: {:?}", e);
+        }
+    }
+
+    fn add_trusted_peer<G: ConnectionGate>(id: PeerId, gossip: &mut GossipDriver<G>) {
+        gossip.trust_peer(id);
+    }
+
+    fn remove_trusted_peer<G: ConnectionGate>(id: PeerId, gossip: &mut GossipDriver<G>) {
+        gossip.untrust_peer(&id);
+    }
+
+    fn list_trusted_peers<G: ConnectionGate>(s: Sender<Vec<PeerId>>, gossip: &GossipDriver<G>) {
+        let trusted_peers = gossip.connection_gate.list_trusted_peers();
+        if let Err(e) = s.send(trusted_peers) {
+            warn!(target: "p2p::rpc", "Failed to send trusted peers through response channel: {:?}", e);
+        }
     }
 
     fn block_addr<G: ConnectionGate>(address: IpAddr, gossip: &mut GossipDriver<G>) {
@@ -160,11 +306,19 @@ impl P2pRpcRequest {
     fn block_peer<G: ConnectionGate>(id: PeerId, gossip: &mut GossipDriver<G>) {
         gossip.connection_gate.block_peer(&id);
         gossip.swarm.behaviour_mut().gossipsub.blacklist_peer(&id);
+        if let Some(history) = &mut gossip.peer_history {
+            history.set_blocked(id, true);
+            history.sync();
+        }
     }
 
     fn unblock_peer<G: ConnectionGate>(id: PeerId, gossip: &mut GossipDriver<G>) {
         gossip.connection_gate.unblock_peer(&id);
         gossip.swarm.behaviour_mut().gossipsub.remove_blacklisted_peer(&id);
+        if let Some(history) = &mut gossip.peer_history {
+            history.set_blocked(id, false);
+            history.sync();
+        }
     }
 
     fn list_blocked_peers<G: ConnectionGate>(s: Sender<Vec<PeerId>>, gossip: &GossipDriver<G>) {
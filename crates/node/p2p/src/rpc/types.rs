@@ -161,6 +161,122 @@ pub struct PeerScores {
     pub req_resp: ReqRespScores,
 }
 
+/// The breakdown of the gossipsub score of the peers.
+///
+/// Returned by the `opp2p_peerScores` RPC method.
+#[derive(Clone, Default, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerScoreDump {
+    /// The gossipsub score thresholds, if peer scoring is enabled.
+    ///
+    /// These are fixed when the node starts, and are not changed by `opp2p_reloadScoringConfig`.
+    pub thresholds: Option<ScoreThresholds>,
+    /// The score under which peers are banned, if peer banning is enabled.
+    pub ban_threshold: Option<f64>,
+    /// A map from peer id to the breakdown of its score.
+    pub peers: HashMap<String, PeerScoreBreakdown>,
+}
+
+/// The gossipsub score thresholds.
+///
+/// These are fixed once the gossipsub behaviour is built.
+#[derive(Clone, Default, Debug, Copy, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoreThresholds {
+    /// The score under which gossip is neither emitted to nor accepted from a peer.
+    pub gossip_threshold: f64,
+    /// The score under which self-published messages are not propagated to a peer.
+    pub publish_threshold: f64,
+    /// The score under which all messages from a peer are ignored.
+    pub graylist_threshold: f64,
+    /// The score above which peer exchange from a peer is accepted.
+    pub accept_px_threshold: f64,
+    /// The median mesh score above which opportunistic grafting is not triggered.
+    pub opportunistic_graft_threshold: f64,
+}
+
+impl From<&libp2p::gossipsub::PeerScoreThresholds> for ScoreThresholds {
+    fn from(thresholds: &libp2p::gossipsub::PeerScoreThresholds) -> Self {
+        Self {
+            gossip_threshold: thresholds.gossip_threshold,
+            publish_threshold: thresholds.publish_threshold,
+            graylist_threshold: thresholds.graylist_threshold,
+            accept_px_threshold: thresholds.accept_px_threshold,
+            opportunistic_graft_threshold: thresholds.opportunistic_graft_threshold,
+        }
+    }
+}
+
+/// The estimated breakdown of the gossipsub score of a peer into its weighted components.
+///
+/// `rust-libp2p` only exposes the total score of a peer (see
+/// `<https://github.com/libp2p/rust-libp2p/issues/6058>`), so the components are estimates
+/// mirrored by the [`crate::PeerScoreTracker`]. Only [`Self::total`] is the score gossipsub acts
+/// on.
+#[derive(Clone, Default, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerScoreBreakdown {
+    /// The total score, as computed by gossipsub.
+    pub total: f64,
+    /// Whether the peer is connected. The score of disconnected peers is retained for a while.
+    pub connected: bool,
+    /// A map from topic to the estimated topic score components (P1-P4).
+    pub topics: HashMap<String, TopicScoreBreakdown>,
+    /// The application-specific score (P5).
+    pub app_specific: f64,
+    /// The estimated IP colocation factor (P6).
+    #[serde(rename = "IPColocationFactor")]
+    pub ip_colocation_factor: f64,
+    /// The part of the total score that is not explained by the estimated components, after the
+    /// topic score cap.
+    ///
+    /// The behavioural penalty (P7) counter is not observable outside of gossipsub, so it is not
+    /// estimated on its own. This residual includes it, along with the estimation error of the
+    /// other components, and can be positive even though the penalty itself never is.
+    pub unexplained: f64,
+    /// The ban state of the peer.
+    pub ban_state: PeerBanState,
+}
+
+/// The estimated weighted score components of a peer in a topic.
+#[derive(Clone, Default, Debug, Copy, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopicScoreBreakdown {
+    /// Whether the peer is in the topic mesh.
+    pub in_mesh: bool,
+    /// The time in mesh component (P1).
+    pub time_in_mesh: f64,
+    /// The first message deliveries component (P2).
+    pub first_message_deliveries: f64,
+    /// The mesh message delivery deficit component (P3).
+    ///
+    /// Duplicate messages are not surfaced by gossipsub, so only the messages first delivered by
+    /// the peer are counted, which can overestimate the deficit of the peer.
+    pub mesh_message_deliveries: f64,
+    /// The mesh failure penalty component (P3b).
+    pub mesh_failure_penalty: f64,
+    /// The invalid message deliveries component (P4).
+    pub invalid_message_deliveries: f64,
+}
+
+/// The ban state of a peer.
+#[derive(Clone, Default, Debug, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerBanState {
+    /// Whether the peer is blocked by the connection gate.
+    pub blocked: bool,
+    /// Whether the score of the peer is below the ban threshold.
+    pub below_ban_threshold: bool,
+    /// Whether the score of the peer is below the graylist threshold.
+    ///
+    /// This and the following flags compare the total score to the fixed gossipsub thresholds.
+    pub graylisted: bool,
+    /// Whether the score of the peer is below the publish threshold.
+    pub below_publish_threshold: bool,
+    /// Whether the score of the peer is below the gossip threshold.
+    pub below_gossip_threshold: bool,
+}
+
 /// Peer count data.
 #[derive(Clone, Default, Debug, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -0,0 +1,507 @@
+//! Persistent Peer Store
+
+use libp2p::{Multiaddr, PeerId};
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    fs::File,
+    io::{BufReader, Write},
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// The maximum number of peers that can be stored in the peer store.
+const MAX_PEERS: usize = 2048;
+
+/// The maximum number of last-seen addresses kept for a peer.
+const MAX_ADDRESSES: usize = 8;
+
+/// The maximum number of score samples kept for a peer.
+const MAX_SCORE_SAMPLES: usize = 32;
+
+/// A sample of a peer's gossip score.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ScoreSample {
+    /// The unix timestamp at which the score was sampled, in seconds.
+    pub timestamp: u64,
+    /// The gossip score of the peer.
+    pub score: f64,
+}
+
+/// The history of a peer, persisted in the [`PeerStore`].
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PeerRecord {
+    /// The addresses the peer was last connected on, most recent first.
+    pub addresses: VecDeque<Multiaddr>,
+    /// The unix timestamp of the last connection to the peer, in seconds.
+    pub last_seen: Option<u64>,
+    /// The number of connections successfully established with the peer.
+    pub successful_connections: u64,
+    /// The number of failed attempts to dial the peer.
+    pub failed_connections: u64,
+    /// The most recent score samples of the peer, oldest first.
+    pub score_history: VecDeque<ScoreSample>,
+    /// The unix timestamp until which the peer is banned, in seconds.
+    pub banned_until: Option<u64>,
+    /// Whether the peer was blocked through the `opp2p_blockPeer` RPC.
+    pub blocked: bool,
+    /// Whether the peer was protected through the `opp2p_protectPeer` RPC.
+    pub protected: bool,
+}
+
+impl PeerRecord {
+    /// Returns the ratio of successful connections, with a neutral prior of one success and one
+    /// failure so that peers without history have a rate of `0.5`.
+    pub fn success_rate(&self) -> f64 {
+        (self.successful_connections + 1) as f64 /
+            (self.successful_connections + self.failed_connections + 2) as f64
+    }
+
+    /// Returns the average of the recorded scores, if any.
+    pub fn average_score(&self) -> Option<f64> {
+        if self.score_history.is_empty() {
+            return None;
+        }
+        let total = self.score_history.iter().map(|s| s.score).sum::<f64>();
+        Some(total / self.score_history.len() as f64)
+    }
+
+    /// Returns the reputation of the peer.
+    ///
+    /// The reputation is the [`PeerRecord::success_rate`], adjusted by the
+    /// [`PeerRecord::average_score`] scaled down to `[-1, 1]`. A peer without history has a
+    /// reputation of [`PeerStore::NEUTRAL_REPUTATION`].
+    pub fn reputation(&self) -> f64 {
+        let score = self.average_score().unwrap_or_default().clamp(-100.0, 100.0) / 100.0;
+        self.success_rate() + score
+    }
+
+    /// Returns if the peer is banned at the given unix timestamp.
+    pub fn is_banned(&self, now: u64) -> bool {
+        self.banned_until.is_some_and(|until| until > now)
+    }
+
+    /// Returns if the record must be kept regardless of the peer's reputation.
+    fn is_pinned(&self, now: u64) -> bool {
+        self.blocked || self.protected || self.is_banned(now)
+    }
+}
+
+/// On-disk storage for the history of peers.
+///
+/// The [`PeerStore`] is a JSON file that maps peer ids to their [`PeerRecord`]s, used to restore
+/// bans, blocked and protected peers after a restart, and to prefer dialing peers with a good
+/// history.
+///
+/// When the number of peers within the [`PeerStore`] exceeds `MAX_PEERS`, the peers with the
+/// lowest reputation are removed when the store is written to disk.
+///
+/// With [`PeerStore::with_writer`], the store is written by a background thread, so that
+/// [`PeerStore::sync`] never blocks the caller on disk I/O.
+#[derive(Debug, Default, Clone)]
+pub struct PeerStore {
+    /// The file path for the [`PeerStore`].
+    pub path: PathBuf,
+    /// The [`PeerRecord`]s of the peers.
+    pub peers: HashMap<PeerId, PeerRecord>,
+    /// The sender of the background writer, if any.
+    writer: Option<mpsc::Sender<WriterMessage>>,
+}
+
+/// A message to the background writer of a [`PeerStore`].
+#[derive(Debug)]
+enum WriterMessage {
+    /// Persists the given records.
+    Write(BTreeMap<String, PeerRecord>),
+    /// Acknowledges once all previously sent records are persisted.
+    Flush(mpsc::SyncSender<()>),
+}
+
+impl PeerStore {
+    /// The reputation of a peer without history.
+    pub const NEUTRAL_REPUTATION: f64 = 0.5;
+
+    /// Returns the current unix timestamp, in seconds.
+    pub fn timestamp() -> u64 {
+        SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default()
+    }
+
+    /// Returns the [`PathBuf`] for the given chain id.
+    pub fn path(chain_id: u64, datadir: Option<PathBuf>) -> PathBuf {
+        let mut path = datadir.unwrap_or_else(|| {
+            let mut home = dirs::home_dir().expect("Failed to get home directory");
+            home.push(".kona");
+            home
+        });
+        path.push(chain_id.to_string());
+        path.push("peerstore.json");
+        path
+    }
+
+    /// Reads a new [`PeerStore`] from the given chain id and data directory.
+    ///
+    /// If the file cannot be read, an empty [`PeerStore`] is returned.
+    pub fn from_chain_id(chain_id: u64, datadir: Option<PathBuf>) -> Self {
+        Self::from_file(Self::path(chain_id, datadir))
+    }
+
+    /// Reads a new [`PeerStore`] from the given path.
+    ///
+    /// If the file cannot be read, an empty [`PeerStore`] is returned. Records with an invalid
+    /// peer id or format are ignored.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Self {
+        let p = path.as_ref().to_path_buf();
+        let records = File::open(path)
+            .map(|file| {
+                debug!(target: "peerstore", "Reading peer store from disk: {:?}", p);
+                serde_json::from_reader::<_, BTreeMap<String, serde_json::Value>>(BufReader::new(
+                    file,
+                ))
+                .unwrap_or_else(|e| {
+                    warn!(target: "peerstore", "Failed to read peer store from disk: {:?}", e);
+                    BTreeMap::new()
+                })
+            })
+            .unwrap_or_default();
+
+        let mut peers = HashMap::with_capacity(records.len());
+        for (peer_id, record) in records {
+            let Ok(peer_id) = peer_id.parse::<PeerId>() else {
+                warn!(target: "peerstore", "Failed to parse peer id: {}", peer_id);
+                continue;
+            };
+            match serde_json::from_value::<PeerRecord>(record) {
+                Ok(record) => {
+                    peers.insert(peer_id, record);
+                }
+                Err(e) => {
+                    warn!(target: "peerstore", "Failed to deserialize peer record: {:?}", e);
+                }
+            }
+        }
+        Self { path: p, peers, writer: None }
+    }
+
+    /// Writes the store from a background thread, instead of from the caller of
+    /// [`Self::sync`].
+    ///
+    /// If the thread cannot be spawned, the store keeps being written by the caller.
+    pub fn with_writer(mut self) -> Self {
+        let (writer, messages) = mpsc::channel();
+        let path = self.path.clone();
+        match std::thread::Builder::new()
+            .name("peerstore".to_string())
+            .spawn(move || Self::run_writer(&path, messages))
+        {
+            Ok(_) => self.writer = Some(writer),
+            Err(e) => warn!(target: "peerstore", "Failed to spawn the peer store writer: {:?}", e),
+        }
+        self
+    }
+
+    /// Returns the number of peers in the store.
+    pub fn len(&self) -> usize {
+        self.peers.len()
+    }
+
+    /// Returns if the store is empty.
+    pub fn is_empty(&self) -> bool {
+        self.peers.is_empty()
+    }
+
+    /// Returns the [`PeerRecord`] of the given peer.
+    pub fn get(&self, peer_id: &PeerId) -> Option<&PeerRecord> {
+        self.peers.get(peer_id)
+    }
+
+    /// Records a connection established with the peer on the given address.
+    pub fn record_connected(&mut self, peer_id: PeerId, addr: Multiaddr, now: u64) {
+        let record = self.peers.entry(peer_id).or_default();
+        record.successful_connections += 1;
+        record.last_seen = Some(now);
+        record.addresses.retain(|a| a != &addr);
+        record.addresses.push_front(addr);
+        record.addresses.truncate(MAX_ADDRESSES);
+    }
+
+    /// Records a failed attempt to dial the peer.
+    pub fn record_dial_failure(&mut self, peer_id: PeerId) {
+        self.peers.entry(peer_id).or_default().failed_connections += 1;
+    }
+
+    /// Records a sample of the peer's gossip score.
+    pub fn record_score(&mut self, peer_id: PeerId, score: f64, now: u64) {
+        let record = self.peers.entry(peer_id).or_default();
+        record.score_history.push_back(ScoreSample { timestamp: now, score });
+        if record.score_history.len() > MAX_SCORE_SAMPLES {
+            record.score_history.pop_front();
+        }
+    }
+
+    /// Bans the peer until the given unix timestamp.
+    pub fn ban(&mut self, peer_id: PeerId, until: u64) {
+        self.peers.entry(peer_id).or_default().banned_until = Some(until);
+    }
+
+    /// Marks the peer as blocked or unblocked.
+    pub fn set_blocked(&mut self, peer_id: PeerId, blocked: bool) {
+        self.peers.entry(peer_id).or_default().blocked = blocked;
+    }
+
+    /// Marks the peer as protected or unprotected.
+    pub fn set_protected(&mut self, peer_id: PeerId, protected: bool) {
+        self.peers.entry(peer_id).or_default().protected = protected;
+    }
+
+    /// Returns the blocked peers.
+    pub fn blocked_peers(&self) -> impl Iterator<Item = &PeerId> {
+        self.peers.iter().filter(|(_, r)| r.blocked).map(|(id, _)| id)
+    }
+
+    /// Returns the protected peers.
+    pub fn protected_peers(&self) -> impl Iterator<Item = &PeerId> {
+        self.peers.iter().filter(|(_, r)| r.protected).map(|(id, _)| id)
+    }
+
+    /// Returns the peers banned at the given unix timestamp, with the end of their ban.
+    pub fn banned_peers(&self, now: u64) -> impl Iterator<Item = (&PeerId, u64)> {
+        self.peers
+            .iter()
+            .filter_map(move |(id, r)| r.banned_until.filter(|until| *until > now).map(|u| (id, u)))
+    }
+
+    /// Returns the reputation of the peer, or [`Self::NEUTRAL_REPUTATION`] if it is unknown.
+    pub fn reputation(&self, peer_id: &PeerId) -> f64 {
+        self.peers.get(peer_id).map_or(Self::NEUTRAL_REPUTATION, PeerRecord::reputation)
+    }
+
+    /// Returns if the peer is neither blocked nor banned at the given unix timestamp.
+    pub fn is_dialable(&self, peer_id: &PeerId, now: u64) -> bool {
+        self.peers.get(peer_id).is_none_or(|r| !r.blocked && !r.is_banned(now))
+    }
+
+    /// Returns the dialable peers that were connected to at least once, with their last-seen
+    /// addresses, ordered by decreasing reputation.
+    pub fn preferred_peers(&self, now: u64) -> Vec<(PeerId, &PeerRecord)> {
+        let mut peers = self
+            .peers
+            .iter()
+            .filter(|(id, r)| !r.addresses.is_empty() && self.is_dialable(id, now))
+            .map(|(id, r)| (*id, r))
+            .collect::<Vec<_>>();
+        peers.sort_by(|(_, a), (_, b)| b.reputation().total_cmp(&a.reputation()));
+        peers
+    }
+
+    /// Writes the [`PeerStore`] to disk.
+    ///
+    /// If the store has a background writer, the records are handed over to it and only the
+    /// latest of the pending records is written. This method will **not** panic on failure to
+    /// write to disk.
+    pub fn sync(&mut self) {
+        self.prune(Self::timestamp());
+        let records = self
+            .peers
+            .iter()
+            .map(|(id, r)| (id.to_string(), r.clone()))
+            .collect::<BTreeMap<_, _>>();
+        if let Some(writer) = &self.writer {
+            if writer.send(WriterMessage::Write(records)).is_err() {
+                warn!(target: "peerstore", "Peer store writer stopped, not persisting");
+            }
+            return;
+        }
+        if let Err(e) = Self::write_to_file(&self.path, &records) {
+            warn!(target: "peerstore", "Failed to write peer store to disk: {:?}", e);
+        }
+    }
+
+    /// Blocks until every record synced so far is written to disk.
+    pub fn flush(&self) {
+        let Some(writer) = &self.writer else {
+            return;
+        };
+        let (ack, done) = mpsc::sync_channel(1);
+        if writer.send(WriterMessage::Flush(ack)).is_ok() {
+            let _ = done.recv();
+        }
+    }
+
+    /// Removes the peers with the lowest reputation until the store holds at most `MAX_PEERS`
+    /// peers. Blocked, protected and banned peers are never removed.
+    fn prune(&mut self, now: u64) {
+        let excess = self.peers.len().saturating_sub(MAX_PEERS);
+        if excess == 0 {
+            return;
+        }
+        let mut candidates = self
+            .peers
+            .iter()
+            .filter(|(_, r)| !r.is_pinned(now))
+            .map(|(id, r)| (*id, r.reputation()))
+            .collect::<Vec<_>>();
+        candidates.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        for (peer_id, _) in candidates.into_iter().take(excess) {
+            self.peers.remove(&peer_id);
+        }
+        debug!(target: "peerstore", "Pruned {} peers from the peer store", excess);
+    }
+
+    /// Runs the background writer, persisting the latest received records until every
+    /// [`PeerStore`] handle is dropped.
+    fn run_writer(path: &Path, messages: mpsc::Receiver<WriterMessage>) {
+        while let Ok(message) = messages.recv() {
+            let mut latest = None;
+            let mut acks = Vec::new();
+            // Only the most recent of the queued records needs to be written.
+            for message in std::iter::once(message).chain(messages.try_iter()) {
+                match message {
+                    WriterMessage::Write(records) => latest = Some(records),
+                    WriterMessage::Flush(ack) => acks.push(ack),
+                }
+            }
+            if let Some(records) = latest {
+                if let Err(e) = Self::write_to_file(path, &records) {
+                    warn!(target: "peerstore", "Failed to write peer store to disk: {:?}", e);
+                }
+            }
+            for ack in acks {
+                let _ = ack.send(());
+            }
+        }
+    }
+
+    /// Writes the records to disk.
+    ///
+    /// The records are first written to a temporary file which is then renamed over the store,
+    /// so that a crash never leaves a partially written store behind.
+    fn write_to_file(
+        path: &Path,
+        records: &BTreeMap<String, PeerRecord>,
+    ) -> Result<(), std::io::Error> {
+        // If the directory does not exist, create it.
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp_path = path.with_extension("tmp");
+        let mut file = File::create(&tmp_path)?;
+        serde_json::to_writer(&mut file, records)?;
+        file.flush()?;
+        file.sync_all()?;
+        std::fs::rename(&tmp_path, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addr(port: u16) -> Multiaddr {
+        format!("/ip4/127.0.0.1/tcp/{port}").parse().unwrap()
+    }
+
+    #[test]
+    fn test_peer_store_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("peerstore.json");
+
+        let (good, banned, blocked) = (PeerId::random(), PeerId::random(), PeerId::random());
+        let mut store = PeerStore::from_file(&path);
+        assert!(store.is_empty());
+
+        store.record_connected(good, addr(9000), 10);
+        store.record_score(good, 5.0, 10);
+        store.ban(banned, 100);
+        store.set_blocked(blocked, true);
+        store.set_protected(good, true);
+        store.sync();
+
+        let store = PeerStore::from_file(&path);
+        assert_eq!(store.len(), 3);
+        let record = store.get(&good).unwrap();
+        assert_eq!(record.addresses, VecDeque::from([addr(9000)]));
+        assert_eq!(record.last_seen, Some(10));
+        assert_eq!(record.successful_connections, 1);
+        assert_eq!(
+            record.score_history,
+            VecDeque::from([ScoreSample { timestamp: 10, score: 5.0 }])
+        );
+        assert_eq!(store.protected_peers().collect::<Vec<_>>(), vec![&good]);
+        assert_eq!(store.blocked_peers().collect::<Vec<_>>(), vec![&blocked]);
+        assert_eq!(store.banned_peers(50).collect::<Vec<_>>(), vec![(&banned, 100)]);
+        assert_eq!(store.banned_peers(100).count(), 0);
+    }
+
+    #[test]
+    fn test_peer_store_background_writer() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("peerstore.json");
+        let (blocked, banned) = (PeerId::random(), PeerId::random());
+
+        let mut store = PeerStore::from_file(&path).with_writer();
+        store.set_blocked(blocked, true);
+        store.sync();
+        store.ban(banned, 100);
+        store.sync();
+        store.flush();
+
+        // Only the complete store is written, without any temporary file left behind.
+        let restored = PeerStore::from_file(&path);
+        assert_eq!(restored.len(), 2);
+        assert_eq!(restored.blocked_peers().collect::<Vec<_>>(), vec![&blocked]);
+        assert_eq!(restored.banned_peers(50).collect::<Vec<_>>(), vec![(&banned, 100)]);
+        assert!(!path.with_extension("tmp").exists());
+    }
+
+    #[test]
+    fn test_peer_store_ignores_invalid_records() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("peerstore.json");
+        let peer = PeerId::random();
+        std::fs::write(
+            &path,
+            format!(
+                r#"{{"{peer}": {{"failedConnections": 2}}, "invalid": {{}}, "{}": 1}}"#,
+                PeerId::random()
+            ),
+        )
+        .unwrap();
+
+        let store = PeerStore::from_file(&path);
+        assert_eq!(store.len(), 1);
+        assert_eq!(store.get(&peer).unwrap().failed_connections, 2);
+    }
+
+    #[test]
+    fn test_preferred_peers() {
+        let mut store = PeerStore::default();
+        let (reliable, flaky, banned, unseen) =
+            (PeerId::random(), PeerId::random(), PeerId::random(), PeerId::random());
+
+        for _ in 0..3 {
+            store.record_connected(reliable, addr(1), 10);
+        }
+        store.record_connected(flaky, addr(2), 10);
+        for _ in 0..3 {
+            store.record_dial_failure(flaky);
+        }
+        store.record_connected(banned, addr(3), 10);
+        store.ban(banned, 20);
+        store.record_dial_failure(unseen);
+
+        let preferred = store.preferred_peers(15).into_iter().map(|(id, _)| id).collect::<Vec<_>>();
+        assert_eq!(preferred, vec![reliable, flaky]);
+        assert!(store.reputation(&reliable) > PeerStore::NEUTRAL_REPUTATION);
+        assert!(store.reputation(&flaky) < PeerStore::NEUTRAL_REPUTATION);
+        assert_eq!(store.reputation(&PeerId::random()), PeerStore::NEUTRAL_REPUTATION);
+
+        // Negative scores lower the reputation.
+        store.record_score(reliable, -100.0, 10);
+        assert!(store.reputation(&reliable) < store.reputation(&flaky));
+
+        // The ban expires.
+        assert!(!store.is_dialable(&banned, 15));
+        assert!(store.is_dialable(&banned, 20));
+    }
+}
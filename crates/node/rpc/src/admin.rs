@@ -3,6 +3,7 @@
 use crate::AdminApiServer;
 use alloy_primitives::B256;
 use async_trait::async_trait;
+use core::fmt::Display;
 use jsonrpsee::{
     core::RpcResult,
     types::{ErrorCode, ErrorObject},
@@ -15,16 +16,78 @@ use tokio::sync::oneshot;
 pub enum SequencerAdminQuery {
     /// A query to check if the sequencer is active.
     SequencerActive(oneshot::Sender<bool>),
-    /// A query to start the sequencer.
-    StartSequencer,
-    /// A query to stop the sequencer.
-    StopSequencer(oneshot::Sender<B256>),
+    /// A query to start the sequencer, responding with the reason if it cannot be started.
+    StartSequencer(oneshot::Sender<Result<(), String>>),
+    /// A query to stop the sequencer, responding with the hash of the unsafe head it stopped at,
+    /// or with the reason if the stopped state cannot be persisted.
+    StopSequencer(oneshot::Sender<Result<B256, String>>),
     /// A query to check if the conductor is enabled.
     ConductorEnabled(oneshot::Sender<bool>),
-    /// A query to set the recover mode.
-    SetRecoveryMode(bool),
+    /// A query to set the recover mode, responding with the reason if it cannot be persisted.
+    SetRecoveryMode(bool, oneshot::Sender<Result<(), String>>),
     /// A query to override the leader.
     OverrideLeader,
+    /// A query to set the [`SequencerBuildMode`], responding with the reason if it cannot be
+    /// persisted.
+    SetBuildMode(SequencerBuildMode, oneshot::Sender<Result<(), String>>),
+    /// A query to get the current [`SequencerBuildMode`].
+    BuildMode(oneshot::Sender<SequencerBuildMode>),
+}
+
+/// The mode the sequencer builds blocks in.
+///
+/// In the [`SequencerBuildMode::NoTxPool`] mode, the sequencer builds blocks without the
+/// transactions of the execution layer's transaction pool, so they only include the L1 deposits
+/// and the protocol's upgrade transactions. The payload attributes cannot filter the transaction
+/// pool, so individual senders cannot be left out of blocks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "mode", rename_all = "camelCase")]
+pub enum SequencerBuildMode {
+    /// Blocks include the transactions of the transaction pool.
+    #[default]
+    Normal,
+    /// Blocks only include deposits.
+    NoTxPool {
+        /// The number of blocks left to build without the transaction pool, after which the
+        /// sequencer returns to the [`SequencerBuildMode::Normal`] mode. If `None`, blocks are
+        /// built without the transaction pool until the mode is changed.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        blocks: Option<u64>,
+    },
+}
+
+impl SequencerBuildMode {
+    /// Returns the name of the mode, without the number of blocks left.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Normal => "normal",
+            Self::NoTxPool { .. } => "no-tx-pool",
+        }
+    }
+
+    /// Returns whether blocks are built without the transaction pool.
+    pub const fn is_no_tx_pool(&self) -> bool {
+        matches!(self, Self::NoTxPool { .. })
+    }
+
+    /// Returns the mode to build the next block in, after a block was built in this mode.
+    pub const fn advance(self) -> Self {
+        match self {
+            Self::NoTxPool { blocks: Some(0 | 1) } => Self::Normal,
+            Self::NoTxPool { blocks: Some(blocks) } => Self::NoTxPool { blocks: Some(blocks - 1) },
+            mode => mode,
+        }
+    }
+}
+
+impl Display for SequencerBuildMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Normal => write!(f, "normal"),
+            Self::NoTxPool { blocks: None } => write!(f, "no-tx-pool"),
+            Self::NoTxPool { blocks: Some(blocks) } => write!(f, "no-tx-pool({blocks})"),
+        }
+    }
 }
 
 /// The query types to the network actor for the admin api.
@@ -82,10 +145,15 @@ impl AdminApiServer for AdminRpc {
             return Err(ErrorObject::from(ErrorCode::MethodNotFound));
         };
 
+        let (tx, rx) = oneshot::channel();
+
         sequencer_sender
-            .send(SequencerAdminQuery::StartSequencer)
+            .send(SequencerAdminQuery::StartSequencer(tx))
             .await
-            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))?.map_err(|message| {
+            ErrorObject::owned(ErrorCode::InternalError.code(), message, None::<()>)
+        })
     }
 
     async fn admin_stop_sequencer(&self) -> RpcResult<B256> {
@@ -100,7 +168,9 @@ impl AdminApiServer for AdminRpc {
             .send(SequencerAdminQuery::StopSequencer(tx))
             .await
             .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
-        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))?.map_err(|message| {
+            ErrorObject::owned(ErrorCode::InternalError.code(), message, None::<()>)
+        })
     }
 
     async fn admin_conductor_enabled(&self) -> RpcResult<bool> {
@@ -124,10 +194,15 @@ impl AdminApiServer for AdminRpc {
             return Err(ErrorObject::from(ErrorCode::MethodNotFound));
         };
 
+        let (tx, rx) = oneshot::channel();
+
         sequencer_sender
-            .send(SequencerAdminQuery::SetRecoveryMode(mode))
+            .send(SequencerAdminQuery::SetRecoveryMode(mode, tx))
             .await
-            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))?.map_err(|message| {
+            ErrorObject::owned(ErrorCode::InternalError.code(), message, None::<()>)
+        })
     }
 
     async fn admin_override_leader(&self) -> RpcResult<()> {
@@ -141,4 +216,78 @@ impl AdminApiServer for AdminRpc {
             .await
             .map_err(|_| ErrorObject::from(ErrorCode::InternalError))
     }
+
+    async fn admin_set_sequencer_build_mode(&self, mode: SequencerBuildMode) -> RpcResult<()> {
+        // If the sequencer is not enabled (mode runs in validator mode), return an error.
+        let Some(ref sequencer_sender) = self.sequencer_sender else {
+            return Err(ErrorObject::from(ErrorCode::MethodNotFound));
+        };
+
+        if mode == (SequencerBuildMode::NoTxPool { blocks: Some(0) }) {
+            return Err(ErrorObject::from(ErrorCode::InvalidParams));
+        }
+
+        let (tx, rx) = oneshot::channel();
+
+        sequencer_sender
+            .send(SequencerAdminQuery::SetBuildMode(mode, tx))
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))?.map_err(|message| {
+            ErrorObject::owned(ErrorCode::InternalError.code(), message, None::<()>)
+        })
+    }
+
+    async fn admin_sequencer_build_mode(&self) -> RpcResult<SequencerBuildMode> {
+        // If the sequencer is not enabled (mode runs in validator mode), return an error.
+        let Some(ref sequencer_sender) = self.sequencer_sender else {
+            return Err(ErrorObject::from(ErrorCode::MethodNotFound));
+        };
+
+        let (tx, rx) = oneshot::channel();
+
+        sequencer_sender
+            .send(SequencerAdminQuery::BuildMode(tx))
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_mode_advance() {
+        assert_eq!(SequencerBuildMode::Normal.advance(), SequencerBuildMode::Normal);
+        assert_eq!(
+            SequencerBuildMode::NoTxPool { blocks: None }.advance(),
+            SequencerBuildMode::NoTxPool { blocks: None }
+        );
+        assert_eq!(
+            SequencerBuildMode::NoTxPool { blocks: Some(2) }.advance(),
+            SequencerBuildMode::NoTxPool { blocks: Some(1) }
+        );
+        assert_eq!(
+            SequencerBuildMode::NoTxPool { blocks: Some(1) }.advance(),
+            SequencerBuildMode::Normal
+        );
+    }
+
+    #[test]
+    fn test_build_mode_serde() {
+        let mode: SequencerBuildMode =
+            serde_json::from_str(r#"{"mode":"noTxPool","blocks":3}"#).unwrap();
+        assert_eq!(mode, SequencerBuildMode::NoTxPool { blocks: Some(3) });
+        assert_eq!(mode.to_string(), "no-tx-pool(3)");
+        assert_eq!(mode.name(), "no-tx-pool");
+
+        let mode: SequencerBuildMode = serde_json::from_str(r#"{"mode":"noTxPool"}"#).unwrap();
+        assert_eq!(mode, SequencerBuildMode::NoTxPool { blocks: None });
+        assert_eq!(serde_json::to_string(&mode).unwrap(), r#"{"mode":"noTxPool"}"#);
+        assert_eq!(mode.to_string(), "no-tx-pool");
+        assert!(!SequencerBuildMode::Normal.is_no_tx_pool());
+        assert_eq!(SequencerBuildMode::Normal.name(), "normal");
+    }
 }
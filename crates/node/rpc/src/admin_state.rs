@@ -0,0 +1,129 @@
+//! Contains the [`AdminStatePersistence`] store.
+
+use crate::SequencerBuildMode;
+use alloy_primitives::B256;
+use std::{
+    fs::File,
+    io::{BufReader, Write},
+    path::{Path, PathBuf},
+};
+
+/// The sequencer state set through the admin API, as persisted to disk.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SequencerAdminState {
+    /// Whether the sequencer is active.
+    pub sequencer_active: bool,
+    /// The hash of the unsafe head when the sequencer was last stopped, if it was.
+    pub last_stop_hash: Option<B256>,
+    /// Whether the sequencer is in recover mode.
+    pub recover_mode: bool,
+    /// The mode the sequencer builds blocks in. States persisted before the build mode was
+    /// introduced are read in the [`SequencerBuildMode::Normal`] mode.
+    #[serde(default)]
+    pub build_mode: SequencerBuildMode,
+}
+
+/// Persists the [`SequencerAdminState`] set through the admin API, so that it survives restarts.
+///
+/// The state is written to a JSON file, atomically replacing the previous one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdminStatePersistence {
+    /// The file path the state is persisted to.
+    path: PathBuf,
+}
+
+impl AdminStatePersistence {
+    /// Creates a new [`AdminStatePersistence`] persisting the state to the given file.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self { path: path.as_ref().to_path_buf() }
+    }
+
+    /// Returns the file path the state is persisted to.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads the persisted [`SequencerAdminState`].
+    ///
+    /// Returns `Ok(None)` if no state was persisted yet.
+    pub fn read(&self) -> Result<Option<SequencerAdminState>, std::io::Error> {
+        let file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        debug!(target: "admin_state", "Reading admin state from disk: {:?}", self.path);
+        Ok(Some(serde_json::from_reader(BufReader::new(file))?))
+    }
+
+    /// Writes the [`SequencerAdminState`] to disk.
+    ///
+    /// The state is first written to a temporary file which is then renamed over the persisted
+    /// file, so that a crash never leaves a partially written state behind.
+    pub fn write(&self, state: &SequencerAdminState) -> Result<(), std::io::Error> {
+        // If the directory does not exist, create it.
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp_path = self.path.with_extension("tmp");
+        let mut file = File::create(&tmp_path)?;
+        serde_json::to_writer(&mut file, state)?;
+        file.flush()?;
+        file.sync_all()?;
+        std::fs::rename(&tmp_path, &self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_missing_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let persistence = AdminStatePersistence::new(dir.path().join("admin_state.json"));
+        assert_eq!(persistence.read().unwrap(), None);
+    }
+
+    #[test]
+    fn test_write_read_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let persistence = AdminStatePersistence::new(dir.path().join("nested/admin_state.json"));
+
+        let state = SequencerAdminState {
+            sequencer_active: false,
+            last_stop_hash: Some(B256::with_last_byte(1)),
+            recover_mode: true,
+            build_mode: SequencerBuildMode::NoTxPool { blocks: Some(3) },
+        };
+        persistence.write(&state).unwrap();
+        assert_eq!(persistence.read().unwrap(), Some(state));
+        assert!(!persistence.path().with_extension("tmp").exists());
+
+        let state = SequencerAdminState { sequencer_active: true, ..state };
+        persistence.write(&state).unwrap();
+        assert_eq!(persistence.read().unwrap(), Some(state));
+    }
+
+    #[test]
+    fn test_read_state_without_build_mode() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("admin_state.json");
+        std::fs::write(
+            &path,
+            r#"{"sequencerActive":true,"lastStopHash":null,"recoverMode":false}"#,
+        )
+        .unwrap();
+        let state = AdminStatePersistence::new(path).read().unwrap().unwrap();
+        assert_eq!(state.build_mode, SequencerBuildMode::Normal);
+    }
+
+    #[test]
+    fn test_read_corrupted_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("admin_state.json");
+        std::fs::write(&path, "{").unwrap();
+        assert!(AdminStatePersistence::new(path).read().is_err());
+    }
+}
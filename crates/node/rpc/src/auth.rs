@@ -0,0 +1,123 @@
+//! JWT authentication middleware for the RPC server.
+
+use alloy_rpc_types_engine::JwtSecret;
+use jsonrpsee::server::{HttpBody, HttpRequest, HttpResponse};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tower::{Layer, Service};
+
+/// A [`Layer`] that authenticates the HTTP and WebSocket requests of the RPC server with a
+/// `Authorization: Bearer <jwt>` header, signed with the [`JwtSecret`].
+///
+/// Unauthenticated requests are rejected with a `401 Unauthorized` response.
+#[derive(Debug, Clone)]
+pub struct JwtAuthLayer {
+    /// The secret used to validate the JWT tokens.
+    secret: JwtSecret,
+}
+
+impl JwtAuthLayer {
+    /// Creates a new [`JwtAuthLayer`] validating the tokens with the given [`JwtSecret`].
+    pub const fn new(secret: JwtSecret) -> Self {
+        Self { secret }
+    }
+}
+
+impl<S> Layer<S> for JwtAuthLayer {
+    type Service = JwtAuthService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        JwtAuthService { inner, secret: self.secret }
+    }
+}
+
+/// The [`Service`] created by the [`JwtAuthLayer`].
+#[derive(Debug, Clone)]
+pub struct JwtAuthService<S> {
+    /// The inner service.
+    inner: S,
+    /// The secret used to validate the JWT tokens.
+    secret: JwtSecret,
+}
+
+impl<S> JwtAuthService<S> {
+    /// Validates the bearer token of the request, returning the reason of the failure if any.
+    fn authorize<B>(&self, request: &HttpRequest<B>) -> Result<(), String> {
+        let header = request
+            .headers()
+            .get("authorization")
+            .ok_or_else(|| "Missing authorization header".to_string())?;
+        let token = header
+            .to_str()
+            .ok()
+            .and_then(|header| header.strip_prefix("Bearer "))
+            .ok_or_else(|| "Invalid authorization header".to_string())?;
+        self.secret.validate(token).map_err(|err| err.to_string())
+    }
+}
+
+impl<S, B> Service<HttpRequest<B>> for JwtAuthService<S>
+where
+    S: Service<HttpRequest<B>, Response = HttpResponse>,
+    S::Future: Send + 'static,
+{
+    type Response = HttpResponse;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: HttpRequest<B>) -> Self::Future {
+        if let Err(reason) = self.authorize(&request) {
+            warn!(target: "rpc::auth", %reason, "Rejected unauthenticated RPC request");
+            let response = HttpResponse::<()>::builder()
+                .status(401)
+                .body(HttpBody::from(reason))
+                .expect("Critical: Failed to build unauthorized response");
+            return Box::pin(async move { Ok(response) });
+        }
+        Box::pin(self.inner.call(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_rpc_types_engine::Claims;
+
+    fn service() -> JwtAuthService<()> {
+        JwtAuthService { inner: (), secret: JwtSecret::random() }
+    }
+
+    fn request(authorization: Option<String>) -> HttpRequest<()> {
+        let mut builder = HttpRequest::<()>::builder();
+        if let Some(authorization) = authorization {
+            builder = builder.header("authorization", authorization);
+        }
+        builder.body(()).unwrap()
+    }
+
+    #[test]
+    fn test_authorize_valid_token() {
+        let service = service();
+        let token = service.secret.encode(&Claims::with_current_timestamp()).unwrap();
+        assert!(service.authorize(&request(Some(format!("Bearer {token}")))).is_ok());
+    }
+
+    #[test]
+    fn test_authorize_rejects_missing_header() {
+        assert!(service().authorize(&request(None)).is_err());
+    }
+
+    #[test]
+    fn test_authorize_rejects_token_with_other_secret() {
+        let token = JwtSecret::random().encode(&Claims::with_current_timestamp()).unwrap();
+        assert!(service().authorize(&request(Some(format!("Bearer {token}")))).is_err());
+        assert!(service().authorize(&request(Some(token))).is_err());
+    }
+}
@@ -0,0 +1,102 @@
+//! Contains the [`BatchValidityReports`] store.
+
+use kona_protocol::BatchValidityReport;
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+/// A bounded, shared store of the most recent [`BatchValidityReport`]s produced by the
+/// derivation pipeline.
+///
+/// The derivation pipeline pushes reports into the store, and the dev RPC reads them back. Once
+/// the store is full, the oldest report is evicted.
+#[derive(Debug, Clone)]
+pub struct BatchValidityReports {
+    /// The maximum number of reports retained.
+    capacity: usize,
+    /// The retained reports, ordered from oldest to newest.
+    reports: Arc<Mutex<VecDeque<BatchValidityReport>>>,
+}
+
+impl Default for BatchValidityReports {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl BatchValidityReports {
+    /// The default number of reports retained by the store.
+    pub const DEFAULT_CAPACITY: usize = 256;
+
+    /// Creates a new [`BatchValidityReports`] store that retains up to `capacity` reports.
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, reports: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))) }
+    }
+
+    /// Returns the maximum number of reports retained by the store.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Pushes a report into the store, evicting the oldest report if the store is full.
+    pub fn push(&self, report: BatchValidityReport) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut reports = self.reports.lock().unwrap_or_else(|e| e.into_inner());
+        if reports.len() == self.capacity {
+            reports.pop_front();
+        }
+        reports.push_back(report);
+    }
+
+    /// Returns up to the `count` most recent reports, ordered from oldest to newest.
+    pub fn last(&self, count: usize) -> Vec<BatchValidityReport> {
+        let reports = self.reports.lock().unwrap_or_else(|e| e.into_inner());
+        let skip = reports.len().saturating_sub(count);
+        reports.iter().skip(skip).copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::BlockNumHash;
+    use kona_protocol::{BatchType, BatchValidity, BatchValidityRule};
+
+    fn report(timestamp: u64) -> BatchValidityReport {
+        BatchValidityReport {
+            batch_type: BatchType::Single,
+            timestamp,
+            inclusion_block: BlockNumHash::default(),
+            l2_safe_head: BlockNumHash::default(),
+            validity: BatchValidity::Accept,
+            rule: BatchValidityRule::Accepted,
+        }
+    }
+
+    #[test]
+    fn test_last_reports() {
+        let store = BatchValidityReports::new(4);
+        (0..3).for_each(|i| store.push(report(i)));
+        let last = store.last(2);
+        assert_eq!(last.iter().map(|r| r.timestamp).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(store.last(10).len(), 3);
+    }
+
+    #[test]
+    fn test_evicts_oldest() {
+        let store = BatchValidityReports::new(2);
+        (0..5).for_each(|i| store.push(report(i)));
+        let last = store.last(usize::MAX);
+        assert_eq!(last.iter().map(|r| r.timestamp).collect::<Vec<_>>(), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        let store = BatchValidityReports::new(0);
+        store.push(report(0));
+        assert!(store.last(1).is_empty());
+    }
+}
@@ -1,5 +1,6 @@
 //! Contains the RPC Configuration.
 
+use alloy_rpc_types_engine::JwtSecret;
 use std::{net::SocketAddr, path::PathBuf};
 
 /// The RPC configuration.
@@ -18,6 +19,20 @@ pub struct RpcBuilder {
     pub ws_enabled: bool,
     /// Enable development RPC endpoints
     pub dev_enabled: bool,
+    /// The configuration of the managed-mode interop RPC server, served when the node is managed
+    /// by an interop supervisor.
+    pub managed: Option<ManagedRpcConfig>,
+}
+
+/// The configuration of the managed-mode interop RPC server.
+///
+/// The managed-mode RPC is served on its own socket, and authenticates its callers with a JWT.
+#[derive(Debug, Clone)]
+pub struct ManagedRpcConfig {
+    /// The socket address of the managed-mode RPC server.
+    pub socket: SocketAddr,
+    /// The secret used to authenticate the supervisor.
+    pub jwt_secret: JwtSecret,
 }
 
 impl RpcBuilder {
@@ -31,6 +46,11 @@ impl RpcBuilder {
         self.dev_enabled
     }
 
+    /// Returns the [`ManagedRpcConfig`], if the managed-mode RPC is enabled.
+    pub const fn managed(&self) -> Option<&ManagedRpcConfig> {
+        self.managed.as_ref()
+    }
+
     /// Returns the socket address of the [`RpcBuilder`].
     pub const fn socket(&self) -> SocketAddr {
         self.socket
@@ -0,0 +1,205 @@
+//! Contains the [`DepositIndex`] store.
+
+use alloy_eips::BlockNumHash;
+use alloy_primitives::B256;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    sync::{Arc, Mutex},
+};
+
+/// A deposit transaction derived from a `TransactionDeposited` event of the `OptimismPortal`,
+/// along with the L2 block it was included in.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedDeposit {
+    /// The L1 block that emitted the deposit event.
+    pub l1_block: BlockNumHash,
+    /// The hash of the L1 transaction that emitted the deposit event.
+    pub l1_tx_hash: B256,
+    /// The index of the deposit event log within the L1 block.
+    pub log_index: u64,
+    /// The source hash of the deposit transaction.
+    pub source_hash: B256,
+    /// The hash of the L2 deposit transaction.
+    pub l2_tx_hash: B256,
+    /// The number of the L2 block that includes the deposit transaction.
+    pub l2_block_number: u64,
+}
+
+/// The state of a [`DepositIndex`].
+#[derive(Debug, Default)]
+struct IndexState {
+    /// The indexed deposits, keyed by L2 block number.
+    blocks: BTreeMap<u64, Vec<IndexedDeposit>>,
+    /// The L2 block numbers that include deposits of each L1 transaction.
+    l1_txs: HashMap<B256, BTreeSet<u64>>,
+    /// The total number of indexed deposits.
+    len: usize,
+}
+
+impl IndexState {
+    /// Removes the deposits of the given L2 blocks from the index.
+    fn remove(&mut self, blocks: BTreeMap<u64, Vec<IndexedDeposit>>) {
+        for (number, deposits) in blocks {
+            self.len -= deposits.len();
+            for deposit in deposits {
+                if let Some(numbers) = self.l1_txs.get_mut(&deposit.l1_tx_hash) {
+                    numbers.remove(&number);
+                    if numbers.is_empty() {
+                        self.l1_txs.remove(&deposit.l1_tx_hash);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A bounded, shared index from L1 deposit events to the L2 deposit transactions derived from
+/// them.
+///
+/// The derivation pipeline inserts the deposits of every L2 block it derives, and the rollup RPC
+/// reads them back. Re-deriving an L2 block, e.g. after an L1 reorg, unwinds the deposits of that
+/// block and every block after it. Once the index holds more than its capacity, the deposits of
+/// the oldest L2 blocks are evicted.
+#[derive(Debug, Clone)]
+pub struct DepositIndex {
+    /// The maximum number of deposits retained.
+    capacity: usize,
+    /// The index state.
+    state: Arc<Mutex<IndexState>>,
+}
+
+impl Default for DepositIndex {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl DepositIndex {
+    /// The default number of deposits retained by the index.
+    pub const DEFAULT_CAPACITY: usize = 100_000;
+
+    /// Creates a new [`DepositIndex`] that retains up to `capacity` deposits.
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, state: Arc::new(Mutex::new(IndexState::default())) }
+    }
+
+    /// Returns the maximum number of deposits retained by the index.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of indexed deposits.
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).len
+    }
+
+    /// Returns `true` if the index holds no deposits.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Indexes the deposits of a derived L2 block, replacing the deposits of that block and every
+    /// block after it.
+    pub fn insert(&self, l2_block_number: u64, deposits: Vec<IndexedDeposit>) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        let unwound = state.blocks.split_off(&l2_block_number);
+        state.remove(unwound);
+
+        if deposits.is_empty() {
+            return;
+        }
+        for deposit in &deposits {
+            state.l1_txs.entry(deposit.l1_tx_hash).or_default().insert(l2_block_number);
+        }
+        state.len += deposits.len();
+        state.blocks.insert(l2_block_number, deposits);
+
+        while state.len > self.capacity {
+            let Some((number, deposits)) = state.blocks.pop_first() else {
+                break;
+            };
+            state.remove(BTreeMap::from([(number, deposits)]));
+        }
+    }
+
+    /// Returns the indexed deposits emitted by the given L1 transaction, ordered by log index.
+    pub fn by_l1_tx(&self, l1_tx_hash: B256) -> Vec<IndexedDeposit> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(numbers) = state.l1_txs.get(&l1_tx_hash) else {
+            return Vec::new();
+        };
+        let mut deposits = numbers
+            .iter()
+            .filter_map(|number| state.blocks.get(number))
+            .flatten()
+            .filter(|d| d.l1_tx_hash == l1_tx_hash)
+            .cloned()
+            .collect::<Vec<_>>();
+        deposits.sort_by_key(|d| d.log_index);
+        deposits
+    }
+
+    /// Returns the indexed deposit emitted by the given L1 transaction at the given log index.
+    pub fn get(&self, l1_tx_hash: B256, log_index: u64) -> Option<IndexedDeposit> {
+        self.by_l1_tx(l1_tx_hash).into_iter().find(|d| d.log_index == log_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deposit(l1_tx: u8, log_index: u64, l2_block_number: u64) -> IndexedDeposit {
+        IndexedDeposit {
+            l1_block: BlockNumHash::default(),
+            l1_tx_hash: B256::repeat_byte(l1_tx),
+            log_index,
+            source_hash: B256::with_last_byte(log_index as u8),
+            l2_tx_hash: B256::with_last_byte(l2_block_number as u8),
+            l2_block_number,
+        }
+    }
+
+    #[test]
+    fn test_lookup_deposits() {
+        let index = DepositIndex::default();
+        index.insert(10, vec![deposit(1, 3, 10), deposit(1, 1, 10), deposit(2, 2, 10)]);
+        index.insert(11, vec![]);
+
+        let deposits = index.by_l1_tx(B256::repeat_byte(1));
+        assert_eq!(deposits.iter().map(|d| d.log_index).collect::<Vec<_>>(), vec![1, 3]);
+        assert_eq!(index.get(B256::repeat_byte(2), 2), Some(deposit(2, 2, 10)));
+        assert_eq!(index.get(B256::repeat_byte(2), 3), None);
+        assert!(index.by_l1_tx(B256::repeat_byte(3)).is_empty());
+        assert_eq!(index.len(), 3);
+    }
+
+    #[test]
+    fn test_unwinds_rederived_blocks() {
+        let index = DepositIndex::default();
+        index.insert(10, vec![deposit(1, 0, 10)]);
+        index.insert(20, vec![deposit(2, 0, 20)]);
+        index.insert(30, vec![deposit(3, 0, 30)]);
+
+        // Block 20 is re-derived without deposits.
+        index.insert(20, vec![]);
+        assert_eq!(index.len(), 1);
+        assert!(index.by_l1_tx(B256::repeat_byte(2)).is_empty());
+        assert!(index.by_l1_tx(B256::repeat_byte(3)).is_empty());
+        assert_eq!(index.get(B256::repeat_byte(1), 0), Some(deposit(1, 0, 10)));
+    }
+
+    #[test]
+    fn test_evicts_oldest_blocks() {
+        let index = DepositIndex::new(2);
+        index.insert(10, vec![deposit(1, 0, 10)]);
+        index.insert(11, vec![deposit(1, 1, 11)]);
+        index.insert(12, vec![deposit(2, 0, 12)]);
+
+        assert_eq!(index.len(), 2);
+        assert_eq!(index.get(B256::repeat_byte(1), 0), None);
+        assert_eq!(index.by_l1_tx(B256::repeat_byte(1)), vec![deposit(1, 1, 11)]);
+    }
+}
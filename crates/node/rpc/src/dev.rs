@@ -11,20 +11,26 @@ use jsonrpsee::{
 };
 use kona_engine::{EngineQueries, EngineQuerySender};
 
-use crate::DevEngineApiServer;
+use crate::{BatchValidityReports, DevEngineApiServer};
 use jsonrpsee::core::to_json_raw_value;
+use kona_protocol::BatchValidityReport;
 
 /// Implementation of the development RPC API.
 #[derive(Debug)]
 pub struct DevEngineRpc {
     /// The engine query sender.
     engine_query_sender: EngineQuerySender,
+    /// The validity reports of the batches checked by the derivation pipeline.
+    batch_validity_reports: BatchValidityReports,
 }
 
 impl DevEngineRpc {
     /// Creates a new [`DevEngineRpc`] instance.
-    pub const fn new(engine_query_sender: EngineQuerySender) -> Self {
-        Self { engine_query_sender }
+    pub const fn new(
+        engine_query_sender: EngineQuerySender,
+        batch_validity_reports: BatchValidityReports,
+    ) -> Self {
+        Self { engine_query_sender, batch_validity_reports }
     }
 
     /// Gets an engine queue length watcher for subscriptions.
@@ -111,4 +117,11 @@ impl DevEngineApiServer for DevEngineRpc {
             )
         })
     }
+
+    async fn dev_batch_validity_reports(
+        &self,
+        count: usize,
+    ) -> RpcResult<Vec<BatchValidityReport>> {
+        Ok(self.batch_validity_reports.last(count))
+    }
 }
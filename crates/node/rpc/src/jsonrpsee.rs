@@ -1,6 +1,6 @@
 //! The Optimism RPC API using `jsonrpsee`
 
-use crate::{OutputResponse, SafeHeadResponse};
+use crate::{IndexedDeposit, OutputResponse, SafeHeadResponse, SequencerBuildMode};
 use alloy_eips::BlockNumberOrTag;
 use alloy_primitives::B256;
 use core::net::IpAddr;
@@ -9,9 +9,9 @@ use jsonrpsee::{
     core::{RpcResult, SubscriptionResult},
     proc_macros::rpc,
 };
-use kona_genesis::RollupConfig;
-use kona_p2p::{PeerCount, PeerDump, PeerInfo, PeerStats};
-use kona_protocol::SyncStatus;
+use kona_genesis::{RollupConfig, SystemConfig};
+use kona_p2p::{PeerCount, PeerDump, PeerInfo, PeerScoreDump, PeerStats, ScoringConfig};
+use kona_protocol::{SyncStatus, SystemConfigUpdateRecord};
 use op_alloy_rpc_types_engine::OpExecutionPayloadEnvelope;
 
 #[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), allow(unused_imports))]
@@ -50,6 +50,33 @@ pub trait RollupNodeApi {
     /// Get the software version.
     #[method(name = "version")]
     async fn op_version(&self) -> RpcResult<String>;
+
+    /// Get the system config in effect at an L1 block height, after the updates of the block were
+    /// applied. Returns `null` if the block has not been traversed by the derivation pipeline.
+    #[method(name = "systemConfigAt")]
+    async fn op_system_config_at(&self, l1_block: u64) -> RpcResult<Option<SystemConfig>>;
+
+    /// Get the system config updates emitted between two L1 block heights, inclusive.
+    #[method(name = "systemConfigUpdates")]
+    async fn op_system_config_updates(
+        &self,
+        from_l1_block: u64,
+        to_l1_block: u64,
+    ) -> RpcResult<Vec<SystemConfigUpdateRecord>>;
+
+    /// Get the L2 deposit transactions derived from the deposit events emitted by an L1
+    /// transaction. Requires the deposit index to be enabled.
+    #[method(name = "depositsByL1Tx")]
+    async fn op_deposits_by_l1_tx(&self, l1_tx_hash: B256) -> RpcResult<Vec<IndexedDeposit>>;
+
+    /// Get the L2 deposit transaction derived from the deposit event at a log index of an L1
+    /// transaction. Requires the deposit index to be enabled.
+    #[method(name = "depositByL1Log")]
+    async fn op_deposit_by_l1_log(
+        &self,
+        l1_tx_hash: B256,
+        log_index: u64,
+    ) -> RpcResult<Option<IndexedDeposit>>;
 }
 
 /// The opp2p namespace handles peer interactions.
@@ -127,6 +154,43 @@ pub trait OpP2PApi {
     /// Disconnects from the given peer
     #[method(name = "disconnectPeer")]
     async fn opp2p_disconnect_peer(&self, peer: String) -> RpcResult<()>;
+
+    /// Adds a static peer, which is trusted, always dialed and registered as an explicit gossipsub
+    /// peer. Explicit peering must be configured on both sides.
+    #[method(name = "addStaticPeer")]
+    async fn opp2p_add_static_peer(&self, peer: String) -> RpcResult<()>;
+
+    /// Removes a static peer
+    #[method(name = "removeStaticPeer")]
+    async fn opp2p_remove_static_peer(&self, peer: String) -> RpcResult<()>;
+
+    /// Lists the addresses of the static peers
+    #[method(name = "listStaticPeers")]
+    async fn opp2p_list_static_peers(&self) -> RpcResult<Vec<String>>;
+
+    /// Trusts the given peer
+    #[method(name = "addTrustedPeer")]
+    async fn opp2p_add_trusted_peer(&self, peer: String) -> RpcResult<()>;
+
+    /// Untrusts the given peer
+    #[method(name = "removeTrustedPeer")]
+    async fn opp2p_remove_trusted_peer(&self, peer: String) -> RpcResult<()>;
+
+    /// Lists trusted peers
+    #[method(name = "listTrustedPeers")]
+    async fn opp2p_list_trusted_peers(&self) -> RpcResult<Vec<String>>;
+
+    /// Returns the estimated breakdown of the gossipsub score of the peers, along with their ban
+    /// state.
+    #[method(name = "peerScores")]
+    async fn opp2p_peer_scores(&self) -> RpcResult<PeerScoreDump>;
+
+    /// Reloads the peer scoring config file, returning the applied config.
+    ///
+    /// Only the ban threshold, ban duration and topic score caps are reloaded. The gossip, publish
+    /// and graylist thresholds cannot be changed at runtime.
+    #[method(name = "reloadScoringConfig")]
+    async fn opp2p_reload_scoring_config(&self) -> RpcResult<ScoringConfig>;
 }
 
 /// Websockets API for the node.
@@ -159,6 +223,14 @@ pub trait DevEngineApi {
     /// Get the current number of tasks in the engine queue.
     #[method(name = "taskQueueLength")]
     async fn dev_task_queue_length(&self) -> RpcResult<usize>;
+
+    /// Get the validity reports of the last `count` batches checked by the derivation pipeline,
+    /// ordered from oldest to newest.
+    #[method(name = "batchValidityReports")]
+    async fn dev_batch_validity_reports(
+        &self,
+        count: usize,
+    ) -> RpcResult<Vec<kona_protocol::BatchValidityReport>>;
 }
 
 /// The admin namespace for the consensus node.
@@ -193,4 +265,12 @@ pub trait AdminApi {
     /// Overrides the leader in the conductor.
     #[method(name = "overrideLeader")]
     async fn admin_override_leader(&self) -> RpcResult<()>;
+
+    /// Sets the mode the sequencer builds blocks in.
+    #[method(name = "setSequencerBuildMode")]
+    async fn admin_set_sequencer_build_mode(&self, mode: SequencerBuildMode) -> RpcResult<()>;
+
+    /// Returns the mode the sequencer builds blocks in.
+    #[method(name = "sequencerBuildMode")]
+    async fn admin_sequencer_build_mode(&self) -> RpcResult<SequencerBuildMode>;
 }
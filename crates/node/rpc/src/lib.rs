@@ -10,10 +10,16 @@
 extern crate tracing;
 
 mod admin;
-pub use admin::{AdminRpc, NetworkAdminQuery, SequencerAdminQuery};
+pub use admin::{AdminRpc, NetworkAdminQuery, SequencerAdminQuery, SequencerBuildMode};
+
+mod admin_state;
+pub use admin_state::{AdminStatePersistence, SequencerAdminState};
+
+mod auth;
+pub use auth::{JwtAuthLayer, JwtAuthService};
 
 mod config;
-pub use config::RpcBuilder;
+pub use config::{ManagedRpcConfig, RpcBuilder};
 
 mod net;
 pub use net::P2pRpc;
@@ -26,6 +32,15 @@ pub use response::SafeHeadResponse;
 mod output;
 pub use output::OutputResponse;
 
+mod batch_validity;
+pub use batch_validity::BatchValidityReports;
+
+mod system_config;
+pub use system_config::{MAX_SYSTEM_CONFIG_RECORDS, SystemConfigHistory, SystemConfigHistoryError};
+
+mod deposits;
+pub use deposits::{DepositIndex, IndexedDeposit};
+
 mod dev;
 pub use dev::DevEngineRpc;
 
@@ -40,6 +55,9 @@ mod interop;
 #[cfg(feature = "client")]
 pub use interop::{CheckAccessListClient, InteropTxValidator, InteropTxValidatorError};
 
+mod managed;
+pub use managed::ManagedModeRpc;
+
 mod rollup;
 pub use rollup::RollupRpc;
 
@@ -0,0 +1,370 @@
+//! Implements the managed-mode interop RPC, used by an interop supervisor to drive the node.
+//!
+//! See: <https://specs.optimism.io/interop/managed-mode.html>
+
+use alloy_eips::{BlockNumHash, BlockNumberOrTag};
+use alloy_primitives::BlockHash;
+use async_trait::async_trait;
+use jsonrpsee::{
+    PendingSubscriptionSink,
+    core::{RpcResult, SubscriptionResult, to_json_raw_value},
+    types::{ErrorCode, ErrorObject, ErrorObjectOwned},
+};
+use kona_engine::{EngineQueries, EngineQuerySender};
+use kona_genesis::RollupConfig;
+use kona_interop::{ControlEvent, DerivedRefPair, ManagedEvent};
+use kona_protocol::BlockInfo;
+use kona_supervisor_rpc::jsonrpsee::{ManagedModeApiServer, SubscriptionTopic};
+use kona_supervisor_types::{BlockSeal, OutputV0, Receipts, SubscriptionEvent};
+use std::sync::Arc;
+use tokio::sync::{Mutex, broadcast, mpsc, oneshot};
+
+/// ManagedModeRpc
+///
+/// This is a server implementation of [`ManagedModeApiServer`]. Control signals of the supervisor
+/// are forwarded to the engine as [`ControlEvent`]s, and the [`ManagedEvent`]s emitted by the
+/// node are served through the `interop_subscribe` subscription and `interop_pullEvent`.
+#[derive(Debug)]
+pub struct ManagedModeRpc {
+    /// The channel to send [`ControlEvent`]s to the engine.
+    control_sender: mpsc::Sender<ControlEvent>,
+    /// The channel of the [`ManagedEvent`]s emitted by the node.
+    events_sender: broadcast::Sender<ManagedEvent>,
+    /// The receiver of the [`ManagedEvent`]s served by `interop_pullEvent`.
+    pull_receiver: Arc<Mutex<broadcast::Receiver<ManagedEvent>>>,
+    /// The channel to request a full engine reset.
+    reset_request_sender: mpsc::Sender<()>,
+    /// The channel to send [`EngineQueries`]s.
+    engine_sender: EngineQuerySender,
+}
+
+impl ManagedModeRpc {
+    /// The identifier for the Metric that tracks managed-mode RPC calls.
+    pub const RPC_IDENT: &'static str = "managed_rpc";
+
+    /// Constructs a new [`ManagedModeRpc`].
+    pub fn new(
+        control_sender: mpsc::Sender<ControlEvent>,
+        events_sender: broadcast::Sender<ManagedEvent>,
+        reset_request_sender: mpsc::Sender<()>,
+        engine_sender: EngineQuerySender,
+    ) -> Self {
+        let pull_receiver = Arc::new(Mutex::new(events_sender.subscribe()));
+        Self { control_sender, events_sender, pull_receiver, reset_request_sender, engine_sender }
+    }
+
+    /// Returns an [`ErrorObjectOwned`] for a block that could not be found.
+    fn not_found(message: String) -> ErrorObjectOwned {
+        ErrorObject::owned(ErrorCode::InvalidParams.code(), message, None::<()>)
+    }
+
+    /// Sends a [`ControlEvent`] to the engine.
+    async fn control(&self, event: ControlEvent) -> RpcResult<()> {
+        self.control_sender
+            .send(event)
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+
+    /// Sends an [`EngineQueries`] built with the given response channel, and awaits the response.
+    async fn query<T>(
+        &self,
+        query: impl FnOnce(oneshot::Sender<T>) -> EngineQueries,
+    ) -> RpcResult<T> {
+        let (sender, receiver) = oneshot::channel();
+        self.engine_sender
+            .send(query(sender))
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+        receiver.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+
+    /// Returns the [`RollupConfig`] of the node.
+    async fn rollup_config(&self) -> RpcResult<RollupConfig> {
+        self.query(EngineQueries::Config).await
+    }
+
+    /// Returns the L2 [`BlockInfo`] at the given height.
+    async fn l2_block_ref(&self, number: u64) -> RpcResult<BlockInfo> {
+        let block = BlockNumberOrTag::Number(number);
+        self.query(|sender| EngineQueries::L2BlockRef { block, sender })
+            .await?
+            .map(|block| block.block_info)
+            .ok_or_else(|| Self::not_found(format!("L2 block {number} not found")))
+    }
+
+    /// Returns the L1 [`BlockInfo`] at the given height.
+    async fn l1_block_ref(&self, number: u64) -> RpcResult<BlockInfo> {
+        let block = BlockNumberOrTag::Number(number);
+        self.query(|sender| EngineQueries::L1BlockRef { block, sender })
+            .await?
+            .ok_or_else(|| Self::not_found(format!("L1 block {number} not found")))
+    }
+
+    /// Returns the canonical L2 [`BlockInfo`] of the given block, failing if it was reorged out.
+    async fn canonical_l2_block_ref(&self, id: BlockNumHash) -> RpcResult<BlockInfo> {
+        let block = self.l2_block_ref(id.number).await?;
+        if block.hash != id.hash {
+            return Err(Self::not_found(format!(
+                "L2 block {} hash mismatch: expected {}, got {}",
+                id.number, id.hash, block.hash
+            )));
+        }
+        Ok(block)
+    }
+
+    /// Returns the L2 block number at the given timestamp.
+    async fn l2_block_number_at(&self, timestamp: u64) -> RpcResult<u64> {
+        let config = self.rollup_config().await?;
+        if timestamp < config.genesis.l2_time {
+            return Err(Self::not_found(format!("Timestamp {timestamp} is before L2 genesis")));
+        }
+        Ok(config.genesis.l2.number + config.block_number_from_timestamp(timestamp))
+    }
+}
+
+#[async_trait]
+impl ManagedModeApiServer for ManagedModeRpc {
+    async fn subscribe_events(
+        &self,
+        sink: PendingSubscriptionSink,
+        _topic: SubscriptionTopic,
+    ) -> SubscriptionResult {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "interop_subscribe");
+
+        let sink = sink.accept().await?;
+        let mut events = self.events_sender.subscribe();
+
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!(target: "rpc::managed", skipped, "Managed event subscription lagged");
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+
+            let event =
+                to_json_raw_value(&SubscriptionEvent { data: Some(event) }).map_err(|_| {
+                    jsonrpsee::core::SubscriptionError::from(
+                        "Internal error. Impossible to convert managed event to json",
+                    )
+                })?;
+            if sink.send(event).await.is_err() {
+                debug!(target: "rpc::managed", "Managed event subscription dropped");
+                return Ok(());
+            }
+        }
+
+        warn!(target: "rpc::managed", "Managed event stream has been closed.");
+        Ok(())
+    }
+
+    async fn pull_event(&self) -> RpcResult<ManagedEvent> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "interop_pullEvent");
+
+        let mut receiver = self.pull_receiver.lock().await;
+        loop {
+            match receiver.try_recv() {
+                Ok(event) => return Ok(event),
+                Err(broadcast::error::TryRecvError::Lagged(skipped)) => {
+                    warn!(target: "rpc::managed", skipped, "Pulled managed events lagged");
+                }
+                Err(broadcast::error::TryRecvError::Empty) => {
+                    return Err(ErrorObject::owned(
+                        ErrorCode::ServerError(-32000).code(),
+                        "No event to pull",
+                        None::<()>,
+                    ));
+                }
+                Err(broadcast::error::TryRecvError::Closed) => {
+                    return Err(ErrorObject::from(ErrorCode::InternalError));
+                }
+            }
+        }
+    }
+
+    async fn update_cross_unsafe(&self, id: BlockNumHash) -> RpcResult<()> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "interop_updateCrossUnsafe");
+
+        let block = self.canonical_l2_block_ref(id).await?;
+        self.control(ControlEvent::UpdateCrossUnsafe(block)).await
+    }
+
+    async fn update_cross_safe(
+        &self,
+        derived: BlockNumHash,
+        _source: BlockNumHash,
+    ) -> RpcResult<()> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "interop_updateCrossSafe");
+
+        let block = self.canonical_l2_block_ref(derived).await?;
+        self.control(ControlEvent::UpdateCrossSafe(block)).await
+    }
+
+    async fn update_finalized(&self, id: BlockNumHash) -> RpcResult<()> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "interop_updateFinalized");
+
+        let block = self.canonical_l2_block_ref(id).await?;
+        self.control(ControlEvent::UpdateFinalized(block)).await
+    }
+
+    async fn invalidate_block(&self, seal: BlockSeal) -> RpcResult<()> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "interop_invalidateBlock");
+
+        self.control(ControlEvent::InvalidateBlock(seal.hash)).await
+    }
+
+    async fn provide_l1(&self, next_l1: BlockInfo) -> RpcResult<()> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "interop_provideL1");
+
+        self.control(ControlEvent::ProviderL1(next_l1)).await
+    }
+
+    async fn anchor_point(&self) -> RpcResult<DerivedRefPair> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "interop_anchorPoint");
+
+        let config = self.rollup_config().await?;
+        let (source, derived) = tokio::try_join!(
+            self.l1_block_ref(config.genesis.l1.number),
+            self.l2_block_ref(config.genesis.l2.number),
+        )?;
+        Ok(DerivedRefPair { source, derived })
+    }
+
+    async fn reset_pre_interop(&self) -> RpcResult<()> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "interop_resetPreInterop");
+
+        self.reset_request_sender
+            .send(())
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+
+    async fn reset(
+        &self,
+        local_unsafe: BlockNumHash,
+        cross_unsafe: BlockNumHash,
+        local_safe: BlockNumHash,
+        cross_safe: BlockNumHash,
+        finalized: BlockNumHash,
+    ) -> RpcResult<()> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "interop_reset");
+
+        let (local_unsafe, cross_unsafe, local_safe, cross_safe, finalized) = tokio::try_join!(
+            self.canonical_l2_block_ref(local_unsafe),
+            self.canonical_l2_block_ref(cross_unsafe),
+            self.canonical_l2_block_ref(local_safe),
+            self.canonical_l2_block_ref(cross_safe),
+            self.canonical_l2_block_ref(finalized),
+        )?;
+        self.control(ControlEvent::Reset {
+            local_unsafe: Some(local_unsafe),
+            cross_unsafe: Some(cross_unsafe),
+            local_safe: Some(local_safe),
+            cross_safe: Some(cross_safe),
+            finalized: Some(finalized),
+        })
+        .await
+    }
+
+    async fn fetch_receipts(&self, block_hash: BlockHash) -> RpcResult<Receipts> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "interop_fetchReceipts");
+
+        self.query(|sender| EngineQueries::Receipts { block_hash, sender })
+            .await?
+            .ok_or_else(|| Self::not_found(format!("L2 block {block_hash} not found")))
+    }
+
+    async fn l2_block_ref_by_number(&self, number: u64) -> RpcResult<BlockInfo> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "interop_l2BlockRefByNumber");
+
+        self.l2_block_ref(number).await
+    }
+
+    async fn chain_id(&self) -> RpcResult<String> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "interop_chainID");
+
+        Ok(self.rollup_config().await?.l2_chain_id.id().to_string())
+    }
+
+    async fn output_v0_at_timestamp(&self, timestamp: u64) -> RpcResult<OutputV0> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "interop_outputV0AtTimestamp");
+
+        let block = BlockNumberOrTag::Number(self.l2_block_number_at(timestamp).await?);
+        let (_, output, _) =
+            self.query(|sender| EngineQueries::OutputAtBlock { block, sender }).await?;
+        Ok(OutputV0::new(output.state_root, output.bridge_storage_root, output.block_hash))
+    }
+
+    /// The node does not track pending outputs separately, so the output at the timestamp is
+    /// returned.
+    async fn pending_output_v0_at_timestamp(&self, timestamp: u64) -> RpcResult<OutputV0> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "interop_pendingOutputV0AtTimestamp");
+
+        self.output_v0_at_timestamp(timestamp).await
+    }
+
+    async fn l2_block_ref_by_timestamp(&self, timestamp: u64) -> RpcResult<BlockInfo> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "interop_l2BlockRefByTimestamp");
+
+        self.l2_block_ref(self.l2_block_number_at(timestamp).await?).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_genesis::ChainGenesis;
+
+    fn rpc() -> (ManagedModeRpc, mpsc::Receiver<ControlEvent>, mpsc::Receiver<EngineQueries>) {
+        let (control_tx, control_rx) = mpsc::channel(8);
+        let (events_tx, _) = broadcast::channel(8);
+        let (reset_tx, _) = mpsc::channel(1);
+        let (engine_tx, engine_rx) = mpsc::channel(8);
+        (ManagedModeRpc::new(control_tx, events_tx, reset_tx, engine_tx), control_rx, engine_rx)
+    }
+
+    #[tokio::test]
+    async fn test_pull_event() {
+        let (rpc, _control_rx, _engine_rx) = rpc();
+        assert!(rpc.pull_event().await.is_err());
+
+        let event = ManagedEvent { reset: Some("reset".to_string()), ..Default::default() };
+        rpc.events_sender.send(event.clone()).unwrap();
+        assert_eq!(rpc.pull_event().await.unwrap(), event);
+        assert!(rpc.pull_event().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_block_sends_control_event() {
+        let (rpc, mut control_rx, _engine_rx) = rpc();
+        let seal = BlockSeal::new(BlockHash::with_last_byte(1), 1, 2);
+
+        rpc.invalidate_block(seal).await.unwrap();
+        assert_eq!(
+            control_rx.recv().await,
+            Some(ControlEvent::InvalidateBlock(BlockHash::with_last_byte(1)))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_l2_block_number_at_timestamp() {
+        let (rpc, _control_rx, mut engine_rx) = rpc();
+        tokio::spawn(async move {
+            while let Some(EngineQueries::Config(sender)) = engine_rx.recv().await {
+                let config = RollupConfig {
+                    block_time: 2,
+                    genesis: ChainGenesis { l2_time: 10, ..Default::default() },
+                    ..Default::default()
+                };
+                sender.send(config).unwrap();
+            }
+        });
+
+        assert!(rpc.l2_block_number_at(9).await.is_err());
+        assert_eq!(rpc.l2_block_number_at(10).await.unwrap(), 0);
+        assert_eq!(rpc.l2_block_number_at(15).await.unwrap(), 2);
+    }
+}
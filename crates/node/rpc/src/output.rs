@@ -1,7 +1,7 @@
 //! Output Types
 
 use alloy_primitives::B256;
-use kona_protocol::{L2BlockInfo, OutputRoot, SyncStatus};
+use kona_protocol::{L2BlockInfo, OutputRoot, OutputRootError, SyncStatus, VersionedOutputRoot};
 
 /// An [output response][or] for Optimism Rollup.
 ///
@@ -24,15 +24,29 @@ pub struct OutputResponse {
 }
 
 impl OutputResponse {
-    /// Builds an [`OutputResponse`] from its parts.
-    pub fn from_v0(v0: OutputRoot, sync_status: SyncStatus, block_ref: L2BlockInfo) -> Self {
-        Self {
-            version: v0.version(),
-            output_root: v0.hash(),
+    /// Builds an [`OutputResponse`] from a V0 [`OutputRoot`].
+    pub fn from_v0(
+        v0: OutputRoot,
+        sync_status: SyncStatus,
+        block_ref: L2BlockInfo,
+    ) -> Result<Self, OutputRootError> {
+        Self::from_output_root(v0.into(), sync_status, block_ref)
+    }
+
+    /// Builds an [`OutputResponse`] from a [`VersionedOutputRoot`], hashing it with the codec of
+    /// its version.
+    pub fn from_output_root(
+        output_root: VersionedOutputRoot,
+        sync_status: SyncStatus,
+        block_ref: L2BlockInfo,
+    ) -> Result<Self, OutputRootError> {
+        Ok(Self {
+            version: output_root.version(),
+            output_root: output_root.hash()?,
             block_ref,
-            withdrawal_storage_root: v0.bridge_storage_root,
-            state_root: v0.state_root,
+            withdrawal_storage_root: output_root.bridge_storage_root(),
+            state_root: output_root.state_root(),
             sync_status,
-        }
+        })
     }
 }
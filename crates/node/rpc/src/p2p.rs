@@ -11,7 +11,9 @@ use jsonrpsee::{
     core::RpcResult,
     types::{ErrorCode, ErrorObject},
 };
-use kona_p2p::{P2pRpcRequest, PeerCount, PeerDump, PeerInfo, PeerStats};
+use kona_p2p::{
+    P2pRpcRequest, PeerCount, PeerDump, PeerInfo, PeerScoreDump, PeerStats, ScoringConfig,
+};
 use std::{net::IpAddr, str::FromStr};
 
 use crate::{OpP2PApiServer, net::P2pRpc};
@@ -216,6 +218,105 @@ impl OpP2PApiServer for P2pRpc {
             .await
             .map_err(|_| ErrorObject::from(ErrorCode::InternalError))
     }
+
+    async fn opp2p_add_static_peer(&self, peer: String) -> RpcResult<()> {
+        kona_macros::inc!(gauge, kona_p2p::Metrics::RPC_CALLS, "method" => "opp2p_addStaticPeer");
+        let address = libp2p::Multiaddr::from_str(&peer)
+            .map_err(|_| ErrorObject::from(ErrorCode::InvalidParams))?;
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.sender
+            .send(P2pRpcRequest::AddStaticPeer { address, out: tx })
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+
+        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))?.map_err(|message| {
+            ErrorObject::owned(ErrorCode::InvalidParams.code(), message, None::<()>)
+        })
+    }
+
+    async fn opp2p_remove_static_peer(&self, id: String) -> RpcResult<()> {
+        kona_macros::inc!(gauge, kona_p2p::Metrics::RPC_CALLS, "method" => "opp2p_removeStaticPeer");
+        let peer_id = libp2p::PeerId::from_str(&id)
+            .map_err(|_| ErrorObject::from(ErrorCode::InvalidParams))?;
+        self.sender
+            .send(P2pRpcRequest::RemoveStaticPeer { peer_id })
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+
+    async fn opp2p_list_static_peers(&self) -> RpcResult<Vec<String>> {
+        kona_macros::inc!(gauge, kona_p2p::Metrics::RPC_CALLS, "method" => "opp2p_listStaticPeers");
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.sender
+            .send(P2pRpcRequest::ListStaticPeers(tx))
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+
+        rx.await
+            .map(|addrs| addrs.iter().map(|a| a.to_string()).collect())
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+
+    async fn opp2p_add_trusted_peer(&self, id: String) -> RpcResult<()> {
+        kona_macros::inc!(gauge, kona_p2p::Metrics::RPC_CALLS, "method" => "opp2p_addTrustedPeer");
+        let peer_id = libp2p::PeerId::from_str(&id)
+            .map_err(|_| ErrorObject::from(ErrorCode::InvalidParams))?;
+        self.sender
+            .send(P2pRpcRequest::AddTrustedPeer { peer_id })
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+
+    async fn opp2p_remove_trusted_peer(&self, id: String) -> RpcResult<()> {
+        kona_macros::inc!(gauge, kona_p2p::Metrics::RPC_CALLS, "method" => "opp2p_removeTrustedPeer");
+        let peer_id = libp2p::PeerId::from_str(&id)
+            .map_err(|_| ErrorObject::from(ErrorCode::InvalidParams))?;
+        self.sender
+            .send(P2pRpcRequest::RemoveTrustedPeer { peer_id })
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+
+    async fn opp2p_list_trusted_peers(&self) -> RpcResult<Vec<String>> {
+        kona_macros::inc!(gauge, kona_p2p::Metrics::RPC_CALLS, "method" => "opp2p_listTrustedPeers");
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.sender
+            .send(P2pRpcRequest::ListTrustedPeers(tx))
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+
+        rx.await
+            .map(|peers| peers.iter().map(|p| p.to_string()).collect())
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+
+    async fn opp2p_peer_scores(&self) -> RpcResult<PeerScoreDump> {
+        kona_macros::inc!(gauge, kona_p2p::Metrics::RPC_CALLS, "method" => "opp2p_peerScores");
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.sender
+            .send(P2pRpcRequest::PeerScores(tx))
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+
+        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+
+    async fn opp2p_reload_scoring_config(&self) -> RpcResult<ScoringConfig> {
+        kona_macros::inc!(
+            gauge,
+            kona_p2p::Metrics::RPC_CALLS,
+            "method" => "opp2p_reloadScoringConfig"
+        );
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.sender
+            .send(P2pRpcRequest::ReloadScoringConfig(tx))
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+
+        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))?.map_err(|message| {
+            ErrorObject::owned(ErrorCode::InternalError.code(), message, None::<()>)
+        })
+    }
 }
 
 #[cfg(test)]
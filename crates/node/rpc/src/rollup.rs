@@ -3,18 +3,19 @@
 //! Implemented in the op-node in <https://github.com/ethereum-optimism/optimism/blob/174e55f0a1e73b49b80a561fd3fedd4fea5770c6/op-service/sources/rollupclient.go#L16>
 
 use alloy_eips::BlockNumberOrTag;
+use alloy_primitives::B256;
 use async_trait::async_trait;
 use jsonrpsee::{
     core::RpcResult,
     types::{ErrorCode, ErrorObject},
 };
 use kona_engine::{EngineQueries, EngineQuerySender, EngineState};
-use kona_genesis::RollupConfig;
-use kona_protocol::SyncStatus;
+use kona_genesis::{RollupConfig, SystemConfig};
+use kona_protocol::{SyncStatus, SystemConfigUpdateRecord};
 
 use crate::{
-    L1State, L1WatcherQueries, OutputResponse, RollupNodeApiServer, SafeHeadResponse,
-    l1_watcher::L1WatcherQuerySender,
+    DepositIndex, IndexedDeposit, L1State, L1WatcherQueries, OutputResponse, RollupNodeApiServer,
+    SafeHeadResponse, SystemConfigHistory, l1_watcher::L1WatcherQuerySender,
 };
 
 /// RollupRpc
@@ -26,6 +27,10 @@ pub struct RollupRpc {
     pub engine_sender: EngineQuerySender,
     /// The channel to send [`crate::L1WatcherQueries`]s.
     pub l1_watcher_sender: L1WatcherQuerySender,
+    /// The history of the system config updates applied by the derivation pipeline.
+    pub system_config_history: SystemConfigHistory,
+    /// The index of the deposits derived by the derivation pipeline, if enabled.
+    pub deposit_index: Option<DepositIndex>,
 }
 
 impl RollupRpc {
@@ -36,8 +41,21 @@ impl RollupRpc {
     pub const fn new(
         engine_sender: EngineQuerySender,
         l1_watcher_sender: L1WatcherQuerySender,
+        system_config_history: SystemConfigHistory,
+        deposit_index: Option<DepositIndex>,
     ) -> Self {
-        Self { engine_sender, l1_watcher_sender }
+        Self { engine_sender, l1_watcher_sender, system_config_history, deposit_index }
+    }
+
+    /// Returns the deposit index, or an error if it is disabled.
+    fn deposit_index(&self) -> RpcResult<&DepositIndex> {
+        self.deposit_index.as_ref().ok_or_else(|| {
+            ErrorObject::owned(
+                ErrorCode::MethodNotFound.code(),
+                "Deposit index is disabled",
+                None::<()>,
+            )
+        })
     }
 
     // Important note: we zero-out the fields that can't be derived yet to follow op-node's
@@ -90,7 +108,9 @@ impl RollupNodeApiServer for RollupRpc {
 
         let sync_status = Self::sync_status_from_actor_queries(l1_sync_status, l2_sync_status);
 
-        Ok(OutputResponse::from_v0(output_root, sync_status, l2_block_info))
+        OutputResponse::from_output_root(output_root.into(), sync_status, l2_block_info).map_err(
+            |e| ErrorObject::owned(ErrorCode::InternalError.code(), e.to_string(), None::<()>),
+        )
     }
 
     /// This RPC endpoint is not supported. It is not necessary to track the safe head for every L1
@@ -149,4 +169,39 @@ impl RollupNodeApiServer for RollupRpc {
 
         return Ok(RPC_VERSION.to_string());
     }
+
+    async fn op_system_config_at(&self, l1_block: u64) -> RpcResult<Option<SystemConfig>> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "op_systemConfigAt");
+
+        Ok(self.system_config_history.at(l1_block))
+    }
+
+    async fn op_system_config_updates(
+        &self,
+        from_l1_block: u64,
+        to_l1_block: u64,
+    ) -> RpcResult<Vec<SystemConfigUpdateRecord>> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "op_systemConfigUpdates");
+
+        if from_l1_block > to_l1_block {
+            return Err(ErrorObject::from(ErrorCode::InvalidParams));
+        }
+        Ok(self.system_config_history.updates(from_l1_block, to_l1_block))
+    }
+
+    async fn op_deposits_by_l1_tx(&self, l1_tx_hash: B256) -> RpcResult<Vec<IndexedDeposit>> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "op_depositsByL1Tx");
+
+        Ok(self.deposit_index()?.by_l1_tx(l1_tx_hash))
+    }
+
+    async fn op_deposit_by_l1_log(
+        &self,
+        l1_tx_hash: B256,
+        log_index: u64,
+    ) -> RpcResult<Option<IndexedDeposit>> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "op_depositByL1Log");
+
+        Ok(self.deposit_index()?.get(l1_tx_hash, log_index))
+    }
 }
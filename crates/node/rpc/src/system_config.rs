@@ -0,0 +1,443 @@
+//! Contains the [`SystemConfigHistory`] store.
+
+use kona_genesis::SystemConfig;
+use kona_protocol::{BlockInfo, SystemConfigUpdateRecord};
+use std::{
+    fs::File,
+    io::{BufReader, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, mpsc},
+};
+
+/// The maximum number of system config updates kept in a [`SystemConfigHistory`]. Once exceeded,
+/// the updates of the oldest L1 blocks are folded into the start of the history.
+pub const MAX_SYSTEM_CONFIG_RECORDS: usize = 4096;
+
+/// The number of L1 blocks after which the head of a [`SystemConfigHistory`] is persisted, even if
+/// no system config update was recorded in the meantime.
+const HEAD_PERSIST_INTERVAL: u64 = 32;
+
+/// An error loading a [`SystemConfigHistory`] from disk.
+#[derive(Debug, thiserror::Error)]
+pub enum SystemConfigHistoryError {
+    /// The history file could not be read.
+    #[error("Failed to read the system config history: {0}")]
+    Io(#[from] std::io::Error),
+    /// The history file could not be parsed.
+    #[error("Failed to parse the system config history: {0}")]
+    Parse(#[from] serde_json::Error),
+}
+
+/// The state of a [`SystemConfigHistory`], as persisted to disk.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HistoryState {
+    /// The first L1 block covered by the history.
+    start: Option<BlockInfo>,
+    /// The system config in effect before the first L1 block covered by the history.
+    start_config: SystemConfig,
+    /// The last L1 block covered by the history.
+    head: Option<BlockInfo>,
+    /// The system config updates, ordered by L1 block number and log index.
+    records: Vec<SystemConfigUpdateRecord>,
+}
+
+impl HistoryState {
+    /// Records the updates of an L1 block, unwinding any records the block replaces.
+    ///
+    /// Returns `true` if the start of the history or its records changed.
+    fn record(
+        &mut self,
+        block: &BlockInfo,
+        system_config: &SystemConfig,
+        updates: &[SystemConfigUpdateRecord],
+    ) -> bool {
+        let start = self.start;
+        let records = self.records.len();
+
+        // If blocks were skipped, the history is no longer contiguous and starts over.
+        if self.head.is_some_and(|head| block.number > head.number + 1) {
+            self.start = None;
+            self.records.clear();
+        }
+
+        // Revisiting a block invalidates every record from it onwards. A parent hash mismatch
+        // means the records of the previous block were reorged out.
+        self.records.retain(|r| {
+            r.l1_block.number < block.number &&
+                (r.l1_block.number + 1 != block.number || r.l1_block.hash == block.parent_hash)
+        });
+
+        if self.start.is_none_or(|start| block.number <= start.number) {
+            self.start = Some(*block);
+            self.start_config = *system_config;
+            self.records.clear();
+        }
+        self.head = Some(*block);
+        self.records.extend_from_slice(updates);
+        self.prune(MAX_SYSTEM_CONFIG_RECORDS);
+
+        self.start != start || self.records.len() != records || !updates.is_empty()
+    }
+
+    /// Folds the updates of the oldest L1 blocks into the start of the history, until at most
+    /// `max_records` updates are left.
+    ///
+    /// The updates of a block are folded together, so that the start config is always the config
+    /// in effect after a whole block.
+    fn prune(&mut self, max_records: usize) {
+        if self.records.len() <= max_records {
+            return;
+        }
+        let pruned = self.records[self.records.len() - max_records - 1].l1_block;
+        // The last update of a block holds the config in effect after the whole block.
+        if let Some(last) = self.records.iter().rev().find(|r| r.l1_block.number == pruned.number) {
+            self.start_config = last.system_config;
+        }
+        self.start = Some(pruned);
+        self.records.retain(|r| r.l1_block.number > pruned.number);
+    }
+}
+
+/// A shared store of the system config updates applied by the derivation pipeline.
+///
+/// The history covers a contiguous range of L1 blocks, starting at the first block the pipeline
+/// traversed, and keeps at most [`MAX_SYSTEM_CONFIG_RECORDS`] updates. Records are unwound when the
+/// pipeline revisits an L1 block after a reset, so the history follows L1 reorgs.
+///
+/// If loaded with [`SystemConfigHistory::from_file`], the history is persisted to disk as a JSON
+/// file and survives restarts. It is written by a background thread, only when its records change
+/// or every few L1 blocks otherwise, so that recording never blocks the derivation pipeline on
+/// disk I/O.
+#[derive(Debug, Clone, Default)]
+pub struct SystemConfigHistory {
+    /// The history state.
+    state: Arc<Mutex<HistoryState>>,
+    /// The L1 block number of the head that was last sent to the writer.
+    persisted_head: Arc<Mutex<Option<u64>>>,
+    /// The sender of the background writer, if the history is persisted.
+    writer: Option<mpsc::Sender<WriterMessage>>,
+}
+
+/// A message to the background writer of a [`SystemConfigHistory`].
+#[derive(Debug)]
+enum WriterMessage {
+    /// Persists the given state.
+    Write(HistoryState),
+    /// Acknowledges once all previously sent states are persisted.
+    Flush(mpsc::SyncSender<()>),
+}
+
+impl SystemConfigHistory {
+    /// Returns the [`PathBuf`] of the history for the given chain id, stored under
+    /// `<datadir>/<chain_id>/`.
+    pub fn path(chain_id: u64, datadir: &Path) -> PathBuf {
+        let mut path = datadir.to_path_buf();
+        path.push(chain_id.to_string());
+        path.push("system_config_history.json");
+        path
+    }
+
+    /// Reads a [`SystemConfigHistory`] from the given file, persisting any changes back to it.
+    ///
+    /// If the file does not exist, an empty history is returned. A file that cannot be read or
+    /// parsed is an error, rather than being silently replaced by an empty history.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, SystemConfigHistoryError> {
+        let path = path.as_ref().to_path_buf();
+        let state = match File::open(&path) {
+            Ok(file) => {
+                debug!(target: "system_config_history", "Reading history from disk: {:?}", path);
+                serde_json::from_reader(BufReader::new(file))?
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HistoryState::default(),
+            Err(e) => return Err(e.into()),
+        };
+
+        let (writer, messages) = mpsc::channel();
+        std::thread::Builder::new()
+            .name("system-config-history".to_string())
+            .spawn(move || Self::run_writer(&path, messages))?;
+
+        let persisted_head = state.head.map(|head| head.number);
+        Ok(Self {
+            state: Arc::new(Mutex::new(state)),
+            persisted_head: Arc::new(Mutex::new(persisted_head)),
+            writer: Some(writer),
+        })
+    }
+
+    /// Records the updates of an L1 block traversed by the derivation pipeline, given the
+    /// [`SystemConfig`] in effect before the block.
+    pub fn record(
+        &self,
+        block: &BlockInfo,
+        system_config: &SystemConfig,
+        updates: &[SystemConfigUpdateRecord],
+    ) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let changed = state.record(block, system_config, updates);
+        let Some(writer) = &self.writer else {
+            return;
+        };
+
+        let mut persisted_head = self.persisted_head.lock().unwrap_or_else(|e| e.into_inner());
+        let head_due =
+            persisted_head.is_none_or(|n| block.number.abs_diff(n) >= HEAD_PERSIST_INTERVAL);
+        if !changed && !head_due {
+            return;
+        }
+        *persisted_head = Some(block.number);
+        if writer.send(WriterMessage::Write(state.clone())).is_err() {
+            warn!(target: "system_config_history", "History writer stopped, not persisting");
+        }
+    }
+
+    /// Blocks until every change recorded so far is persisted to disk.
+    pub fn flush(&self) {
+        let Some(writer) = &self.writer else {
+            return;
+        };
+        let (ack, done) = mpsc::sync_channel(1);
+        if writer.send(WriterMessage::Flush(ack)).is_ok() {
+            let _ = done.recv();
+        }
+    }
+
+    /// Returns the [`SystemConfig`] in effect at the given L1 block number, after the updates of
+    /// the block were applied.
+    ///
+    /// Returns `None` if the block is not covered by the history.
+    pub fn at(&self, l1_block: u64) -> Option<SystemConfig> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let (start, head) = state.start.zip(state.head)?;
+        if l1_block < start.number || l1_block > head.number {
+            return None;
+        }
+        let config = state
+            .records
+            .iter()
+            .rev()
+            .find(|r| r.l1_block.number <= l1_block)
+            .map_or(state.start_config, |r| r.system_config);
+        Some(config)
+    }
+
+    /// Returns the system config updates emitted between the given L1 block numbers, inclusive.
+    pub fn updates(&self, from: u64, to: u64) -> Vec<SystemConfigUpdateRecord> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.records.iter().filter(|r| (from..=to).contains(&r.l1_block.number)).cloned().collect()
+    }
+
+    /// Runs the background writer, persisting the latest received state until every
+    /// [`SystemConfigHistory`] handle is dropped.
+    fn run_writer(path: &Path, messages: mpsc::Receiver<WriterMessage>) {
+        while let Ok(message) = messages.recv() {
+            let mut latest = None;
+            let mut acks = Vec::new();
+            // Only the most recent of the queued states needs to be written.
+            for message in std::iter::once(message).chain(messages.try_iter()) {
+                match message {
+                    WriterMessage::Write(state) => latest = Some(state),
+                    WriterMessage::Flush(ack) => acks.push(ack),
+                }
+            }
+            if let Some(state) = latest {
+                if let Err(e) = Self::write_to_file(path, &state) {
+                    warn!(
+                        target: "system_config_history",
+                        "Failed to write history to disk: {:?}", e
+                    );
+                }
+            }
+            for ack in acks {
+                let _ = ack.send(());
+            }
+        }
+    }
+
+    /// Writes the history to disk.
+    ///
+    /// The history is first written to a temporary file which is then renamed over the persisted
+    /// file, so that a crash never leaves a partially written history behind.
+    fn write_to_file(path: &Path, state: &HistoryState) -> Result<(), std::io::Error> {
+        // If the directory does not exist, create it.
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp_path = path.with_extension("tmp");
+        let mut file = File::create(&tmp_path)?;
+        serde_json::to_writer(&mut file, state)?;
+        file.flush()?;
+        file.sync_all()?;
+        std::fs::rename(&tmp_path, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Address, B256};
+    use kona_genesis::{BatcherUpdate, SystemConfigUpdate};
+
+    fn hash(number: u64, fork: u8) -> B256 {
+        let mut hash = B256::with_last_byte(fork);
+        hash[..8].copy_from_slice(&number.to_be_bytes());
+        hash
+    }
+
+    fn block(number: u64, fork: u8) -> BlockInfo {
+        BlockInfo {
+            hash: hash(number, fork),
+            number,
+            parent_hash: hash(number.wrapping_sub(1), fork),
+            timestamp: number,
+        }
+    }
+
+    fn batcher_record(block: BlockInfo, batcher: u8) -> SystemConfigUpdateRecord {
+        let batcher_address = Address::repeat_byte(batcher);
+        SystemConfigUpdateRecord {
+            l1_block: block,
+            log_index: 0,
+            update: SystemConfigUpdate::Batcher(BatcherUpdate { batcher_address }),
+            system_config: SystemConfig { batcher_address, ..Default::default() },
+        }
+    }
+
+    /// Records the blocks `from..=to` on the given fork, with a batcher update in `update_at`.
+    fn record_range(history: &SystemConfigHistory, from: u64, to: u64, fork: u8, update_at: u64) {
+        for number in from..=to {
+            let block = block(number, fork);
+            let config = history.at(number.saturating_sub(1)).unwrap_or_default();
+            let updates =
+                if number == update_at { vec![batcher_record(block, fork)] } else { vec![] };
+            history.record(&block, &config, &updates);
+        }
+    }
+
+    #[test]
+    fn test_system_config_at() {
+        let history = SystemConfigHistory::default();
+        record_range(&history, 10, 20, 1, 15);
+
+        assert_eq!(history.at(9), None);
+        assert_eq!(history.at(14), Some(SystemConfig::default()));
+        assert_eq!(history.at(15).unwrap().batcher_address, Address::repeat_byte(1));
+        assert_eq!(history.at(20).unwrap().batcher_address, Address::repeat_byte(1));
+        assert_eq!(history.at(21), None);
+    }
+
+    #[test]
+    fn test_updates_range() {
+        let history = SystemConfigHistory::default();
+        record_range(&history, 10, 20, 1, 15);
+
+        assert_eq!(history.updates(10, 14), vec![]);
+        assert_eq!(history.updates(15, 15), vec![batcher_record(block(15, 1), 1)]);
+        assert_eq!(history.updates(0, u64::MAX).len(), 1);
+    }
+
+    #[test]
+    fn test_unwinds_on_reorg() {
+        let history = SystemConfigHistory::default();
+        record_range(&history, 10, 20, 1, 15);
+
+        // The pipeline resets to block 12 and traverses a new fork without the update.
+        let mut next = block(13, 2);
+        next.parent_hash = block(12, 1).hash;
+        history.record(&next, &SystemConfig::default(), &[]);
+        assert!(history.updates(0, u64::MAX).is_empty());
+        assert_eq!(history.at(13), Some(SystemConfig::default()));
+        assert_eq!(history.at(14), None);
+
+        record_range(&history, 14, 18, 2, 17);
+        assert_eq!(history.updates(0, u64::MAX), vec![batcher_record(block(17, 2), 2)]);
+        assert_eq!(history.at(16), Some(SystemConfig::default()));
+    }
+
+    #[test]
+    fn test_drops_reorged_parent() {
+        let history = SystemConfigHistory::default();
+        record_range(&history, 10, 15, 1, 15);
+
+        // Block 16 does not build on the recorded block 15.
+        history.record(&block(16, 2), &SystemConfig::default(), &[]);
+        assert!(history.updates(0, u64::MAX).is_empty());
+    }
+
+    #[test]
+    fn test_restarts_after_gap() {
+        let history = SystemConfigHistory::default();
+        record_range(&history, 10, 15, 1, 12);
+
+        history.record(&block(30, 1), &SystemConfig::default(), &[]);
+        assert!(history.updates(0, u64::MAX).is_empty());
+        assert_eq!(history.at(12), None);
+        assert_eq!(history.at(30), Some(SystemConfig::default()));
+    }
+
+    #[test]
+    fn test_prunes_oldest_blocks() {
+        let mut state = HistoryState::default();
+        let records = |b: BlockInfo| vec![batcher_record(b, 1), batcher_record(b, 2)];
+        for number in 10..=13 {
+            let block = block(number, 1);
+            state.record(&block, &SystemConfig::default(), &records(block));
+        }
+        state.prune(5);
+
+        // The updates of blocks 10 and 11 are folded into the start of the history.
+        assert_eq!(state.records.len(), 4);
+        assert_eq!(state.start, Some(block(11, 1)));
+        assert_eq!(state.start_config.batcher_address, Address::repeat_byte(2));
+        assert!(state.records.iter().all(|r| r.l1_block.number > 11));
+    }
+
+    #[test]
+    fn test_persists_to_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = SystemConfigHistory::path(10, dir.path());
+
+        let history = SystemConfigHistory::from_file(&path).unwrap();
+        record_range(&history, 10, 20, 1, 15);
+        history.flush();
+        assert!(path.exists());
+        assert!(!path.with_extension("tmp").exists());
+
+        let reloaded = SystemConfigHistory::from_file(&path).unwrap();
+        assert_eq!(reloaded.updates(0, u64::MAX), history.updates(0, u64::MAX));
+        assert_eq!(reloaded.at(15), history.at(15));
+        assert_eq!(reloaded.at(9), None);
+    }
+
+    #[test]
+    fn test_persists_only_on_change() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("system_config_history.json");
+
+        let history = SystemConfigHistory::from_file(&path).unwrap();
+        record_range(&history, 10, 12, 1, 0);
+        history.flush();
+        let persisted = std::fs::read_to_string(&path).unwrap();
+
+        // Blocks without updates within the persist interval are not written.
+        record_range(&history, 13, 20, 1, 0);
+        history.flush();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), persisted);
+
+        record_range(&history, 21, 21, 1, 21);
+        history.flush();
+        assert_ne!(std::fs::read_to_string(&path).unwrap(), persisted);
+    }
+
+    #[test]
+    fn test_corrupted_file_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("system_config_history.json");
+        std::fs::write(&path, "{").unwrap();
+        assert!(matches!(
+            SystemConfigHistory::from_file(&path),
+            Err(SystemConfigHistoryError::Parse(_))
+        ));
+    }
+}
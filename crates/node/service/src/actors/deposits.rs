@@ -0,0 +1,307 @@
+//! Indexes the deposit transactions derived by the derivation actor.
+
+use alloy_eips::{BlockId, BlockNumHash, Decodable2718};
+use alloy_primitives::{Address, B256};
+use alloy_provider::{Provider, RootProvider};
+use alloy_rpc_types_eth::Log;
+use kona_protocol::{DEPOSIT_EVENT_ABI_HASH, L1BlockInfoTx, OpAttributesWithParent};
+use kona_rpc::{DepositIndex, IndexedDeposit};
+use op_alloy_consensus::{OpTxEnvelope, TxDeposit, UserDepositSource};
+use std::collections::HashMap;
+use thiserror::Error;
+use tokio::sync::mpsc;
+
+/// Indexes the user deposit transactions of derived payload attributes into a [`DepositIndex`].
+///
+/// User deposits are matched to the `TransactionDeposited` events of the `OptimismPortal` by
+/// their source hash, which commits to the L1 origin block hash and the log index of the event.
+///
+/// The indexer runs as a background task, see [`DepositIndexer::spawn`], so that fetching the
+/// receipts of the L1 origin never delays derivation.
+#[derive(Debug, Clone)]
+pub struct DepositIndexer {
+    /// The L1 provider used to fetch the receipts of the L1 origin.
+    pub l1_provider: RootProvider,
+    /// The L1 address of the deposit contract.
+    pub deposit_contract: Address,
+    /// The index to insert the deposits into.
+    pub index: DepositIndex,
+}
+
+impl DepositIndexer {
+    /// Creates a new [`DepositIndexer`].
+    pub const fn new(
+        l1_provider: RootProvider,
+        deposit_contract: Address,
+        index: DepositIndex,
+    ) -> Self {
+        Self { l1_provider, deposit_contract, index }
+    }
+
+    /// Spawns the indexer as a background task, returning the sender to submit the payload
+    /// attributes to index. Attributes are indexed in the order they are submitted.
+    ///
+    /// The task exits once the returned sender is dropped.
+    pub fn spawn(self) -> mpsc::UnboundedSender<OpAttributesWithParent> {
+        let (attributes_tx, mut attributes_rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            while let Some(attributes) = attributes_rx.recv().await {
+                self.index(&attributes).await;
+            }
+        });
+        attributes_tx
+    }
+
+    /// Indexes the user deposits of the given payload attributes.
+    ///
+    /// Failures are logged and leave the block without indexed deposits, they never interrupt
+    /// derivation.
+    pub async fn index(&self, attributes: &OpAttributesWithParent) {
+        let block_number = attributes.block_number();
+        let deposits = match self.deposits(attributes).await {
+            Ok(deposits) => deposits,
+            Err(e) => {
+                warn!(target: "deposit_index", block_number, "Failed to index deposits: {e}");
+                Vec::new()
+            }
+        };
+        if !deposits.is_empty() {
+            let count = deposits.len();
+            debug!(target: "deposit_index", block_number, count, "Indexed deposits");
+        }
+        // Always insert, so deposits of a previously derived block at this height are unwound.
+        self.index.insert(block_number, deposits);
+    }
+
+    /// Matches the user deposits of the given payload attributes to the deposit events of their
+    /// L1 origin.
+    async fn deposits(
+        &self,
+        attributes: &OpAttributesWithParent,
+    ) -> Result<Vec<IndexedDeposit>, DepositIndexerError> {
+        let (l1_origin, user_deposits) = user_deposits(attributes)?;
+        // Without user deposits, there is nothing to match against the L1 origin's receipts.
+        if user_deposits.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let receipts = self
+            .l1_provider
+            .get_block_receipts(BlockId::hash(l1_origin.hash))
+            .await
+            .map_err(|e| DepositIndexerError::Provider(e.to_string()))?
+            .ok_or(DepositIndexerError::MissingReceipts(l1_origin.hash))?;
+
+        Ok(match_deposits(
+            self.deposit_contract,
+            l1_origin,
+            attributes.block_number(),
+            &user_deposits,
+            receipts.iter().flat_map(|receipt| receipt.inner.logs()),
+        ))
+    }
+}
+
+/// Returns the L1 origin and the user deposits of the given payload attributes, along with the L2
+/// transaction hash of each deposit.
+fn user_deposits(
+    attributes: &OpAttributesWithParent,
+) -> Result<(BlockNumHash, Vec<(B256, TxDeposit)>), DepositIndexerError> {
+    let transactions = attributes.inner().transactions.as_deref().unwrap_or_default();
+
+    let mut deposits = Vec::new();
+    for tx in transactions {
+        let envelope = OpTxEnvelope::decode_2718(&mut &tx[..])
+            .map_err(|_| DepositIndexerError::MalformedTransaction)?;
+        let OpTxEnvelope::Deposit(deposit) = envelope else {
+            // Deposits always come first in the block.
+            break;
+        };
+        deposits.push((deposit.hash(), deposit.into_inner()));
+    }
+
+    // The first deposit is always the L1 info transaction.
+    if deposits.is_empty() {
+        return Err(DepositIndexerError::MissingL1InfoTransaction);
+    }
+    let (_, l1_info) = deposits.remove(0);
+    let l1_origin = L1BlockInfoTx::decode_calldata(l1_info.input.as_ref())
+        .map_err(|_| DepositIndexerError::MalformedTransaction)?
+        .id();
+    Ok((l1_origin, deposits))
+}
+
+/// Matches the user deposits of an L2 block to the deposit events in the logs of its L1 origin.
+///
+/// Deposits without a matching event, e.g. network upgrade transactions, are skipped.
+fn match_deposits<'a>(
+    deposit_contract: Address,
+    l1_origin: BlockNumHash,
+    l2_block_number: u64,
+    user_deposits: &[(B256, TxDeposit)],
+    logs: impl IntoIterator<Item = &'a Log>,
+) -> Vec<IndexedDeposit> {
+    let events = logs
+        .into_iter()
+        .filter(|log| {
+            log.address() == deposit_contract &&
+                log.topics().first() == Some(&DEPOSIT_EVENT_ABI_HASH)
+        })
+        .filter_map(|log| {
+            let (tx_hash, log_index) = log.transaction_hash.zip(log.log_index)?;
+            let source_hash = UserDepositSource::new(l1_origin.hash, log_index).source_hash();
+            Some((source_hash, (tx_hash, log_index)))
+        })
+        .collect::<HashMap<_, _>>();
+
+    user_deposits
+        .iter()
+        .filter_map(|(l2_tx_hash, TxDeposit { source_hash, .. })| {
+            let (l1_tx_hash, log_index) = events.get(source_hash)?;
+            Some(IndexedDeposit {
+                l1_block: l1_origin,
+                l1_tx_hash: *l1_tx_hash,
+                log_index: *log_index,
+                source_hash: *source_hash,
+                l2_tx_hash: *l2_tx_hash,
+                l2_block_number,
+            })
+        })
+        .collect()
+}
+
+/// An error that occurs while indexing deposits.
+#[derive(Debug, Error)]
+enum DepositIndexerError {
+    /// A transaction of the payload attributes could not be decoded.
+    #[error("malformed transaction in payload attributes")]
+    MalformedTransaction,
+    /// The payload attributes do not start with an L1 info transaction.
+    #[error("missing L1 info transaction in payload attributes")]
+    MissingL1InfoTransaction,
+    /// The receipts of the L1 origin could not be found.
+    #[error("missing receipts for L1 origin {0}")]
+    MissingReceipts(B256),
+    /// The L1 provider returned an error.
+    #[error("L1 provider error: {0}")]
+    Provider(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::Encodable2718;
+    use alloy_primitives::{Bytes, LogData, Sealed};
+    use alloy_rpc_client::RpcClient;
+    use alloy_transport::mock::{Asserter, MockTransport};
+    use kona_protocol::L1BlockInfoBedrock;
+    use op_alloy_rpc_types_engine::OpPayloadAttributes;
+
+    const PORTAL: Address = Address::repeat_byte(0xaa);
+    const L1_ORIGIN: BlockNumHash = BlockNumHash { number: 100, hash: B256::repeat_byte(0x11) };
+
+    fn encode(deposit: TxDeposit) -> Bytes {
+        OpTxEnvelope::Deposit(Sealed::new(deposit)).encoded_2718().into()
+    }
+
+    fn l1_info() -> TxDeposit {
+        let info = L1BlockInfoTx::Bedrock(L1BlockInfoBedrock {
+            number: L1_ORIGIN.number,
+            block_hash: L1_ORIGIN.hash,
+            ..Default::default()
+        });
+        TxDeposit { input: info.encode_calldata(), ..Default::default() }
+    }
+
+    fn user_deposit(log_index: u64) -> TxDeposit {
+        let source_hash = UserDepositSource::new(L1_ORIGIN.hash, log_index).source_hash();
+        TxDeposit { source_hash, ..Default::default() }
+    }
+
+    fn attributes(deposits: Vec<TxDeposit>) -> OpAttributesWithParent {
+        let mut attributes = OpAttributesWithParent {
+            inner: OpPayloadAttributes::default(),
+            parent: Default::default(),
+            derived_from: None,
+            is_last_in_span: true,
+        };
+        attributes.parent.block_info.number = 41;
+        let transactions = std::iter::once(l1_info()).chain(deposits).map(encode).collect();
+        attributes.inner.transactions = Some(transactions);
+        attributes
+    }
+
+    fn deposit_log(address: Address, tx_hash: u8, log_index: u64) -> Log {
+        Log {
+            inner: alloy_primitives::Log {
+                address,
+                data: LogData::new_unchecked(vec![DEPOSIT_EVENT_ABI_HASH], Bytes::new()),
+            },
+            transaction_hash: Some(B256::repeat_byte(tx_hash)),
+            log_index: Some(log_index),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_user_deposits_skip_l1_info() {
+        let deposits = vec![user_deposit(3), user_deposit(7)];
+        let (l1_origin, user_deposits) = user_deposits(&attributes(deposits.clone())).unwrap();
+
+        assert_eq!(l1_origin, L1_ORIGIN);
+        assert_eq!(user_deposits.into_iter().map(|(_, d)| d).collect::<Vec<_>>(), deposits);
+    }
+
+    #[test]
+    fn test_user_deposits_missing_l1_info() {
+        let mut attributes = attributes(vec![]);
+        attributes.inner.transactions = Some(vec![]);
+        assert!(matches!(
+            user_deposits(&attributes),
+            Err(DepositIndexerError::MissingL1InfoTransaction)
+        ));
+    }
+
+    #[test]
+    fn test_match_deposits() {
+        let attributes = attributes(vec![user_deposit(3), user_deposit(7), user_deposit(9)]);
+        let (l1_origin, user_deposits) = user_deposits(&attributes).unwrap();
+        let logs = [
+            deposit_log(PORTAL, 1, 3),
+            // Events of other contracts never match.
+            deposit_log(Address::repeat_byte(0xbb), 2, 7),
+            deposit_log(PORTAL, 3, 9),
+        ];
+
+        let matched =
+            match_deposits(PORTAL, l1_origin, attributes.block_number(), &user_deposits, &logs);
+
+        assert_eq!(matched.len(), 2);
+        assert_eq!(matched[0].l1_tx_hash, B256::repeat_byte(1));
+        assert_eq!(matched[0].log_index, 3);
+        assert_eq!(matched[0].l2_tx_hash, user_deposits[0].0);
+        assert_eq!(matched[0].l2_block_number, 42);
+        assert_eq!(matched[0].l1_block, L1_ORIGIN);
+        assert_eq!(matched[1].l1_tx_hash, B256::repeat_byte(3));
+        assert_eq!(matched[1].log_index, 9);
+    }
+
+    #[test]
+    fn test_match_deposits_wrong_log_index() {
+        let attributes = attributes(vec![user_deposit(3)]);
+        let (l1_origin, user_deposits) = user_deposits(&attributes).unwrap();
+        let logs = [deposit_log(PORTAL, 1, 4)];
+
+        assert!(match_deposits(PORTAL, l1_origin, 42, &user_deposits, &logs).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_index_without_user_deposits_skips_receipts() {
+        let asserter = Asserter::new();
+        let provider = RootProvider::new(RpcClient::new(MockTransport::new(asserter), false));
+        let indexer = DepositIndexer::new(provider, PORTAL, DepositIndex::default());
+
+        // Any receipts request would fail, as the asserter holds no responses.
+        assert!(indexer.deposits(&attributes(vec![])).await.unwrap().is_empty());
+    }
+}
@@ -1,25 +1,30 @@
 //! [NodeActor] implementation for the derivation sub-routine.
 
-use std::sync::Arc;
+use std::{collections::VecDeque, sync::Arc};
 
-use crate::{InteropMode, Metrics, NodeActor, actors::CancellableContext};
+use crate::{DepositIndexer, InteropMode, Metrics, NodeActor, actors::CancellableContext};
 use alloy_provider::RootProvider;
 use async_trait::async_trait;
 use kona_derive::{
-    ActivationSignal, Pipeline, PipelineError, PipelineErrorKind, ResetError, ResetSignal, Signal,
-    SignalReceiver, StepResult,
+    ActivationSignal, BatchValidityHook, Pipeline, PipelineError, PipelineErrorKind, PipelineHooks,
+    ResetError, ResetSignal, Signal, SignalReceiver, StepResult, SystemConfigHook,
+};
+use kona_genesis::{RollupConfig, SystemConfig};
+use kona_interop::{DerivedRefPair, ManagedEvent};
+use kona_protocol::{
+    BatchValidityReport, BlockInfo, DecompressorRegistry, L2BlockInfo, OpAttributesWithParent,
+    SystemConfigUpdateRecord,
 };
-use kona_genesis::RollupConfig;
-use kona_protocol::{BlockInfo, L2BlockInfo, OpAttributesWithParent};
 use kona_providers_alloy::{
     AlloyChainProvider, AlloyL2ChainProvider, OnlineBeaconClient, OnlineBlobProvider,
     OnlinePipeline,
 };
+use kona_rpc::{BatchValidityReports, DepositIndex, SystemConfigHistory};
 use op_alloy_network::Optimism;
 use thiserror::Error;
 use tokio::{
     select,
-    sync::{mpsc, oneshot, watch},
+    sync::{broadcast, mpsc, oneshot, watch},
 };
 use tokio_util::sync::{CancellationToken, WaitForCancellationFuture};
 
@@ -62,6 +67,8 @@ where
     ///
     /// Specs: <https://specs.optimism.io/protocol/derivation.html#l1-sync-payload-attributes-processing>
     derivation_signal_rx: mpsc::Receiver<Signal>,
+    /// The validity reports of the batches checked by the derivation pipeline.
+    batch_validity_reports: BatchValidityReports,
 }
 
 /// The state for the derivation actor.
@@ -78,6 +85,14 @@ where
     /// A flag indicating whether or not derivation is waiting for a signal. When waiting for a
     /// signal, derivation cannot process any incoming events.
     pub waiting_for_signal: bool,
+    /// Submits the derived attributes to the background [`DepositIndexer`], if enabled.
+    pub deposit_indexer: Option<mpsc::UnboundedSender<OpAttributesWithParent>>,
+    /// The derived attributes that are indexed by the [`DepositIndexer`] once the engine includes
+    /// them in the safe chain.
+    pub pending_deposits: VecDeque<OpAttributesWithParent>,
+    /// A channel to send [`ManagedEvent`]s to the interop supervisor, when the node runs in
+    /// managed mode.
+    pub managed_events: Option<broadcast::Sender<ManagedEvent>>,
 }
 
 /// The size of the cache used in the derivation pipeline's providers.
@@ -89,8 +104,45 @@ pub trait PipelineBuilder: Send + Sync + 'static {
     /// The type of pipeline to build.
     type Pipeline: Pipeline + SignalReceiver + Send + Sync + 'static;
 
-    /// Builds the derivation pipeline.
-    async fn build(self) -> DerivationState<Self::Pipeline>;
+    /// Builds the derivation pipeline, publishing the validity reports of the batches it checks
+    /// into the given [`BatchValidityReports`].
+    async fn build(
+        self,
+        batch_validity_reports: BatchValidityReports,
+    ) -> DerivationState<Self::Pipeline>;
+
+    /// Returns the [`SystemConfigHistory`] that records the system config updates applied by the
+    /// derivation pipeline.
+    fn system_config_history(&self) -> SystemConfigHistory;
+
+    /// Returns the [`DepositIndex`] that indexes the deposits derived by the derivation pipeline,
+    /// if enabled.
+    fn deposit_index(&self) -> Option<DepositIndex>;
+}
+
+/// A [`BatchValidityHook`] that records the validity reports into [`BatchValidityReports`].
+#[derive(Debug)]
+struct BatchValidityRecorder(BatchValidityReports);
+
+impl BatchValidityHook for BatchValidityRecorder {
+    fn on_report(&self, report: &BatchValidityReport) {
+        self.0.push(*report);
+    }
+}
+
+/// A [`SystemConfigHook`] that records the system config updates into a [`SystemConfigHistory`].
+#[derive(Debug)]
+struct SystemConfigRecorder(SystemConfigHistory);
+
+impl SystemConfigHook for SystemConfigRecorder {
+    fn on_l1_block(
+        &self,
+        block: &BlockInfo,
+        system_config: &SystemConfig,
+        updates: &[SystemConfigUpdateRecord],
+    ) {
+        self.0.record(block, system_config, updates);
+    }
 }
 
 /// The configuration necessary to build the derivation actor.
@@ -106,13 +158,22 @@ pub struct DerivationBuilder {
     pub rollup_config: Arc<RollupConfig>,
     /// The interop mode.
     pub interop_mode: InteropMode,
+    /// The history of the system config updates applied by the derivation pipeline.
+    pub system_config_history: SystemConfigHistory,
+    /// The index of the deposits derived by the derivation pipeline, if enabled.
+    pub deposit_index: Option<DepositIndex>,
+    /// The decompressors that the derivation pipeline decompresses channels with.
+    pub decompressors: DecompressorRegistry,
 }
 
 #[async_trait]
 impl PipelineBuilder for DerivationBuilder {
     type Pipeline = OnlinePipeline;
 
-    async fn build(self) -> DerivationState<OnlinePipeline> {
+    async fn build(
+        self,
+        batch_validity_reports: BatchValidityReports,
+    ) -> DerivationState<OnlinePipeline> {
         // Create the caching L1/L2 EL providers for derivation.
         let l1_derivation_provider =
             AlloyChainProvider::new(self.l1_provider.clone(), DERIVATION_PROVIDER_CACHE_SIZE);
@@ -122,22 +183,45 @@ impl PipelineBuilder for DerivationBuilder {
             DERIVATION_PROVIDER_CACHE_SIZE,
         );
 
+        let hooks = PipelineHooks {
+            batch_validity: Some(Arc::new(BatchValidityRecorder(batch_validity_reports))),
+            system_config: Some(Arc::new(SystemConfigRecorder(self.system_config_history.clone()))),
+        };
         let pipeline = match self.interop_mode {
             InteropMode::Polled => OnlinePipeline::new_polled(
                 self.rollup_config.clone(),
                 OnlineBlobProvider::init(self.l1_beacon.clone()).await,
                 l1_derivation_provider,
                 l2_derivation_provider,
+                hooks.clone(),
+                self.decompressors.clone(),
             ),
             InteropMode::Indexed => OnlinePipeline::new_indexed(
                 self.rollup_config.clone(),
                 OnlineBlobProvider::init(self.l1_beacon.clone()).await,
                 l1_derivation_provider,
                 l2_derivation_provider,
+                hooks,
+                self.decompressors.clone(),
             ),
         };
 
-        DerivationState::new(pipeline)
+        let deposit_indexer = self.deposit_index.map(|index| {
+            DepositIndexer::new(
+                self.l1_provider,
+                self.rollup_config.deposit_contract_address,
+                index,
+            )
+        });
+        DerivationState::new(pipeline).with_deposit_indexer(deposit_indexer)
+    }
+
+    fn system_config_history(&self) -> SystemConfigHistory {
+        self.system_config_history.clone()
+    }
+
+    fn deposit_index(&self) -> Option<DepositIndex> {
+        self.deposit_index.clone()
     }
 }
 
@@ -157,6 +241,14 @@ pub struct DerivationInboundChannels {
     /// This channel should be used by the engine actor to send [`Signal`]s to the derivation
     /// pipeline. The signals are received by `DerivationActor::derivation_signal_rx`.
     pub derivation_signal_tx: mpsc::Sender<Signal>,
+    /// The validity reports of the batches checked by the derivation pipeline, read by the dev
+    /// RPC.
+    pub batch_validity_reports: BatchValidityReports,
+    /// The history of the system config updates applied by the derivation pipeline, read by the
+    /// rollup RPC.
+    pub system_config_history: SystemConfigHistory,
+    /// The index of the deposits derived by the derivation pipeline, read by the rollup RPC.
+    pub deposit_index: Option<DepositIndex>,
 }
 
 /// The communication context used by the derivation actor.
@@ -169,6 +261,8 @@ pub struct DerivationContext {
     /// The reset request sender, used to handle [`PipelineErrorKind::Reset`] events and forward
     /// them to the engine.
     pub reset_request_tx: mpsc::Sender<()>,
+    /// Sends [`ManagedEvent`]s to the interop supervisor, when the node runs in managed mode.
+    pub managed_events_tx: Option<broadcast::Sender<ManagedEvent>>,
 }
 
 impl CancellableContext for DerivationContext {
@@ -183,7 +277,72 @@ where
 {
     /// Creates a new instance of the [DerivationState].
     pub const fn new(pipeline: P) -> Self {
-        Self { pipeline, derivation_idle: true, waiting_for_signal: false }
+        Self {
+            pipeline,
+            derivation_idle: true,
+            waiting_for_signal: false,
+            deposit_indexer: None,
+            pending_deposits: VecDeque::new(),
+            managed_events: None,
+        }
+    }
+
+    /// Sets the [`DepositIndexer`] that indexes the deposits of the derived attributes, spawning
+    /// it as a background task.
+    pub fn with_deposit_indexer(self, deposit_indexer: Option<DepositIndexer>) -> Self {
+        Self { deposit_indexer: deposit_indexer.map(DepositIndexer::spawn), ..self }
+    }
+
+    /// Queues the derived attributes, so that their deposits are indexed once the engine includes
+    /// them in the safe chain.
+    fn queue_deposits(&mut self, attributes: &OpAttributesWithParent) {
+        if self.deposit_indexer.is_none() {
+            return;
+        }
+        // Attributes derived again at the same height replace the ones that were not included.
+        let block_number = attributes.block_number();
+        self.pending_deposits.retain(|pending| pending.block_number() < block_number);
+        self.pending_deposits.push_back(attributes.clone());
+    }
+
+    /// Submits the queued attributes that the engine included in the safe chain to the
+    /// [`DepositIndexer`].
+    fn index_included_deposits(&mut self, safe_head: &L2BlockInfo) {
+        let Some(indexer) = &self.deposit_indexer else {
+            return;
+        };
+        while self
+            .pending_deposits
+            .front()
+            .is_some_and(|pending| pending.block_number() <= safe_head.block_info.number)
+        {
+            let Some(attributes) = self.pending_deposits.pop_front() else {
+                break;
+            };
+            if indexer.send(attributes).is_err() {
+                warn!(target: "derivation", "Deposit indexer stopped, no longer indexing deposits");
+                self.deposit_indexer = None;
+                self.pending_deposits.clear();
+                return;
+            }
+        }
+    }
+
+    /// Sets the channel used to send [`ManagedEvent`]s to the interop supervisor.
+    pub fn with_managed_events(
+        self,
+        managed_events: Option<broadcast::Sender<ManagedEvent>>,
+    ) -> Self {
+        Self { managed_events, ..self }
+    }
+
+    /// Sends a [`ManagedEvent`] to the interop supervisor, if the node runs in managed mode.
+    fn emit(&self, event: ManagedEvent) {
+        if let Some(managed_events) = &self.managed_events {
+            // Sending only fails when there are no subscribers, in which case the event is
+            // dropped.
+            let _ = managed_events.send(event);
+        }
     }
 
     /// Handles a [`Signal`] received over the derivation signal receiver channel.
@@ -216,10 +375,14 @@ where
                 StepResult::PreparedAttributes => { /* continue; attributes will be sent off. */ }
                 StepResult::AdvancedOrigin => {
                     let origin =
-                        self.pipeline.origin().ok_or(PipelineError::MissingOrigin.crit())?.number;
-
-                    kona_macros::set!(counter, Metrics::DERIVATION_L1_ORIGIN, origin);
-                    debug!(target: "derivation", l1_block = origin, "Advanced L1 origin");
+                        self.pipeline.origin().ok_or(PipelineError::MissingOrigin.crit())?;
+
+                    kona_macros::set!(counter, Metrics::DERIVATION_L1_ORIGIN, origin.number);
+                    debug!(target: "derivation", l1_block = origin.number, "Advanced L1 origin");
+                    self.emit(ManagedEvent {
+                        derivation_origin_update: Some(origin),
+                        ..Default::default()
+                    });
                 }
                 StepResult::OriginAdvanceErr(e) | StepResult::StepFailed(e) => {
                     match e {
@@ -234,6 +397,15 @@ where
                                 target: "derivation",
                                 "Exhausted data source for now; Yielding until the chain has extended."
                             );
+                            if let Some(source) = self.pipeline.origin() {
+                                self.emit(ManagedEvent {
+                                    exhaust_l1: Some(DerivedRefPair {
+                                        source,
+                                        derived: l2_safe_head.block_info,
+                                    }),
+                                    ..Default::default()
+                                });
+                            }
                             return Err(DerivationError::Yield);
                         }
                         PipelineErrorKind::Reset(e) => {
@@ -270,7 +442,7 @@ where
                                     kona_macros::inc!(counter, Metrics::L1_REORG_COUNT);
                                 }
                                 // send the `reset` signal to the engine actor only when interop is
-                                // not active.
+                                // not active. Otherwise, the supervisor is asked to reset the node.
                                 if !self
                                     .pipeline
                                     .rollup_config()
@@ -280,6 +452,11 @@ where
                                         error!(target: "derivation", ?e, "Failed to send reset request");
                                         DerivationError::Sender(Box::new(e))
                                     })?;
+                                } else {
+                                    self.emit(ManagedEvent {
+                                        reset: Some(e.to_string()),
+                                        ..Default::default()
+                                    });
                                 }
                                 self.waiting_for_signal = true;
                                 return Err(DerivationError::Yield);
@@ -354,6 +531,9 @@ where
             return Ok(());
         }
 
+        // Index the deposits of the attributes the engine included since the last step.
+        self.index_included_deposits(&engine_safe_head);
+
         // Advance the pipeline as much as possible, new data may be available or there still may be
         // payloads in the attributes queue.
         let payload_attrs =
@@ -375,6 +555,9 @@ where
         // Mark the L2 safe head as seen.
         engine_l2_safe_head.borrow_and_update();
 
+        // Index the deposits of the attributes once the engine includes them, if enabled.
+        self.queue_deposits(&payload_attrs);
+
         // Send payload attributes out for processing.
         derived_attributes_tx
             .send(payload_attrs)
@@ -396,12 +579,16 @@ where
             watch::channel(L2BlockInfo::default());
         let (el_sync_complete_tx, el_sync_complete_rx) = oneshot::channel();
         let (derivation_signal_tx, derivation_signal_rx) = mpsc::channel(16);
+        let batch_validity_reports = BatchValidityReports::default();
+        let system_config_history = state.system_config_history();
+        let deposit_index = state.deposit_index();
         let actor = Self {
             state,
             l1_head_updates: l1_head_updates_rx,
             engine_l2_safe_head: engine_l2_safe_head_rx,
             el_sync_complete_rx,
             derivation_signal_rx,
+            batch_validity_reports: batch_validity_reports.clone(),
         };
 
         (
@@ -410,6 +597,9 @@ where
                 engine_l2_safe_head_tx,
                 el_sync_complete_tx,
                 derivation_signal_tx,
+                batch_validity_reports,
+                system_config_history,
+                deposit_index,
             },
             actor,
         )
@@ -435,10 +625,15 @@ where
         DerivationContext {
             derived_attributes_tx,
             reset_request_tx,
+            managed_events_tx,
             cancellation,
         }: Self::OutboundData,
     ) -> Result<(), Self::Error> {
-        let mut state = self.state.build().await;
+        let mut state = self
+            .state
+            .build(self.batch_validity_reports.clone())
+            .await
+            .with_managed_events(managed_events_tx);
 
         loop {
             select! {
@@ -461,8 +656,14 @@ where
                         return Err(DerivationError::SignalReceiveFailed);
                     };
 
+                    let provided_block = matches!(signal, Signal::ProvideBlock(_));
                     state.signal(signal).await;
                     state.waiting_for_signal = false;
+
+                    // A block provided by the supervisor is new data for the pipeline.
+                    if provided_block {
+                        state.process(InboundDerivationMessage::NewDataAvailable, &mut self.engine_l2_safe_head, &self.el_sync_complete_rx, &derived_attributes_tx, &reset_request_tx).await?;
+                    }
                 }
                 msg = self.l1_head_updates.changed() => {
                     if let Err(err) = msg {
@@ -1,27 +1,28 @@
 //! The [`EngineActor`].
 
-use super::{EngineError, L2Finalizer};
+use super::{EngineError, L2Finalizer, ManagedModeHandler};
 use alloy_rpc_types_engine::JwtSecret;
 use async_trait::async_trait;
 use futures::future::OptionFuture;
 use kona_derive::{ResetSignal, Signal};
 use kona_engine::{
     BuildTask, ConsolidateTask, Engine, EngineClient, EngineQueries,
-    EngineState as InnerEngineState, EngineTask, EngineTaskError, EngineTaskErrorSeverity,
-    InsertTask,
+    EngineState as InnerEngineState, EngineSyncStateUpdate, EngineTask, EngineTaskError,
+    EngineTaskErrorSeverity, InsertTask,
 };
-use kona_genesis::RollupConfig;
+use kona_genesis::{RollupConfig, SystemConfig};
+use kona_interop::{ControlEvent, ManagedEvent};
 use kona_protocol::{BlockInfo, L2BlockInfo, OpAttributesWithParent};
 use op_alloy_rpc_types_engine::OpExecutionPayloadEnvelope;
 use std::sync::Arc;
 use tokio::{
-    sync::{mpsc, oneshot, watch},
+    sync::{broadcast, mpsc, oneshot, watch},
     task::JoinHandle,
 };
 use tokio_util::sync::{CancellationToken, WaitForCancellationFuture};
 use url::Url;
 
-use crate::{NodeActor, NodeMode, actors::CancellableContext};
+use crate::{InteropMode, NodeActor, NodeMode, actors::CancellableContext};
 
 /// The [`EngineActor`] is responsible for managing the operations sent to the execution layer's
 /// Engine API. To accomplish this, it uses the [`Engine`] task queue to order Engine API
@@ -47,6 +48,12 @@ pub struct EngineActor {
         Option<mpsc::Receiver<(OpAttributesWithParent, mpsc::Sender<OpExecutionPayloadEnvelope>)>>,
     /// The [`L2Finalizer`], used to finalize L2 blocks.
     finalizer: L2Finalizer,
+    /// The [`ManagedModeHandler`], used to apply the decisions of an interop supervisor.
+    ///
+    /// ## Note
+    /// This is `Some` when the node is in [`InteropMode::Indexed`] mode, in which case the
+    /// [`L2Finalizer`] is unused.
+    managed: Option<ManagedModeHandler>,
 }
 
 /// The outbound data for the [`EngineActor`].
@@ -64,10 +71,9 @@ pub struct EngineInboundData {
     /// A channel to send [`OpExecutionPayloadEnvelope`] to the engine actor.
     ///
     /// ## Note
-    /// The sequencer actor should not need to send [`OpExecutionPayloadEnvelope`]s to the engine
-    /// actor through that channel. Instead, it should use the `build_request_tx` channel to
-    /// trigger [`BuildTask`] tasks which should insert the block newly built to the engine
-    /// state upon completion.
+    /// The [`BuildTask`]s triggered through the `build_request_tx` channel do not insert the
+    /// blocks they build. The sequencer actor inserts them through this channel, once they are
+    /// committed to the conductor.
     pub unsafe_block_tx: mpsc::Sender<OpExecutionPayloadEnvelope>,
     /// A channel to send reset requests.
     pub reset_request_tx: mpsc::Sender<()>,
@@ -75,6 +81,16 @@ pub struct EngineInboundData {
     pub inbound_queries_tx: mpsc::Sender<EngineQueries>,
     /// A channel that sends new finalized L1 blocks intermittently.
     pub finalized_l1_block_tx: watch::Sender<Option<BlockInfo>>,
+    /// A channel to send [`ControlEvent`]s from the supervisor to the engine actor.
+    ///
+    /// ## Note
+    /// This is `Some` when the node is in [`InteropMode::Indexed`] mode, and `None` otherwise.
+    pub managed_control_tx: Option<mpsc::Sender<ControlEvent>>,
+    /// The channel that broadcasts the [`ManagedEvent`]s of the node to the supervisor.
+    ///
+    /// ## Note
+    /// This is `Some` when the node is in [`InteropMode::Indexed`] mode, and `None` otherwise.
+    pub managed_events_tx: Option<broadcast::Sender<ManagedEvent>>,
 }
 
 /// Configuration for the Engine Actor.
@@ -92,6 +108,10 @@ pub struct EngineBuilder {
     /// When the node is in sequencer mode, the engine actor will receive requests to build blocks
     /// from the sequencer actor.
     pub mode: NodeMode,
+    /// The interop mode of the node.
+    /// When the node is in indexed mode, the cross-verified heads of the engine are managed by an
+    /// interop supervisor.
+    pub interop_mode: InteropMode,
 }
 
 impl EngineBuilder {
@@ -99,7 +119,8 @@ impl EngineBuilder {
     /// updates.
     fn build_state(self) -> EngineActorState {
         let client = self.client();
-        let state = InnerEngineState::default();
+        let state =
+            InnerEngineState { managed: self.interop_mode.is_indexed(), ..Default::default() };
         let (engine_state_send, _) = tokio::sync::watch::channel(state);
         let (engine_queue_length_send, _) = tokio::sync::watch::channel(0);
 
@@ -174,6 +195,15 @@ impl EngineActor {
             (None, None)
         };
 
+        let (managed, managed_control_tx, managed_events_tx) = if config.interop_mode.is_indexed() {
+            let (control_tx, control_rx) = mpsc::channel(1024);
+            let (events_tx, _) = broadcast::channel(1024);
+            let handler = ManagedModeHandler::new(control_rx, events_tx.clone());
+            (Some(handler), Some(control_tx), Some(events_tx))
+        } else {
+            (None, None, None)
+        };
+
         let actor = Self {
             builder: config,
             attributes_rx,
@@ -182,6 +212,7 @@ impl EngineActor {
             inbound_queries: inbound_queries_rx,
             build_request_rx,
             finalizer: L2Finalizer::new(finalized_l1_block_rx),
+            managed,
         };
 
         let outbound_data = EngineInboundData {
@@ -191,6 +222,8 @@ impl EngineActor {
             attributes_tx,
             unsafe_block_tx,
             reset_request_tx,
+            managed_control_tx,
+            managed_events_tx,
         };
 
         (outbound_data, actor)
@@ -232,9 +265,31 @@ impl EngineActorState {
         finalizer: &mut L2Finalizer,
     ) -> Result<(), EngineError> {
         // Reset the engine.
-        let (l2_safe_head, l1_origin, system_config) =
-            self.engine.reset(self.client.clone(), self.rollup.clone()).await?;
+        let reset = self.engine.reset(self.client.clone(), self.rollup.clone()).await?;
+        self.propagate_reset(reset, derivation_signal_tx, engine_l2_safe_head_tx, finalizer).await
+    }
+
+    /// Resets the inner [`Engine`] to the given heads and propagates the reset to the derivation
+    /// actor.
+    pub(super) async fn reset_to(
+        &mut self,
+        heads: EngineSyncStateUpdate,
+        derivation_signal_tx: &mpsc::Sender<Signal>,
+        engine_l2_safe_head_tx: &watch::Sender<L2BlockInfo>,
+        finalizer: &mut L2Finalizer,
+    ) -> Result<(), EngineError> {
+        let reset = self.engine.reset_to(self.client.clone(), self.rollup.clone(), heads).await?;
+        self.propagate_reset(reset, derivation_signal_tx, engine_l2_safe_head_tx, finalizer).await
+    }
 
+    /// Propagates a reset of the inner [`Engine`] to the derivation actor.
+    async fn propagate_reset(
+        &mut self,
+        (l2_safe_head, l1_origin, system_config): (L2BlockInfo, BlockInfo, SystemConfig),
+        derivation_signal_tx: &mpsc::Sender<Signal>,
+        engine_l2_safe_head_tx: &watch::Sender<L2BlockInfo>,
+        finalizer: &mut L2Finalizer,
+    ) -> Result<(), EngineError> {
         // Signal the derivation actor to reset.
         let signal = ResetSignal { l2_safe_head, l1_origin, system_config: Some(system_config) };
         match derivation_signal_tx.send(signal.signal()).await {
@@ -340,8 +395,11 @@ impl EngineActorState {
     }
 
     /// Attempts to update the safe head via the watch channel.
+    ///
+    /// The derivation pipeline builds on the local-safe head, which only differs from the safe
+    /// head when the cross-verified heads are managed by an interop supervisor.
     fn maybe_update_safe_head(&self, engine_l2_safe_head_tx: &watch::Sender<L2BlockInfo>) {
-        let state_safe_head = self.engine.state().sync_state.safe_head();
+        let state_safe_head = self.engine.state().sync_state.local_safe_head();
         let update = |head: &mut L2BlockInfo| {
             if head != &state_safe_head {
                 *head = state_safe_head;
@@ -403,6 +461,11 @@ impl NodeActor for EngineActor {
                 });
             }
 
+            // Notify the supervisor of the head updates, if the node is in managed mode.
+            if let Some(managed) = self.managed.as_mut() {
+                managed.on_state_update(&state).await;
+            }
+
             tokio::select! {
                 biased;
 
@@ -438,7 +501,9 @@ impl NodeActor for EngineActor {
                         // The payload is not derived in this case.
                         false,
                         Some(response_tx),
-                    ));
+                    )
+                    // The sequencer imports the payload once it is committed to the conductor.
+                    .with_import(false));
                     state.engine.enqueue(task);
                 }
                 unsafe_block = self.unsafe_block_rx.recv() => {
@@ -461,7 +526,11 @@ impl NodeActor for EngineActor {
                         cancellation.cancel();
                         return Err(EngineError::ChannelClosed);
                     };
-                    self.finalizer.enqueue_for_finalization(&attributes);
+                    // In managed mode, the supervisor finalizes L2 blocks.
+                    match self.managed.as_mut() {
+                        Some(managed) => managed.enqueue_derived(&attributes),
+                        None => self.finalizer.enqueue_for_finalization(&attributes),
+                    }
 
                     let task = EngineTask::Consolidate(ConsolidateTask::new(
                         state.client.clone(),
@@ -471,6 +540,24 @@ impl NodeActor for EngineActor {
                     ));
                     state.engine.enqueue(task);
                 }
+                Some(event) = OptionFuture::from(self.managed.as_mut().map(|m| m.next_control_event())), if self.managed.is_some() => {
+                    let Some(event) = event else {
+                        error!(target: "engine", "Supervisor control event receiver closed unexpectedly while in managed mode");
+                        cancellation.cancel();
+                        return Err(EngineError::ChannelClosed);
+                    };
+                    if let Some(managed) = self.managed.as_mut() {
+                        managed
+                            .handle_control_event(
+                                event,
+                                &mut state,
+                                &derivation_signal_tx,
+                                &engine_l2_safe_head_tx,
+                                &mut self.finalizer,
+                            )
+                            .await?;
+                    }
+                }
                 msg = self.finalizer.new_finalized_block() => {
                     if let Err(err) = msg {
                         error!(target: "engine", ?err, "L1 finalized block receiver closed unexpectedly");
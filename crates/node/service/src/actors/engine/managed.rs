@@ -0,0 +1,357 @@
+//! The [`ManagedModeHandler`].
+
+use super::{EngineError, L2Finalizer, actor::EngineActorState};
+use alloy_primitives::B256;
+use alloy_provider::Provider;
+use kona_derive::Signal;
+use kona_engine::{
+    BuildTask, CrossUpdate, CrossUpdateTask, EngineSyncState, EngineSyncStateUpdate, EngineTask,
+};
+use kona_interop::{BlockReplacement, ControlEvent, DerivedRefPair, ManagedEvent};
+use kona_protocol::{BlockInfo, L2BlockInfo, OpAttributesWithParent};
+use std::collections::BTreeMap;
+use tokio::sync::{broadcast, mpsc, watch};
+
+/// The [`ManagedModeHandler`] drives the engine on behalf of an interop supervisor, when the node
+/// runs in managed mode.
+///
+/// It applies the [`ControlEvent`]s sent by the supervisor through the engine task queue, and emits
+/// [`ManagedEvent`]s as the unsafe and local-safe heads of the engine progress.
+///
+/// See: <https://specs.optimism.io/interop/managed-mode.html>
+#[derive(Debug)]
+pub struct ManagedModeHandler {
+    /// A channel to receive [`ControlEvent`]s from the supervisor.
+    control_rx: mpsc::Receiver<ControlEvent>,
+    /// A channel to send [`ManagedEvent`]s to the supervisor.
+    events_tx: broadcast::Sender<ManagedEvent>,
+    /// The derived [`OpAttributesWithParent`] of the blocks that are not cross-safe yet, by L2
+    /// block number. Used to find the L1 source of local-safe blocks, and to build deposits-only
+    /// replacements of invalidated blocks. Holds at most [`Self::MAX_DERIVED`] entries.
+    derived: BTreeMap<u64, OpAttributesWithParent>,
+    /// The number and hash of the invalidated block that is pending its replacement.
+    pending_replacement: Option<(u64, B256)>,
+    /// Whether the engine is being reset by the supervisor.
+    resetting: bool,
+    /// The last observed [`EngineSyncState`], used to detect head updates.
+    last_sync_state: EngineSyncState,
+}
+
+impl ManagedModeHandler {
+    /// The maximum number of derived blocks kept while waiting for the supervisor to promote them
+    /// to cross-safe. When the supervisor stalls, the oldest entries are dropped, and invalidating
+    /// their blocks requests a reset instead of a replacement.
+    pub const MAX_DERIVED: usize = 4096;
+
+    /// Creates a new [`ManagedModeHandler`].
+    pub fn new(
+        control_rx: mpsc::Receiver<ControlEvent>,
+        events_tx: broadcast::Sender<ManagedEvent>,
+    ) -> Self {
+        Self {
+            control_rx,
+            events_tx,
+            derived: BTreeMap::new(),
+            pending_replacement: None,
+            resetting: false,
+            last_sync_state: EngineSyncState::default(),
+        }
+    }
+
+    /// Receives the next [`ControlEvent`] from the supervisor.
+    pub async fn next_control_event(&mut self) -> Option<ControlEvent> {
+        self.control_rx.recv().await
+    }
+
+    /// Records derived [`OpAttributesWithParent`], until their block becomes cross-safe.
+    pub fn enqueue_derived(&mut self, attributes: &OpAttributesWithParent) {
+        self.derived.insert(attributes.block_number(), attributes.clone());
+        while self.derived.len() > Self::MAX_DERIVED {
+            self.derived.pop_first();
+        }
+    }
+
+    /// Drops the derived attributes of the blocks up to and including the given block number,
+    /// which can no longer be invalidated.
+    fn prune_derived(&mut self, number: u64) {
+        self.derived = self.derived.split_off(&number.saturating_add(1));
+    }
+
+    /// Sends a [`ManagedEvent`] to the subscribed supervisors.
+    fn emit(&self, event: ManagedEvent) {
+        trace!(target: "engine", %event, "Emitting managed event");
+        // Sending only fails when there are no subscribers, in which case the event is dropped.
+        let _ = self.events_tx.send(event);
+    }
+
+    /// Asks the supervisor to reset the node.
+    fn emit_reset(&self, reason: String) {
+        warn!(target: "engine", %reason, "Requesting a reset from the supervisor");
+        self.emit(ManagedEvent { reset: Some(reason), ..Default::default() });
+    }
+
+    /// Emits the [`ManagedEvent`]s for the head updates since the last observed engine state.
+    pub(super) async fn on_state_update(&mut self, state: &EngineActorState) {
+        let sync_state = state.engine.state().sync_state;
+        let last = std::mem::replace(&mut self.last_sync_state, sync_state);
+        let resetting = std::mem::take(&mut self.resetting);
+
+        if sync_state.unsafe_head() != last.unsafe_head() {
+            self.emit(ManagedEvent {
+                unsafe_block: Some(sync_state.unsafe_head().block_info),
+                ..Default::default()
+            });
+        }
+
+        let local_safe = sync_state.local_safe_head();
+        let last_local_safe = last.local_safe_head();
+        if local_safe == last_local_safe || last_local_safe == L2BlockInfo::default() {
+            return;
+        }
+
+        // The local-safe head replaces an invalidated block.
+        if let Some((number, invalidated)) = self.pending_replacement {
+            if local_safe.block_info.number == number && local_safe.block_info.hash != invalidated {
+                self.pending_replacement = None;
+                self.emit(ManagedEvent {
+                    replace_block: Some(BlockReplacement::new(local_safe.block_info, invalidated)),
+                    ..Default::default()
+                });
+                return;
+            }
+        }
+
+        // The local-safe head moved backwards without the supervisor asking for it.
+        if local_safe.block_info.number <= last_local_safe.block_info.number {
+            if !resetting {
+                self.emit_reset(format!(
+                    "Engine reset its local-safe head from {} to {}",
+                    last_local_safe.block_info.number, local_safe.block_info.number
+                ));
+            }
+            return;
+        }
+
+        // Emit a derivation update for every new local-safe block, in order.
+        for number in last_local_safe.block_info.number + 1..=local_safe.block_info.number {
+            let derived = if number == local_safe.block_info.number {
+                local_safe
+            } else {
+                match state.client.l2_block_info_by_label(number.into()).await {
+                    Ok(Some(block)) => block,
+                    Ok(None) | Err(_) => {
+                        warn!(target: "engine", number, "Failed to fetch local-safe block");
+                        return;
+                    }
+                }
+            };
+            let Some(source) = self.derived.get(&number).and_then(|attrs| attrs.derived_from)
+            else {
+                warn!(target: "engine", number, "Unknown L1 source of local-safe block");
+                continue;
+            };
+            self.emit(ManagedEvent {
+                derivation_update: Some(DerivedRefPair { source, derived: derived.block_info }),
+                ..Default::default()
+            });
+        }
+    }
+
+    /// Fetches the [`L2BlockInfo`] of the given block, checking that it is canonical.
+    async fn canonical_block(
+        state: &EngineActorState,
+        block: &BlockInfo,
+    ) -> Result<L2BlockInfo, String> {
+        match state.client.l2_block_info_by_label(block.number.into()).await {
+            Ok(Some(info)) if info.block_info.hash == block.hash => Ok(info),
+            Ok(Some(info)) => Err(format!(
+                "Block {} hash mismatch: expected {}, got {}",
+                block.number, block.hash, info.block_info.hash
+            )),
+            Ok(None) => Err(format!("Block {} not found", block.number)),
+            Err(err) => Err(format!("Failed to fetch block {}: {err}", block.number)),
+        }
+    }
+
+    /// Applies a [`ControlEvent`] sent by the supervisor.
+    pub(super) async fn handle_control_event(
+        &mut self,
+        event: ControlEvent,
+        state: &mut EngineActorState,
+        derivation_signal_tx: &mpsc::Sender<Signal>,
+        engine_l2_safe_head_tx: &watch::Sender<L2BlockInfo>,
+        finalizer: &mut L2Finalizer,
+    ) -> Result<(), EngineError> {
+        debug!(target: "engine", ?event, "Received control event from the supervisor");
+
+        let update = match event {
+            ControlEvent::UpdateCrossUnsafe(block) => CrossUpdate::CrossUnsafe(block.id()),
+            ControlEvent::UpdateCrossSafe(block) => {
+                // Cross-safe blocks can no longer be invalidated.
+                self.prune_derived(block.number);
+                CrossUpdate::CrossSafe(block.id())
+            }
+            ControlEvent::UpdateFinalized(block) => {
+                // Finalized blocks are cross-safe, even if the cross-safe update was missed.
+                self.prune_derived(block.number);
+                CrossUpdate::Finalized(block.id())
+            }
+            ControlEvent::ProviderL1(block) => {
+                derivation_signal_tx
+                    .send(Signal::ProvideBlock(block))
+                    .await
+                    .map_err(|_| EngineError::ChannelClosed)?;
+                return Ok(());
+            }
+            ControlEvent::InvalidateBlock(hash) => {
+                return self.invalidate_block(hash, state, derivation_signal_tx).await;
+            }
+            ControlEvent::Reset {
+                local_unsafe,
+                cross_unsafe,
+                local_safe,
+                cross_safe,
+                finalized,
+            } => {
+                let mut heads = EngineSyncStateUpdate::default();
+                for (head, block) in [
+                    (&mut heads.unsafe_head, local_unsafe),
+                    (&mut heads.cross_unsafe_head, cross_unsafe),
+                    (&mut heads.local_safe_head, local_safe),
+                    (&mut heads.safe_head, cross_safe),
+                    (&mut heads.finalized_head, finalized),
+                ] {
+                    let Some(block) = block else { continue };
+                    match Self::canonical_block(state, &block).await {
+                        Ok(info) => *head = Some(info),
+                        Err(reason) => {
+                            self.emit_reset(reason);
+                            return Ok(());
+                        }
+                    }
+                }
+
+                info!(target: "engine", ?heads, "Resetting engine on behalf of the supervisor");
+                self.resetting = true;
+                self.pending_replacement = None;
+                if let Some(local_safe) = heads.local_safe_head {
+                    self.derived.retain(|&number, _| number <= local_safe.block_info.number);
+                }
+                if let Some(cross_safe) = heads.safe_head.or(heads.finalized_head) {
+                    self.prune_derived(cross_safe.block_info.number);
+                }
+                state
+                    .reset_to(heads, derivation_signal_tx, engine_l2_safe_head_tx, finalizer)
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        let task = EngineTask::CrossUpdate(CrossUpdateTask::new(
+            state.client.clone(),
+            state.rollup.clone(),
+            update,
+        ));
+        state.engine.enqueue(task);
+        Ok(())
+    }
+
+    /// Replaces the invalidated block with a deposits-only block, built from the same derived
+    /// attributes, and flushes the derivation pipeline.
+    async fn invalidate_block(
+        &mut self,
+        hash: B256,
+        state: &mut EngineActorState,
+        derivation_signal_tx: &mpsc::Sender<Signal>,
+    ) -> Result<(), EngineError> {
+        let number = match state.client.l2_engine().get_block(hash.into()).await {
+            Ok(Some(block)) => block.header.number,
+            Ok(None) => {
+                warn!(target: "engine", %hash, "Invalidated block not found, ignoring");
+                return Ok(());
+            }
+            Err(err) => {
+                warn!(target: "engine", %hash, ?err, "Failed to fetch invalidated block");
+                return Ok(());
+            }
+        };
+
+        let Some(attributes) = self.derived.get(&number) else {
+            self.emit_reset(format!("Cannot replace invalidated block {number}: not derived"));
+            return Ok(());
+        };
+        let attributes = attributes.as_deposits_only();
+
+        info!(target: "engine", number, %hash, "Replacing invalidated block");
+
+        // The blocks built on top of the invalidated block are reorged out by the replacement.
+        self.derived.retain(|&n, _| n < number);
+        self.derived.insert(number, attributes.clone());
+        self.pending_replacement = Some((number, hash));
+
+        let task = EngineTask::Build(BuildTask::new(
+            state.client.clone(),
+            state.rollup.clone(),
+            attributes,
+            true,
+            None,
+        ));
+        state.engine.enqueue(task);
+
+        // Flush the channel of the invalidated block out of the derivation pipeline.
+        derivation_signal_tx
+            .send(Signal::FlushChannel)
+            .await
+            .map_err(|_| EngineError::ChannelClosed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use op_alloy_rpc_types_engine::OpPayloadAttributes;
+
+    fn attributes(number: u64) -> OpAttributesWithParent {
+        let parent = L2BlockInfo {
+            block_info: BlockInfo { number: number - 1, ..Default::default() },
+            ..Default::default()
+        };
+        OpAttributesWithParent::new(OpPayloadAttributes::default(), parent, None, true)
+    }
+
+    fn handler() -> ManagedModeHandler {
+        let (_, control_rx) = mpsc::channel(1);
+        let (events_tx, _) = broadcast::channel(1);
+        ManagedModeHandler::new(control_rx, events_tx)
+    }
+
+    #[test]
+    fn test_derived_is_capped() {
+        let mut handler = handler();
+        let max = ManagedModeHandler::MAX_DERIVED as u64;
+        for number in 1..=max + 10 {
+            handler.enqueue_derived(&attributes(number));
+        }
+
+        assert_eq!(handler.derived.len(), ManagedModeHandler::MAX_DERIVED);
+        assert_eq!(handler.derived.first_key_value().map(|(n, _)| *n), Some(11));
+        assert_eq!(handler.derived.last_key_value().map(|(n, _)| *n), Some(max + 10));
+    }
+
+    #[test]
+    fn test_prune_derived() {
+        let mut handler = handler();
+        for number in 1..=10 {
+            handler.enqueue_derived(&attributes(number));
+        }
+
+        handler.prune_derived(4);
+        assert_eq!(
+            handler.derived.keys().copied().collect::<Vec<_>>(),
+            (5..=10).collect::<Vec<_>>()
+        );
+
+        handler.prune_derived(10);
+        assert!(handler.derived.is_empty());
+    }
+}
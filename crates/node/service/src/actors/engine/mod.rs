@@ -8,3 +8,6 @@ pub use error::EngineError;
 
 mod finalizer;
 pub use finalizer::L2Finalizer;
+
+mod managed;
+pub use managed::ManagedModeHandler;
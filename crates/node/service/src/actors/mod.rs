@@ -8,6 +8,7 @@ pub use traits::{CancellableContext, NodeActor};
 mod engine;
 pub use engine::{
     EngineActor, EngineBuilder, EngineContext, EngineError, EngineInboundData, L2Finalizer,
+    ManagedModeHandler,
 };
 
 mod rpc;
@@ -19,6 +20,9 @@ pub use derivation::{
     DerivationInboundChannels, DerivationState, InboundDerivationMessage, PipelineBuilder,
 };
 
+mod deposits;
+pub use deposits::DepositIndexer;
+
 mod l1_watcher_rpc;
 pub use l1_watcher_rpc::{
     L1WatcherRpc, L1WatcherRpcContext, L1WatcherRpcError, L1WatcherRpcInboundChannels,
@@ -33,7 +37,9 @@ pub use network::{
 
 mod sequencer;
 pub use sequencer::{
-    AttributesBuilderConfig, ConductorClient, ConductorError, DelayedL1OriginSelectorProvider,
-    L1OriginSelector, L1OriginSelectorError, L1OriginSelectorProvider, SequencerActor,
-    SequencerActorError, SequencerBuilder, SequencerConfig, SequencerContext, SequencerInboundData,
+    AttributesBuilderConfig, ConductorClient, ConductorError, ConservativePolicy,
+    DelayedL1OriginSelectorProvider, FastestAllowedPolicy, FixedLagPolicy, L1OriginPolicy,
+    L1OriginPolicyKind, L1OriginSelector, L1OriginSelectorError, L1OriginSelectorProvider,
+    SequencerActor, SequencerActorError, SequencerBuilder, SequencerConfig, SequencerContext,
+    SequencerInboundData,
 };
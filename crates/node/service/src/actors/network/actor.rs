@@ -164,6 +164,8 @@ impl NodeActor for NetworkActor {
                         target: "network",
                         "Received shutdown signal. Exiting network task."
                     );
+                    handler.gossip.sync_peer_history();
+                    handler.gossip.flush_peer_history().await;
                     return Ok(());
                 }
                 block = unsafe_block_rx.recv() => {
@@ -243,6 +245,15 @@ impl NodeActor for NetworkActor {
                 _ = handler.peer_score_inspector.tick(), if handler.gossip.peer_monitoring.as_ref().is_some() => {
                     handler.handle_peer_monitoring().await;
                 },
+                _ = handler.peerstore_sync.tick(), if handler.gossip.peer_history.is_some() => {
+                    handler.gossip.sync_peer_history();
+                },
+                _ = handler.static_peer_dialer.tick(), if !handler.gossip.static_peers.is_empty() => {
+                    handler.gossip.dial_static_peers();
+                },
+                _ = handler.mesh_sampler.tick(), if handler.gossip.peer_scores.is_enabled() => {
+                    handler.gossip.refresh_peer_scores();
+                },
                 Some(NetworkAdminQuery::PostUnsafePayload { payload }) = self.admin_rpc.recv(), if !self.admin_rpc.is_closed() => {
                     debug!(target: "node::p2p", "Broadcasting unsafe payload from admin api");
                     if unsafe_block_tx.send(payload).is_err() {
@@ -4,9 +4,9 @@ use alloy_primitives::Address;
 use discv5::{Config as Discv5Config, Enr};
 use kona_genesis::RollupConfig;
 use kona_p2p::{Discv5Builder, GaterConfig, GossipDriverBuilder, LocalNode};
-use kona_peers::{PeerMonitoring, PeerScoreLevel};
+use kona_peers::{PeerMonitoring, PeerScoreLevel, PeerStore};
 use kona_sources::BlockSigner;
-use libp2p::{Multiaddr, identity::Keypair};
+use libp2p::{Multiaddr, PeerId, identity::Keypair};
 use std::{path::PathBuf, time::Duration};
 
 use crate::{
@@ -27,6 +27,8 @@ pub struct NetworkBuilder {
 
 impl From<NetworkConfig> for NetworkBuilder {
     fn from(config: NetworkConfig) -> Self {
+        let peerstore =
+            PeerStore::path(config.rollup_config.l2_chain_id.id(), config.bootstore.clone());
         Self::new(
             config.rollup_config,
             config.unsafe_block_signer,
@@ -37,11 +39,15 @@ impl From<NetworkConfig> for NetworkBuilder {
         )
         .with_discovery_randomize(config.discovery_randomize)
         .with_bootstore(config.bootstore)
+        .with_peerstore(Some(peerstore))
         .with_bootnodes(config.bootnodes)
+        .with_static_peers(config.static_peers)
+        .with_trusted_peers(config.trusted_peers)
         .with_discovery_interval(config.discovery_interval)
         .with_gossip_config(config.gossip_config)
         .with_peer_scoring(config.scoring)
         .with_peer_monitoring(config.monitor_peers)
+        .with_scoring_config(config.scoring_config)
         .with_topic_scoring(config.topic_scoring)
         .with_gater_config(config.gater_config)
         .with_signer(config.gossip_signer)
@@ -92,6 +98,21 @@ impl NetworkBuilder {
         self
     }
 
+    /// Sets the [`PeerStore`] path for the [`GossipDriverBuilder`].
+    pub fn with_peerstore(self, peerstore: Option<PathBuf>) -> Self {
+        Self { gossip: self.gossip.with_peerstore(peerstore), ..self }
+    }
+
+    /// Sets the static peers for the [`GossipDriverBuilder`].
+    pub fn with_static_peers(self, static_peers: Vec<Multiaddr>) -> Self {
+        Self { gossip: self.gossip.with_static_peers(static_peers), ..self }
+    }
+
+    /// Sets the trusted peers for the [`GossipDriverBuilder`].
+    pub fn with_trusted_peers(self, trusted_peers: Vec<PeerId>) -> Self {
+        Self { gossip: self.gossip.with_trusted_peers(trusted_peers), ..self }
+    }
+
     /// Sets the interval at which to randomize discovery peers.
     pub fn with_discovery_randomize(self, randomize: Option<Duration>) -> Self {
         Self { discovery: self.discovery.with_discovery_randomize(randomize), ..self }
@@ -117,6 +138,11 @@ impl NetworkBuilder {
         Self { gossip: self.gossip.with_peer_monitoring(peer_monitoring), ..self }
     }
 
+    /// Sets the peer scoring config file for the [`GossipDriverBuilder`].
+    pub fn with_scoring_config(self, scoring_config: Option<PathBuf>) -> Self {
+        Self { gossip: self.gossip.with_scoring_config(scoring_config), ..self }
+    }
+
     /// Sets the discovery interval for the [`Discv5Builder`].
     pub fn with_discovery_interval(self, interval: tokio::time::Duration) -> Self {
         Self { discovery: self.discovery.with_interval(interval), ..self }
@@ -6,7 +6,7 @@ use kona_genesis::RollupConfig;
 use kona_p2p::{GaterConfig, LocalNode};
 use kona_peers::{PeerMonitoring, PeerScoreLevel};
 use kona_sources::BlockSigner;
-use libp2p::{Multiaddr, identity::Keypair};
+use libp2p::{Multiaddr, PeerId, identity::Keypair};
 use std::path::PathBuf;
 use tokio::time::Duration;
 
@@ -36,12 +36,18 @@ pub struct NetworkConfig {
     pub topic_scoring: bool,
     /// Peer score monitoring config.
     pub monitor_peers: Option<PeerMonitoring>,
+    /// An optional path to the peer scoring config file.
+    pub scoring_config: Option<PathBuf>,
     /// An optional path to the bootstore.
     pub bootstore: Option<PathBuf>,
     /// The configuration for the connection gater.
     pub gater_config: GaterConfig,
     /// An optional list of bootnode ENRs to start the node with.
     pub bootnodes: Vec<Enr>,
+    /// The static peers, which are trusted and always dialed.
+    pub static_peers: Vec<Multiaddr>,
+    /// The trusted peers, which are exempt from dial limits, bans and address blocks.
+    pub trusted_peers: Vec<PeerId>,
     /// The [`RollupConfig`].
     pub rollup_config: RollupConfig,
     /// A signer for gossip payloads.
@@ -70,12 +76,15 @@ impl NetworkConfig {
             unsafe_block_signer,
             keypair: Keypair::generate_secp256k1(),
             bootnodes: Default::default(),
+            static_peers: Default::default(),
+            trusted_peers: Default::default(),
             bootstore: Default::default(),
             gater_config: Default::default(),
             gossip_config: Default::default(),
             scoring: Default::default(),
             topic_scoring: Default::default(),
             monitor_peers: Default::default(),
+            scoring_config: Default::default(),
             gossip_signer: Default::default(),
         }
     }
@@ -1,12 +1,21 @@
 use alloy_primitives::Address;
 use futures::future::OptionFuture;
-use kona_p2p::{ConnectionGater, Discv5Driver, GossipDriver, PEER_SCORE_INSPECT_FREQUENCY};
+use kona_p2p::{
+    ConnectionGater, Discv5Driver, GOSSIP_HEARTBEAT, GossipDriver, PEER_SCORE_INSPECT_FREQUENCY,
+};
 use kona_sources::{BlockSigner, BlockSignerStartError};
 use libp2p::TransportError;
+use std::time::Duration;
 use tokio::sync::watch;
 
 use crate::actors::network::handler::NetworkHandler;
 
+/// The interval at which the peer history is written to disk.
+const PEERSTORE_SYNC_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The interval at which static peers due for a dial are dialed.
+const STATIC_PEER_DIAL_INTERVAL: Duration = Duration::from_secs(1);
+
 /// A network driver. This is the driver that is used to start the network.
 #[derive(Debug)]
 pub struct NetworkDriver {
@@ -40,9 +49,21 @@ impl NetworkDriver {
         // Start the libp2p Swarm
         self.gossip.start().await?;
 
+        // Dial the peers with the best history first.
+        self.gossip.dial_preferred_peers();
+
         // We are checking the peer scores every [`PEER_SCORE_INSPECT_FREQUENCY`] seconds.
         let peer_score_inspector = tokio::time::interval(*PEER_SCORE_INSPECT_FREQUENCY);
 
+        // The peer history is written to disk every [`PEERSTORE_SYNC_INTERVAL`].
+        let peerstore_sync = tokio::time::interval(PEERSTORE_SYNC_INTERVAL);
+
+        // Static peers due for a dial are checked every [`STATIC_PEER_DIAL_INTERVAL`].
+        let static_peer_dialer = tokio::time::interval(STATIC_PEER_DIAL_INTERVAL);
+
+        // The gossipsub meshes are sampled into the peer score tracker every heartbeat.
+        let mesh_sampler = tokio::time::interval(*GOSSIP_HEARTBEAT);
+
         // Start the block signer if it is configured.
         let signer =
             OptionFuture::from(self.signer.map(async |s| s.start().await)).await.transpose()?;
@@ -53,6 +74,9 @@ impl NetworkDriver {
             enr_receiver,
             unsafe_block_signer_sender: self.unsafe_block_signer_sender,
             peer_score_inspector,
+            peerstore_sync,
+            static_peer_dialer,
+            mesh_sampler,
             signer,
         })
     }
@@ -2,7 +2,8 @@ use std::collections::HashSet;
 
 use alloy_primitives::Address;
 use discv5::Enr;
-use kona_p2p::{ConnectionGater, Discv5Handler, GossipDriver, HandlerRequest};
+use kona_p2p::{ConnectionGate, ConnectionGater, Discv5Handler, GossipDriver, HandlerRequest};
+use kona_peers::PeerStore;
 use kona_sources::BlockSignerHandler;
 use tokio::sync::{mpsc, watch};
 
@@ -19,6 +20,12 @@ pub struct NetworkHandler {
     pub unsafe_block_signer_sender: watch::Sender<Address>,
     /// The peer score inspector. Is used to ban peers that are below a given threshold.
     pub peer_score_inspector: tokio::time::Interval,
+    /// The interval at which the peer history is written to disk.
+    pub peerstore_sync: tokio::time::Interval,
+    /// The interval at which static peers due for a dial are dialed.
+    pub static_peer_dialer: tokio::time::Interval,
+    /// The interval at which the gossipsub meshes are sampled into the peer score tracker.
+    pub mesh_sampler: tokio::time::Interval,
     /// A handler for the block signer.
     pub signer: Option<BlockSignerHandler>,
 }
@@ -30,6 +37,9 @@ impl NetworkHandler {
             return;
         };
 
+        // Trusted peers are never banned.
+        let trusted_peers = self.gossip.connection_gate.list_trusted_peers();
+
         // We iterate over all connected peers and check their scores.
         // We collect a list of peers to remove
         let peers_to_remove = self
@@ -50,7 +60,7 @@ impl NetworkHandler {
                     score
                 );
 
-                if score < ban_peers.ban_threshold {
+                if score < ban_peers.ban_threshold && !trusted_peers.contains(peer_id) {
                     return Some(*peer_id);
                 }
 
@@ -77,8 +87,15 @@ impl NetworkHandler {
                             );
                         }
 
+                        // Ban the peer from the gossip layer, and persist the ban so that it
+                        // survives restarts.
+                        self.gossip.connection_gate.ban_peer(&peer_to_remove, ban_peers.ban_duration);
+                        if let Some(history) = &mut self.gossip.peer_history {
+                            let until = PeerStore::timestamp() + ban_peers.ban_duration.as_secs();
+                            history.ban(peer_to_remove, until);
+                        }
+
                         if let Some(info) = self.gossip.peerstore.remove(&peer_to_remove){
-                            use kona_p2p::ConnectionGate;
                             self.gossip.connection_gate.remove_dial(&peer_to_remove);
                             let score = self.gossip.swarm.behaviour().gossipsub.peer_score(&peer_to_remove).unwrap_or_default();
                             kona_macros::inc!(gauge, kona_p2p::Metrics::BANNED_PEERS, "peer_id" => peer_to_remove.to_string(), "score" => score.to_string());
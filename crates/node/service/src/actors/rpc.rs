@@ -2,11 +2,14 @@
 
 use crate::{NodeActor, actors::CancellableContext};
 use async_trait::async_trait;
+use kona_interop::{ControlEvent, ManagedEvent};
 use kona_p2p::P2pRpcRequest;
 use kona_rpc::{
-    AdminApiServer, AdminRpc, DevEngineApiServer, DevEngineRpc, HealthzResponse, NetworkAdminQuery,
-    OpP2PApiServer, RollupNodeApiServer, SequencerAdminQuery, WsRPC, WsServer,
+    AdminApiServer, AdminRpc, DevEngineApiServer, DevEngineRpc, HealthzResponse, JwtAuthLayer,
+    ManagedModeRpc, ManagedRpcConfig, NetworkAdminQuery, OpP2PApiServer, RollupNodeApiServer,
+    SequencerAdminQuery, WsRPC, WsServer,
 };
+use kona_supervisor_rpc::jsonrpsee::ManagedModeApiServer;
 use std::time::Duration;
 
 use jsonrpsee::{
@@ -15,8 +18,11 @@ use jsonrpsee::{
     server::{Server, ServerHandle, middleware::http::ProxyGetRequestLayer},
 };
 use kona_engine::EngineQueries;
-use kona_rpc::{L1WatcherQueries, P2pRpc, RollupRpc, RpcBuilder};
-use tokio::sync::mpsc;
+use kona_rpc::{
+    BatchValidityReports, DepositIndex, L1WatcherQueries, P2pRpc, RollupRpc, RpcBuilder,
+    SystemConfigHistory,
+};
+use tokio::sync::{broadcast, mpsc};
 use tokio_util::sync::{CancellationToken, WaitForCancellationFuture};
 
 /// An error returned by the [`RpcActor`].
@@ -34,6 +40,9 @@ pub enum RpcActorError {
     /// Failed to stop the RPC server.
     #[error("Failed to stop the RPC server")]
     StopFailed,
+    /// The managed-mode RPC is configured, but the node does not run in managed mode.
+    #[error("The managed-mode RPC is configured, but the node does not run in managed mode")]
+    NotManaged,
 }
 
 /// An actor that handles the RPC server for the rollup node.
@@ -63,6 +72,18 @@ pub struct RpcContext {
     pub l1_watcher_queries: mpsc::Sender<L1WatcherQueries>,
     /// The engine query sender.
     pub engine_query: mpsc::Sender<EngineQueries>,
+    /// The validity reports of the batches checked by the derivation pipeline.
+    pub batch_validity_reports: BatchValidityReports,
+    /// The history of the system config updates applied by the derivation pipeline.
+    pub system_config_history: SystemConfigHistory,
+    /// The index of the deposits derived by the derivation pipeline, if enabled.
+    pub deposit_index: Option<DepositIndex>,
+    /// The sender of the supervisor's [`ControlEvent`]s, when the node runs in managed mode.
+    pub managed_control: Option<mpsc::Sender<ControlEvent>>,
+    /// The channel of the [`ManagedEvent`]s, when the node runs in managed mode.
+    pub managed_events: Option<broadcast::Sender<ManagedEvent>>,
+    /// The sender of full engine reset requests.
+    pub reset_request: mpsc::Sender<()>,
     /// The cancellation token, shared between all tasks.
     pub cancellation: CancellationToken,
 }
@@ -101,6 +122,28 @@ async fn launch(
     Ok(server.start(module))
 }
 
+/// Launches the jsonrpsee [`Server`] of the managed-mode interop RPC, authenticating its callers
+/// with the configured JWT secret.
+///
+/// ## Errors
+///
+/// - [`std::io::Error`] if the server fails to start.
+async fn launch_managed(
+    config: &ManagedRpcConfig,
+    module: RpcModule<()>,
+) -> Result<ServerHandle, std::io::Error> {
+    let middleware = tower::ServiceBuilder::new().layer(JwtAuthLayer::new(config.jwt_secret));
+    let server = Server::builder().set_http_middleware(middleware).build(config.socket).await?;
+
+    if let Ok(addr) = server.local_addr() {
+        info!(target: "rpc", addr = ?addr, "Managed-mode RPC server bound to address");
+    } else {
+        error!(target: "rpc", "Failed to get local address for managed-mode RPC server");
+    }
+
+    Ok(server.start(module))
+}
+
 #[async_trait]
 impl NodeActor for RpcActor {
     type Error = RpcActorError;
@@ -121,6 +164,12 @@ impl NodeActor for RpcActor {
             engine_query,
             network_admin,
             sequencer_admin,
+            batch_validity_reports,
+            system_config_history,
+            deposit_index,
+            managed_control,
+            managed_events,
+            reset_request,
         }: Self::OutboundData,
     ) -> Result<(), Self::Error> {
         let mut modules = RpcModule::new(());
@@ -140,19 +189,40 @@ impl NodeActor for RpcActor {
         )?;
 
         // Create context for communication between actors.
-        let rollup_rpc = RollupRpc::new(engine_query.clone(), l1_watcher_queries);
+        let rollup_rpc = RollupRpc::new(
+            engine_query.clone(),
+            l1_watcher_queries,
+            system_config_history,
+            deposit_index,
+        );
         modules.merge(rollup_rpc.into_rpc())?;
 
         // Add development RPC module for engine state introspection if enabled
         if self.config.dev_enabled() {
-            let dev_rpc = DevEngineRpc::new(engine_query.clone());
+            let dev_rpc = DevEngineRpc::new(engine_query.clone(), batch_validity_reports);
             modules.merge(dev_rpc.into_rpc())?;
         }
 
         if self.config.ws_enabled() {
-            modules.merge(WsRPC::new(engine_query).into_rpc())?;
+            modules.merge(WsRPC::new(engine_query.clone()).into_rpc())?;
         }
 
+        // Serve the managed-mode interop RPC on its own authenticated server. Its handle is kept
+        // alive until the actor exits.
+        let _managed_handle = match self.config.managed() {
+            Some(managed_config) => {
+                let (Some(control), Some(events)) = (managed_control, managed_events) else {
+                    return Err(RpcActorError::NotManaged);
+                };
+                let mut managed_modules = RpcModule::new(());
+                managed_modules.merge(
+                    ManagedModeRpc::new(control, events, reset_request, engine_query).into_rpc(),
+                )?;
+                Some(launch_managed(managed_config, managed_modules).await?)
+            }
+            None => None,
+        };
+
         let restarts = self.config.restart_count();
 
         let mut handle = launch(&self.config, modules.clone()).await?;
@@ -199,6 +269,7 @@ mod tests {
             admin_persistence: None,
             ws_enabled: false,
             dev_enabled: false,
+            managed: None,
         };
         let result = launch(&launcher, RpcModule::new(())).await;
         assert!(result.is_ok());
@@ -213,6 +284,7 @@ mod tests {
             admin_persistence: None,
             ws_enabled: false,
             dev_enabled: false,
+            managed: None,
         };
         let mut modules = RpcModule::new(());
 
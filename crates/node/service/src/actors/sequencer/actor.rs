@@ -1,16 +1,20 @@
 //! The [`SequencerActor`].
 
 use super::{
-    DelayedL1OriginSelectorProvider, L1OriginSelector, L1OriginSelectorError, SequencerConfig,
+    DelayedL1OriginSelectorProvider, L1OriginSelector, L1OriginSelectorError,
+    L1OriginSelectorProvider, SequencerConfig,
 };
 use crate::{CancellableContext, NodeActor, actors::sequencer::conductor::ConductorClient};
+use alloy_primitives::B256;
 use alloy_provider::RootProvider;
 use async_trait::async_trait;
 use kona_derive::{AttributesBuilder, PipelineErrorKind, StatefulAttributesBuilder};
 use kona_genesis::RollupConfig;
 use kona_protocol::{BlockInfo, L2BlockInfo, OpAttributesWithParent};
 use kona_providers_alloy::{AlloyChainProvider, AlloyL2ChainProvider};
-use kona_rpc::SequencerAdminQuery;
+use kona_rpc::{
+    AdminStatePersistence, SequencerAdminQuery, SequencerAdminState, SequencerBuildMode,
+};
 use op_alloy_network::Optimism;
 use op_alloy_rpc_types_engine::OpExecutionPayloadEnvelope;
 use std::{
@@ -20,6 +24,7 @@ use std::{
 use tokio::{
     select,
     sync::{mpsc, watch},
+    time::MissedTickBehavior,
 };
 use tokio_util::sync::{CancellationToken, WaitForCancellationFuture};
 
@@ -38,15 +43,20 @@ pub struct SequencerActor<AB: AttributesBuilderConfig> {
 
 /// The state of the [`SequencerActor`].
 #[derive(Debug)]
-pub(super) struct SequencerActorState<AB: AttributesBuilder> {
+pub(super) struct SequencerActorState<
+    AB: AttributesBuilder,
+    P: L1OriginSelectorProvider = DelayedL1OriginSelectorProvider,
+> {
     /// The [`RollupConfig`] for the chain being sequenced.
     pub cfg: Arc<RollupConfig>,
     /// The [`AttributesBuilder`].
     pub builder: AB,
     /// The [`L1OriginSelector`].
-    pub origin_selector: L1OriginSelector<DelayedL1OriginSelectorProvider>,
+    pub origin_selector: L1OriginSelector<P>,
     /// The ticker for building new blocks.
     pub build_ticker: tokio::time::Interval,
+    /// The ticker for checking that the node is still the leader of the conductor, if enabled.
+    pub leader_ticker: tokio::time::Interval,
     /// The conductor RPC client.
     pub conductor: Option<ConductorClient>,
     /// Whether the sequencer is active. This is used inside communications between the sequencer
@@ -60,6 +70,24 @@ pub(super) struct SequencerActorState<AB: AttributesBuilder> {
     /// ## Default value
     /// At startup, the sequencer is _NOT_ in recovery mode.
     pub is_recovery_mode: bool,
+    /// The hash of the unsafe head when the sequencer was last stopped, if it was.
+    pub last_stop_hash: Option<B256>,
+    /// Persists the state set through the admin API, if enabled.
+    pub admin_persistence: Option<AdminStatePersistence>,
+    /// The mode the sequencer builds blocks in.
+    ///
+    /// ## Default value
+    /// At startup, the sequencer builds blocks in the [`SequencerBuildMode::Normal`] mode, unless
+    /// another mode was persisted through the admin API.
+    pub build_mode: SequencerBuildMode,
+    /// The hash of the last payload committed to the conductor, if the conductor is enabled.
+    pub last_committed_hash: Option<B256>,
+    /// The maximum number of L2 blocks the unsafe head may be ahead of the safe head before the
+    /// sequencer pauses. Disabled if 0.
+    pub max_safe_lag: u64,
+    /// The number of L2 blocks the unsafe head may be ahead of the safe head before the sequencer
+    /// is throttled. Disabled if 0.
+    pub throttle_safe_lag: u64,
 }
 
 /// A trait for building [`AttributesBuilder`]s.
@@ -72,15 +100,23 @@ pub trait AttributesBuilderConfig {
 }
 
 impl SequencerActorState<StatefulAttributesBuilder<AlloyChainProvider, AlloyL2ChainProvider>> {
+    /// Creates the [`SequencerActorState`], restoring the state persisted through the admin API.
+    ///
+    /// Fails if the persisted admin state cannot be read, rather than falling back to the startup
+    /// flags, which could restart a sequencer that was stopped through the admin API.
     fn new(
         seq_builder: SequencerBuilder,
         l1_head_watcher: watch::Receiver<Option<BlockInfo>>,
-    ) -> Self {
+    ) -> Result<Self, SequencerActorError> {
         let SequencerConfig {
             sequencer_stopped,
             sequencer_recovery_mode,
             conductor_rpc_url,
             l1_conf_delay,
+            admin_persistence,
+            origin_policy,
+            max_safe_lag,
+            throttle_safe_lag,
         } = seq_builder.seq_cfg.clone();
 
         let cfg = seq_builder.rollup_cfg.clone();
@@ -93,23 +129,57 @@ impl SequencerActorState<StatefulAttributesBuilder<AlloyChainProvider, AlloyL2Ch
 
         let builder = seq_builder.build();
         let build_ticker = tokio::time::interval(Duration::from_secs(cfg.block_time));
+        let mut leader_ticker = tokio::time::interval(LEADER_CHECK_INTERVAL);
+        leader_ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let origin_selector =
+            L1OriginSelector::new(cfg.clone(), l1_provider).with_policy(origin_policy);
 
-        let origin_selector = L1OriginSelector::new(cfg.clone(), l1_provider);
+        // The state persisted through the admin API takes precedence over the startup flags.
+        let admin_persistence = admin_persistence.map(AdminStatePersistence::new);
+        let persisted = admin_persistence
+            .as_ref()
+            .map(AdminStatePersistence::read)
+            .transpose()
+            .map_err(SequencerActorError::AdminState)?
+            .flatten();
+        let admin_state = persisted.unwrap_or(SequencerAdminState {
+            sequencer_active: !sequencer_stopped,
+            last_stop_hash: None,
+            recover_mode: sequencer_recovery_mode,
+            build_mode: SequencerBuildMode::Normal,
+        });
+        if persisted.is_some() {
+            info!(target: "sequencer", ?admin_state, "Loaded persisted admin state");
+        }
 
-        Self {
+        Ok(Self {
             cfg,
             builder,
             origin_selector,
             build_ticker,
+            leader_ticker,
             conductor,
-            is_active: !sequencer_stopped,
-            is_recovery_mode: sequencer_recovery_mode,
-        }
+            is_active: admin_state.sequencer_active,
+            is_recovery_mode: admin_state.recover_mode,
+            last_stop_hash: admin_state.last_stop_hash,
+            admin_persistence,
+            build_mode: admin_state.build_mode,
+            last_committed_hash: None,
+            max_safe_lag,
+            throttle_safe_lag,
+        })
     }
 }
 
 const DERIVATION_PROVIDER_CACHE_SIZE: usize = 1024;
 
+/// The interval at which the sequencer checks that it is still the leader of the conductor.
+const LEADER_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The maximum time the sequencer waits for a built payload to become the unsafe head.
+const PAYLOAD_INSERTION_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// The builder for the [`SequencerActor`].
 #[derive(Debug)]
 pub struct SequencerBuilder {
@@ -159,6 +229,8 @@ pub struct SequencerContext {
     pub cancellation: CancellationToken,
     /// Watch channel to observe the L1 head of the chain.
     pub l1_head_rx: watch::Receiver<Option<BlockInfo>>,
+    /// Watch channel to observe the safe head of the engine.
+    pub safe_head_rx: watch::Receiver<L2BlockInfo>,
     /// Sender to request the engine to reset.
     pub reset_request_tx: mpsc::Sender<()>,
     /// Sender to request the execution layer to build a payload attributes on top of the
@@ -168,6 +240,9 @@ pub struct SequencerContext {
     /// A sender to asynchronously sign and gossip built [`OpExecutionPayloadEnvelope`]s to the
     /// network actor.
     pub gossip_payload_tx: mpsc::Sender<OpExecutionPayloadEnvelope>,
+    /// A sender to insert built [`OpExecutionPayloadEnvelope`]s into the engine, once they are
+    /// committed to the conductor.
+    pub unsafe_block_tx: mpsc::Sender<OpExecutionPayloadEnvelope>,
 }
 
 impl CancellableContext for SequencerContext {
@@ -188,6 +263,9 @@ pub enum SequencerActorError {
     /// A channel was unexpectedly closed.
     #[error("Channel closed unexpectedly")]
     ChannelClosed,
+    /// The state persisted through the admin API could not be read.
+    #[error("Failed to read the persisted admin state: {0}")]
+    AdminState(std::io::Error),
 }
 
 impl<AB: AttributesBuilderConfig> SequencerActor<AB> {
@@ -201,7 +279,7 @@ impl<AB: AttributesBuilderConfig> SequencerActor<AB> {
     }
 }
 
-impl<AB: AttributesBuilder> SequencerActorState<AB> {
+impl<AB: AttributesBuilder, P: L1OriginSelectorProvider> SequencerActorState<AB, P> {
     /// Starts the build job for the next L2 block, on top of the current unsafe head.
     async fn build_block(
         &mut self,
@@ -210,6 +288,22 @@ impl<AB: AttributesBuilder> SequencerActorState<AB> {
         in_recovery_mode: bool,
     ) -> Result<(), SequencerActorError> {
         let unsafe_head = *unsafe_head_rx.borrow();
+
+        // Pause sequencing while the unsafe head is too far ahead of the safe head, and throttle
+        // it while approaching that distance.
+        let safe_head = *ctx.safe_head_rx.borrow();
+        let safe_lag = unsafe_head.block_info.number.saturating_sub(safe_head.block_info.number);
+        if self.max_safe_lag != 0 && safe_lag >= self.max_safe_lag {
+            warn!(
+                target: "sequencer",
+                safe_lag,
+                max_safe_lag = self.max_safe_lag,
+                "Unsafe head is too far ahead of the safe head, pausing sequencing"
+            );
+            return Ok(());
+        }
+        let is_throttled = self.throttle_safe_lag != 0 && safe_lag >= self.throttle_safe_lag;
+
         let l1_origin = match self
             .origin_selector
             .next_l1_origin(unsafe_head, self.is_recovery_mode)
@@ -290,6 +384,20 @@ impl<AB: AttributesBuilder> SequencerActorState<AB> {
             l1_origin.timestamp + self.cfg.max_sequencer_drift(l1_origin.timestamp))
         .then_some(true);
 
+        // Leave out the transaction pool if requested through the admin API.
+        if self.build_mode.is_no_tx_pool() {
+            debug!(target: "sequencer", mode = %self.build_mode, "Sequencing without tx pool");
+            attributes.no_tx_pool = Some(true);
+        }
+
+        // Build empty blocks while throttled, to let the batcher catch up with the unsafe head
+        // before the sequencer drift forces deposits-only blocks. The block cadence is slowed
+        // down below.
+        if is_throttled {
+            debug!(target: "sequencer", safe_lag, "Sequencing throttled, building empty block");
+            attributes.no_tx_pool = Some(true);
+        }
+
         // Do not include transactions in the first Ecotone block.
         if self.cfg.is_first_ecotone_block(attributes.payload_attributes.timestamp) {
             info!(target: "sequencer", "Sequencing ecotone upgrade block");
@@ -355,24 +463,55 @@ impl<AB: AttributesBuilder> SequencerActorState<AB> {
             _build_request_start.elapsed()
         );
 
-        // If the conductor is available, commit the payload to it.
-        if let Some(conductor) = &self.conductor {
+        // If the conductor is available, commit the payload to it before inserting it, so that the
+        // unsafe head never holds a block the conductor did not replicate.
+        if let Some(conductor) = self.conductor.clone() {
             let _conductor_commitment_start = Instant::now();
-            if let Err(err) = conductor.commit_unsafe_payload(&payload).await {
-                error!(target: "sequencer", ?err, "Failed to commit unsafe payload to conductor");
-            }
+            let committed = conductor.commit_unsafe_payload(&payload).await;
 
             kona_macros::set!(
                 gauge,
                 crate::Metrics::SEQUENCER_CONDUCTOR_COMMITMENT_DURATION,
                 _conductor_commitment_start.elapsed()
             );
+
+            // Drop payloads that were not replicated by the conductor.
+            if let Err(err) = committed {
+                error!(target: "sequencer", ?err, "Failed to commit unsafe payload to conductor");
+
+                // If the leadership was lost, stop building until the conductor hands it back.
+                self.check_leadership().await;
+                return Ok(());
+            }
+            self.last_committed_hash = Some(payload.execution_payload.block_hash());
+        }
+
+        if !self.insert_payload(ctx, unsafe_head_rx, payload.clone()).await? {
+            return Ok(());
+        }
+
+        // Count the block against the blocks left to build without the transaction pool.
+        let build_mode = self.build_mode.advance();
+        if build_mode != self.build_mode {
+            info!(target: "sequencer", mode = %build_mode, "Updated build mode");
+            let state = SequencerAdminState { build_mode, ..self.admin_state() };
+            if let Err(err) = self.set_admin_state(state) {
+                error!(target: "sequencer", ?err, "Failed to persist admin state");
+                self.build_mode = build_mode;
+            }
+
+            // Update metrics, if configured.
+            #[cfg(feature = "metrics")]
+            self.update_metrics();
         }
 
         let now =
             SystemTime::now().duration_since(UNIX_EPOCH).expect("Time went backwards").as_secs();
         let then = payload.execution_payload.timestamp() + self.cfg.block_time;
-        if then.saturating_sub(now) <= self.cfg.block_time {
+        // While throttled, build blocks at half the cadence and do not catch up on missed slots.
+        if is_throttled {
+            self.build_ticker.reset_after(Duration::from_secs(self.cfg.block_time * 2));
+        } else if then.saturating_sub(now) <= self.cfg.block_time {
             warn!(
                 target: "sequencer",
                 "Next block timestamp is more than a block time away from now, building immediately"
@@ -383,6 +522,89 @@ impl<AB: AttributesBuilder> SequencerActorState<AB> {
         self.schedule_gossip(ctx, payload).await
     }
 
+    /// Inserts a built payload into the engine, and waits for it to become the unsafe head.
+    ///
+    /// Returns whether the payload became the unsafe head, as the engine may move the unsafe head
+    /// to another block instead, e.g. if the engine is reset, or reject the payload.
+    async fn insert_payload(
+        &mut self,
+        ctx: &mut SequencerContext,
+        unsafe_head_rx: &mut watch::Receiver<L2BlockInfo>,
+        payload: OpExecutionPayloadEnvelope,
+    ) -> Result<bool, SequencerActorError> {
+        let hash = payload.execution_payload.block_hash();
+
+        // Only the unsafe head updates following the insertion are relevant.
+        unsafe_head_rx.mark_unchanged();
+        if let Err(err) = ctx.unsafe_block_tx.send(payload).await {
+            error!(target: "sequencer", ?err, "Failed to send built payload to engine");
+            ctx.cancellation.cancel();
+            return Err(SequencerActorError::ChannelClosed);
+        }
+
+        select! {
+            biased;
+            _ = ctx.cancellation.cancelled() => return Ok(false),
+            changed = tokio::time::timeout(PAYLOAD_INSERTION_TIMEOUT, unsafe_head_rx.changed()) => {
+                match changed {
+                    Ok(Ok(())) => {}
+                    Ok(Err(_)) => {
+                        error!(target: "sequencer", "Failed to receive unsafe head update after insertion");
+                        ctx.cancellation.cancel();
+                        return Err(SequencerActorError::ChannelClosed);
+                    }
+                    Err(_) => {
+                        warn!(target: "sequencer", %hash, "Timed out waiting for built payload insertion");
+                        return Ok(false);
+                    }
+                }
+            }
+        }
+
+        let unsafe_head = unsafe_head_rx.borrow_and_update().block_info.hash;
+        if unsafe_head != hash {
+            warn!(target: "sequencer", %hash, %unsafe_head, "Built payload was not inserted");
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    /// Stops the sequencer if the conductor reports that the node is no longer its leader.
+    async fn check_leadership(&mut self) {
+        let Some(conductor) = self.conductor.as_ref() else {
+            return;
+        };
+
+        match conductor.leader().await {
+            Ok(true) => {}
+            Ok(false) => self.stop_on_lost_leadership(),
+            Err(err) => warn!(target: "sequencer", ?err, "Failed to check conductor leadership"),
+        }
+    }
+
+    /// Stops the sequencer after losing the conductor leadership, reporting the last head committed
+    /// to the conductor as the stop hash.
+    fn stop_on_lost_leadership(&mut self) {
+        warn!(
+            target: "sequencer",
+            last_committed = ?self.last_committed_hash,
+            "Lost conductor leadership, stopping sequencer"
+        );
+        let state = SequencerAdminState {
+            sequencer_active: false,
+            last_stop_hash: self.last_committed_hash,
+            ..self.admin_state()
+        };
+        if let Err(err) = self.set_admin_state(state) {
+            error!(target: "sequencer", ?err, "Failed to persist admin state");
+            self.is_active = false;
+        }
+
+        // Update metrics, if configured.
+        #[cfg(feature = "metrics")]
+        self.update_metrics();
+    }
+
     /// Waits for the next payload to be built and returns it, if there is a payload receiver
     /// present.
     async fn try_wait_for_payload(
@@ -441,13 +663,26 @@ impl<AB: AttributesBuilder> SequencerActorState<AB> {
     /// Updates the metrics for the sequencer actor.
     #[cfg(feature = "metrics")]
     fn update_metrics(&self) {
-        let state_flags: [(&str, String); 2] = [
+        let state_flags: [(&str, String); 3] = [
             ("active", self.is_active.to_string()),
             ("recovery", self.is_recovery_mode.to_string()),
+            ("build_mode", self.build_mode.name().to_string()),
         ];
 
         let gauge = metrics::gauge!(crate::Metrics::SEQUENCER_STATE, &state_flags);
         gauge.set(1);
+
+        // The blocks left are tracked apart from the state flags, to keep the label values
+        // bounded.
+        let blocks_left = match self.build_mode {
+            SequencerBuildMode::NoTxPool { blocks: Some(blocks) } => blocks,
+            _ => 0,
+        };
+        kona_macros::set!(
+            gauge,
+            crate::Metrics::SEQUENCER_NO_TX_POOL_BLOCKS_LEFT,
+            blocks_left as f64
+        );
     }
 }
 
@@ -463,7 +698,14 @@ impl NodeActor for SequencerActor<SequencerBuilder> {
     }
 
     async fn start(mut self, mut ctx: Self::OutboundData) -> Result<(), Self::Error> {
-        let mut state = SequencerActorState::new(self.builder, ctx.l1_head_rx.clone());
+        let mut state = match SequencerActorState::new(self.builder, ctx.l1_head_rx.clone()) {
+            Ok(state) => state,
+            Err(err) => {
+                error!(target: "sequencer", %err, "Failed to initialize sequencer");
+                ctx.cancellation.cancel();
+                return Err(err);
+            }
+        };
 
         // Initialize metrics, if configured.
         #[cfg(feature = "metrics")]
@@ -501,6 +743,10 @@ impl NodeActor for SequencerActor<SequencerBuilder> {
                     #[cfg(feature = "metrics")]
                     state.update_metrics();
                 }
+                // Stop building blocks once the conductor hands the leadership to another node.
+                _ = state.leader_ticker.tick(), if state.is_active && state.conductor.is_some() => {
+                    state.check_leadership().await;
+                }
                 // The sequencer must be active to build new blocks.
                 _ = state.build_ticker.tick(), if state.is_active => {
                     state.build_block(&mut ctx, &mut self.unsafe_head_rx, state.is_recovery_mode).await?;
@@ -509,3 +755,473 @@ impl NodeActor for SequencerActor<SequencerBuilder> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::actors::sequencer::conductor::tests::{MockConductor, payload};
+    use alloy_eips::BlockNumHash;
+    use alloy_rpc_types_engine::ExecutionPayloadV1;
+    use arbitrary::Arbitrary;
+    use kona_derive::PipelineResult;
+    use op_alloy_rpc_types_engine::{OpExecutionPayload, OpPayloadAttributes};
+    use rand::Rng;
+
+    /// The L1 origin of every test block.
+    const L1_ORIGIN: BlockInfo = BlockInfo::new(B256::repeat_byte(1), 1, B256::ZERO, 0);
+
+    /// An [`AttributesBuilder`] building empty attributes on top of the parent block.
+    #[derive(Debug)]
+    struct MockAttributesBuilder {
+        block_time: u64,
+    }
+
+    #[async_trait]
+    impl AttributesBuilder for MockAttributesBuilder {
+        async fn prepare_payload_attributes(
+            &mut self,
+            l2_parent: L2BlockInfo,
+            _: BlockNumHash,
+        ) -> PipelineResult<OpPayloadAttributes> {
+            let mut attributes = OpPayloadAttributes::default();
+            attributes.payload_attributes.timestamp =
+                l2_parent.block_info.timestamp + self.block_time;
+            Ok(attributes)
+        }
+    }
+
+    /// An [`L1OriginSelectorProvider`] that only knows the [`L1_ORIGIN`].
+    #[derive(Debug)]
+    struct MockL1;
+
+    #[async_trait]
+    impl L1OriginSelectorProvider for MockL1 {
+        async fn get_block_by_hash(
+            &self,
+            hash: B256,
+        ) -> Result<Option<BlockInfo>, L1OriginSelectorError> {
+            Ok((hash == L1_ORIGIN.hash).then_some(L1_ORIGIN))
+        }
+
+        async fn get_block_by_number(
+            &self,
+            number: u64,
+        ) -> Result<Option<BlockInfo>, L1OriginSelectorError> {
+            Ok((number == L1_ORIGIN.number).then_some(L1_ORIGIN))
+        }
+    }
+
+    /// Drives a [`SequencerActorState`] against a mock engine, which builds a block for every build
+    /// request, and inserts the blocks sent back by the sequencer.
+    struct Harness {
+        state: SequencerActorState<MockAttributesBuilder, MockL1>,
+        ctx: SequencerContext,
+        unsafe_head_rx: watch::Receiver<L2BlockInfo>,
+        safe_head_tx: watch::Sender<L2BlockInfo>,
+        /// The attributes of the blocks built by the mock engine.
+        built_rx: mpsc::UnboundedReceiver<OpAttributesWithParent>,
+        /// The payloads scheduled to be gossipped.
+        gossip_rx: mpsc::Receiver<OpExecutionPayloadEnvelope>,
+    }
+
+    impl Harness {
+        fn new() -> Self {
+            let cfg = Arc::new(RollupConfig {
+                block_time: 2,
+                max_sequencer_drift: 600,
+                ..Default::default()
+            });
+            let genesis = L2BlockInfo::new(
+                BlockInfo::new(B256::repeat_byte(2), 0, B256::ZERO, 0),
+                L1_ORIGIN.id(),
+                0,
+            );
+            let (unsafe_head_tx, unsafe_head_rx) = watch::channel(genesis);
+            let (safe_head_tx, safe_head_rx) = watch::channel(genesis);
+            let (_, l1_head_rx) = watch::channel(None);
+            let (reset_request_tx, _) = mpsc::channel(16);
+            let (build_request_tx, mut build_request_rx) = mpsc::channel::<(
+                OpAttributesWithParent,
+                mpsc::Sender<OpExecutionPayloadEnvelope>,
+            )>(1);
+            let (gossip_payload_tx, gossip_rx) = mpsc::channel(1024);
+            let (unsafe_block_tx, mut unsafe_block_rx) =
+                mpsc::channel::<OpExecutionPayloadEnvelope>(1);
+            let (built_tx, built_rx) = mpsc::unbounded_channel();
+
+            // The mock engine builds the block and returns the payload to the sequencer, without
+            // inserting it.
+            tokio::spawn(async move {
+                while let Some((attributes, payload_tx)) = build_request_rx.recv().await {
+                    let parent = attributes.parent;
+                    let mut bytes = [0u8; 4096];
+                    rand::rng().fill(bytes.as_mut_slice());
+                    let mut payload =
+                        ExecutionPayloadV1::arbitrary(&mut arbitrary::Unstructured::new(&bytes))
+                            .unwrap();
+                    payload.parent_hash = parent.block_info.hash;
+                    payload.block_number = parent.block_info.number + 1;
+                    payload.timestamp = attributes.inner.payload_attributes.timestamp;
+
+                    built_tx.send(attributes).unwrap();
+                    let envelope = OpExecutionPayloadEnvelope {
+                        execution_payload: OpExecutionPayload::V1(payload),
+                        parent_beacon_block_root: None,
+                    };
+                    if payload_tx.send(envelope).await.is_err() {
+                        return;
+                    }
+                }
+            });
+
+            // The mock engine inserts the blocks sent back by the sequencer as the unsafe head.
+            tokio::spawn(async move {
+                while let Some(envelope) = unsafe_block_rx.recv().await {
+                    let payload = envelope.execution_payload;
+                    let block_info = BlockInfo::new(
+                        payload.block_hash(),
+                        payload.block_number(),
+                        payload.parent_hash(),
+                        payload.timestamp(),
+                    );
+                    let seq_num = unsafe_head_tx.borrow().seq_num + 1;
+                    unsafe_head_tx.send_replace(L2BlockInfo::new(
+                        block_info,
+                        L1_ORIGIN.id(),
+                        seq_num,
+                    ));
+                }
+            });
+
+            let state = SequencerActorState {
+                cfg: cfg.clone(),
+                builder: MockAttributesBuilder { block_time: cfg.block_time },
+                origin_selector: L1OriginSelector::new(cfg.clone(), MockL1),
+                build_ticker: tokio::time::interval(Duration::from_secs(cfg.block_time)),
+                leader_ticker: tokio::time::interval(LEADER_CHECK_INTERVAL),
+                conductor: None,
+                is_active: true,
+                is_recovery_mode: false,
+                last_stop_hash: None,
+                admin_persistence: None,
+                build_mode: SequencerBuildMode::Normal,
+                last_committed_hash: None,
+                max_safe_lag: 0,
+                throttle_safe_lag: 0,
+            };
+            let ctx = SequencerContext {
+                cancellation: CancellationToken::new(),
+                l1_head_rx,
+                safe_head_rx,
+                reset_request_tx,
+                build_request_tx,
+                gossip_payload_tx,
+                unsafe_block_tx,
+            };
+
+            Self { state, ctx, unsafe_head_rx, safe_head_tx, built_rx, gossip_rx }
+        }
+
+        /// Runs a block building job, returning the attributes of the built block, if any.
+        async fn build(&mut self) -> Option<OpAttributesWithParent> {
+            self.state.build_block(&mut self.ctx, &mut self.unsafe_head_rx, false).await.unwrap();
+            self.built_rx.try_recv().ok()
+        }
+
+        /// Moves the safe head up to the unsafe head.
+        fn advance_safe_head(&self) {
+            self.safe_head_tx.send_replace(*self.unsafe_head_rx.borrow());
+        }
+
+        /// Returns how long the build ticker waits before the next block building job.
+        async fn next_tick(&mut self) -> Duration {
+            let start = tokio::time::Instant::now();
+            self.state.build_ticker.tick().await;
+            start.elapsed()
+        }
+
+        /// Handles the given admin query.
+        async fn admin(&mut self, query: SequencerAdminQuery) {
+            self.state.handle_admin_query(query, &mut self.unsafe_head_rx).await.unwrap();
+        }
+
+        /// Sets the [`SequencerBuildMode`] through the admin API.
+        async fn set_build_mode(&mut self, mode: SequencerBuildMode) -> Result<(), String> {
+            let (tx, rx) = tokio::sync::oneshot::channel();
+            self.admin(SequencerAdminQuery::SetBuildMode(mode, tx)).await;
+            rx.await.unwrap()
+        }
+
+        /// Returns the [`SequencerBuildMode`] reported through the admin API.
+        async fn build_mode(&mut self) -> SequencerBuildMode {
+            let (tx, rx) = tokio::sync::oneshot::channel();
+            self.admin(SequencerAdminQuery::BuildMode(tx)).await;
+            rx.await.unwrap()
+        }
+    }
+
+    /// Builds a [`SequencerActor`] persisting its admin state to the given file, the context to
+    /// start it with, and the receiver of its engine reset requests.
+    fn sequencer(
+        admin_persistence: &std::path::Path,
+    ) -> (
+        SequencerInboundData,
+        SequencerActor<SequencerBuilder>,
+        SequencerContext,
+        mpsc::Receiver<()>,
+    ) {
+        let url: url::Url = "http://127.0.0.1:1".parse().unwrap();
+        let builder = SequencerBuilder {
+            seq_cfg: SequencerConfig {
+                admin_persistence: Some(admin_persistence.to_path_buf()),
+                ..Default::default()
+            },
+            rollup_cfg: Arc::new(RollupConfig { block_time: 2, ..Default::default() }),
+            l1_provider: RootProvider::new_http(url.clone()),
+            l2_provider: RootProvider::new_http(url),
+        };
+        let (inbound, actor) = SequencerActor::new(builder);
+
+        let (_, l1_head_rx) = watch::channel(None);
+        let (_, safe_head_rx) = watch::channel(L2BlockInfo::default());
+        let (reset_request_tx, reset_request_rx) = mpsc::channel(1);
+        let (build_request_tx, _) = mpsc::channel(1);
+        let (gossip_payload_tx, _) = mpsc::channel(1);
+        let (unsafe_block_tx, _) = mpsc::channel(1);
+        let ctx = SequencerContext {
+            cancellation: CancellationToken::new(),
+            l1_head_rx,
+            safe_head_rx,
+            reset_request_tx,
+            build_request_tx,
+            gossip_payload_tx,
+            unsafe_block_tx,
+        };
+        (inbound, actor, ctx, reset_request_rx)
+    }
+
+    #[tokio::test]
+    async fn test_admin_state_reloaded_at_startup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("admin_state.json");
+        let persisted = SequencerAdminState {
+            sequencer_active: false,
+            last_stop_hash: Some(B256::repeat_byte(3)),
+            recover_mode: true,
+            build_mode: SequencerBuildMode::NoTxPool { blocks: Some(5) },
+        };
+        AdminStatePersistence::new(&path).write(&persisted).unwrap();
+
+        let (inbound, actor, ctx, mut reset_request_rx) = sequencer(&path);
+        let cancellation = ctx.cancellation.clone();
+        let handle = tokio::spawn(actor.start(ctx));
+
+        // Complete the initial engine reset.
+        reset_request_rx.recv().await.unwrap();
+        inbound.unsafe_head_tx.send_replace(L2BlockInfo::default());
+
+        // The persisted state takes precedence over the startup flags, which leave the sequencer
+        // active.
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        inbound.admin_query_tx.send(SequencerAdminQuery::SequencerActive(tx)).await.unwrap();
+        assert!(!rx.await.unwrap());
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        inbound.admin_query_tx.send(SequencerAdminQuery::BuildMode(tx)).await.unwrap();
+        assert_eq!(rx.await.unwrap(), persisted.build_mode);
+
+        cancellation.cancel();
+        handle.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_unreadable_admin_state_fails_startup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("admin_state.json");
+        std::fs::write(&path, "not json").unwrap();
+
+        let (_inbound, actor, ctx, _reset_request_rx) = sequencer(&path);
+        let cancellation = ctx.cancellation.clone();
+        let err = actor.start(ctx).await.unwrap_err();
+        assert!(matches!(err, SequencerActorError::AdminState(_)));
+        assert!(cancellation.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_admin_state_persist_failure() {
+        let mut harness = Harness::new();
+        let dir = tempfile::tempdir().unwrap();
+        // The parent of the persisted file is a regular file, so the state cannot be written.
+        let file = dir.path().join("file");
+        std::fs::write(&file, "").unwrap();
+        harness.state.admin_persistence =
+            Some(AdminStatePersistence::new(file.join("admin_state.json")));
+
+        // The admin API reports the failure, and the state is left unchanged.
+        let mode = SequencerBuildMode::NoTxPool { blocks: None };
+        assert!(harness.set_build_mode(mode).await.is_err());
+        assert_eq!(harness.build_mode().await, SequencerBuildMode::Normal);
+
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        harness.admin(SequencerAdminQuery::SetRecoveryMode(true, tx)).await;
+        assert!(rx.await.unwrap().is_err());
+        assert!(!harness.state.is_recovery_mode);
+
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        harness.admin(SequencerAdminQuery::StopSequencer(tx)).await;
+        assert!(rx.await.unwrap().is_err());
+        assert!(harness.state.is_active);
+    }
+
+    #[tokio::test]
+    async fn test_build_mode_normal() {
+        let mut harness = Harness::new();
+        for _ in 0..3 {
+            assert_eq!(harness.build().await.unwrap().inner.no_tx_pool, None);
+        }
+        assert_eq!(harness.build_mode().await, SequencerBuildMode::Normal);
+        assert_eq!(harness.gossip_rx.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_build_mode_no_tx_pool() {
+        let mut harness = Harness::new();
+        let mode = SequencerBuildMode::NoTxPool { blocks: None };
+        harness.set_build_mode(mode).await.unwrap();
+
+        // Blocks are built without the transaction pool until the mode is changed.
+        for _ in 0..3 {
+            assert_eq!(harness.build().await.unwrap().inner.no_tx_pool, Some(true));
+        }
+        assert_eq!(harness.build_mode().await, mode);
+
+        harness.set_build_mode(SequencerBuildMode::Normal).await.unwrap();
+        assert_eq!(harness.build().await.unwrap().inner.no_tx_pool, None);
+    }
+
+    #[tokio::test]
+    async fn test_build_mode_no_tx_pool_blocks() {
+        let mut harness = Harness::new();
+        let dir = tempfile::tempdir().unwrap();
+        let persistence = AdminStatePersistence::new(dir.path().join("admin_state.json"));
+        harness.state.admin_persistence = Some(persistence.clone());
+
+        let mode = SequencerBuildMode::NoTxPool { blocks: Some(2) };
+        harness.set_build_mode(mode).await.unwrap();
+        assert_eq!(persistence.read().unwrap().unwrap().build_mode, mode);
+
+        // The blocks left are counted down and persisted, so that a restart resumes them.
+        assert_eq!(harness.build().await.unwrap().inner.no_tx_pool, Some(true));
+        let mode = SequencerBuildMode::NoTxPool { blocks: Some(1) };
+        assert_eq!(harness.build_mode().await, mode);
+        assert_eq!(persistence.read().unwrap().unwrap().build_mode, mode);
+
+        // The sequencer returns to the normal mode after the last block.
+        assert_eq!(harness.build().await.unwrap().inner.no_tx_pool, Some(true));
+        assert_eq!(harness.build_mode().await, SequencerBuildMode::Normal);
+        assert_eq!(persistence.read().unwrap().unwrap().build_mode, SequencerBuildMode::Normal);
+        assert_eq!(harness.build().await.unwrap().inner.no_tx_pool, None);
+    }
+
+    #[tokio::test]
+    async fn test_max_safe_lag_pauses_sequencing() {
+        let mut harness = Harness::new();
+        harness.state.max_safe_lag = 2;
+
+        assert!(harness.build().await.is_some());
+        assert!(harness.build().await.is_some());
+
+        // The unsafe head is 2 blocks ahead of the safe head, sequencing is paused.
+        assert!(harness.build().await.is_none());
+        assert!(harness.build().await.is_none());
+        assert_eq!(harness.unsafe_head_rx.borrow().block_info.number, 2);
+        assert_eq!(harness.gossip_rx.len(), 2);
+
+        // Sequencing resumes once the safe head catches up.
+        harness.advance_safe_head();
+        assert!(harness.build().await.is_some());
+        assert_eq!(harness.unsafe_head_rx.borrow().block_info.number, 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_throttle_safe_lag_slows_sequencing() {
+        let mut harness = Harness::new();
+        harness.state.throttle_safe_lag = 1;
+        let block_time = Duration::from_secs(harness.state.cfg.block_time);
+        assert_eq!(harness.next_tick().await, Duration::ZERO);
+
+        // Below the throttle lag, blocks include the transaction pool and missed slots are
+        // caught up on.
+        assert_eq!(harness.build().await.unwrap().inner.no_tx_pool, None);
+        assert_eq!(harness.next_tick().await, Duration::ZERO);
+
+        // Once throttled, empty blocks are built at half the block cadence.
+        for _ in 0..2 {
+            assert_eq!(harness.build().await.unwrap().inner.no_tx_pool, Some(true));
+            assert_eq!(harness.next_tick().await, block_time * 2);
+        }
+
+        // The throttle is lifted once the safe head catches up.
+        harness.advance_safe_head();
+        assert_eq!(harness.build().await.unwrap().inner.no_tx_pool, None);
+        assert_eq!(harness.next_tick().await, Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_conductor_leadership_handoff() {
+        let mut harness = Harness::new();
+        let conductor = MockConductor::spawn(true).await;
+        harness.state.conductor = Some(conductor.client.clone());
+
+        // The leader commits its blocks to the conductor before inserting and gossipping them.
+        assert!(harness.build().await.is_some());
+        let head = harness.unsafe_head_rx.borrow().block_info;
+        let committed = conductor.state.lock().unwrap().committed.clone();
+        assert_eq!(committed.len(), 1);
+        assert_eq!(committed[0].execution_payload.block_hash(), head.hash);
+        assert_eq!(harness.gossip_rx.try_recv().unwrap(), committed[0]);
+
+        // Once the leadership is handed over, the next block is dropped instead of being inserted,
+        // and the sequencer stops at the last committed block.
+        conductor.state.lock().unwrap().leader = false;
+        assert!(harness.build().await.is_some());
+        assert_eq!(harness.unsafe_head_rx.borrow().block_info, head);
+        assert!(harness.gossip_rx.try_recv().is_err());
+        assert!(!harness.state.is_active);
+        assert_eq!(harness.state.last_stop_hash, Some(head.hash));
+
+        // The sequencer cannot be started while another node is the leader.
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        harness.admin(SequencerAdminQuery::StartSequencer(tx)).await;
+        assert!(rx.await.unwrap().is_err());
+        assert!(!harness.state.is_active);
+
+        // Once the leadership is handed back, the sequencer resumes from the last committed block.
+        conductor.state.lock().unwrap().leader = true;
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        harness.admin(SequencerAdminQuery::StartSequencer(tx)).await;
+        rx.await.unwrap().unwrap();
+        assert!(harness.state.is_active);
+        assert_eq!(harness.build().await.unwrap().parent.block_info, head);
+        assert_eq!(conductor.state.lock().unwrap().committed.len(), 2);
+
+        // The periodic leadership check stops the sequencer without building a block.
+        conductor.state.lock().unwrap().leader = false;
+        harness.state.check_leadership().await;
+        assert!(!harness.state.is_active);
+    }
+
+    #[tokio::test]
+    async fn test_conductor_rejects_start_on_unreplicated_head() {
+        let mut harness = Harness::new();
+        let conductor = MockConductor::spawn(true).await;
+        harness.state.conductor = Some(conductor.client.clone());
+        harness.state.is_active = false;
+
+        // Another leader committed a block the node did not insert.
+        conductor.state.lock().unwrap().committed.push(payload());
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        harness.admin(SequencerAdminQuery::StartSequencer(tx)).await;
+        assert!(rx.await.unwrap().is_err());
+        assert!(!harness.state.is_active);
+    }
+}
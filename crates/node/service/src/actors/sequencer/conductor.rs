@@ -1,3 +1,4 @@
+use alloy_primitives::B256;
 use alloy_rpc_client::ReqwestClient;
 use alloy_transport::{RpcError, TransportErrorKind};
 use op_alloy_rpc_types_engine::OpExecutionPayloadEnvelope;
@@ -43,6 +44,34 @@ impl ConductorClient {
         let _result: () = self.rpc.request("conductor_commitUnsafePayload", [payload]).await?;
         Ok(())
     }
+
+    /// Returns the latest unsafe payload committed to the conductor, if any.
+    pub async fn latest_unsafe_payload(
+        &self,
+    ) -> Result<Option<OpExecutionPayloadEnvelope>, ConductorError> {
+        let result = self.rpc.request("conductor_latestUnsafePayload", ()).await?;
+        Ok(result)
+    }
+
+    /// Confirms that the node can take over block production after gaining the leadership.
+    ///
+    /// The node must be the leader of the conductor, and its unsafe head must match the latest
+    /// payload committed to the conductor, so that it does not build on top of a block that the
+    /// previous leader did not replicate.
+    pub async fn confirm_leadership(&self, unsafe_head: B256) -> Result<(), ConductorError> {
+        if !self.leader().await? {
+            return Err(ConductorError::NotLeader);
+        }
+
+        if let Some(latest) = self.latest_unsafe_payload().await? {
+            let committed = latest.execution_payload.block_hash();
+            if committed != unsafe_head {
+                return Err(ConductorError::UnsafeHeadMismatch { unsafe_head, committed });
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// Error type for conductor operations
@@ -51,4 +80,149 @@ pub enum ConductorError {
     /// An error occurred while making an RPC call to the conductor.
     #[error("RPC error: {0}")]
     Rpc(#[from] RpcError<TransportErrorKind>),
+    /// The node is not the leader of the conductor.
+    #[error("Not the leader of the conductor")]
+    NotLeader,
+    /// The unsafe head does not match the latest payload committed to the conductor.
+    #[error("Unsafe head {unsafe_head} does not match the latest committed payload {committed}")]
+    UnsafeHeadMismatch {
+        /// The hash of the unsafe head.
+        unsafe_head: B256,
+        /// The hash of the latest payload committed to the conductor.
+        committed: B256,
+    },
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use alloy_rpc_types_engine::ExecutionPayloadV1;
+    use arbitrary::Arbitrary;
+    use jsonrpsee::{
+        RpcModule,
+        core::RpcResult,
+        server::{Server, ServerHandle},
+        types::{ErrorCode, ErrorObject},
+    };
+    use op_alloy_rpc_types_engine::OpExecutionPayload;
+    use rand::Rng;
+    use std::sync::{Arc, Mutex};
+
+    /// The state of the [`MockConductor`].
+    #[derive(Debug, Default)]
+    pub(crate) struct MockConductorState {
+        /// Whether the node is the leader.
+        pub(crate) leader: bool,
+        /// The payloads committed to the conductor.
+        pub(crate) committed: Vec<OpExecutionPayloadEnvelope>,
+    }
+
+    /// A mock conductor JSON-RPC server.
+    #[derive(Debug)]
+    pub(crate) struct MockConductor {
+        /// The state of the conductor.
+        pub(crate) state: Arc<Mutex<MockConductorState>>,
+        /// The [`ConductorClient`] connected to the server.
+        pub(crate) client: ConductorClient,
+        /// The handle keeping the server alive.
+        _handle: ServerHandle,
+    }
+
+    impl MockConductor {
+        /// Spawns a new [`MockConductor`], with the node as leader or follower.
+        pub(crate) async fn spawn(leader: bool) -> Self {
+            let state = Arc::new(Mutex::new(MockConductorState { leader, ..Default::default() }));
+            let mut module = RpcModule::new(state.clone());
+            module
+                .register_method("conductor_leader", |_, state, _| {
+                    RpcResult::Ok(state.lock().unwrap().leader)
+                })
+                .unwrap();
+            module.register_method("conductor_active", |_, _, _| RpcResult::Ok(true)).unwrap();
+            module
+                .register_method("conductor_overrideLeader", |_, state, _| {
+                    state.lock().unwrap().leader = true;
+                    RpcResult::Ok(())
+                })
+                .unwrap();
+            module
+                .register_method(
+                    "conductor_commitUnsafePayload",
+                    |params, state, _| -> RpcResult<()> {
+                        let payload: OpExecutionPayloadEnvelope = params.one()?;
+                        let mut state = state.lock().unwrap();
+                        if !state.leader {
+                            return Err(ErrorObject::owned(
+                                ErrorCode::InternalError.code(),
+                                "not the leader",
+                                None::<()>,
+                            ));
+                        }
+                        state.committed.push(payload);
+                        Ok(())
+                    },
+                )
+                .unwrap();
+            module
+                .register_method("conductor_latestUnsafePayload", |_, state, _| {
+                    RpcResult::Ok(state.lock().unwrap().committed.last().cloned())
+                })
+                .unwrap();
+
+            let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}", server.local_addr().unwrap()).parse().unwrap();
+            let handle = server.start(module);
+
+            Self { state, client: ConductorClient::new_http(url), _handle: handle }
+        }
+    }
+
+    /// Returns a random [`OpExecutionPayloadEnvelope`].
+    pub(crate) fn payload() -> OpExecutionPayloadEnvelope {
+        let mut bytes = [0u8; 4096];
+        rand::rng().fill(bytes.as_mut_slice());
+        OpExecutionPayloadEnvelope {
+            execution_payload: OpExecutionPayload::V1(
+                ExecutionPayloadV1::arbitrary(&mut arbitrary::Unstructured::new(&bytes)).unwrap(),
+            ),
+            parent_beacon_block_root: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_commit_requires_leadership() {
+        let conductor = MockConductor::spawn(false).await;
+        assert!(!conductor.client.leader().await.unwrap());
+        assert!(conductor.client.commit_unsafe_payload(&payload()).await.is_err());
+
+        conductor.client.override_leader().await.unwrap();
+        assert!(conductor.client.leader().await.unwrap());
+
+        let payload = payload();
+        conductor.client.commit_unsafe_payload(&payload).await.unwrap();
+        assert_eq!(conductor.state.lock().unwrap().committed, vec![payload.clone()]);
+        assert_eq!(conductor.client.latest_unsafe_payload().await.unwrap(), Some(payload));
+    }
+
+    #[tokio::test]
+    async fn test_confirm_leadership() {
+        let conductor = MockConductor::spawn(false).await;
+        let err = conductor.client.confirm_leadership(B256::ZERO).await.unwrap_err();
+        assert!(matches!(err, ConductorError::NotLeader));
+
+        // Without any committed payload, any unsafe head is accepted.
+        conductor.client.override_leader().await.unwrap();
+        conductor.client.confirm_leadership(B256::ZERO).await.unwrap();
+
+        let payload = payload();
+        let committed = payload.execution_payload.block_hash();
+        conductor.client.commit_unsafe_payload(&payload).await.unwrap();
+        conductor.client.confirm_leadership(committed).await.unwrap();
+
+        let err = conductor.client.confirm_leadership(B256::ZERO).await.unwrap_err();
+        let ConductorError::UnsafeHeadMismatch { unsafe_head, committed: c } = err else {
+            panic!("expected an unsafe head mismatch, got {err:?}");
+        };
+        assert_eq!((unsafe_head, c), (B256::ZERO, committed));
+    }
 }
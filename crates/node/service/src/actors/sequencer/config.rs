@@ -2,6 +2,8 @@
 //!
 //! [`SequencerActor`]: super::SequencerActor
 
+use super::L1OriginPolicyKind;
+use std::path::PathBuf;
 use url::Url;
 
 /// Configuration for the [`SequencerActor`].
@@ -17,4 +19,15 @@ pub struct SequencerConfig {
     pub conductor_rpc_url: Option<Url>,
     /// The confirmation delay for the sequencer.
     pub l1_conf_delay: u64,
+    /// The policy deciding when the sequencer advances to the next L1 origin.
+    pub origin_policy: L1OriginPolicyKind,
+    /// The maximum number of L2 blocks the unsafe head may be ahead of the safe head. Once
+    /// reached, the sequencer pauses until the safe head catches up. Disabled if 0.
+    pub max_safe_lag: u64,
+    /// The number of L2 blocks the unsafe head may be ahead of the safe head before the sequencer
+    /// is throttled, building empty blocks at half the block cadence. Disabled if 0.
+    pub throttle_safe_lag: u64,
+    /// The file the sequencer state set through the admin API is persisted to. If [`Some`], the
+    /// persisted state takes precedence over the startup flags.
+    pub admin_persistence: Option<PathBuf>,
 }
@@ -3,6 +3,11 @@
 mod config;
 pub use config::SequencerConfig;
 
+mod origin_policy;
+pub use origin_policy::{
+    ConservativePolicy, FastestAllowedPolicy, FixedLagPolicy, L1OriginPolicy, L1OriginPolicyKind,
+};
+
 mod origin_selector;
 pub use origin_selector::{
     DelayedL1OriginSelectorProvider, L1OriginSelector, L1OriginSelectorError,
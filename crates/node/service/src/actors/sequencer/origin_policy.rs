@@ -0,0 +1,122 @@
+//! The [`L1OriginPolicy`] trait and its implementations.
+
+use kona_genesis::RollupConfig;
+use kona_protocol::BlockInfo;
+use std::fmt::Debug;
+
+/// An [`L1OriginPolicy`] decides when the [`L1OriginSelector`] advances the sequencer to the next
+/// L1 origin.
+///
+/// The policy is only consulted when advancing is allowed, i.e. when the next L1 origin is known
+/// and its timestamp is not ahead of the next L2 block. Once the next L2 block would be past the
+/// sequencer drift, the selector advances regardless of the policy.
+///
+/// [`L1OriginSelector`]: super::L1OriginSelector
+pub trait L1OriginPolicy: Debug + Send + Sync {
+    /// Returns whether the L2 block at `next_l2_time` should adopt the `next` L1 origin, rather
+    /// than staying on the `current` one.
+    fn should_advance(
+        &self,
+        cfg: &RollupConfig,
+        next_l2_time: u64,
+        current: &BlockInfo,
+        next: &BlockInfo,
+    ) -> bool;
+}
+
+/// Advances to the next L1 origin as soon as it is allowed to.
+///
+/// This keeps the L1 origin as close as possible to the L1 head, and is the default policy.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FastestAllowedPolicy;
+
+impl L1OriginPolicy for FastestAllowedPolicy {
+    fn should_advance(&self, _: &RollupConfig, _: u64, _: &BlockInfo, _: &BlockInfo) -> bool {
+        true
+    }
+}
+
+/// Advances to the next L1 origin once it is half of the sequencer drift window older than the
+/// next L2 block.
+///
+/// This minimizes the exposure of the L2 chain to reorgs of recent L1 blocks. As the age of the
+/// next L1 origin is bounded, the L1 origin lags the L2 chain by at most half of the drift window
+/// plus an L1 block time, leaving the other half as headroom before the sequencer is forced to
+/// build deposits-only blocks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ConservativePolicy;
+
+impl L1OriginPolicy for ConservativePolicy {
+    fn should_advance(
+        &self,
+        cfg: &RollupConfig,
+        next_l2_time: u64,
+        _: &BlockInfo,
+        next: &BlockInfo,
+    ) -> bool {
+        let max_seq_drift = cfg.max_sequencer_drift(next.timestamp);
+        next_l2_time >= next.timestamp.saturating_add(max_seq_drift / 2)
+    }
+}
+
+/// Advances to the next L1 origin once it is at least `lag` seconds older than the next L2 block.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FixedLagPolicy {
+    /// The minimum age of the next L1 origin, in seconds, relative to the next L2 block.
+    pub lag: u64,
+}
+
+impl FixedLagPolicy {
+    /// Creates a new [`FixedLagPolicy`] with the given lag, in seconds.
+    pub const fn new(lag: u64) -> Self {
+        Self { lag }
+    }
+}
+
+impl L1OriginPolicy for FixedLagPolicy {
+    fn should_advance(
+        &self,
+        _: &RollupConfig,
+        next_l2_time: u64,
+        _: &BlockInfo,
+        next: &BlockInfo,
+    ) -> bool {
+        next_l2_time >= next.timestamp.saturating_add(self.lag)
+    }
+}
+
+/// The built-in [`L1OriginPolicy`]s, selectable through the [`SequencerConfig`].
+///
+/// [`SequencerConfig`]: super::SequencerConfig
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum L1OriginPolicyKind {
+    /// The [`FastestAllowedPolicy`].
+    #[default]
+    FastestAllowed,
+    /// The [`ConservativePolicy`].
+    Conservative,
+    /// The [`FixedLagPolicy`], with the given lag in seconds.
+    FixedLag(u64),
+}
+
+impl L1OriginPolicy for L1OriginPolicyKind {
+    fn should_advance(
+        &self,
+        cfg: &RollupConfig,
+        next_l2_time: u64,
+        current: &BlockInfo,
+        next: &BlockInfo,
+    ) -> bool {
+        match self {
+            Self::FastestAllowed => {
+                FastestAllowedPolicy.should_advance(cfg, next_l2_time, current, next)
+            }
+            Self::Conservative => {
+                ConservativePolicy.should_advance(cfg, next_l2_time, current, next)
+            }
+            Self::FixedLag(lag) => {
+                FixedLagPolicy::new(*lag).should_advance(cfg, next_l2_time, current, next)
+            }
+        }
+    }
+}
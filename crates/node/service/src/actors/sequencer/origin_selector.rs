@@ -1,5 +1,6 @@
 //! The [`L1OriginSelector`].
 
+use super::{FastestAllowedPolicy, L1OriginPolicy};
 use alloy_primitives::B256;
 use alloy_provider::{Provider, RootProvider};
 use alloy_transport::{RpcError, TransportErrorKind};
@@ -21,12 +22,23 @@ pub struct L1OriginSelector<P: L1OriginSelectorProvider> {
     current: Option<BlockInfo>,
     /// The next L1 origin.
     next: Option<BlockInfo>,
+    /// The [`L1OriginPolicy`] deciding when to advance to the next L1 origin.
+    policy: Box<dyn L1OriginPolicy>,
 }
 
 impl<P: L1OriginSelectorProvider> L1OriginSelector<P> {
     /// Creates a new [`L1OriginSelector`].
-    pub const fn new(cfg: Arc<RollupConfig>, l1: P) -> Self {
-        Self { cfg, l1, current: None, next: None }
+    ///
+    /// The selector advances to the next L1 origin as soon as it is allowed to, see
+    /// [`Self::with_policy`] to select another [`L1OriginPolicy`].
+    pub fn new(cfg: Arc<RollupConfig>, l1: P) -> Self {
+        Self { cfg, l1, current: None, next: None, policy: Box::new(FastestAllowedPolicy) }
+    }
+
+    /// Sets the [`L1OriginPolicy`] deciding when to advance to the next L1 origin.
+    pub fn with_policy<O: L1OriginPolicy + 'static>(mut self, policy: O) -> Self {
+        self.policy = Box::new(policy);
+        self
     }
 
     /// Returns the current L1 origin.
@@ -45,7 +57,7 @@ impl<P: L1OriginSelectorProvider> L1OriginSelector<P> {
     /// The L1 origin is selected based off of the sequencing epoch, determined by the next L2
     /// block's timestamp in relation to the current L1 origin's timestamp. If the next L2
     /// block's timestamp is greater than the L2 unsafe head's L1 origin timestamp, the L1
-    /// origin is the block following the current L1 origin.
+    /// origin is the block following the current L1 origin, once the [`L1OriginPolicy`] agrees.
     pub async fn next_l1_origin(
         &mut self,
         unsafe_head: L2BlockInfo,
@@ -53,22 +65,26 @@ impl<P: L1OriginSelectorProvider> L1OriginSelector<P> {
     ) -> Result<BlockInfo, L1OriginSelectorError> {
         self.select_origins(&unsafe_head, is_recovery_mode).await?;
 
-        // Start building on the next L1 origin block if the next L2 block's timestamp is
-        // greater than or equal to the next L1 origin's timestamp.
-        if let Some(next) = self.next {
-            if unsafe_head.block_info.timestamp + self.cfg.block_time >= next.timestamp {
-                return Ok(next);
-            }
-        }
-
         let Some(current) = self.current else {
             unreachable!("Current L1 origin should always be set by `select_origins`");
         };
 
+        let next_l2_time = unsafe_head.block_info.timestamp + self.cfg.block_time;
         let max_seq_drift = self.cfg.max_sequencer_drift(current.timestamp);
-        let past_seq_drift = unsafe_head.block_info.timestamp + self.cfg.block_time -
-            current.timestamp >
-            max_seq_drift;
+        let past_seq_drift = next_l2_time - current.timestamp > max_seq_drift;
+
+        // Start building on the next L1 origin block if the next L2 block's timestamp is
+        // greater than or equal to the next L1 origin's timestamp, and the policy allows it. The
+        // policy is bypassed in recovery mode and once past the sequencer drift.
+        if let Some(next) = self.next {
+            if next_l2_time >= next.timestamp &&
+                (is_recovery_mode ||
+                    past_seq_drift ||
+                    self.policy.should_advance(&self.cfg, next_l2_time, &current, &next))
+            {
+                return Ok(next);
+            }
+        }
 
         // If the sequencer drift has not been exceeded, return the current L1 origin.
         if !past_seq_drift {
@@ -83,11 +99,7 @@ impl<P: L1OriginSelectorProvider> L1OriginSelector<P> {
             "Next L2 block time is past the sequencer drift"
         );
 
-        if self
-            .next
-            .map(|n| unsafe_head.block_info.timestamp + self.cfg.block_time < n.timestamp)
-            .unwrap_or(false)
-        {
+        if self.next.map(|n| next_l2_time < n.timestamp).unwrap_or(false) {
             // If the next L1 origin is ahead of the next L2 block's timestamp, return the current
             // origin.
             return Ok(current);
@@ -178,22 +190,39 @@ pub trait L1OriginSelectorProvider {
     ) -> Result<Option<BlockInfo>, L1OriginSelectorError>;
 }
 
-/// A wrapper around the [`RootProvider`] that delays the view of the L1 chain by a configurable
-/// amount of blocks.
+#[async_trait]
+impl L1OriginSelectorProvider for RootProvider {
+    async fn get_block_by_hash(
+        &self,
+        hash: B256,
+    ) -> Result<Option<BlockInfo>, L1OriginSelectorError> {
+        Ok(Provider::get_block_by_hash(self, hash).await?.map(Into::into))
+    }
+
+    async fn get_block_by_number(
+        &self,
+        number: u64,
+    ) -> Result<Option<BlockInfo>, L1OriginSelectorError> {
+        Ok(Provider::get_block_by_number(self, number.into()).await?.map(Into::into))
+    }
+}
+
+/// A wrapper around an [`L1OriginSelectorProvider`] that delays the view of the L1 chain by a
+/// configurable amount of blocks.
 #[derive(Debug)]
-pub struct DelayedL1OriginSelectorProvider {
-    /// The inner [`RootProvider`].
-    inner: RootProvider,
+pub struct DelayedL1OriginSelectorProvider<P = RootProvider> {
+    /// The inner [`L1OriginSelectorProvider`].
+    inner: P,
     /// The L1 head watch channel.
     l1_head: watch::Receiver<Option<BlockInfo>>,
     /// The confirmation depth to delay the view of the L1 chain.
     confirmation_depth: u64,
 }
 
-impl DelayedL1OriginSelectorProvider {
+impl<P> DelayedL1OriginSelectorProvider<P> {
     /// Creates a new [`DelayedL1OriginSelectorProvider`].
     pub const fn new(
-        inner: RootProvider,
+        inner: P,
         l1_head: watch::Receiver<Option<BlockInfo>>,
         confirmation_depth: u64,
     ) -> Self {
@@ -202,13 +231,15 @@ impl DelayedL1OriginSelectorProvider {
 }
 
 #[async_trait]
-impl L1OriginSelectorProvider for DelayedL1OriginSelectorProvider {
+impl<P: L1OriginSelectorProvider + Send + Sync> L1OriginSelectorProvider
+    for DelayedL1OriginSelectorProvider<P>
+{
     async fn get_block_by_hash(
         &self,
         hash: B256,
     ) -> Result<Option<BlockInfo>, L1OriginSelectorError> {
         // By-hash lookups are not delayed, as they're direct indexes.
-        Ok(Provider::get_block_by_hash(&self.inner, hash).await?.map(Into::into))
+        self.inner.get_block_by_hash(hash).await
     }
 
     async fn get_block_by_number(
@@ -217,16 +248,14 @@ impl L1OriginSelectorProvider for DelayedL1OriginSelectorProvider {
     ) -> Result<Option<BlockInfo>, L1OriginSelectorError> {
         let Some(l1_head) = *self.l1_head.borrow() else {
             // If the L1 head is not available, do not enforce a confirmation delay.
-            return Ok(Provider::get_block_by_number(&self.inner, number.into())
-                .await?
-                .map(Into::into));
+            return self.inner.get_block_by_number(number).await;
         };
 
         if number == 0 ||
             self.confirmation_depth == 0 ||
             number + self.confirmation_depth <= l1_head.number
         {
-            Ok(Provider::get_block_by_number(&self.inner, number.into()).await?.map(Into::into))
+            self.inner.get_block_by_number(number).await
         } else {
             Ok(None)
         }
@@ -236,6 +265,7 @@ impl L1OriginSelectorProvider for DelayedL1OriginSelectorProvider {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::L1OriginPolicyKind;
     use alloy_eips::NumHash;
     use rstest::rstest;
     use std::collections::HashSet;
@@ -469,4 +499,128 @@ mod test {
             assert!(matches!(next_err, L1OriginSelectorError::NotEnoughData(_)));
         }
     }
+
+    /// Builds a [`DelayedL1OriginSelectorProvider`] over three L1 blocks, spaced by 12 seconds,
+    /// with the given L1 head and a confirmation depth of 1.
+    fn delayed_provider(
+        l1_head: u64,
+    ) -> DelayedL1OriginSelectorProvider<MockOriginSelectorProvider> {
+        let mut provider = MockOriginSelectorProvider::default();
+        for i in 0..3u8 {
+            provider.with_block(BlockInfo {
+                parent_hash: B256::with_last_byte(i.saturating_sub(1)),
+                hash: B256::with_last_byte(i),
+                number: u64::from(i),
+                timestamp: u64::from(i) * 12,
+            });
+        }
+        let (_, l1_head_rx) =
+            watch::channel(Some(BlockInfo { number: l1_head, ..Default::default() }));
+        DelayedL1OriginSelectorProvider::new(provider, l1_head_rx, 1)
+    }
+
+    /// Returns an unsafe head at the given timestamp, with the genesis L1 block as origin.
+    fn unsafe_head(timestamp: u64) -> L2BlockInfo {
+        L2BlockInfo {
+            block_info: BlockInfo { number: timestamp / 2, timestamp, ..Default::default() },
+            l1_origin: NumHash { number: 0, hash: B256::ZERO },
+            seq_num: 0,
+        }
+    }
+
+    #[tokio::test]
+    #[rstest]
+    #[case::fastest_allowed(L1OriginPolicyKind::FastestAllowed, 12, 2, 1)]
+    #[case::fastest_allowed_unconfirmed(L1OriginPolicyKind::FastestAllowed, 12, 1, 0)]
+    #[case::conservative_within_half_drift(L1OriginPolicyKind::Conservative, 12, 2, 0)]
+    #[case::conservative_next_too_recent(L1OriginPolicyKind::Conservative, 300, 2, 0)]
+    #[case::conservative_past_half_drift(L1OriginPolicyKind::Conservative, 310, 2, 1)]
+    #[case::fixed_lag_reached(L1OriginPolicyKind::FixedLag(2), 12, 2, 1)]
+    #[case::fixed_lag_not_reached(L1OriginPolicyKind::FixedLag(4), 12, 2, 0)]
+    #[case::fixed_lag_past_drift(L1OriginPolicyKind::FixedLag(1000), 600, 2, 1)]
+    async fn test_next_l1_origin_policies(
+        #[case] policy: L1OriginPolicyKind,
+        #[case] unsafe_head_time: u64,
+        #[case] l1_head: u64,
+        #[case] expected_epoch: u64,
+    ) {
+        let cfg = Arc::new(RollupConfig {
+            block_time: 2,
+            max_sequencer_drift: 600,
+            ..Default::default()
+        });
+        let mut selector =
+            L1OriginSelector::new(cfg, delayed_provider(l1_head)).with_policy(policy);
+
+        let next = selector.next_l1_origin(unsafe_head(unsafe_head_time), false).await.unwrap();
+        assert_eq!(next.hash, B256::with_last_byte(expected_epoch as u8));
+        assert_eq!(next.number, expected_epoch);
+    }
+
+    #[tokio::test]
+    async fn test_next_l1_origin_conservative_bounded_lag() {
+        // Assume an L1 slot time of 12 seconds.
+        const L1_SLOT_TIME: u64 = 12;
+        const NUM_EPOCHS: u64 = 200;
+
+        let cfg = Arc::new(RollupConfig {
+            block_time: 2,
+            max_sequencer_drift: 600,
+            ..Default::default()
+        });
+        let mut provider = MockOriginSelectorProvider::default();
+        for i in 0..NUM_EPOCHS as u8 {
+            provider.with_block(BlockInfo {
+                parent_hash: B256::with_last_byte(i.saturating_sub(1)),
+                hash: B256::with_last_byte(i),
+                number: u64::from(i),
+                timestamp: u64::from(i) * L1_SLOT_TIME,
+            });
+        }
+        let mut selector = L1OriginSelector::new(cfg.clone(), provider)
+            .with_policy(L1OriginPolicyKind::Conservative);
+
+        // Sequence the L2 chain over all epochs, building every block on the selected origin.
+        let mut unsafe_head = L2BlockInfo::default();
+        for number in 0..(NUM_EPOCHS - 1) * L1_SLOT_TIME / cfg.block_time {
+            let origin = selector.next_l1_origin(unsafe_head, false).await.unwrap();
+            assert!(origin.number >= unsafe_head.l1_origin.number);
+
+            // The origin lags the L2 chain by at most half of the drift window plus an L1 slot,
+            // so that the sequencer drift is never reached.
+            let next_l2_time = unsafe_head.block_info.timestamp + cfg.block_time;
+            let lag = next_l2_time - origin.timestamp;
+            assert!(lag < cfg.max_sequencer_drift / 2 + L1_SLOT_TIME, "lag {lag} at {number}");
+
+            unsafe_head = L2BlockInfo {
+                block_info: BlockInfo {
+                    number: number + 1,
+                    timestamp: next_l2_time,
+                    ..Default::default()
+                },
+                l1_origin: origin.id(),
+                seq_num: 0,
+            };
+        }
+
+        // The origin kept advancing, half of the drift window behind the L2 chain.
+        let last_origin = unsafe_head.l1_origin.number * L1_SLOT_TIME;
+        assert!(unsafe_head.block_info.timestamp - last_origin >= cfg.max_sequencer_drift / 2);
+    }
+
+    #[tokio::test]
+    async fn test_next_l1_origin_recovery_mode_bypasses_policy() {
+        let cfg = Arc::new(RollupConfig {
+            block_time: 2,
+            max_sequencer_drift: 600,
+            ..Default::default()
+        });
+        let mut selector = L1OriginSelector::new(cfg, delayed_provider(2))
+            .with_policy(L1OriginPolicyKind::Conservative);
+
+        let next = selector.next_l1_origin(unsafe_head(12), false).await.unwrap();
+        assert_eq!(next.number, 0);
+        let next = selector.next_l1_origin(unsafe_head(12), true).await.unwrap();
+        assert_eq!(next.number, 1);
+    }
 }
@@ -3,10 +3,12 @@
 
 use kona_derive::AttributesBuilder;
 use kona_protocol::L2BlockInfo;
-use kona_rpc::SequencerAdminQuery;
-use tokio::sync::watch;
+use kona_rpc::{SequencerAdminQuery, SequencerAdminState};
+use tokio::sync::{oneshot, watch};
 
-use crate::actors::sequencer::actor::SequencerActorState;
+use crate::actors::sequencer::{
+    L1OriginSelectorProvider, actor::SequencerActorState, conductor::ConductorError,
+};
 
 /// Error type for sequencer RPC operations
 #[derive(Debug, thiserror::Error)]
@@ -16,9 +18,70 @@ pub(super) enum SequencerRpcError {
         "Failed to send response to admin query. The response channel was closed, this may mean that the rpc actor was shut down."
     )]
     SendResponse,
+    /// An error occurred while persisting the admin state. The state was left unchanged.
+    #[error("Failed to persist the admin state: {0}")]
+    Persist(#[from] std::io::Error),
+    /// The sequencer cannot take over block production from the conductor.
+    #[error("Cannot start the sequencer: {0}")]
+    Conductor(#[from] ConductorError),
 }
 
-impl<AB: AttributesBuilder> SequencerActorState<AB> {
+impl<AB: AttributesBuilder, P: L1OriginSelectorProvider> SequencerActorState<AB, P> {
+    /// Returns the current [`SequencerAdminState`].
+    pub(super) const fn admin_state(&self) -> SequencerAdminState {
+        SequencerAdminState {
+            sequencer_active: self.is_active,
+            last_stop_hash: self.last_stop_hash,
+            recover_mode: self.is_recovery_mode,
+            build_mode: self.build_mode,
+        }
+    }
+
+    /// Applies the given [`SequencerAdminState`], persisting it first if persistence is enabled.
+    pub(super) fn set_admin_state(
+        &mut self,
+        state: SequencerAdminState,
+    ) -> Result<(), SequencerRpcError> {
+        if let Some(persistence) = &self.admin_persistence {
+            persistence.write(&state)?;
+        }
+        self.is_active = state.sequencer_active;
+        self.last_stop_hash = state.last_stop_hash;
+        self.is_recovery_mode = state.recover_mode;
+        self.build_mode = state.build_mode;
+        Ok(())
+    }
+
+    /// Starts the sequencer.
+    ///
+    /// If the conductor is enabled, the sequencer is taking over block production after gaining
+    /// the leadership, and first confirms that its unsafe head matches the latest payload
+    /// committed to the conductor.
+    async fn start_sequencer(
+        &mut self,
+        unsafe_head: &watch::Receiver<L2BlockInfo>,
+    ) -> Result<(), SequencerRpcError> {
+        if let Some(conductor) = &self.conductor {
+            let hash = unsafe_head.borrow().hash();
+            conductor.confirm_leadership(hash).await?;
+        }
+
+        info!(target: "sequencer", "Starting sequencer");
+        self.set_admin_state(SequencerAdminState { sequencer_active: true, ..self.admin_state() })
+    }
+
+    /// Responds to an admin query with the result of the requested state change, logging the
+    /// reason if it failed.
+    fn respond<T>(
+        tx: oneshot::Sender<Result<T, String>>,
+        result: Result<T, SequencerRpcError>,
+    ) -> Result<(), SequencerRpcError> {
+        if let Err(err) = &result {
+            warn!(target: "sequencer", %err, "Failed to handle admin query");
+        }
+        tx.send(result.map_err(|err| err.to_string())).map_err(|_| SequencerRpcError::SendResponse)
+    }
+
     pub(super) async fn handle_admin_query(
         &mut self,
         query: SequencerAdminQuery,
@@ -28,23 +91,49 @@ impl<AB: AttributesBuilder> SequencerActorState<AB> {
             SequencerAdminQuery::SequencerActive(tx) => {
                 tx.send(self.is_active).map_err(|_| SequencerRpcError::SendResponse)?;
             }
-            SequencerAdminQuery::StartSequencer => {
-                info!(target: "sequencer", "Starting sequencer");
-                self.is_active = true;
+            SequencerAdminQuery::StartSequencer(tx) => {
+                let result = self.start_sequencer(unsafe_head).await;
+                Self::respond(tx, result)?;
             }
             SequencerAdminQuery::StopSequencer(tx) => {
                 info!(target: "sequencer", "Stopping sequencer");
-                self.is_active = false;
-
-                tx.send(unsafe_head.borrow().hash())
-                    .map_err(|_| SequencerRpcError::SendResponse)?;
+                // With the conductor enabled, report the last head committed to it, which the next
+                // leader resumes from.
+                let hash = self.last_committed_hash.unwrap_or_else(|| unsafe_head.borrow().hash());
+                let result = self
+                    .set_admin_state(SequencerAdminState {
+                        sequencer_active: false,
+                        last_stop_hash: Some(hash),
+                        ..self.admin_state()
+                    })
+                    .map(|_| hash);
+                Self::respond(tx, result)?;
             }
             SequencerAdminQuery::ConductorEnabled(tx) => {
                 tx.send(self.conductor.is_some()).map_err(|_| SequencerRpcError::SendResponse)?;
             }
-            SequencerAdminQuery::SetRecoveryMode(is_active) => {
-                self.is_recovery_mode = is_active;
-                info!(target: "sequencer", is_active, "Updated recovery mode");
+            SequencerAdminQuery::SetRecoveryMode(is_active, tx) => {
+                let result = self.set_admin_state(SequencerAdminState {
+                    recover_mode: is_active,
+                    ..self.admin_state()
+                });
+                if result.is_ok() {
+                    info!(target: "sequencer", is_active, "Updated recovery mode");
+                }
+                Self::respond(tx, result)?;
+            }
+            SequencerAdminQuery::SetBuildMode(mode, tx) => {
+                let result = self.set_admin_state(SequencerAdminState {
+                    build_mode: mode,
+                    ..self.admin_state()
+                });
+                if result.is_ok() {
+                    info!(target: "sequencer", %mode, "Updated build mode");
+                }
+                Self::respond(tx, result)?;
+            }
+            SequencerAdminQuery::BuildMode(tx) => {
+                tx.send(self.build_mode).map_err(|_| SequencerRpcError::SendResponse)?;
             }
             SequencerAdminQuery::OverrideLeader => {
                 if let Some(conductor) = self.conductor.as_mut() {
@@ -15,15 +15,17 @@ pub use service::{InteropMode, NodeMode, RollupNode, RollupNodeBuilder, RollupNo
 mod actors;
 pub use actors::{
     AttributesBuilderConfig, CancellableContext, ConductorClient, ConductorError,
-    DelayedL1OriginSelectorProvider, DerivationActor, DerivationBuilder, DerivationContext,
-    DerivationError, DerivationInboundChannels, DerivationState, EngineActor, EngineBuilder,
-    EngineContext, EngineError, EngineInboundData, InboundDerivationMessage, L1OriginSelector,
-    L1OriginSelectorError, L1OriginSelectorProvider, L1WatcherRpc, L1WatcherRpcContext,
-    L1WatcherRpcError, L1WatcherRpcInboundChannels, L1WatcherRpcState, L2Finalizer, NetworkActor,
-    NetworkActorError, NetworkBuilder, NetworkBuilderError, NetworkConfig, NetworkContext,
-    NetworkDriver, NetworkDriverError, NetworkHandler, NetworkInboundData, NodeActor,
-    PipelineBuilder, RpcActor, RpcActorError, RpcContext, SequencerActor, SequencerActorError,
-    SequencerBuilder, SequencerConfig, SequencerContext, SequencerInboundData,
+    ConservativePolicy, DelayedL1OriginSelectorProvider, DepositIndexer, DerivationActor,
+    DerivationBuilder, DerivationContext, DerivationError, DerivationInboundChannels,
+    DerivationState, EngineActor, EngineBuilder, EngineContext, EngineError, EngineInboundData,
+    FastestAllowedPolicy, FixedLagPolicy, InboundDerivationMessage, L1OriginPolicy,
+    L1OriginPolicyKind, L1OriginSelector, L1OriginSelectorError, L1OriginSelectorProvider,
+    L1WatcherRpc, L1WatcherRpcContext, L1WatcherRpcError, L1WatcherRpcInboundChannels,
+    L1WatcherRpcState, L2Finalizer, ManagedModeHandler, NetworkActor, NetworkActorError,
+    NetworkBuilder, NetworkBuilderError, NetworkConfig, NetworkContext, NetworkDriver,
+    NetworkDriverError, NetworkHandler, NetworkInboundData, NodeActor, PipelineBuilder, RpcActor,
+    RpcActorError, RpcContext, SequencerActor, SequencerActorError, SequencerBuilder,
+    SequencerConfig, SequencerContext, SequencerInboundData,
 };
 
 mod metrics;
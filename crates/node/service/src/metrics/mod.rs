@@ -17,6 +17,10 @@ impl Metrics {
     /// Identifier for the counter that tracks sequencer state flags.
     pub const SEQUENCER_STATE: &str = "kona_node_sequencer_state";
 
+    /// Gauge for the number of blocks the sequencer has left to build without the transaction
+    /// pool.
+    pub const SEQUENCER_NO_TX_POOL_BLOCKS_LEFT: &str = "kona_node_sequencer_no_tx_pool_blocks_left";
+
     /// Gauge for the sequencer's attributes builder duration.
     pub const SEQUENCER_ATTRIBUTES_BUILDER_DURATION: &str =
         "kona_node_sequencer_attributes_build_duration";
@@ -58,6 +62,12 @@ impl Metrics {
         // Sequencer state
         metrics::describe_counter!(Self::SEQUENCER_STATE, "Tracks sequencer state flags");
 
+        // Sequencer blocks left to build without the transaction pool
+        metrics::describe_gauge!(
+            Self::SEQUENCER_NO_TX_POOL_BLOCKS_LEFT,
+            "Blocks left to build without the transaction pool, 0 if the build mode has no block limit"
+        );
+
         // Sequencer attributes builder duration
         metrics::describe_gauge!(
             Self::SEQUENCER_ATTRIBUTES_BUILDER_DURATION,
@@ -115,6 +115,9 @@ pub trait RollupNodeService {
                 l1_head_updates_tx,
                 engine_l2_safe_head_tx,
                 el_sync_complete_tx,
+                batch_validity_reports,
+                system_config_history,
+                deposit_index,
             },
             derivation,
         ) = Self::DerivationActor::build(self.derivation_builder());
@@ -128,6 +131,8 @@ pub trait RollupNodeService {
                 reset_request_tx,
                 inbound_queries_tx: engine_rpc,
                 finalized_l1_block_tx,
+                managed_control_tx,
+                managed_events_tx,
             },
             engine,
         ) = Self::EngineActor::build(self.engine_builder());
@@ -164,17 +169,25 @@ pub trait RollupNodeService {
                         sequencer_admin: sequencer_inbound_data.as_ref().map(|s| s.admin_query_tx.clone()),
                         l1_watcher_queries: da_watcher_rpc,
                         engine_query: engine_rpc,
+                        batch_validity_reports,
+                        system_config_history,
+                        deposit_index,
+                        managed_control: managed_control_tx,
+                        managed_events: managed_events_tx.clone(),
+                        reset_request: reset_request_tx.clone(),
                     }
                 )),
                 sequencer.map(|s| (
                     s,
                     SequencerContext {
                         l1_head_rx: l1_head_updates_tx.subscribe(),
+                        safe_head_rx: engine_l2_safe_head_tx.subscribe(),
                         reset_request_tx: reset_request_tx.clone(),
                         build_request_tx: build_request_tx.expect(
                             "`build_request_tx` not set while in sequencer mode. This should never happen.",
                         ),
                         gossip_payload_tx,
+                        unsafe_block_tx: unsafe_block_tx.clone(),
                         cancellation: cancellation.clone(),
                     })
                 ),
@@ -196,6 +209,7 @@ pub trait RollupNodeService {
                     DerivationContext {
                         reset_request_tx: reset_request_tx.clone(),
                         derived_attributes_tx: attributes_tx,
+                        managed_events_tx,
                         cancellation: cancellation.clone(),
                 })),
                 Some((engine,
@@ -47,3 +47,15 @@ pub enum InteropMode {
     #[display("Indexed")]
     Indexed,
 }
+
+impl InteropMode {
+    /// Returns `true` if [`Self`] is [`Self::Polled`].
+    pub const fn is_polled(&self) -> bool {
+        matches!(self, Self::Polled)
+    }
+
+    /// Returns `true` if [`Self`] is [`Self::Indexed`].
+    pub const fn is_indexed(&self) -> bool {
+        matches!(self, Self::Indexed)
+    }
+}
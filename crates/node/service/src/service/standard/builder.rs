@@ -16,8 +16,9 @@ use tower::ServiceBuilder;
 use url::Url;
 
 use kona_genesis::RollupConfig;
+use kona_protocol::DecompressorRegistry;
 use kona_providers_alloy::OnlineBeaconClient;
-use kona_rpc::RpcBuilder;
+use kona_rpc::{DepositIndex, RpcBuilder, SystemConfigHistory};
 
 /// The [`RollupNodeBuilder`] is used to construct a [`RollupNode`] service.
 #[derive(Debug, Default)]
@@ -42,6 +43,12 @@ pub struct RollupNodeBuilder {
     mode: NodeMode,
     /// Whether to run the node in interop mode.
     interop_mode: InteropMode,
+    /// The [`SystemConfigHistory`] that records the system config updates.
+    system_config_history: SystemConfigHistory,
+    /// The [`DepositIndex`] that indexes the derived deposits, if enabled.
+    deposit_index: Option<DepositIndex>,
+    /// The [`DecompressorRegistry`] that the derivation pipeline decompresses channels with.
+    decompressors: DecompressorRegistry,
 }
 
 impl RollupNodeBuilder {
@@ -85,11 +92,37 @@ impl RollupNodeBuilder {
         Self { rpc_config, ..self }
     }
 
+    /// Sets the [`InteropMode`] on the [`RollupNodeBuilder`].
+    ///
+    /// In [`InteropMode::Indexed`], the node is managed by an interop supervisor through the
+    /// managed-mode RPC.
+    pub fn with_interop_mode(self, interop_mode: InteropMode) -> Self {
+        Self { interop_mode, ..self }
+    }
+
     /// Appends the [`SequencerConfig`] to the builder.
     pub fn with_sequencer_config(self, sequencer_config: SequencerConfig) -> Self {
         Self { sequencer_config: Some(sequencer_config), ..self }
     }
 
+    /// Sets the [`SystemConfigHistory`] that records the system config updates applied by the
+    /// derivation pipeline. Defaults to an in-memory history.
+    pub fn with_system_config_history(self, system_config_history: SystemConfigHistory) -> Self {
+        Self { system_config_history, ..self }
+    }
+
+    /// Sets the [`DepositIndex`] that indexes the deposits derived by the derivation pipeline.
+    /// Deposits are not indexed if `None`, which is the default.
+    pub fn with_deposit_index(self, deposit_index: Option<DepositIndex>) -> Self {
+        Self { deposit_index, ..self }
+    }
+
+    /// Sets the [`DecompressorRegistry`] that the derivation pipeline decompresses channels
+    /// with. Defaults to the built-in zlib and brotli decompressors.
+    pub fn with_decompressors(self, decompressors: DecompressorRegistry) -> Self {
+        Self { decompressors, ..self }
+    }
+
     /// Assembles the [`RollupNode`] service.
     ///
     /// ## Panics
@@ -127,10 +160,16 @@ impl RollupNodeBuilder {
             engine_url,
             jwt_secret,
             mode: self.mode,
+            interop_mode: self.interop_mode,
         };
 
         let p2p_config = self.p2p_config.expect("P2P config not set");
-        let sequencer_config = self.sequencer_config.unwrap_or_default();
+        let mut sequencer_config = self.sequencer_config.unwrap_or_default();
+        // The sequencer persists the state set through the admin API to the file configured on
+        // the RPC server.
+        sequencer_config.admin_persistence = sequencer_config
+            .admin_persistence
+            .or_else(|| self.rpc_config.as_ref().and_then(|rpc| rpc.admin_persistence.clone()));
 
         RollupNode {
             config: rollup_config,
@@ -142,6 +181,9 @@ impl RollupNodeBuilder {
             rpc_builder: self.rpc_config,
             p2p_config,
             sequencer_config,
+            system_config_history: self.system_config_history,
+            deposit_index: self.deposit_index,
+            decompressors: self.decompressors,
         }
     }
 }
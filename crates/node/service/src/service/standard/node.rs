@@ -12,10 +12,11 @@ use op_alloy_network::Optimism;
 use std::sync::Arc;
 
 use kona_genesis::RollupConfig;
+use kona_protocol::DecompressorRegistry;
 use kona_providers_alloy::{
     AlloyChainProvider, AlloyL2ChainProvider, OnlineBeaconClient, OnlinePipeline,
 };
-use kona_rpc::RpcBuilder;
+use kona_rpc::{DepositIndex, RpcBuilder, SystemConfigHistory};
 
 /// The standard implementation of the [RollupNode] service, using the governance approved OP Stack
 /// configuration of components.
@@ -39,6 +40,12 @@ pub struct RollupNode {
     pub(crate) p2p_config: NetworkConfig,
     /// The [`SequencerConfig`] for the node.
     pub(crate) sequencer_config: SequencerConfig,
+    /// The [`SystemConfigHistory`] for the node.
+    pub(crate) system_config_history: SystemConfigHistory,
+    /// The [`DepositIndex`] for the node, if enabled.
+    pub(crate) deposit_index: Option<DepositIndex>,
+    /// The [`DecompressorRegistry`] that the derivation pipeline decompresses channels with.
+    pub(crate) decompressors: DecompressorRegistry,
 }
 
 impl RollupNode {
@@ -98,6 +105,9 @@ impl RollupNodeService for RollupNode {
             l2_provider: self.l2_provider.clone(),
             rollup_config: self.config.clone(),
             interop_mode: self.interop_mode,
+            system_config_history: self.system_config_history.clone(),
+            deposit_index: self.deposit_index.clone(),
+            decompressors: self.decompressors.clone(),
         }
     }
 }
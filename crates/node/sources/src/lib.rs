@@ -22,5 +22,6 @@ pub use metrics::Metrics;
 mod signer;
 pub use signer::{
     BlockSigner, BlockSignerError, BlockSignerHandler, BlockSignerStartError, CertificateError,
-    ClientCert, RemoteSigner, RemoteSignerError, RemoteSignerHandler, RemoteSignerStartError,
+    ClientCert, KeystoreError, KeystorePassword, KeystoreSigner, RemoteSigner, RemoteSignerError,
+    RemoteSignerGroup, RemoteSignerGroupHandler, RemoteSignerHandler, RemoteSignerStartError,
 };
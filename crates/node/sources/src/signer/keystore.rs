@@ -0,0 +1,166 @@
+//! A block signer loaded from an encrypted Ethereum JSON keystore.
+
+use alloy_primitives::ChainId;
+use alloy_signer::Signer;
+use alloy_signer_local::PrivateKeySigner;
+use std::path::PathBuf;
+use thiserror::Error;
+use zeroize::Zeroizing;
+
+/// The source of the password unlocking a [`KeystoreSigner`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeystorePassword {
+    /// The password is read from a file. Trailing newlines are ignored.
+    File(PathBuf),
+    /// The password is read from the environment variable with the given name.
+    Env(String),
+}
+
+impl KeystorePassword {
+    /// Reads the password from its source.
+    fn read(&self) -> Result<Zeroizing<String>, KeystoreError> {
+        let password = match self {
+            Self::File(path) => {
+                Zeroizing::new(std::fs::read_to_string(path).map_err(KeystoreError::PasswordFile)?)
+            }
+            Self::Env(name) => Zeroizing::new(
+                std::env::var(name).map_err(|err| KeystoreError::PasswordEnv(name.clone(), err))?,
+            ),
+        };
+        Ok(Zeroizing::new(password.trim_end_matches(['\r', '\n']).to_string()))
+    }
+}
+
+/// A block signer whose private key is loaded from an encrypted Ethereum JSON keystore, using
+/// either the scrypt or the pbkdf2 key derivation function.
+///
+/// The password and the decrypted key are zeroed once the signer is unlocked, and the key held by
+/// the unlocked [`PrivateKeySigner`] is zeroed when it is dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeystoreSigner {
+    /// The path to the encrypted JSON keystore.
+    pub keystore: PathBuf,
+    /// The source of the password unlocking the keystore.
+    pub password: KeystorePassword,
+    /// The chain ID of the unlocked signer.
+    pub chain_id: Option<ChainId>,
+}
+
+/// Errors that can occur when unlocking a [`KeystoreSigner`].
+#[derive(Debug, Error)]
+pub enum KeystoreError {
+    /// Failed to read the password file.
+    #[error("Failed to read the keystore password file: {0}")]
+    PasswordFile(std::io::Error),
+    /// Failed to read the password environment variable.
+    #[error("Failed to read the keystore password from `{0}`: {1}")]
+    PasswordEnv(String, std::env::VarError),
+    /// Failed to decrypt the keystore.
+    #[error("Failed to decrypt the keystore: {0}")]
+    Decrypt(#[from] eth_keystore::KeystoreError),
+    /// The decrypted key is not a valid private key.
+    #[error("Invalid keystore private key: {0}")]
+    InvalidKey(#[from] alloy_signer::k256::ecdsa::Error),
+}
+
+impl KeystoreSigner {
+    /// Creates a new [`KeystoreSigner`].
+    pub const fn new(keystore: PathBuf, password: KeystorePassword) -> Self {
+        Self { keystore, password, chain_id: None }
+    }
+
+    /// Sets the chain ID of the unlocked signer.
+    pub const fn with_chain_id(mut self, chain_id: Option<ChainId>) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    /// Decrypts the keystore, returning the unlocked [`PrivateKeySigner`].
+    ///
+    /// The key derivation function is deliberately slow, so this blocks for up to a few seconds
+    /// and must not be called on an async runtime thread.
+    pub fn unlock(&self) -> Result<PrivateKeySigner, KeystoreError> {
+        let password = self.password.read()?;
+        let secret =
+            Zeroizing::new(eth_keystore::decrypt_key(&self.keystore, password.as_bytes())?);
+        let signer = PrivateKeySigner::from_slice(&secret)?.with_chain_id(self.chain_id);
+
+        info!(target: "signer", address = %signer.address(), "Unlocked keystore signer");
+        Ok(signer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256};
+
+    /// The pbkdf2 test vector of the Web3 Secret Storage Definition.
+    const PBKDF2_KEYSTORE: &str = r#"{
+        "crypto": {
+            "cipher": "aes-128-ctr",
+            "cipherparams": { "iv": "6087dab2f9fdbbfaddc31a909735c1e6" },
+            "ciphertext": "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+            "kdf": "pbkdf2",
+            "kdfparams": {
+                "c": 262144,
+                "dklen": 32,
+                "prf": "hmac-sha256",
+                "salt": "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+            },
+            "mac": "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+        },
+        "id": "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version": 3
+    }"#;
+
+    fn keystore_signer(dir: &tempfile::TempDir, password: &str) -> KeystoreSigner {
+        let keystore = dir.path().join("keystore.json");
+        std::fs::write(&keystore, PBKDF2_KEYSTORE).unwrap();
+        let password_file = dir.path().join("password.txt");
+        std::fs::write(&password_file, password).unwrap();
+        KeystoreSigner::new(keystore, KeystorePassword::File(password_file))
+    }
+
+    #[test]
+    fn test_unlock_keystore() {
+        let dir = tempfile::tempdir().unwrap();
+        let signer =
+            keystore_signer(&dir, "testpassword\n").with_chain_id(Some(10)).unlock().unwrap();
+        assert_eq!(signer.chain_id(), Some(10));
+        assert_eq!(
+            signer.to_bytes(),
+            b256!("0x7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d")
+        );
+        assert_eq!(signer.address(), address!("0x008aeeda4d805471df9b2a5b0f38a0c3bcba786b"));
+    }
+
+    #[tokio::test]
+    async fn test_start_keystore_signer() {
+        let dir = tempfile::tempdir().unwrap();
+        let signer = keystore_signer(&dir, "testpassword").with_chain_id(Some(10));
+        let handler = crate::BlockSigner::from(signer).start().await.unwrap();
+        let crate::BlockSignerHandler::Local(signer) = handler else {
+            panic!("expected a local signer, got {handler:?}");
+        };
+        assert_eq!(signer.address(), address!("0x008aeeda4d805471df9b2a5b0f38a0c3bcba786b"));
+        assert_eq!(signer.chain_id(), Some(10));
+    }
+
+    #[test]
+    fn test_unlock_keystore_wrong_password() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = keystore_signer(&dir, "wrongpassword").unlock().unwrap_err();
+        assert!(matches!(err, KeystoreError::Decrypt(_)));
+    }
+
+    #[test]
+    fn test_read_password() {
+        let dir = tempfile::tempdir().unwrap();
+        let signer = keystore_signer(&dir, "testpassword\r\n");
+        assert_eq!(signer.password.read().unwrap().as_str(), "testpassword");
+
+        let missing = KeystorePassword::Env("KONA_TEST_MISSING_KEYSTORE_PASSWORD".to_string());
+        assert!(matches!(missing.read(), Err(KeystoreError::PasswordEnv(..))));
+    }
+}
@@ -4,6 +4,8 @@
 //!
 //! 1. A local block signer that is used to sign blocks with a locally available private key.
 //! 2. A remote block signer that is used to sign blocks with a remote private key.
+//! 3. A group of remote block signers, failing over between them.
+//! 4. A local block signer whose private key is loaded from an encrypted JSON keystore.
 
 use alloy_primitives::{Address, ChainId};
 use alloy_signer::{Signature, SignerSync};
@@ -11,10 +13,13 @@ use derive_more::From;
 use op_alloy_rpc_types_engine::PayloadHash;
 use std::fmt::Debug;
 
+mod keystore;
+pub use keystore::{KeystoreError, KeystorePassword, KeystoreSigner};
+
 mod remote;
 pub use remote::{
-    CertificateError, ClientCert, RemoteSigner, RemoteSignerError, RemoteSignerHandler,
-    RemoteSignerStartError,
+    CertificateError, ClientCert, RemoteSigner, RemoteSignerError, RemoteSignerGroup,
+    RemoteSignerGroupHandler, RemoteSignerHandler, RemoteSignerStartError,
 };
 
 /// A builder for a block signer.
@@ -24,6 +29,10 @@ pub enum BlockSigner {
     Local(#[from] alloy_signer_local::PrivateKeySigner),
     /// A remote block signer that is used to sign blocks with a remote private key.
     Remote(#[from] RemoteSigner),
+    /// A group of remote block signers, failing over between them.
+    RemoteGroup(#[from] RemoteSignerGroup),
+    /// A local block signer whose private key is loaded from an encrypted JSON keystore.
+    Keystore(#[from] KeystoreSigner),
 }
 
 /// A handler for a block signer.
@@ -33,6 +42,8 @@ pub enum BlockSignerHandler {
     Local(alloy_signer_local::PrivateKeySigner),
     /// A remote block signer that is used to sign blocks with a remote private key.
     Remote(RemoteSignerHandler),
+    /// A group of remote block signers, failing over between them.
+    RemoteGroup(RemoteSignerGroupHandler),
 }
 
 /// Errors that can occur when starting a block signer.
@@ -44,6 +55,12 @@ pub enum BlockSignerStartError {
     /// An error that can occur when signing a block with a remote signer.
     #[error(transparent)]
     Remote(#[from] RemoteSignerStartError),
+    /// An error that can occur when unlocking a keystore signer.
+    #[error(transparent)]
+    Keystore(#[from] KeystoreError),
+    /// The task unlocking a keystore signer failed to complete.
+    #[error("Keystore unlocking task failed: {0}")]
+    KeystoreTask(#[from] tokio::task::JoinError),
 }
 
 /// Errors that can occur when signing a block.
@@ -63,6 +80,12 @@ impl BlockSigner {
         match self {
             Self::Local(signer) => Ok(BlockSignerHandler::Local(signer)),
             Self::Remote(signer) => Ok(BlockSignerHandler::Remote(signer.start().await?)),
+            Self::RemoteGroup(group) => Ok(BlockSignerHandler::RemoteGroup(group.start().await?)),
+            Self::Keystore(signer) => {
+                // The key derivation blocks, keep it off the runtime threads.
+                let signer = tokio::task::spawn_blocking(move || signer.unlock()).await??;
+                Ok(BlockSignerHandler::Local(signer))
+            }
         }
     }
 }
@@ -82,6 +105,9 @@ impl BlockSignerHandler {
             Self::Remote(signer) => {
                 signer.sign_block_v1(payload_hash, chain_id, sender_address).await?
             }
+            Self::RemoteGroup(group) => {
+                group.sign_block_v1(payload_hash, chain_id, sender_address).await?
+            }
         };
 
         Ok(signature)
@@ -1,4 +1,8 @@
-use std::{io::BufReader, sync::Arc};
+use std::{
+    io::BufReader,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use alloy_rpc_client::{ClientBuilder, RpcClient};
 use alloy_transport_http::Http;
@@ -96,17 +100,47 @@ impl RemoteSigner {
         }
     }
 
-    /// Starts a certificate watcher that monitors client certificate files and reloads the client
+    /// Returns the paths of the configured certificate files.
+    fn certificate_paths(&self) -> Vec<&Path> {
+        let client_cert = self.client_cert.iter().flat_map(|c| [c.cert.as_path(), c.key.as_path()]);
+        client_cert.chain(self.ca_cert.as_deref()).collect()
+    }
+
+    /// Returns whether the event affects one of the configured certificate files.
+    ///
+    /// Changes to the `..`-prefixed entries Kubernetes uses to swap the files of mounted secrets
+    /// are considered as well.
+    fn is_certificate_event(&self, event: &Event) -> bool {
+        let paths = self.certificate_paths();
+        event.paths.iter().filter_map(|path| path.file_name()).any(|name| {
+            name.to_string_lossy().starts_with("..") ||
+                paths.iter().any(|path| path.file_name() == Some(name))
+        })
+    }
+
+    /// Starts a certificate watcher that monitors the certificate files and reloads the client
     /// automatically when they are updated.
     ///
-    /// Returns `Ok(None)` if no client certificates are configured.
+    /// Returns `Ok(None)` if no certificates are configured.
     pub(super) async fn start_certificate_watcher(
         &self,
         client: Arc<RwLock<RpcClient>>,
     ) -> Result<Option<RecommendedWatcher>, notify::Error> {
-        let Some(ref client_cert) = self.client_cert.clone() else {
+        // Watch the directories containing the certificates rather than the files themselves, as
+        // the watch on a file is lost once it is replaced.
+        let mut dirs: Vec<PathBuf> = self
+            .certificate_paths()
+            .into_iter()
+            .map(|path| match path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+                _ => PathBuf::from("."),
+            })
+            .collect();
+        if dirs.is_empty() {
             return Ok(None);
-        };
+        }
+        dirs.sort();
+        dirs.dedup();
 
         // Clone the builder to avoid borrowing issues
         let builder = self.clone();
@@ -116,8 +150,9 @@ impl RemoteSigner {
 
         tracing::info!(target: "signer", "Starting certificate watcher for automatic TLS reload");
 
-        watcher.watch(&client_cert.cert, RecursiveMode::NonRecursive)?;
-        watcher.watch(&client_cert.key, RecursiveMode::NonRecursive)?;
+        for dir in dirs {
+            watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+        }
 
         Ok(Some(watcher))
     }
@@ -132,7 +167,9 @@ impl RemoteSigner {
         res: Result<Event, notify::Error>,
     ) {
         match res {
-            Ok(Event { kind: EventKind::Modify(_), .. }) => {
+            Ok(event @ Event { kind: EventKind::Modify(_) | EventKind::Create(_), .. })
+                if self.is_certificate_event(&event) =>
+            {
                 tracing::debug!(
                     target: "signer:certificate-watcher",
                     "Certificate file changed, reloading TLS configuration"
@@ -155,7 +192,7 @@ impl RemoteSigner {
                 }
             }
             Ok(event) => {
-                tracing::trace!(target: "signer:certificate-watcher", event = ?event, "Ignoring non-modify event.");
+                tracing::trace!(target: "signer:certificate-watcher", event = ?event, "Ignoring non-certificate event.");
             }
             Err(e) => {
                 tracing::error!(target: "signer:certificate-watcher", error = %e, "Failed to receive event from watcher channel.");
@@ -163,3 +200,54 @@ impl RemoteSigner {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{CreateKind, ModifyKind};
+    use reqwest::header::HeaderMap;
+
+    fn signer() -> RemoteSigner {
+        RemoteSigner {
+            endpoint: "http://localhost:8080".parse().unwrap(),
+            address: Default::default(),
+            client_cert: Some(ClientCert {
+                cert: PathBuf::from("/certs/tls.crt"),
+                key: PathBuf::from("/certs/tls.key"),
+            }),
+            ca_cert: Some(PathBuf::from("/ca/ca.crt")),
+            headers: HeaderMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_is_certificate_event() {
+        let signer = signer();
+        let event = |path: &str| {
+            Event::new(EventKind::Modify(ModifyKind::Any)).add_path(PathBuf::from(path))
+        };
+
+        assert!(signer.is_certificate_event(&event("/certs/tls.crt")));
+        assert!(signer.is_certificate_event(&event("/certs/tls.key")));
+        assert!(signer.is_certificate_event(&event("/ca/ca.crt")));
+        assert!(
+            signer.is_certificate_event(
+                &Event::new(EventKind::Create(CreateKind::Any))
+                    .add_path(PathBuf::from("/certs/..data"))
+            )
+        );
+        assert!(!signer.is_certificate_event(&event("/certs/other.pem")));
+    }
+
+    #[test]
+    fn test_certificate_paths() {
+        let signer = signer();
+        assert_eq!(
+            signer.certificate_paths(),
+            [Path::new("/certs/tls.crt"), Path::new("/certs/tls.key"), Path::new("/ca/ca.crt")]
+        );
+
+        let signer = RemoteSigner { client_cert: None, ca_cert: None, ..signer };
+        assert!(signer.certificate_paths().is_empty());
+    }
+}
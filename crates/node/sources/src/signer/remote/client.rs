@@ -56,6 +56,12 @@ pub enum RemoteSignerStartError {
     /// Certificate watcher error
     #[error("Certificate watcher error: {0}")]
     CertificateWatcher(#[from] notify::Error),
+    /// No remote signer was configured.
+    #[error("No remote signer configured")]
+    NoSigners,
+    /// None of the remote signers is reachable.
+    #[error("None of the remote signers is reachable")]
+    NoHealthySigner,
 }
 
 impl RemoteSigner {
@@ -73,7 +79,9 @@ impl RemoteSigner {
     /// - Client private key file (if mTLS is configured)
     /// - CA certificate file (if custom CA is configured)
     ///
-    /// When any of these files are modified, the watcher will:
+    /// The directories containing these files are watched, so that files replaced atomically, or
+    /// through a symlink swap as done for Kubernetes secrets, are picked up as well. When any of
+    /// these files are modified, the watcher will:
     /// 1. Log the certificate change event
     /// 2. Reload the certificate files from disk
     /// 3. Rebuild the HTTP client with the new TLS configuration
@@ -81,19 +89,33 @@ impl RemoteSigner {
     ///
     /// This enables zero-downtime certificate rotation in production environments.
     pub async fn start(self) -> Result<RemoteSignerHandler, RemoteSignerStartError> {
-        let http_client = self.build_http_client()?;
-        let transport = Http::with_client(http_client, self.endpoint.clone());
-        let client = ClientBuilder::default().transport(transport, true);
+        let handler = self.connect().await?;
 
         // Try to ping the signer to check if it's reachable
-        let version: String =
-            client.request("health_status", ()).await.map_err(RemoteSignerStartError::Ping)?;
+        let version: String = handler
+            .client
+            .read()
+            .await
+            .request("health_status", ())
+            .await
+            .map_err(RemoteSignerStartError::Ping)?;
 
         tracing::info!(target: "signer", version, "Connected to op-signer server");
 
+        Ok(handler)
+    }
+
+    /// Creates the client of the remote signer, without checking that the signer is reachable.
+    ///
+    /// Like [`Self::start`], this starts the certificate watcher if certificates are configured.
+    pub(super) async fn connect(self) -> Result<RemoteSignerHandler, RemoteSignerStartError> {
+        let http_client = self.build_http_client()?;
+        let transport = Http::with_client(http_client, self.endpoint.clone());
+        let client = ClientBuilder::default().transport(transport, true);
+
         let client = Arc::new(RwLock::new(client));
 
-        // Start certificate watcher if client certificates are configured
+        // Start certificate watcher if certificates are configured
         let watcher_handle = self.start_certificate_watcher(client.clone()).await?;
 
         Ok(RemoteSignerHandler { client, watcher_handle, address: self.address })
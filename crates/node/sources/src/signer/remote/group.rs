@@ -0,0 +1,413 @@
+//! Failover between an ordered list of [`RemoteSigner`]s.
+
+use alloy_primitives::{Address, ChainId};
+use alloy_signer::Signature;
+use op_alloy_rpc_types_engine::PayloadHash;
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+use tokio::{
+    task::{JoinHandle, JoinSet},
+    time::MissedTickBehavior,
+};
+use url::Url;
+
+use crate::{RemoteSigner, RemoteSignerError, RemoteSignerHandler, RemoteSignerStartError};
+
+/// The default interval between two health probes of the remote signers.
+const DEFAULT_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The default time a remote signer has to answer a health probe or a signing request.
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// An ordered list of [`RemoteSigner`]s to fail over between.
+///
+/// Blocks are signed by the first healthy signer whose address matches the unsafe block signer
+/// address at the time of signing. Configuring signers for both the current and the next unsafe
+/// block signer address lets the node follow a signer rotation in the system config without a
+/// restart.
+///
+/// The health of every signer is probed periodically and concurrently in the background, and a
+/// signer that fails to sign a block, or does not answer within the request timeout, is considered
+/// unhealthy until its next successful probe.
+#[derive(Debug, Clone)]
+pub struct RemoteSignerGroup {
+    /// The remote signers, in order of preference.
+    pub signers: Vec<RemoteSigner>,
+    /// The interval between two health probes of the remote signers.
+    pub health_check_interval: Duration,
+    /// The time a remote signer has to answer a health probe or a signing request.
+    pub request_timeout: Duration,
+}
+
+impl RemoteSignerGroup {
+    /// Creates a new [`RemoteSignerGroup`] from the remote signers, in order of preference.
+    pub const fn new(signers: Vec<RemoteSigner>) -> Self {
+        Self {
+            signers,
+            health_check_interval: DEFAULT_HEALTH_CHECK_INTERVAL,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+        }
+    }
+
+    /// Sets the interval between two health probes of the remote signers.
+    pub const fn with_health_check_interval(mut self, interval: Duration) -> Self {
+        self.health_check_interval = interval;
+        self
+    }
+
+    /// Sets the time a remote signer has to answer a health probe or a signing request.
+    pub const fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// Starts the remote signers and their background health probes.
+    ///
+    /// Unreachable signers are started as unhealthy, but at least one of the signers must be
+    /// reachable.
+    pub async fn start(self) -> Result<RemoteSignerGroupHandler, RemoteSignerStartError> {
+        if self.signers.is_empty() {
+            return Err(RemoteSignerStartError::NoSigners);
+        }
+
+        let mut members = Vec::with_capacity(self.signers.len());
+        for signer in self.signers {
+            let endpoint = signer.endpoint.clone();
+            let handler = signer.connect().await?;
+            members.push(RemoteSignerMember {
+                endpoint,
+                handler,
+                healthy: AtomicBool::new(false),
+                timeout: self.request_timeout,
+            });
+        }
+        let members: Arc<[RemoteSignerMember]> = members.into();
+
+        // Probe the signers concurrently, so that unreachable signers do not delay the startup.
+        let mut probes = JoinSet::new();
+        for index in 0..members.len() {
+            let members = members.clone();
+            probes.spawn(async move {
+                let member = &members[index];
+                match member.health_status().await {
+                    Ok(version) => {
+                        info!(target: "signer", endpoint = %member.endpoint, version, "Connected to op-signer server");
+                        member.healthy.store(true, Ordering::Relaxed);
+                    }
+                    Err(err) => {
+                        warn!(target: "signer", endpoint = %member.endpoint, %err, "Remote signer is unreachable");
+                    }
+                }
+            });
+        }
+        probes.join_all().await;
+
+        if !members.iter().any(RemoteSignerMember::is_healthy) {
+            return Err(RemoteSignerStartError::NoHealthySigner);
+        }
+
+        let health_task = tokio::spawn(probe_health(members.clone(), self.health_check_interval));
+
+        Ok(RemoteSignerGroupHandler { members, health_task })
+    }
+}
+
+/// A started remote signer of a [`RemoteSignerGroupHandler`].
+#[derive(Debug)]
+struct RemoteSignerMember {
+    /// The endpoint of the remote signer.
+    endpoint: Url,
+    /// The handler of the remote signer.
+    handler: RemoteSignerHandler,
+    /// Whether the remote signer is healthy.
+    healthy: AtomicBool,
+    /// The time the remote signer has to answer a request.
+    timeout: Duration,
+}
+
+impl RemoteSignerMember {
+    /// Returns the version of the remote signer, failing if it does not answer in time.
+    async fn health_status(&self) -> Result<String, RemoteSignerError> {
+        tokio::time::timeout(self.timeout, self.handler.health_status())
+            .await
+            .map_err(|_| RemoteSignerError::Timeout(self.timeout))?
+    }
+
+    /// Signs a block payload hash, failing if the remote signer does not answer in time.
+    async fn sign_block_v1(
+        &self,
+        payload_hash: PayloadHash,
+        chain_id: ChainId,
+        sender_address: Address,
+    ) -> Result<Signature, RemoteSignerError> {
+        tokio::time::timeout(
+            self.timeout,
+            self.handler.sign_block_v1(payload_hash, chain_id, sender_address),
+        )
+        .await
+        .map_err(|_| RemoteSignerError::Timeout(self.timeout))?
+    }
+
+    /// Returns whether the remote signer is healthy.
+    fn is_healthy(&self) -> bool {
+        self.healthy.load(Ordering::Relaxed)
+    }
+
+    /// Updates the health of the remote signer, logging transitions.
+    fn set_healthy(&self, healthy: bool) {
+        if self.healthy.swap(healthy, Ordering::Relaxed) == healthy {
+            return;
+        }
+        if healthy {
+            info!(target: "signer", endpoint = %self.endpoint, "Remote signer is healthy again");
+        } else {
+            warn!(target: "signer", endpoint = %self.endpoint, "Remote signer is unhealthy");
+        }
+    }
+}
+
+/// Probes the health of the remote signers concurrently at every interval.
+async fn probe_health(members: Arc<[RemoteSignerMember]>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    loop {
+        ticker.tick().await;
+        let mut probes = JoinSet::new();
+        for index in 0..members.len() {
+            let members = members.clone();
+            probes.spawn(async move {
+                let member = &members[index];
+                let result = member.health_status().await;
+                if let Err(err) = &result {
+                    debug!(target: "signer", endpoint = %member.endpoint, %err, "Health probe failed");
+                }
+                member.set_healthy(result.is_ok());
+            });
+        }
+        probes.join_all().await;
+    }
+}
+
+/// The handler of a started [`RemoteSignerGroup`].
+///
+/// Dropping the handler stops the background health probes.
+#[derive(Debug)]
+pub struct RemoteSignerGroupHandler {
+    /// The remote signers, in order of preference.
+    members: Arc<[RemoteSignerMember]>,
+    /// The handle of the background health probes.
+    health_task: JoinHandle<()>,
+}
+
+impl Drop for RemoteSignerGroupHandler {
+    fn drop(&mut self) {
+        self.health_task.abort();
+    }
+}
+
+impl RemoteSignerGroupHandler {
+    /// Returns the endpoint and the health of every remote signer, in order of preference.
+    pub fn health(&self) -> Vec<(Url, bool)> {
+        self.members.iter().map(|m| (m.endpoint.clone(), m.is_healthy())).collect()
+    }
+
+    /// Signs a block payload hash with the remote signers of the `sender_address`.
+    ///
+    /// The healthy signers are tried first, in order of preference, before falling back to the
+    /// unhealthy ones in case their last health probe is outdated. The error of the last signer is
+    /// returned if none of them succeeds.
+    pub async fn sign_block_v1(
+        &self,
+        payload_hash: PayloadHash,
+        chain_id: ChainId,
+        sender_address: Address,
+    ) -> Result<Signature, RemoteSignerError> {
+        let mut candidates: Vec<&RemoteSignerMember> =
+            self.members.iter().filter(|m| m.handler.address() == sender_address).collect();
+        candidates.sort_by_key(|m| !m.is_healthy());
+
+        let mut last_err = RemoteSignerError::NoSignerForAddress(sender_address);
+        for member in candidates {
+            match member.sign_block_v1(payload_hash, chain_id, sender_address).await {
+                Ok(signature) => {
+                    member.set_healthy(true);
+                    return Ok(signature);
+                }
+                Err(err) => {
+                    warn!(
+                        target: "signer",
+                        endpoint = %member.endpoint,
+                        %err,
+                        "Failed to sign block, failing over"
+                    );
+                    member.set_healthy(false);
+                    last_err = err;
+                }
+            }
+        }
+
+        Err(last_err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use alloy_signer::SignerSync;
+    use alloy_signer_local::PrivateKeySigner;
+    use jsonrpsee::{
+        RpcModule,
+        core::RpcResult,
+        server::{Server, ServerHandle},
+    };
+    use reqwest::header::HeaderMap;
+
+    /// Spawns a mock op-signer server signing with the given key.
+    async fn spawn_signer(key: PrivateKeySigner) -> (RemoteSigner, ServerHandle) {
+        spawn_slow_signer(key, Duration::ZERO).await
+    }
+
+    /// Spawns a mock op-signer server signing with the given key, after the given delay.
+    async fn spawn_slow_signer(
+        key: PrivateKeySigner,
+        delay: Duration,
+    ) -> (RemoteSigner, ServerHandle) {
+        let address = key.address();
+        let mut module = RpcModule::new(key);
+        module.register_method("health_status", |_, _, _| RpcResult::Ok("v1")).unwrap();
+        module
+            .register_async_method("opsigner_signBlockPayload", move |params, key, _| async move {
+                tokio::time::sleep(delay).await;
+                let args: serde_json::Value = params.one()?;
+                let payload_hash: B256 = serde_json::from_value(args["payloadHash"].clone())
+                    .map_err(|_| jsonrpsee::types::ErrorCode::InvalidParams)?;
+                let chain_id = args["chainId"].as_u64().unwrap_or_default();
+                let message = PayloadHash(payload_hash).signature_message(chain_id);
+                let signature = key.sign_hash_sync(&message).unwrap();
+                let signature = alloy_primitives::hex::encode_prefixed(signature.as_bytes());
+                RpcResult::Ok(serde_json::json!({ "signature": signature }))
+            })
+            .unwrap();
+
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", server.local_addr().unwrap()).parse().unwrap();
+        let handle = server.start(module);
+        let signer = RemoteSigner {
+            endpoint,
+            address,
+            client_cert: None,
+            ca_cert: None,
+            headers: HeaderMap::new(),
+        };
+        (signer, handle)
+    }
+
+    /// Returns a [`RemoteSigner`] for an endpoint that is not reachable.
+    fn unreachable_signer(address: Address) -> RemoteSigner {
+        RemoteSigner {
+            endpoint: "http://127.0.0.1:1".parse().unwrap(),
+            address,
+            client_cert: None,
+            ca_cert: None,
+            headers: HeaderMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_start_requires_healthy_signer() {
+        let err = RemoteSignerGroup::new(vec![]).start().await.unwrap_err();
+        assert!(matches!(err, RemoteSignerStartError::NoSigners));
+
+        let group = RemoteSignerGroup::new(vec![unreachable_signer(Address::ZERO)]);
+        let err = group.start().await.unwrap_err();
+        assert!(matches!(err, RemoteSignerStartError::NoHealthySigner));
+    }
+
+    #[tokio::test]
+    async fn test_sign_fails_over_to_healthy_signer() {
+        let key = PrivateKeySigner::random();
+        let address = key.address();
+        let (signer, _handle) = spawn_signer(key).await;
+
+        let group = RemoteSignerGroup::new(vec![unreachable_signer(address), signer]);
+        let handler = group.start().await.unwrap();
+        assert_eq!(handler.health().iter().map(|(_, h)| *h).collect::<Vec<_>>(), [false, true]);
+
+        let payload_hash = PayloadHash(B256::with_last_byte(1));
+        let signature = handler.sign_block_v1(payload_hash, 10, address).await.unwrap();
+        let recovered =
+            signature.recover_address_from_prehash(&payload_hash.signature_message(10)).unwrap();
+        assert_eq!(recovered, address);
+    }
+
+    #[tokio::test]
+    async fn test_sign_follows_signer_rotation() {
+        let (current_key, next_key) = (PrivateKeySigner::random(), PrivateKeySigner::random());
+        let (current, next) = (current_key.address(), next_key.address());
+        let (current_signer, _current_handle) = spawn_signer(current_key).await;
+        let (next_signer, _next_handle) = spawn_signer(next_key).await;
+
+        let handler =
+            RemoteSignerGroup::new(vec![current_signer, next_signer]).start().await.unwrap();
+
+        let payload_hash = PayloadHash(B256::with_last_byte(1));
+        let message = payload_hash.signature_message(10);
+        for address in [current, next] {
+            let signature = handler.sign_block_v1(payload_hash, 10, address).await.unwrap();
+            assert_eq!(signature.recover_address_from_prehash(&message).unwrap(), address);
+        }
+
+        let err = handler.sign_block_v1(payload_hash, 10, Address::ZERO).await.unwrap_err();
+        assert!(matches!(err, RemoteSignerError::NoSignerForAddress(Address::ZERO)));
+    }
+
+    #[tokio::test]
+    async fn test_health_probe_marks_signer_unhealthy() {
+        let key = PrivateKeySigner::random();
+        let (signer, handle) = spawn_signer(key).await;
+
+        let handler = RemoteSignerGroup::new(vec![signer])
+            .with_health_check_interval(Duration::from_millis(10))
+            .start()
+            .await
+            .unwrap();
+        assert!(handler.health()[0].1);
+
+        handle.stop().unwrap();
+        handle.stopped().await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!handler.health()[0].1);
+    }
+
+    #[tokio::test]
+    async fn test_sign_times_out_and_fails_over() {
+        let key = PrivateKeySigner::random();
+        let address = key.address();
+        let (slow_signer, _slow_handle) =
+            spawn_slow_signer(key.clone(), Duration::from_secs(10)).await;
+        let (signer, _handle) = spawn_signer(key).await;
+
+        let handler = RemoteSignerGroup::new(vec![slow_signer, signer])
+            .with_request_timeout(Duration::from_millis(100))
+            .start()
+            .await
+            .unwrap();
+        assert_eq!(handler.health().iter().map(|(_, h)| *h).collect::<Vec<_>>(), [true, true]);
+
+        // The slow signer does not answer in time, so the block is signed by the next one.
+        let payload_hash = PayloadHash(B256::with_last_byte(1));
+        let start = std::time::Instant::now();
+        let signature = handler.sign_block_v1(payload_hash, 10, address).await.unwrap();
+        assert!(start.elapsed() < Duration::from_secs(5));
+        let recovered =
+            signature.recover_address_from_prehash(&payload_hash.signature_message(10)).unwrap();
+        assert_eq!(recovered, address);
+        assert_eq!(handler.health().iter().map(|(_, h)| *h).collect::<Vec<_>>(), [false, true]);
+    }
+}
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use alloy_primitives::{Address, B256, ChainId, SignatureError};
 use alloy_rpc_client::RpcClient;
@@ -67,6 +67,12 @@ pub enum RemoteSignerError {
         /// The remote signer address.
         remote_signer: Address,
     },
+    /// No remote signer is configured for the unsafe block signer address.
+    #[error("No remote signer configured for the unsafe block signer address {0}")]
+    NoSignerForAddress(Address),
+    /// The remote signer did not respond in time.
+    #[error("Remote signer did not respond within {0:?}")]
+    Timeout(Duration),
 }
 
 impl RemoteSignerHandler {
@@ -75,6 +81,21 @@ impl RemoteSignerHandler {
         self.watcher_handle.is_some()
     }
 
+    /// Returns the address of the signer.
+    pub const fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Probes the health of the remote signer, returning its version.
+    pub async fn health_status(&self) -> Result<String, RemoteSignerError> {
+        self.client
+            .read()
+            .await
+            .request("health_status", ())
+            .await
+            .map_err(RemoteSignerError::PingError)
+    }
+
     /// Signs a block payload hash using the remote signer via JSON-RPC
     pub async fn sign_block_v1(
         &self,
@@ -3,5 +3,8 @@ pub use cert::{CertificateError, ClientCert};
 mod client;
 pub use client::{RemoteSigner, RemoteSignerStartError};
 
+mod group;
+pub use group::{RemoteSignerGroup, RemoteSignerGroupHandler};
+
 mod handler;
 pub use handler::{RemoteSignerError, RemoteSignerHandler};
@@ -13,7 +13,7 @@ use alloy_primitives::{B256, Sealable, U256, logs_bloom};
 use alloy_trie::EMPTY_ROOT_HASH;
 use kona_genesis::RollupConfig;
 use kona_mpt::{TrieHinter, ordered_trie_with_encoder};
-use kona_protocol::{OutputRoot, Predeploys};
+use kona_protocol::{OutputRoot, Predeploys, VersionedOutputRoot};
 use op_alloy_consensus::OpReceiptEnvelope;
 use op_alloy_rpc_types_engine::OpPayloadAttributes;
 use revm::{context::BlockEnv, database::BundleState};
@@ -131,9 +131,12 @@ where
         let parent_header = self.trie_db.parent_block_header();
 
         // Construct the raw output and hash it.
-        let output_root_hash =
-            OutputRoot::from_parts(parent_header.state_root, storage_root, parent_header.seal())
-                .hash();
+        let output_root_hash = VersionedOutputRoot::from(OutputRoot::from_parts(
+            parent_header.state_root,
+            storage_root,
+            parent_header.seal(),
+        ))
+        .hash()?;
 
         info!(
             target: "block_builder",
@@ -177,6 +177,12 @@ pub enum ExecutorError {
     /// - Incorrect executor lifecycle management
     #[error("Missing the executor")]
     MissingExecutor,
+    /// Output root encoding error.
+    ///
+    /// This error occurs when the output root of the executed block cannot
+    /// be encoded with the codec of its version.
+    #[error("Output root error: {0}")]
+    OutputRoot(#[from] kona_protocol::OutputRootError),
 }
 
 /// Result type alias for operations that may fail with [`ExecutorError`].
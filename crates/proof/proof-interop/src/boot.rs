@@ -4,8 +4,8 @@
 use crate::{HintType, INVALID_TRANSITION, INVALID_TRANSITION_HASH, PreState};
 use alloc::{string::ToString, vec::Vec};
 use alloy_primitives::{B256, Bytes, U256};
-use alloy_rlp::Decodable;
 use kona_genesis::RollupConfig;
+use kona_interop::SuperRootRegistry;
 use kona_preimage::{
     CommsClient, HintWriterClient, PreimageKey, PreimageKeyType, PreimageOracleClient,
     errors::PreimageOracleError,
@@ -104,7 +104,7 @@ impl BootInfo {
         }
 
         let agreed_pre_state =
-            PreState::decode(&mut raw_pre_state.as_ref()).map_err(OracleProviderError::Rlp)?;
+            PreState::decode_with_registry(&SuperRootRegistry::new(), &mut raw_pre_state.as_ref())?;
 
         let chain_ids: Vec<_> = match agreed_pre_state {
             PreState::SuperRoot(ref super_root) => {
@@ -166,6 +166,9 @@ pub enum BootstrapError {
     /// The pre-state is invalid and the post-state claim is also invalid.
     #[error("No-op state transition detected; both pre and post states are `INVALID`.")]
     InvalidToInvalid,
+    /// The agreed pre-state could not be decoded.
+    #[error(transparent)]
+    PreState(#[from] PreStateError),
 }
 
 /// Reads the raw pre-state from the preimage oracle.
@@ -3,8 +3,11 @@
 use alloc::vec::Vec;
 use alloy_primitives::{B256, Bytes, b256, keccak256};
 use alloy_rlp::{Buf, Decodable, Encodable, Header, RlpDecodable, RlpEncodable};
-use kona_interop::{OutputRootWithChain, SUPER_ROOT_VERSION, SuperRoot};
+use kona_interop::{
+    OutputRootWithChain, SuperRoot, SuperRootError, SuperRootRegistry, VersionedSuperRoot,
+};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// The current [TransitionState] encoding format version.
 pub(crate) const TRANSITION_STATE_VERSION: u8 = 255;
@@ -125,27 +128,63 @@ impl Encodable for PreState {
     }
 }
 
-impl Decodable for PreState {
-    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+impl PreState {
+    /// Decodes a [PreState], decoding super roots with the codec registered in `registry` for
+    /// their version byte.
+    ///
+    /// Returns [PreStateError::SuperRoot] with [SuperRootError::UnknownVersion] if no codec is
+    /// registered for the version byte, and [PreStateError::UnsupportedSuperRoot] if the codec
+    /// decodes a super root version that the program cannot transition from.
+    pub fn decode_with_registry(
+        registry: &SuperRootRegistry,
+        buf: &mut &[u8],
+    ) -> Result<Self, PreStateError> {
         if buf.is_empty() {
-            return Err(alloy_rlp::Error::UnexpectedLength);
+            return Err(PreStateError::Rlp(alloy_rlp::Error::UnexpectedLength));
         }
 
-        match buf[0] {
-            TRANSITION_STATE_VERSION => {
-                let transition_state = TransitionState::decode(buf)?;
-                Ok(Self::TransitionState(transition_state))
+        if buf[0] == TRANSITION_STATE_VERSION {
+            let transition_state = TransitionState::decode(buf)?;
+            return Ok(Self::TransitionState(transition_state));
+        }
+
+        // Any other version byte is a super root version, decoded by the codec registered for it.
+        match registry.decode(buf)? {
+            VersionedSuperRoot::V1(super_root) => Ok(Self::SuperRoot(super_root)),
+            other => Err(PreStateError::UnsupportedSuperRoot(other.version())),
+        }
+    }
+}
+
+impl Decodable for PreState {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        Self::decode_with_registry(&SuperRootRegistry::new(), buf).map_err(|e| match e {
+            PreStateError::Rlp(e) => e,
+            PreStateError::SuperRoot(SuperRootError::UnknownVersion(_)) => {
+                alloy_rlp::Error::Custom("unknown super root version")
             }
-            SUPER_ROOT_VERSION => {
-                let super_root =
-                    SuperRoot::decode(buf).map_err(|_| alloy_rlp::Error::UnexpectedString)?;
-                Ok(Self::SuperRoot(super_root))
+            PreStateError::SuperRoot(_) => alloy_rlp::Error::UnexpectedString,
+            PreStateError::UnsupportedSuperRoot(_) => {
+                alloy_rlp::Error::Custom("unsupported super root version")
             }
-            _ => Err(alloy_rlp::Error::Custom("invalid version byte")),
-        }
+        })
     }
 }
 
+/// An error that occurred while decoding a [PreState].
+#[derive(Debug, Error)]
+pub enum PreStateError {
+    /// The transition state could not be RLP decoded.
+    #[error("Failed to decode transition state: {0}")]
+    Rlp(#[from] alloy_rlp::Error),
+    /// The super root could not be decoded by the registry.
+    #[error("Failed to decode super root: {0}")]
+    SuperRoot(#[from] SuperRootError),
+    /// The super root was decoded, but the program cannot transition from its version.
+    #[error("Unsupported super root version: {0}")]
+    UnsupportedSuperRoot(u8),
+}
+
 /// The [TransitionState] is a super-structure of the [SuperRoot] that represents the progress of a
 /// pending superchain state transition from one [SuperRoot] to the next.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -255,11 +294,34 @@ impl OptimisticBlock {
 
 #[cfg(test)]
 mod test {
-    use super::{OptimisticBlock, SuperRoot, TransitionState};
+    use super::{OptimisticBlock, PreState, PreStateError, SuperRoot, TransitionState};
     use alloc::{vec, vec::Vec};
     use alloy_primitives::B256;
     use alloy_rlp::{Decodable, Encodable};
-    use kona_interop::OutputRootWithChain;
+    use kona_interop::{OutputRootWithChain, SuperRootError, SuperRootRegistry};
+
+    #[test]
+    fn test_decode_super_root_pre_state_with_registry() {
+        let super_root = SuperRoot::new(10, vec![OutputRootWithChain::new(1, B256::default())]);
+
+        let mut rlp_buf = Vec::new();
+        super_root.encode(&mut rlp_buf);
+
+        let pre_state =
+            PreState::decode_with_registry(&SuperRootRegistry::new(), &mut rlp_buf.as_slice())
+                .unwrap();
+        assert_eq!(pre_state, PreState::SuperRoot(super_root));
+    }
+
+    #[test]
+    fn test_decode_unknown_super_root_version() {
+        let buf = [0x05u8; 41];
+
+        let err = PreState::decode_with_registry(&SuperRootRegistry::new(), &mut buf.as_slice())
+            .unwrap_err();
+        assert!(matches!(err, PreStateError::SuperRoot(SuperRootError::UnknownVersion(0x05))));
+        assert!(PreState::decode(&mut buf.as_slice()).is_err());
+    }
 
     #[test]
     fn test_static_transition_state_roundtrip() {
@@ -0,0 +1,119 @@
+#![allow(missing_docs)]
+//! Compares the compression ratio and speed of the [CompressionBackend]s on recorded channels.
+//!
+//! The corpus holds the recorded mainnet channel in the `kona-protocol` test data and the recorded
+//! channels in `testdata/channels.hex`, whose origin is described in `testdata/README.md`. Each
+//! channel is recompressed on its own, as the
+//! batcher compresses channels independently. Additional recorded channels can be provided through
+//! the `KONA_COMP_BENCH_DATA` environment variable, as the path to a file holding one hex encoded,
+//! compressed channel per line.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use kona_comp::{BrotliBackend, BrotliLevel, CompressionBackend, CompressionRegistry, ZlibBackend};
+use kona_genesis::MAX_RLP_BYTES_PER_CHANNEL_FJORD;
+
+/// The recorded mainnet channel in the `kona-protocol` test data.
+const MAINNET_CHANNEL: &str = include_str!("../../protocol/testdata/batch.hex");
+
+/// The recorded channels, see `testdata/README.md`.
+const CALLDATA_CHANNELS: &str = include_str!("../testdata/channels.hex");
+
+const MAX: usize = MAX_RLP_BYTES_PER_CHANNEL_FJORD as usize;
+
+/// Decompresses a recorded channel into its raw batch data.
+///
+/// Channels are decompressed incrementally, like the derivation pipeline reads them, so that
+/// channels whose compressed stream was flushed but not closed by the batcher are read in full.
+fn decompress_channel(registry: &CompressionRegistry, channel: &[u8]) -> Vec<u8> {
+    let decompressor = registry.get(channel[0]).expect("Unsupported recorded channel compression");
+    let mut decoder = decompressor.stream_decoder().expect("Recorded channel is not streamable");
+    let (mut offset, mut raw, mut chunk) = (0, Vec::new(), vec![0u8; 64 * 1024]);
+    loop {
+        let (consumed, written, finished) =
+            decoder.decompress(&channel[offset..], &mut chunk).expect("Invalid recorded channel");
+        offset += consumed;
+        raw.extend_from_slice(&chunk[..written]);
+        if finished || (consumed == 0 && written == 0) {
+            return raw;
+        }
+    }
+}
+
+/// Loads the recorded channels and decompresses them into their raw batch data.
+fn recorded_batches() -> Vec<Vec<u8>> {
+    let extra = std::env::var("KONA_COMP_BENCH_DATA")
+        .map(|path| std::fs::read_to_string(path).expect("Failed to read bench data"))
+        .unwrap_or_default();
+
+    let registry = CompressionRegistry::default();
+    MAINNET_CHANNEL
+        .lines()
+        .chain(CALLDATA_CHANNELS.lines())
+        .chain(extra.lines())
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let channel = alloy_primitives::hex::decode(line).expect("Invalid hex channel");
+            decompress_channel(&registry, &channel)
+        })
+        .collect()
+}
+
+/// Returns the backends to compare.
+fn backends() -> Vec<Box<dyn CompressionBackend>> {
+    let mut backends: Vec<Box<dyn CompressionBackend>> = vec![Box::new(ZlibBackend)];
+    for level in [BrotliLevel::Brotli9, BrotliLevel::Brotli10, BrotliLevel::Brotli11] {
+        backends.push(Box::new(BrotliBackend::new(level)));
+    }
+    #[cfg(feature = "zstd")]
+    backends.push(Box::new(kona_comp::ZstdBackend::default()));
+    backends
+}
+
+fn compression(c: &mut Criterion) {
+    let channels = recorded_batches();
+    let raw_len = channels.iter().map(Vec::len).sum::<usize>();
+    let backends = backends();
+
+    for backend in &backends {
+        let compressed_len =
+            channels.iter().map(|raw| backend.compress(raw).unwrap().len()).sum::<usize>();
+        println!(
+            "{}: {} channels, {} -> {} bytes, ratio {:.3}",
+            backend.name(),
+            channels.len(),
+            raw_len,
+            compressed_len,
+            compressed_len as f64 / raw_len as f64
+        );
+    }
+
+    let mut g = c.benchmark_group("compression");
+    g.sample_size(10);
+    g.throughput(Throughput::Bytes(raw_len as u64));
+
+    for backend in &backends {
+        g.bench_with_input(BenchmarkId::new("compress", backend.name()), &channels, |b, raw| {
+            b.iter(|| raw.iter().map(|raw| backend.compress(raw).unwrap()).collect::<Vec<_>>())
+        });
+
+        let compressed =
+            channels.iter().map(|raw| backend.compress(raw).unwrap()).collect::<Vec<_>>();
+        g.bench_with_input(
+            BenchmarkId::new("decompress", backend.name()),
+            &compressed,
+            |b, compressed| {
+                b.iter(|| {
+                    compressed
+                        .iter()
+                        .map(|channel| backend.decompress(channel, MAX).unwrap())
+                        .collect::<Vec<_>>()
+                })
+            },
+        );
+    }
+    g.finish();
+}
+
+criterion_group!(benches, compression);
+criterion_main!(benches);
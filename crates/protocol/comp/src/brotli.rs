@@ -1,6 +1,12 @@
 //! Contains brotli compression utilities.
 
-use crate::{ChannelCompressor, CompressorError, CompressorResult, CompressorWriter};
+use crate::{
+    ChannelCompressor, CompressionBackend, CompressorError, CompressorResult, CompressorWriter,
+};
+use kona_genesis::RollupConfig;
+use kona_protocol::{
+    BatchReader, BrotliDecompressor, DecompressionError, Decompressor, StreamDecoder,
+};
 use std::vec::Vec;
 
 /// The brotli encoding level used in Optimism.
@@ -130,6 +136,64 @@ impl ChannelCompressor for BrotliCompressor {
     }
 }
 
+/// The brotli [CompressionBackend], producing brotli channels as used since Fjord.
+#[derive(Debug, Clone, Copy)]
+pub struct BrotliBackend {
+    /// The compression level.
+    pub level: BrotliLevel,
+}
+
+impl BrotliBackend {
+    /// Creates a new brotli backend with the given compression level.
+    pub fn new(level: impl Into<BrotliLevel>) -> Self {
+        Self { level: level.into() }
+    }
+}
+
+impl Default for BrotliBackend {
+    fn default() -> Self {
+        Self::new(BrotliLevel::Brotli10)
+    }
+}
+
+impl Decompressor for BrotliBackend {
+    fn supports(&self, version: u8) -> bool {
+        BrotliDecompressor.supports(version)
+    }
+
+    fn is_active(&self, cfg: &RollupConfig, timestamp: u64) -> bool {
+        BrotliDecompressor.is_active(cfg, timestamp)
+    }
+
+    fn decompress(
+        &self,
+        data: &[u8],
+        max_rlp_bytes_per_channel: usize,
+    ) -> Result<Vec<u8>, DecompressionError> {
+        BrotliDecompressor.decompress(data, max_rlp_bytes_per_channel)
+    }
+
+    fn stream_decoder(&self) -> Option<Box<dyn StreamDecoder>> {
+        BrotliDecompressor.stream_decoder()
+    }
+}
+
+impl CompressionBackend for BrotliBackend {
+    fn name(&self) -> &'static str {
+        match self.level {
+            BrotliLevel::Brotli9 => "brotli-9",
+            BrotliLevel::Brotli10 => "brotli-10",
+            BrotliLevel::Brotli11 => "brotli-11",
+        }
+    }
+
+    fn compress(&self, data: &[u8]) -> CompressorResult<Vec<u8>> {
+        let mut channel = std::vec![BatchReader::CHANNEL_VERSION_BROTLI];
+        channel.extend(compress_brotli(data, self.level).map_err(|_| CompressorError::Brotli)?);
+        Ok(channel)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
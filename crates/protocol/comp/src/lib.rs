@@ -13,7 +13,10 @@ mod channel_out;
 pub use channel_out::{ChannelOut, ChannelOutError};
 
 mod traits;
-pub use traits::{ChannelCompressor, CompressorWriter};
+pub use traits::{ChannelCompressor, CompressionBackend, CompressorWriter};
+
+mod registry;
+pub use registry::CompressionRegistry;
 
 mod config;
 pub use config::Config;
@@ -22,12 +25,19 @@ mod types;
 pub use types::{CompressionAlgo, CompressorError, CompressorResult, CompressorType};
 
 mod zlib;
-pub use zlib::{ZlibCompressor, compress_zlib, decompress_zlib};
+pub use zlib::{ZlibBackend, ZlibCompressor, compress_zlib, decompress_zlib};
+
+#[cfg(feature = "zstd")]
+mod zstd;
+#[cfg(feature = "zstd")]
+pub use zstd::ZstdBackend;
 
 #[cfg(feature = "std")]
 mod brotli;
 #[cfg(feature = "std")]
-pub use brotli::{BrotliCompressionError, BrotliCompressor, BrotliLevel, compress_brotli};
+pub use brotli::{
+    BrotliBackend, BrotliCompressionError, BrotliCompressor, BrotliLevel, compress_brotli,
+};
 
 #[cfg(feature = "std")]
 mod variant;
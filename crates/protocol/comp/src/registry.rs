@@ -0,0 +1,111 @@
+//! Contains the [CompressionRegistry].
+
+use crate::{CompressionBackend, ZlibBackend};
+use alloc::{sync::Arc, vec::Vec};
+use kona_protocol::{Decompressor, DecompressorRegistry};
+
+/// A registry of [CompressionBackend]s, selected by the channel version byte.
+///
+/// Backends registered later take precedence over earlier ones for the same channel version byte.
+/// The default registry holds the zlib backend and, with the `std` feature, the brotli backend.
+#[derive(Debug, Clone)]
+pub struct CompressionRegistry {
+    /// The registered backends.
+    backends: Vec<Arc<dyn CompressionBackend>>,
+}
+
+impl Default for CompressionRegistry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        registry.register(ZlibBackend);
+        #[cfg(feature = "std")]
+        registry.register(crate::BrotliBackend::default());
+        registry
+    }
+}
+
+impl CompressionRegistry {
+    /// Creates a new [CompressionRegistry] without any backends.
+    pub const fn empty() -> Self {
+        Self { backends: Vec::new() }
+    }
+
+    /// Registers the given [CompressionBackend].
+    pub fn register<B: CompressionBackend + 'static>(&mut self, backend: B) -> &mut Self {
+        self.backends.push(Arc::new(backend));
+        self
+    }
+
+    /// Returns the registry with the given [CompressionBackend] registered.
+    pub fn with<B: CompressionBackend + 'static>(mut self, backend: B) -> Self {
+        self.register(backend);
+        self
+    }
+
+    /// Returns the [CompressionBackend] for channel data starting with the given version byte.
+    pub fn get(&self, version: u8) -> Option<&dyn CompressionBackend> {
+        self.backends.iter().rev().map(|b| b.as_ref()).find(|b| b.supports(version))
+    }
+
+    /// Returns an iterator over the registered backends, in registration order.
+    pub fn backends(&self) -> impl Iterator<Item = &dyn CompressionBackend> {
+        self.backends.iter().map(|b| b.as_ref())
+    }
+
+    /// Returns a [DecompressorRegistry] for the `BatchReader`, holding the decompressors of the
+    /// registered backends.
+    pub fn decompressors(&self) -> DecompressorRegistry {
+        let mut decompressors = DecompressorRegistry::new();
+        for backend in &self.backends {
+            decompressors.register_shared(Arc::clone(backend) as Arc<dyn Decompressor>);
+        }
+        decompressors
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use kona_genesis::{MAX_RLP_BYTES_PER_CHANNEL_FJORD, RollupConfig};
+    use kona_protocol::BatchReader;
+
+    const MAX: usize = MAX_RLP_BYTES_PER_CHANNEL_FJORD as usize;
+
+    fn roundtrip(registry: &CompressionRegistry, backend: &dyn CompressionBackend) {
+        let data = b"kona compression registry roundtrip data, repeated, repeated, repeated";
+        let channel = backend.compress(data).unwrap();
+        let decompressor = registry.get(channel[0]).unwrap();
+        assert_eq!(decompressor.name(), backend.name());
+        assert_eq!(decompressor.decompress(&channel, MAX).unwrap(), data);
+
+        // The channel is readable through the protocol decompressor registry as well.
+        let decompressors = registry.decompressors();
+        let decompressor = decompressors.get(channel[0]).unwrap();
+        assert_eq!(decompressor.decompress(&channel, MAX).unwrap(), data);
+    }
+
+    #[test]
+    fn test_default_registry_roundtrip() {
+        let registry = CompressionRegistry::default();
+        for backend in registry.backends() {
+            roundtrip(&registry, backend);
+        }
+        assert!(registry.get(0x78).is_some_and(|b| b.name() == "zlib"));
+    }
+
+    #[test]
+    fn test_later_registration_takes_precedence() {
+        let registry = CompressionRegistry::empty().with(ZlibBackend);
+        assert!(registry.get(BatchReader::CHANNEL_VERSION_BROTLI).is_none());
+        assert!(registry.get(0x78).unwrap().is_active(&RollupConfig::default(), 0));
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_zstd_registry_roundtrip() {
+        let registry = CompressionRegistry::default().with(crate::ZstdBackend::new(2, Some(10)));
+        let zstd = registry.get(2).unwrap();
+        assert!(!zstd.is_active(&RollupConfig::default(), 9));
+        roundtrip(&registry, zstd);
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::CompressorResult;
 use alloc::vec::Vec;
+use kona_protocol::Decompressor;
 
 /// Compressor Writer
 ///
@@ -37,3 +38,75 @@ pub trait ChannelCompressor: CompressorWriter {
     /// Returns the compressed data buffer.
     fn get_compressed(&self) -> Vec<u8>;
 }
+
+/// Compression Backend
+///
+/// A compression scheme for channels, pairing a compressor with the [Decompressor] for the channel
+/// version byte it produces.
+pub trait CompressionBackend: Decompressor {
+    /// Returns the name of the compression backend.
+    fn name(&self) -> &'static str;
+
+    /// Compresses the given data into channel data, including its channel version byte.
+    fn compress(&self, data: &[u8]) -> CompressorResult<Vec<u8>>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ZlibBackend;
+    use kona_genesis::{MAX_RLP_BYTES_PER_CHANNEL_FJORD, RollupConfig};
+    use kona_protocol::{BatchReader, DecompressorRegistry};
+
+    const MAX: usize = MAX_RLP_BYTES_PER_CHANNEL_FJORD as usize;
+
+    fn roundtrip<B: CompressionBackend + 'static>(backend: B) {
+        let data = b"kona compression backend roundtrip data, repeated, repeated, repeated";
+        let channel = backend.compress(data).unwrap();
+        assert_eq!(backend.decompress(&channel, MAX).unwrap(), data);
+
+        // The channel is readable through the protocol decompressor registry, both at once and
+        // incrementally.
+        let registry = DecompressorRegistry::new().with(backend);
+        let decompressor = registry.get(channel[0]).unwrap();
+        assert_eq!(decompressor.decompress(&channel, MAX).unwrap(), data);
+
+        let mut decoder = decompressor.stream_decoder().unwrap();
+        let (mut offset, mut decompressed) = (0, Vec::new());
+        loop {
+            let mut chunk = [0u8; 8];
+            let (consumed, written, finished) =
+                decoder.decompress(&channel[offset..], &mut chunk).unwrap();
+            offset += consumed;
+            decompressed.extend_from_slice(&chunk[..written]);
+            if finished || (consumed == 0 && written == 0) {
+                break;
+            }
+        }
+        assert_eq!(decompressed, data);
+    }
+
+    #[test]
+    fn test_zlib_backend_roundtrip() {
+        roundtrip(ZlibBackend);
+        let registry = DecompressorRegistry::new().with(ZlibBackend);
+        assert!(registry.get(0x78).unwrap().is_active(&RollupConfig::default(), 0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_brotli_backend_roundtrip() {
+        roundtrip(crate::BrotliBackend::default());
+        let registry = DecompressorRegistry::new().with(crate::BrotliBackend::default());
+        let brotli = registry.get(BatchReader::CHANNEL_VERSION_BROTLI).unwrap();
+        assert!(!brotli.is_active(&RollupConfig::default(), 0));
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_zstd_backend_roundtrip() {
+        let backend = crate::ZstdBackend::new(2, Some(10));
+        assert!(!backend.is_active(&RollupConfig::default(), 9));
+        roundtrip(backend);
+    }
+}
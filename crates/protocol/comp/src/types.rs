@@ -12,6 +12,9 @@ pub enum CompressorError {
     /// Brotli compression failed.
     #[error("brotli compression failed")]
     Brotli,
+    /// Zstd compression failed.
+    #[error("zstd compression failed")]
+    Zstd,
 }
 
 /// The type of compressor to use.
@@ -1,7 +1,9 @@
 //! Contains ZLIB compression and decompression primitives for Optimism.
 
-use crate::{ChannelCompressor, CompressorResult, CompressorWriter};
-use alloc::vec::Vec;
+use crate::{ChannelCompressor, CompressionBackend, CompressorResult, CompressorWriter};
+use alloc::{boxed::Box, vec::Vec};
+use kona_genesis::RollupConfig;
+use kona_protocol::{DecompressionError, Decompressor, StreamDecoder, ZlibDecompressor};
 use miniz_oxide::inflate::DecompressError;
 
 /// The best compression.
@@ -71,3 +73,40 @@ impl ChannelCompressor for ZlibCompressor {
         self.compressed.clone()
     }
 }
+
+/// The zlib [CompressionBackend], producing zlib streams as used since Bedrock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ZlibBackend;
+
+impl Decompressor for ZlibBackend {
+    fn supports(&self, version: u8) -> bool {
+        ZlibDecompressor.supports(version)
+    }
+
+    fn is_active(&self, cfg: &RollupConfig, timestamp: u64) -> bool {
+        ZlibDecompressor.is_active(cfg, timestamp)
+    }
+
+    fn decompress(
+        &self,
+        data: &[u8],
+        max_rlp_bytes_per_channel: usize,
+    ) -> Result<Vec<u8>, DecompressionError> {
+        ZlibDecompressor.decompress(data, max_rlp_bytes_per_channel)
+    }
+
+    fn stream_decoder(&self) -> Option<Box<dyn StreamDecoder>> {
+        ZlibDecompressor.stream_decoder()
+    }
+}
+
+impl CompressionBackend for ZlibBackend {
+    fn name(&self) -> &'static str {
+        "zlib"
+    }
+
+    fn compress(&self, data: &[u8]) -> CompressorResult<Vec<u8>> {
+        // The zlib header doubles as the channel version byte.
+        Ok(miniz_oxide::deflate::compress_to_vec_zlib(data, BEST_ZLIB_COMPRESSION))
+    }
+}
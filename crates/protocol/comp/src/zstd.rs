@@ -0,0 +1,64 @@
+//! Contains zstd compression utilities.
+
+use crate::{CompressionBackend, CompressorResult};
+use alloc::{boxed::Box, vec::Vec};
+use kona_genesis::RollupConfig;
+use kona_protocol::{DecompressionError, Decompressor, StreamDecoder, ZstdDecompressor};
+use ruzstd::encoding::{CompressionLevel, compress_to_vec};
+
+/// The zstd [CompressionBackend].
+///
+/// Zstd is not part of the OP Stack protocol. Channels compressed with it are only valid on chains
+/// that enable it through a custom hardfork, see [ZstdDecompressor].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZstdBackend {
+    /// The zstd decompressor, holding the channel version byte and activation time.
+    pub decompressor: ZstdDecompressor,
+}
+
+impl ZstdBackend {
+    /// Creates a new zstd backend for the given channel version byte and activation time.
+    pub const fn new(version: u8, activation_time: Option<u64>) -> Self {
+        Self { decompressor: ZstdDecompressor::new(version, activation_time) }
+    }
+}
+
+impl Default for ZstdBackend {
+    fn default() -> Self {
+        Self::new(ZstdDecompressor::DEFAULT_CHANNEL_VERSION, None)
+    }
+}
+
+impl Decompressor for ZstdBackend {
+    fn supports(&self, version: u8) -> bool {
+        self.decompressor.supports(version)
+    }
+
+    fn is_active(&self, cfg: &RollupConfig, timestamp: u64) -> bool {
+        self.decompressor.is_active(cfg, timestamp)
+    }
+
+    fn decompress(
+        &self,
+        data: &[u8],
+        max_rlp_bytes_per_channel: usize,
+    ) -> Result<Vec<u8>, DecompressionError> {
+        self.decompressor.decompress(data, max_rlp_bytes_per_channel)
+    }
+
+    fn stream_decoder(&self) -> Option<Box<dyn StreamDecoder>> {
+        self.decompressor.stream_decoder()
+    }
+}
+
+impl CompressionBackend for ZstdBackend {
+    fn name(&self) -> &'static str {
+        "zstd"
+    }
+
+    fn compress(&self, data: &[u8]) -> CompressorResult<Vec<u8>> {
+        let mut channel = alloc::vec![self.decompressor.version];
+        channel.extend(compress_to_vec(data, CompressionLevel::Fastest));
+        Ok(channel)
+    }
+}
@@ -25,7 +25,7 @@ mod pipeline;
 pub use pipeline::{
     AttributesQueueStage, BatchProviderStage, BatchStreamStage, ChannelProviderStage,
     ChannelReaderStage, DerivationPipeline, FrameQueueStage, IndexedAttributesQueueStage,
-    L1RetrievalStage, PipelineBuilder, PolledAttributesQueueStage,
+    L1RetrievalStage, PipelineBuilder, PipelineHooks, PolledAttributesQueueStage,
 };
 
 mod sources;
@@ -41,9 +41,9 @@ pub use stages::{
 
 mod traits;
 pub use traits::{
-    AttributesBuilder, AttributesProvider, BatchValidationProviderDerive, BlobProvider,
-    ChainProvider, DataAvailabilityProvider, L2ChainProvider, NextAttributes, OriginAdvancer,
-    OriginProvider, Pipeline, ResetProvider, SignalReceiver,
+    AttributesBuilder, AttributesProvider, BatchValidationProviderDerive, BatchValidityHook,
+    BlobProvider, ChainProvider, DataAvailabilityProvider, L2ChainProvider, NextAttributes,
+    OriginAdvancer, OriginProvider, Pipeline, ResetProvider, SignalReceiver, SystemConfigHook,
 };
 
 mod types;
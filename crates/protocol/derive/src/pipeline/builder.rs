@@ -1,15 +1,15 @@
 //! Contains the `PipelineBuilder` object that is used to build a `DerivationPipeline`.
 
 use crate::{
-    AttributesBuilder, AttributesQueue, BatchProvider, BatchStream, ChainProvider, ChannelProvider,
-    ChannelReader, DataAvailabilityProvider, DerivationPipeline, FrameQueue,
-    IndexedAttributesQueueStage, IndexedTraversal, L1Retrieval, L2ChainProvider,
-    PolledAttributesQueueStage, PollingTraversal,
+    AttributesBuilder, AttributesQueue, BatchProvider, BatchStream, BatchValidityHook,
+    ChainProvider, ChannelProvider, ChannelReader, DataAvailabilityProvider, DerivationPipeline,
+    FrameQueue, IndexedAttributesQueueStage, IndexedTraversal, L1Retrieval, L2ChainProvider,
+    PipelineHooks, PolledAttributesQueueStage, PollingTraversal, SystemConfigHook,
 };
 use alloc::sync::Arc;
 use core::fmt::Debug;
 use kona_genesis::RollupConfig;
-use kona_protocol::BlockInfo;
+use kona_protocol::{BlockInfo, DecompressorRegistry};
 
 /// The `PipelineBuilder` constructs a [`DerivationPipeline`] using a builder pattern.
 #[derive(Debug)]
@@ -26,6 +26,9 @@ where
     builder: Option<B>,
     origin: Option<BlockInfo>,
     rollup_config: Option<Arc<RollupConfig>>,
+    batch_validity_hook: Option<Arc<dyn BatchValidityHook>>,
+    system_config_hook: Option<Arc<dyn SystemConfigHook>>,
+    decompressors: DecompressorRegistry,
 }
 
 impl<B, P, T, D> Default for PipelineBuilder<B, P, T, D>
@@ -43,6 +46,9 @@ where
             builder: None,
             origin: None,
             rollup_config: None,
+            batch_validity_hook: None,
+            system_config_hook: None,
+            decompressors: DecompressorRegistry::new(),
         }
     }
 }
@@ -95,6 +101,33 @@ where
         self
     }
 
+    /// Sets the [`BatchValidityHook`] that receives the validity reports of the batch stages.
+    pub fn batch_validity_hook(mut self, hook: Arc<dyn BatchValidityHook>) -> Self {
+        self.batch_validity_hook = Some(hook);
+        self
+    }
+
+    /// Sets the [`PipelineHooks`] that observe the pipeline stages, replacing any hooks set
+    /// previously.
+    pub fn hooks(mut self, hooks: PipelineHooks) -> Self {
+        self.batch_validity_hook = hooks.batch_validity;
+        self.system_config_hook = hooks.system_config;
+        self
+    }
+
+    /// Sets the [`SystemConfigHook`] that receives the system config updates of the traversal
+    /// stage.
+    pub fn system_config_hook(mut self, hook: Arc<dyn SystemConfigHook>) -> Self {
+        self.system_config_hook = Some(hook);
+        self
+    }
+
+    /// Sets the [`DecompressorRegistry`] that the [`ChannelReader`] decompresses channels with.
+    pub fn decompressors(mut self, decompressors: DecompressorRegistry) -> Self {
+        self.decompressors = decompressors;
+        self
+    }
+
     /// Builds a derivation pipeline with the [`PolledAttributesQueueStage`].
     pub fn build_polled(self) -> DerivationPipeline<PolledAttributesQueueStage<D, P, T, B>, T> {
         self.into()
@@ -123,16 +156,20 @@ where
         let attributes_builder = builder.builder.expect("builder must be set");
 
         // Compose the stage stack.
-        let mut l1_traversal = PollingTraversal::new(chain_provider, Arc::clone(&rollup_config));
+        let mut l1_traversal = PollingTraversal::new(chain_provider, Arc::clone(&rollup_config))
+            .with_system_config_hook(builder.system_config_hook);
         l1_traversal.block = Some(builder.origin.expect("origin must be set"));
         let l1_retrieval = L1Retrieval::new(l1_traversal, dap_source);
         let frame_queue = FrameQueue::new(l1_retrieval, Arc::clone(&rollup_config));
         let channel_provider = ChannelProvider::new(Arc::clone(&rollup_config), frame_queue);
-        let channel_reader = ChannelReader::new(channel_provider, Arc::clone(&rollup_config));
+        let channel_reader = ChannelReader::new(channel_provider, Arc::clone(&rollup_config))
+            .with_decompressors(builder.decompressors);
         let batch_stream =
-            BatchStream::new(channel_reader, rollup_config.clone(), l2_chain_provider.clone());
+            BatchStream::new(channel_reader, rollup_config.clone(), l2_chain_provider.clone())
+                .with_validity_hook(builder.batch_validity_hook.clone());
         let batch_provider =
-            BatchProvider::new(rollup_config.clone(), batch_stream, l2_chain_provider.clone());
+            BatchProvider::new(rollup_config.clone(), batch_stream, l2_chain_provider.clone())
+                .with_validity_hook(builder.batch_validity_hook);
         let attributes =
             AttributesQueue::new(rollup_config.clone(), batch_provider, attributes_builder);
 
@@ -158,16 +195,20 @@ where
         let attributes_builder = builder.builder.expect("builder must be set");
 
         // Compose the stage stack.
-        let mut l1_traversal = IndexedTraversal::new(chain_provider, Arc::clone(&rollup_config));
+        let mut l1_traversal = IndexedTraversal::new(chain_provider, Arc::clone(&rollup_config))
+            .with_system_config_hook(builder.system_config_hook);
         l1_traversal.block = Some(builder.origin.expect("origin must be set"));
         let l1_retrieval = L1Retrieval::new(l1_traversal, dap_source);
         let frame_queue = FrameQueue::new(l1_retrieval, Arc::clone(&rollup_config));
         let channel_provider = ChannelProvider::new(Arc::clone(&rollup_config), frame_queue);
-        let channel_reader = ChannelReader::new(channel_provider, Arc::clone(&rollup_config));
+        let channel_reader = ChannelReader::new(channel_provider, Arc::clone(&rollup_config))
+            .with_decompressors(builder.decompressors);
         let batch_stream =
-            BatchStream::new(channel_reader, rollup_config.clone(), l2_chain_provider.clone());
+            BatchStream::new(channel_reader, rollup_config.clone(), l2_chain_provider.clone())
+                .with_validity_hook(builder.batch_validity_hook.clone());
         let batch_provider =
-            BatchProvider::new(rollup_config.clone(), batch_stream, l2_chain_provider.clone());
+            BatchProvider::new(rollup_config.clone(), batch_stream, l2_chain_provider.clone())
+                .with_validity_hook(builder.batch_validity_hook);
         let attributes =
             AttributesQueue::new(rollup_config.clone(), batch_provider, attributes_builder);
 
@@ -0,0 +1,13 @@
+//! Contains the [`PipelineHooks`] that observe the derivation pipeline.
+
+use crate::{BatchValidityHook, SystemConfigHook};
+use alloc::sync::Arc;
+
+/// The optional hooks that observe the stages of a derivation pipeline.
+#[derive(Debug, Clone, Default)]
+pub struct PipelineHooks {
+    /// Receives the validity reports of the batch stages.
+    pub batch_validity: Option<Arc<dyn BatchValidityHook>>,
+    /// Receives the system config updates of the traversal stage.
+    pub system_config: Option<Arc<dyn SystemConfigHook>>,
+}
@@ -6,6 +6,9 @@ pub use builder::PipelineBuilder;
 mod core;
 pub use core::DerivationPipeline;
 
+mod hooks;
+pub use hooks::PipelineHooks;
+
 mod types;
 pub use types::{
     AttributesQueueStage, BatchProviderStage, BatchStreamStage, ChannelProviderStage,
@@ -24,6 +24,13 @@ pub struct BlobData {
 }
 
 impl BlobData {
+    /// Decodes a raw [`Blob`] into the batcher data it carries.
+    ///
+    /// This is useful for tooling that inspects blobs outside of the derivation pipeline.
+    pub fn decode_blob(blob: &Blob) -> Result<Bytes, BlobDecodingError> {
+        Self { data: Some(Bytes::from(*blob)), calldata: None }.decode()
+    }
+
     /// Decodes the blob into raw byte data.
     /// Returns a [`BlobDecodingError`] if the blob is invalid.
     pub(crate) fn decode(&self) -> Result<Bytes, BlobDecodingError> {
@@ -243,6 +250,14 @@ mod tests {
         assert_eq!(blob_data.decode(), Ok(Bytes::from(vec![0u8; 1])));
     }
 
+    #[test]
+    fn test_decode_blob() {
+        let mut blob = Blob::default();
+        blob[VERSIONED_HASH_VERSION_KZG as usize] = BLOB_ENCODING_VERSION;
+        blob[4] = 0x01;
+        assert_eq!(BlobData::decode_blob(&blob), Ok(Bytes::from(vec![0u8; 1])));
+    }
+
     #[test]
     fn test_blob_data_decode_invalid_field_element() {
         let mut data = vec![0u8; alloy_eips::eip4844::BYTES_PER_BLOB + 10];
@@ -2,8 +2,8 @@
 
 use super::NextBatchProvider;
 use crate::{
-    AttributesProvider, BatchQueue, BatchValidator, L2ChainProvider, OriginAdvancer,
-    OriginProvider, PipelineError, PipelineResult, Signal, SignalReceiver,
+    AttributesProvider, BatchQueue, BatchValidator, BatchValidityHook, L2ChainProvider,
+    OriginAdvancer, OriginProvider, PipelineError, PipelineResult, Signal, SignalReceiver,
 };
 use alloc::{boxed::Box, sync::Arc};
 use async_trait::async_trait;
@@ -44,6 +44,8 @@ where
     ///
     /// Must be [`None`] if `prev` or `batch_queue` is [`Some`].
     batch_validator: Option<BatchValidator<P>>,
+    /// Receives the validity reports of the batches checked by the active stage.
+    validity_hook: Option<Arc<dyn BatchValidityHook>>,
 }
 
 impl<P, F> BatchProvider<P, F>
@@ -53,7 +55,21 @@ where
 {
     /// Creates a new [`BatchProvider`] with the given configuration and previous stage.
     pub const fn new(cfg: Arc<RollupConfig>, prev: P, provider: F) -> Self {
-        Self { cfg, provider, prev: Some(prev), batch_queue: None, batch_validator: None }
+        Self {
+            cfg,
+            provider,
+            prev: Some(prev),
+            batch_queue: None,
+            batch_validator: None,
+            validity_hook: None,
+        }
+    }
+
+    /// Sets the [`BatchValidityHook`] that receives the validity reports of batches checked by
+    /// the [`BatchQueue`] and [`BatchValidator`] stages.
+    pub fn with_validity_hook(mut self, hook: Option<Arc<dyn BatchValidityHook>>) -> Self {
+        self.validity_hook = hook;
+        self
     }
 
     /// Attempts to update the active stage of the mux.
@@ -63,16 +79,22 @@ where
             // On the first call to `attempt_update`, we need to determine the active stage to
             // initialize the mux with.
             if self.cfg.is_holocene_active(origin.timestamp) {
-                self.batch_validator = Some(BatchValidator::new(self.cfg.clone(), prev));
+                self.batch_validator = Some(
+                    BatchValidator::new(self.cfg.clone(), prev)
+                        .with_validity_hook(self.validity_hook.clone()),
+                );
             } else {
-                self.batch_queue =
-                    Some(BatchQueue::new(self.cfg.clone(), prev, self.provider.clone()));
+                self.batch_queue = Some(
+                    BatchQueue::new(self.cfg.clone(), prev, self.provider.clone())
+                        .with_validity_hook(self.validity_hook.clone()),
+                );
             }
         } else if self.batch_queue.is_some() && self.cfg.is_holocene_active(origin.timestamp) {
             // If the batch queue is active and Holocene is also active, transition to the batch
             // validator.
             let batch_queue = self.batch_queue.take().expect("Must have batch queue");
-            let mut bv = BatchValidator::new(self.cfg.clone(), batch_queue.prev);
+            let mut bv = BatchValidator::new(self.cfg.clone(), batch_queue.prev)
+                .with_validity_hook(self.validity_hook.clone());
             bv.l1_blocks = batch_queue.l1_blocks;
             self.batch_validator = Some(bv);
         } else if self.batch_validator.is_some() && !self.cfg.is_holocene_active(origin.timestamp) {
@@ -81,7 +103,8 @@ where
             // until Holocene re-activates.
             let batch_validator = self.batch_validator.take().expect("Must have batch validator");
             let mut bq =
-                BatchQueue::new(self.cfg.clone(), batch_validator.prev, self.provider.clone());
+                BatchQueue::new(self.cfg.clone(), batch_validator.prev, self.provider.clone())
+                    .with_validity_hook(self.validity_hook.clone());
             bq.l1_blocks = batch_validator.l1_blocks;
             self.batch_queue = Some(bq);
         }
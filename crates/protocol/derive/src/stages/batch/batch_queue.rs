@@ -1,9 +1,12 @@
 //! This module contains the `BatchQueue` stage implementation.
 
-use super::NextBatchProvider;
+use super::{NextBatchProvider, publish_validity_report};
 use crate::{
     errors::{PipelineEncodingError, PipelineError, PipelineErrorKind, ResetError},
-    traits::{AttributesProvider, L2ChainProvider, OriginAdvancer, OriginProvider, SignalReceiver},
+    traits::{
+        AttributesProvider, BatchValidityHook, L2ChainProvider, OriginAdvancer, OriginProvider,
+        SignalReceiver,
+    },
     types::{PipelineResult, ResetSignal, Signal},
 };
 use alloc::{boxed::Box, sync::Arc, vec::Vec};
@@ -55,6 +58,8 @@ where
     pub(crate) next_spans: Vec<SingleBatch>,
     /// Used to validate the batches.
     pub(crate) fetcher: BF,
+    /// Receives the validity reports of the batches checked by this stage.
+    pub(crate) validity_hook: Option<Arc<dyn BatchValidityHook>>,
 }
 
 impl<P, BF> BatchQueue<P, BF>
@@ -73,9 +78,16 @@ where
             batches: Default::default(),
             next_spans: Default::default(),
             fetcher,
+            validity_hook: None,
         }
     }
 
+    /// Sets the [`BatchValidityHook`] that receives the validity reports of batches.
+    pub fn with_validity_hook(mut self, hook: Option<Arc<dyn BatchValidityHook>>) -> Self {
+        self.validity_hook = hook;
+        self
+    }
+
     /// Pops the next batch from the current queued up span-batch cache.
     /// The parent is used to set the parent hash of the batch.
     /// The parent is verified when the batch is later validated.
@@ -130,9 +142,11 @@ where
         let mut remaining = Vec::new();
         for i in 0..self.batches.len() {
             let batch = &self.batches[i];
-            let validity =
-                batch.check_batch(&self.cfg, &self.l1_blocks, parent, &mut self.fetcher).await;
-            match validity {
+            let report = batch
+                .check_batch_report(&self.cfg, &self.l1_blocks, parent, &mut self.fetcher)
+                .await;
+            publish_validity_report(self.validity_hook.as_ref(), &report);
+            match report.validity {
                 BatchValidity::Future => {
                     // Drop Future batches post-holocene.
                     //
@@ -242,8 +256,10 @@ where
         let origin = self.origin.ok_or(PipelineError::MissingOrigin.crit())?;
         let data = BatchWithInclusionBlock { inclusion_block: origin, batch };
         // If we drop the batch, validation logs the drop reason with WARN level.
-        let validity =
-            data.check_batch(&self.cfg, &self.l1_blocks, parent, &mut self.fetcher).await;
+        let report =
+            data.check_batch_report(&self.cfg, &self.l1_blocks, parent, &mut self.fetcher).await;
+        publish_validity_report(self.validity_hook.as_ref(), &report);
+        let validity = report.validity;
         // Post-Holocene, future batches are dropped due to prevent gaps.
         let drop = validity.is_drop() ||
             (self.cfg.is_holocene_active(origin.timestamp) && validity.is_future());
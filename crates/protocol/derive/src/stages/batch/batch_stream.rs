@@ -1,8 +1,9 @@
 //! This module contains the `BatchStream` stage.
 
+use super::publish_validity_report;
 use crate::{
-    L2ChainProvider, NextBatchProvider, OriginAdvancer, OriginProvider, PipelineEncodingError,
-    PipelineError, PipelineResult, Signal, SignalReceiver,
+    BatchValidityHook, L2ChainProvider, NextBatchProvider, OriginAdvancer, OriginProvider,
+    PipelineEncodingError, PipelineError, PipelineResult, Signal, SignalReceiver,
 };
 use alloc::{boxed::Box, collections::VecDeque, sync::Arc};
 use async_trait::async_trait;
@@ -48,6 +49,8 @@ where
     config: Arc<RollupConfig>,
     /// Used to validate the batches.
     fetcher: BF,
+    /// Receives the validity reports of the span batches checked by this stage.
+    validity_hook: Option<Arc<dyn BatchValidityHook>>,
 }
 
 impl<P, BF> BatchStream<P, BF>
@@ -57,7 +60,13 @@ where
 {
     /// Create a new [`BatchStream`] stage.
     pub const fn new(prev: P, config: Arc<RollupConfig>, fetcher: BF) -> Self {
-        Self { prev, span: None, buffer: VecDeque::new(), config, fetcher }
+        Self { prev, span: None, buffer: VecDeque::new(), config, fetcher, validity_hook: None }
+    }
+
+    /// Sets the [`BatchValidityHook`] that receives the validity reports of span batches.
+    pub fn with_validity_hook(mut self, hook: Option<Arc<dyn BatchValidityHook>>) -> Self {
+        self.validity_hook = hook;
+        self
     }
 
     /// Returns if the [`BatchStream`] stage is active based on the
@@ -148,8 +157,8 @@ where
                 Batch::Span(b) => {
                     #[cfg(feature = "metrics")]
                     let start = std::time::Instant::now();
-                    let (validity, _) = b
-                        .check_batch_prefix(
+                    let (report, _) = b
+                        .check_batch_prefix_report(
                             self.config.as_ref(),
                             l1_origins,
                             parent,
@@ -163,13 +172,9 @@ where
                         start.elapsed().as_secs_f64()
                     );
 
-                    kona_macros::inc!(
-                        gauge,
-                        crate::metrics::Metrics::PIPELINE_BATCH_VALIDITY,
-                        "validity" => validity.to_string(),
-                    );
+                    publish_validity_report(self.validity_hook.as_ref(), &report);
 
-                    match validity {
+                    match report.validity {
                         BatchValidity::Accept => self.span = Some(b),
                         BatchValidity::Drop => {
                             // Flush the stage.
@@ -1,9 +1,11 @@
 //! Contains the [BatchValidator] stage.
 
-use super::NextBatchProvider;
+use super::{NextBatchProvider, publish_validity_report};
 use crate::{
     errors::{PipelineError, PipelineErrorKind, ResetError},
-    traits::{AttributesProvider, OriginAdvancer, OriginProvider, SignalReceiver},
+    traits::{
+        AttributesProvider, BatchValidityHook, OriginAdvancer, OriginProvider, SignalReceiver,
+    },
     types::{PipelineResult, ResetSignal, Signal},
 };
 use alloc::{boxed::Box, sync::Arc, vec::Vec};
@@ -35,6 +37,8 @@ where
     /// If new L2 Block's L1 origin is not included in this list, fetch and
     /// push it to the list.
     pub(crate) l1_blocks: Vec<BlockInfo>,
+    /// Receives the validity reports of the batches checked by this stage.
+    pub(crate) validity_hook: Option<Arc<dyn BatchValidityHook>>,
 }
 
 impl<P> BatchValidator<P>
@@ -43,7 +47,13 @@ where
 {
     /// Create a new [`BatchValidator`] stage.
     pub const fn new(cfg: Arc<RollupConfig>, prev: P) -> Self {
-        Self { cfg, prev, origin: None, l1_blocks: Vec::new() }
+        Self { cfg, prev, origin: None, l1_blocks: Vec::new(), validity_hook: None }
+    }
+
+    /// Sets the [`BatchValidityHook`] that receives the validity reports of batches.
+    pub fn with_validity_hook(mut self, hook: Option<Arc<dyn BatchValidityHook>>) -> Self {
+        self.validity_hook = hook;
+        self
     }
 
     /// Returns `true` if the pipeline origin is behind the parent origin.
@@ -248,12 +258,14 @@ where
         next_batch.parent_hash = parent.block_info.hash;
 
         // Check the validity of the single batch before forwarding it.
-        match next_batch.check_batch(
+        let report = next_batch.check_batch_report(
             self.cfg.as_ref(),
             self.l1_blocks.as_ref(),
             parent,
             &stage_origin,
-        ) {
+        );
+        publish_validity_report(self.validity_hook.as_ref(), &report);
+        match report.validity {
             BatchValidity::Accept => {
                 info!(target: "batch_validator", "Found next batch (epoch #{})", next_batch.epoch_num);
                 Ok(next_batch)
@@ -328,13 +340,16 @@ mod test {
     use crate::{
         AttributesProvider, BatchValidator, NextBatchProvider, OriginAdvancer, PipelineError,
         PipelineErrorKind, PipelineResult, ResetError, ResetSignal, Signal, SignalReceiver,
-        test_utils::{CollectingLayer, TestNextBatchProvider, TraceStorage},
+        test_utils::{CollectingLayer, TestBatchValidityHook, TestNextBatchProvider, TraceStorage},
     };
     use alloc::{sync::Arc, vec, vec::Vec};
     use alloy_eips::{BlockNumHash, NumHash};
     use alloy_primitives::B256;
     use kona_genesis::{HardForkConfig, RollupConfig};
-    use kona_protocol::{Batch, BlockInfo, L2BlockInfo, SingleBatch, SpanBatch};
+    use kona_protocol::{
+        Batch, BatchType, BatchValidity, BatchValidityRule, BlockInfo, L2BlockInfo, SingleBatch,
+        SpanBatch,
+    };
     use tracing::Level;
     use tracing_subscriber::layer::SubscriberExt;
 
@@ -552,6 +567,48 @@ mod test {
         assert_eq!(batch, produced_batch);
     }
 
+    #[tokio::test]
+    async fn test_batch_validator_publishes_validity_report() {
+        let cfg = Arc::new(RollupConfig {
+            hardforks: HardForkConfig { holocene_time: Some(0), ..Default::default() },
+            block_time: 2,
+            max_sequencer_drift: 700,
+            ..Default::default()
+        });
+        let batch = SingleBatch { epoch_num: 2, timestamp: 6, ..Default::default() };
+        let parent = L2BlockInfo {
+            l1_origin: BlockNumHash { number: 0, ..Default::default() },
+            block_info: BlockInfo { timestamp: 2, ..Default::default() },
+            ..Default::default()
+        };
+
+        let batch_vec = vec![PipelineResult::Ok(Batch::Single(batch))];
+        let mut mock = TestNextBatchProvider::new(batch_vec);
+        mock.origin = Some(BlockInfo { number: 1, ..Default::default() });
+
+        let hook = TestBatchValidityHook::default();
+        let mut bv =
+            BatchValidator::new(cfg, mock).with_validity_hook(Some(Arc::new(hook.clone())));
+        bv.signal(Signal::Reset(ResetSignal {
+            l1_origin: BlockInfo { number: 1, ..Default::default() },
+            ..Default::default()
+        }))
+        .await
+        .unwrap();
+        bv.l1_blocks.push(BlockInfo { number: 1, ..Default::default() });
+
+        // The batch skips a block, so it is dropped under Holocene ordering rules.
+        assert_eq!(bv.next_batch(parent).await.unwrap_err(), PipelineError::NotEnoughData.temp());
+        let reports = hook.reports();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].batch_type, BatchType::Single);
+        assert_eq!(reports[0].validity, BatchValidity::Drop);
+        assert_eq!(
+            reports[0].rule,
+            BatchValidityRule::FutureTimestamp { timestamp: 6, next_timestamp: 4, holocene: true }
+        );
+    }
+
     #[tokio::test]
     async fn test_batch_validator_next_batch_sequence_window_expired() {
         let trace_store: TraceStorage = Default::default();
@@ -12,10 +12,10 @@
 //! [ChannelReader]: crate::stages::channel::ChannelReader
 //! [AttributesQueue]: crate::stages::attributes_queue::AttributesQueue
 
-use crate::types::PipelineResult;
-use alloc::boxed::Box;
+use crate::{traits::BatchValidityHook, types::PipelineResult};
+use alloc::{boxed::Box, sync::Arc};
 use async_trait::async_trait;
-use kona_protocol::{Batch, BlockInfo, L2BlockInfo};
+use kona_protocol::{Batch, BatchValidityReport, BlockInfo, L2BlockInfo};
 
 mod batch_stream;
 pub use batch_stream::{BatchStream, BatchStreamProvider};
@@ -56,3 +56,20 @@ pub trait NextBatchProvider {
     /// if an invalid single batch is found. Pre-holocene hardfork, this will be a no-op.
     fn flush(&mut self);
 }
+
+/// Records a [`BatchValidityReport`] in the pipeline metrics and publishes it to the
+/// [`BatchValidityHook`], if one is set.
+pub(crate) fn publish_validity_report(
+    hook: Option<&Arc<dyn BatchValidityHook>>,
+    report: &BatchValidityReport,
+) {
+    kona_macros::inc!(
+        gauge,
+        crate::metrics::Metrics::PIPELINE_BATCH_VALIDITY,
+        "validity" => report.validity.to_string(),
+        "rule" => report.rule.name(),
+    );
+    if let Some(hook) = hook {
+        hook.on_report(report);
+    }
+}
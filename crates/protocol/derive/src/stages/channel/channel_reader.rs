@@ -11,7 +11,7 @@ use core::fmt::Debug;
 use kona_genesis::{
     MAX_RLP_BYTES_PER_CHANNEL_BEDROCK, MAX_RLP_BYTES_PER_CHANNEL_FJORD, RollupConfig,
 };
-use kona_protocol::{Batch, BatchReader, BlockInfo};
+use kona_protocol::{Batch, BatchReader, BlockInfo, DecompressorRegistry, StreamingBatchReader};
 use tracing::{debug, warn};
 
 /// The [`ChannelReader`] provider trait.
@@ -27,12 +27,13 @@ pub trait ChannelReaderProvider {
 /// [`ChannelReader`] is a stateful stage that reads [`Batch`]es from `Channel`s.
 ///
 /// The [`ChannelReader`] pulls `Channel`s from the channel bank as raw data
-/// and pipes it into a `BatchReader`. Since the raw data is compressed,
-/// the `BatchReader` first decompresses the data using the first bytes as
-/// a compression algorithm identifier.
+/// and pipes it into a [`StreamingBatchReader`]. Since the raw data is compressed,
+/// the [`StreamingBatchReader`] decompresses the data incrementally, using the first
+/// bytes as a compression algorithm identifier.
 ///
-/// Once the data is decompressed, it is decoded into a `Batch` and passed
-/// to the next stage in the pipeline.
+/// Each `Batch` is decoded as soon as its bytes are decompressed and passed
+/// to the next stage in the pipeline, so that at most one batch of the channel
+/// is held in memory at a time.
 #[derive(Debug)]
 pub struct ChannelReader<P>
 where
@@ -40,10 +41,12 @@ where
 {
     /// The previous stage of the derivation pipeline.
     prev: P,
-    /// The batch reader.
-    next_batch: Option<BatchReader>,
+    /// The streaming batch reader of the current channel.
+    next_batch: Option<StreamingBatchReader>,
     /// The rollup coonfiguration.
     cfg: Arc<RollupConfig>,
+    /// The decompressors, selected by the channel version byte.
+    decompressors: DecompressorRegistry,
 }
 
 impl<P> ChannelReader<P>
@@ -52,7 +55,13 @@ where
 {
     /// Create a new [`ChannelReader`] stage.
     pub const fn new(prev: P, cfg: Arc<RollupConfig>) -> Self {
-        Self { prev, next_batch: None, cfg }
+        Self { prev, next_batch: None, cfg, decompressors: DecompressorRegistry::new() }
+    }
+
+    /// Sets the [`DecompressorRegistry`] used to decompress channels.
+    pub fn with_decompressors(mut self, decompressors: DecompressorRegistry) -> Self {
+        self.decompressors = decompressors;
+        self
     }
 
     /// Creates the batch reader from available channel data.
@@ -68,8 +77,10 @@ where
                 MAX_RLP_BYTES_PER_CHANNEL_BEDROCK
             };
 
-            self.next_batch =
-                Some(BatchReader::new(&channel[..], max_rlp_bytes_per_channel as usize));
+            self.next_batch = Some(
+                StreamingBatchReader::new(&channel[..], max_rlp_bytes_per_channel as usize)
+                    .with_decompressors(self.decompressors.clone()),
+            );
             kona_macros::set!(gauge, crate::metrics::Metrics::PIPELINE_BATCH_READER_SET, 1);
         }
         Ok(())
@@ -118,11 +129,13 @@ where
 
         // SAFETY: The batch reader must be set above.
         let next_batch = self.next_batch.as_mut().expect("Batch reader must be set");
-        match next_batch.decompress() {
-            Ok(()) => {
+
+        // Decompress and decode the next batch from the channel.
+        match next_batch.next_full_batch(self.cfg.as_ref()) {
+            Ok(Some(batch)) => {
                 // Record the decompressed size and type.
-                let size = next_batch.decompressed.len() as f64;
-                let ty = if next_batch.brotli_used {
+                let size = next_batch.decompressed_len() as f64;
+                let ty = if next_batch.brotli_used() {
                     BatchReader::CHANNEL_VERSION_BROTLI
                 } else {
                     BatchReader::ZLIB_DEFLATE_COMPRESSION_METHOD
@@ -137,17 +150,6 @@ where
                     crate::metrics::Metrics::PIPELINE_LATEST_DECOMPRESSED_BATCH_TYPE,
                     ty as f64
                 );
-            }
-            Err(err) => {
-                debug!(target: "channel_reader", ?err, "Failed to decompress batch");
-                self.next_channel();
-                return Err(PipelineError::NotEnoughData.temp());
-            }
-        }
-
-        // Read the next batch from the reader's decompressed data
-        match next_batch.next_batch(self.cfg.as_ref()).ok_or(PipelineError::NotEnoughData.temp()) {
-            Ok(batch) => {
                 kona_macros::inc!(
                     gauge,
                     crate::metrics::Metrics::PIPELINE_READ_BATCHES,
@@ -155,9 +157,14 @@ where
                 );
                 Ok(batch)
             }
-            Err(e) => {
+            Ok(None) => {
+                self.next_channel();
+                Err(PipelineError::NotEnoughData.temp())
+            }
+            Err(err) => {
+                debug!(target: "channel_reader", ?err, "Failed to read batch");
                 self.next_channel();
-                Err(e)
+                Err(PipelineError::NotEnoughData.temp())
             }
         }
     }
@@ -215,7 +222,7 @@ mod test {
     async fn test_flush_channel_reader() {
         let mock = TestChannelReaderProvider::new(vec![Ok(Some(new_compressed_batch_data()))]);
         let mut reader = ChannelReader::new(mock, Arc::new(RollupConfig::default()));
-        reader.next_batch = Some(BatchReader::new(
+        reader.next_batch = Some(StreamingBatchReader::new(
             new_compressed_batch_data(),
             MAX_RLP_BYTES_PER_CHANNEL_FJORD as usize,
         ));
@@ -227,7 +234,7 @@ mod test {
     async fn test_reset_channel_reader() {
         let mock = TestChannelReaderProvider::new(vec![Ok(None)]);
         let mut reader = ChannelReader::new(mock, Arc::new(RollupConfig::default()));
-        reader.next_batch = Some(BatchReader::new(
+        reader.next_batch = Some(StreamingBatchReader::new(
             vec![0x00, 0x01, 0x02],
             MAX_RLP_BYTES_PER_CHANNEL_FJORD as usize,
         ));
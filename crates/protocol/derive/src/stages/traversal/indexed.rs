@@ -3,6 +3,7 @@
 use crate::{
     ActivationSignal, ChainProvider, L1RetrievalProvider, OriginAdvancer, OriginProvider,
     PipelineError, PipelineResult, ResetError, ResetSignal, Signal, SignalReceiver,
+    SystemConfigHook,
 };
 use alloc::{boxed::Box, sync::Arc};
 use alloy_primitives::Address;
@@ -29,6 +30,8 @@ pub struct IndexedTraversal<Provider: ChainProvider> {
     pub system_config: SystemConfig,
     /// A reference to the rollup config.
     pub rollup_config: Arc<RollupConfig>,
+    /// Receives the system config updates applied by this stage.
+    pub system_config_hook: Option<Arc<dyn SystemConfigHook>>,
 }
 
 #[async_trait]
@@ -56,9 +59,16 @@ impl<F: ChainProvider> IndexedTraversal<F> {
             done: false,
             system_config: SystemConfig::default(),
             rollup_config: cfg,
+            system_config_hook: None,
         }
     }
 
+    /// Sets the [`SystemConfigHook`] that receives the system config updates of each L1 block.
+    pub fn with_system_config_hook(mut self, hook: Option<Arc<dyn SystemConfigHook>>) -> Self {
+        self.system_config_hook = hook;
+        self
+    }
+
     /// Update the origin block in the traversal stage.
     fn update_origin(&mut self, block: BlockInfo) {
         self.done = false;
@@ -90,9 +100,13 @@ impl<F: ChainProvider> IndexedTraversal<F> {
         let receipts =
             self.data_source.receipts_by_hash(block_info.hash).await.map_err(Into::into)?;
 
-        let addr = self.rollup_config.l1_system_config_address;
-        let active = self.rollup_config.is_ecotone_active(block_info.timestamp);
-        match self.system_config.update_with_receipts(&receipts[..], addr, active) {
+        match super::update_system_config(
+            &mut self.system_config,
+            self.system_config_hook.as_ref(),
+            &block_info,
+            &receipts,
+            &self.rollup_config,
+        ) {
             Ok(true) => {
                 let next = block_info.number as f64;
                 kona_macros::set!(gauge, crate::Metrics::PIPELINE_LATEST_SYS_CONFIG_UPDATE, next);
@@ -9,6 +9,12 @@
 //! - [`PollingTraversal`]: An active traversal stage that polls for the next block through its
 //!   provider.
 
+use crate::SystemConfigHook;
+use alloc::sync::Arc;
+use alloy_consensus::Receipt;
+use kona_genesis::{RollupConfig, SystemConfig, SystemConfigUpdateError};
+use kona_protocol::{BlockInfo, SystemConfigUpdateRecord};
+
 mod indexed;
 pub use indexed::IndexedTraversal;
 
@@ -23,3 +29,27 @@ pub enum TraversalStage {
     /// An active traversal stage that polls for the next block through its provider.
     Polling,
 }
+
+/// Applies the system config updates contained in the receipts of the given L1 block, publishing
+/// them to the [`SystemConfigHook`] if one is set.
+///
+/// Returns `true` if any config updates were applied, `false` otherwise.
+pub(crate) fn update_system_config(
+    system_config: &mut SystemConfig,
+    hook: Option<&Arc<dyn SystemConfigHook>>,
+    block: &BlockInfo,
+    receipts: &[Receipt],
+    rollup_config: &RollupConfig,
+) -> Result<bool, SystemConfigUpdateError> {
+    let addr = rollup_config.l1_system_config_address;
+    let active = rollup_config.is_ecotone_active(block.timestamp);
+    let Some(hook) = hook else {
+        return system_config.update_with_receipts(receipts, addr, active);
+    };
+
+    let updates = SystemConfig::updates_from_receipts(receipts, addr, active)?;
+    let prev = *system_config;
+    let records = SystemConfigUpdateRecord::apply_all(*block, system_config, updates);
+    hook.on_l1_block(block, &prev, &records);
+    Ok(!records.is_empty())
+}
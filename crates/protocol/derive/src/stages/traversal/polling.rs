@@ -3,6 +3,7 @@
 use crate::{
     ActivationSignal, ChainProvider, L1RetrievalProvider, OriginAdvancer, OriginProvider,
     PipelineError, PipelineResult, ResetError, ResetSignal, Signal, SignalReceiver,
+    SystemConfigHook,
 };
 use alloc::{boxed::Box, sync::Arc};
 use alloy_primitives::Address;
@@ -29,6 +30,8 @@ pub struct PollingTraversal<Provider: ChainProvider> {
     pub system_config: SystemConfig,
     /// A reference to the rollup config.
     pub rollup_config: Arc<RollupConfig>,
+    /// Receives the system config updates applied by this stage.
+    pub system_config_hook: Option<Arc<dyn SystemConfigHook>>,
 }
 
 #[async_trait]
@@ -56,9 +59,16 @@ impl<F: ChainProvider> PollingTraversal<F> {
             done: false,
             system_config: SystemConfig::default(),
             rollup_config: cfg,
+            system_config_hook: None,
         }
     }
 
+    /// Sets the [`SystemConfigHook`] that receives the system config updates of each L1 block.
+    pub fn with_system_config_hook(mut self, hook: Option<Arc<dyn SystemConfigHook>>) -> Self {
+        self.system_config_hook = hook;
+        self
+    }
+
     /// Update the origin block in the traversal stage.
     fn update_origin(&mut self, block: BlockInfo) {
         self.done = false;
@@ -98,9 +108,13 @@ impl<F: ChainProvider + Send> OriginAdvancer for PollingTraversal<F> {
         let receipts =
             self.data_source.receipts_by_hash(next_l1_origin.hash).await.map_err(Into::into)?;
 
-        let addr = self.rollup_config.l1_system_config_address;
-        let active = self.rollup_config.is_ecotone_active(next_l1_origin.timestamp);
-        match self.system_config.update_with_receipts(&receipts[..], addr, active) {
+        match super::update_system_config(
+            &mut self.system_config,
+            self.system_config_hook.as_ref(),
+            &next_l1_origin,
+            &receipts,
+            &self.rollup_config,
+        ) {
             Ok(true) => {
                 let next = next_l1_origin.number as f64;
                 kona_macros::set!(gauge, crate::Metrics::PIPELINE_LATEST_SYS_CONFIG_UPDATE, next);
@@ -175,8 +189,11 @@ impl<F: ChainProvider + Send> SignalReceiver for PollingTraversal<F> {
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
-    use crate::{errors::PipelineErrorKind, test_utils::TraversalTestHelper};
-    use alloc::vec;
+    use crate::{
+        errors::PipelineErrorKind,
+        test_utils::{TestSystemConfigHook, TraversalTestHelper},
+    };
+    use alloc::{vec, vec::Vec};
     use alloy_primitives::{address, b256};
 
     #[test]
@@ -310,4 +327,24 @@ pub(crate) mod tests {
         let expected = address!("000000000000000000000000000000000000bEEF");
         assert_eq!(traversal.system_config.batcher_address, expected);
     }
+
+    #[tokio::test]
+    async fn test_l1_traversal_system_config_hook() {
+        let blocks = vec![BlockInfo::default(), BlockInfo::default()];
+        let receipts = TraversalTestHelper::new_receipts();
+        let hook = TestSystemConfigHook::default();
+        let mut traversal = TraversalTestHelper::new_from_blocks(blocks, receipts)
+            .with_system_config_hook(Some(Arc::new(hook.clone())));
+        assert!(traversal.advance_origin().await.is_ok());
+
+        let expected = address!("000000000000000000000000000000000000bEEF");
+        assert_eq!(traversal.system_config.batcher_address, expected);
+
+        let blocks = hook.blocks();
+        assert_eq!(blocks.len(), 1);
+        let (block, records) = &blocks[0];
+        assert_eq!(*block, BlockInfo::default());
+        assert_eq!(records.iter().map(|r| r.log_index).collect::<Vec<_>>(), vec![0, 2]);
+        assert!(records.iter().all(|r| r.system_config.batcher_address == expected));
+    }
 }
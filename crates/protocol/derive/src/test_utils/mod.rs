@@ -46,6 +46,12 @@ pub use tracing::{CollectingLayer, TraceStorage};
 mod sys_config_fetcher;
 pub use sys_config_fetcher::{TestSystemConfigL2Fetcher, TestSystemConfigL2FetcherError};
 
+mod validity_hook;
+pub use validity_hook::TestBatchValidityHook;
+
+mod system_config_hook;
+pub use system_config_hook::TestSystemConfigHook;
+
 mod frames;
 pub use frames::{FrameQueueAsserter, FrameQueueBuilder};
 
@@ -0,0 +1,29 @@
+//! A mock implementation of the [`SystemConfigHook`] trait for testing.
+
+use crate::traits::SystemConfigHook;
+use alloc::{sync::Arc, vec::Vec};
+use kona_genesis::SystemConfig;
+use kona_protocol::{BlockInfo, SystemConfigUpdateRecord};
+use spin::Mutex;
+
+/// A [`SystemConfigHook`] that collects the L1 blocks and update records it receives.
+#[derive(Debug, Default, Clone)]
+pub struct TestSystemConfigHook(pub Arc<Mutex<Vec<(BlockInfo, Vec<SystemConfigUpdateRecord>)>>>);
+
+impl TestSystemConfigHook {
+    /// Returns the collected L1 blocks and their update records.
+    pub fn blocks(&self) -> Vec<(BlockInfo, Vec<SystemConfigUpdateRecord>)> {
+        self.0.lock().clone()
+    }
+}
+
+impl SystemConfigHook for TestSystemConfigHook {
+    fn on_l1_block(
+        &self,
+        block: &BlockInfo,
+        _: &SystemConfig,
+        updates: &[SystemConfigUpdateRecord],
+    ) {
+        self.0.lock().push((*block, updates.to_vec()));
+    }
+}
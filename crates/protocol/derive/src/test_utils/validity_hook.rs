@@ -0,0 +1,23 @@
+//! A mock implementation of the [`BatchValidityHook`] trait for testing.
+
+use crate::traits::BatchValidityHook;
+use alloc::{sync::Arc, vec::Vec};
+use kona_protocol::BatchValidityReport;
+use spin::Mutex;
+
+/// A [`BatchValidityHook`] that collects the reports it receives.
+#[derive(Debug, Default, Clone)]
+pub struct TestBatchValidityHook(pub Arc<Mutex<Vec<BatchValidityReport>>>);
+
+impl TestBatchValidityHook {
+    /// Returns the collected reports.
+    pub fn reports(&self) -> Vec<BatchValidityReport> {
+        self.0.lock().clone()
+    }
+}
+
+impl BatchValidityHook for TestBatchValidityHook {
+    fn on_report(&self, report: &BatchValidityReport) {
+        self.0.lock().push(*report);
+    }
+}
@@ -18,3 +18,9 @@ pub use reset::ResetProvider;
 
 mod stages;
 pub use stages::{OriginAdvancer, OriginProvider, SignalReceiver};
+
+mod system_config;
+pub use system_config::SystemConfigHook;
+
+mod validity;
+pub use validity::BatchValidityHook;
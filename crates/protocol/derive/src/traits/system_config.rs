@@ -0,0 +1,23 @@
+//! Contains the [`SystemConfigHook`] trait.
+
+use core::fmt::Debug;
+use kona_genesis::SystemConfig;
+use kona_protocol::{BlockInfo, SystemConfigUpdateRecord};
+
+/// Receives the system config updates applied by the traversal stage of the derivation pipeline.
+///
+/// Hooks are called synchronously from within the pipeline, so implementations should not block.
+pub trait SystemConfigHook: Debug + Send + Sync {
+    /// Called each time the traversal stage advances to a new L1 block, with the [`SystemConfig`]
+    /// in effect before the block and a [`SystemConfigUpdateRecord`] for every update the block
+    /// contains, in log order.
+    ///
+    /// The traversal stage may revisit L1 blocks after a pipeline reset, in which case the hook is
+    /// called again for every block from the new origin onwards.
+    fn on_l1_block(
+        &self,
+        block: &BlockInfo,
+        system_config: &SystemConfig,
+        updates: &[SystemConfigUpdateRecord],
+    );
+}
@@ -0,0 +1,13 @@
+//! Contains the [`BatchValidityHook`] trait.
+
+use core::fmt::Debug;
+use kona_protocol::BatchValidityReport;
+
+/// Receives the [`BatchValidityReport`] of every batch validity check performed by the batch
+/// stages of the derivation pipeline.
+///
+/// Hooks are called synchronously from within the pipeline, so implementations should not block.
+pub trait BatchValidityHook: Debug + Send + Sync {
+    /// Called with the [`BatchValidityReport`] of a batch validity check.
+    fn on_report(&self, report: &BatchValidityReport);
+}
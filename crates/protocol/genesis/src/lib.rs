@@ -57,6 +57,9 @@ pub use chain::{
 mod genesis;
 pub use genesis::ChainGenesis;
 
+mod validation;
+pub use validation::{MIN_SEQ_WINDOW_SIZE, RollupConfigDiff, RollupConfigError};
+
 mod rollup;
 pub use rollup::{
     DEFAULT_INTEROP_MESSAGE_EXPIRY_WINDOW, FJORD_MAX_SEQUENCER_DRIFT, GRANITE_CHANNEL_TIMEOUT,
@@ -1,9 +1,10 @@
 //! Contains the [`SystemConfig`] type.
 
 use crate::{
-    CONFIG_UPDATE_TOPIC, RollupConfig, SystemConfigLog, SystemConfigUpdateError,
-    SystemConfigUpdateKind,
+    CONFIG_UPDATE_TOPIC, RollupConfig, SystemConfigLog, SystemConfigUpdate,
+    SystemConfigUpdateError, SystemConfigUpdateKind,
 };
+use alloc::vec::Vec;
 use alloy_consensus::{Eip658Value, Receipt};
 use alloy_primitives::{Address, B64, Log, U256};
 
@@ -135,6 +136,37 @@ impl SystemConfig {
         Ok(updated)
     }
 
+    /// Filters all L1 receipts to find config updates and decodes them without applying them.
+    ///
+    /// Returns each [SystemConfigUpdate] alongside the index of its log within the block.
+    pub fn updates_from_receipts(
+        receipts: &[Receipt],
+        l1_system_config_address: Address,
+        ecotone_active: bool,
+    ) -> Result<Vec<(u64, SystemConfigUpdate)>, SystemConfigUpdateError> {
+        let mut updates = Vec::new();
+        let mut log_index = 0u64;
+        for receipt in receipts {
+            if Eip658Value::Eip658(false) == receipt.status {
+                log_index += receipt.logs.len() as u64;
+                continue;
+            }
+
+            for log in &receipt.logs {
+                let topics = log.topics();
+                if log.address == l1_system_config_address &&
+                    !topics.is_empty() &&
+                    topics[0] == CONFIG_UPDATE_TOPIC
+                {
+                    let update = SystemConfigLog::new(log.clone(), ecotone_active).build()?;
+                    updates.push((log_index, update));
+                }
+                log_index += 1;
+            }
+        }
+        Ok(updates)
+    }
+
     /// Returns the eip1559 parameters from a [SystemConfig] encoded as a [B64].
     pub fn eip_1559_params(
         &self,
@@ -400,6 +432,49 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_system_config_updates_from_receipts() {
+        const UPDATE_TYPE: B256 =
+            b256!("0000000000000000000000000000000000000000000000000000000000000000");
+
+        let update_log = Log {
+            address: Address::ZERO,
+            data: LogData::new_unchecked(
+                vec![
+                    CONFIG_UPDATE_TOPIC,
+                    CONFIG_UPDATE_EVENT_VERSION_0,
+                    UPDATE_TYPE,
+                ],
+                hex!("00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000beef").into()
+            )
+        };
+        let other_log = Log { address: Address::repeat_byte(1), data: update_log.data.clone() };
+
+        let receipts = [
+            Receipt {
+                logs: vec![other_log.clone(), update_log.clone()],
+                status: Eip658Value::Eip658(true),
+                cumulative_gas_used: 0,
+            },
+            Receipt {
+                logs: vec![update_log.clone()],
+                status: Eip658Value::Eip658(false),
+                cumulative_gas_used: 0,
+            },
+            Receipt {
+                logs: vec![update_log],
+                status: Eip658Value::Eip658(true),
+                cumulative_gas_used: 0,
+            },
+        ];
+
+        let updates = SystemConfig::updates_from_receipts(&receipts, Address::ZERO, false).unwrap();
+        let expected = SystemConfigUpdate::Batcher(crate::BatcherUpdate {
+            batcher_address: address!("000000000000000000000000000000000000bEEF"),
+        });
+        assert_eq!(updates, vec![(1, expected.clone()), (3, expected)]);
+    }
+
     #[test]
     fn test_system_config_update_batcher_log() {
         const UPDATE_TYPE: B256 =
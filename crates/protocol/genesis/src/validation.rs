@@ -0,0 +1,514 @@
+//! Validation and diffing of [RollupConfig]s.
+
+use crate::RollupConfig;
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{Debug, Display};
+
+/// The minimum sequencing window size, in L1 blocks.
+pub const MIN_SEQ_WINDOW_SIZE: u64 = 2;
+
+/// The Alt DA commitment type that requires the challenge contract to be configured.
+const KECCAK_COMMITMENT: &str = "KeccakCommitment";
+
+/// The Alt DA commitment type that is resolved by an external DA server.
+const GENERIC_COMMITMENT: &str = "GenericCommitment";
+
+/// An error returned by [RollupConfig::validate].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RollupConfigError {
+    /// A required field is zero.
+    #[error("{0} must be non-zero")]
+    ZeroField(&'static str),
+    /// The sequencing window is too small.
+    #[error("seq_window_size must be at least {MIN_SEQ_WINDOW_SIZE}, got {0}")]
+    SeqWindowSizeTooSmall(u64),
+    /// The L1 and L2 chain IDs are equal.
+    #[error("L1 and L2 chain IDs must differ, both are {0}")]
+    SameChainIds(u64),
+    /// The genesis system config is missing.
+    #[error("Missing genesis system config")]
+    MissingGenesisSystemConfig,
+    /// A hardfork activates before a hardfork that precedes it.
+    #[error("{fork} activates at {time}, before {previous} at {previous_time}")]
+    HardforkOrder {
+        /// The misordered hardfork.
+        fork: &'static str,
+        /// The activation time of the misordered hardfork.
+        time: u64,
+        /// The hardfork that should activate first.
+        previous: &'static str,
+        /// The activation time of the hardfork that should activate first.
+        previous_time: u64,
+    },
+    /// A hardfork is scheduled while a hardfork that it builds on is not.
+    #[error("{fork} is scheduled, but {previous} is not")]
+    MissingPreviousHardfork {
+        /// The scheduled hardfork.
+        fork: &'static str,
+        /// The unscheduled hardfork that it builds on.
+        previous: &'static str,
+    },
+    /// A field required by a scheduled hardfork is zero.
+    #[error("{fork} is scheduled, but {field} is zero")]
+    MissingForkField {
+        /// The scheduled hardfork.
+        fork: &'static str,
+        /// The field required by the hardfork.
+        field: &'static str,
+    },
+    /// The genesis system config scalar is invalid for a chain with Ecotone active at genesis.
+    #[error("Invalid genesis system config scalar for Ecotone: {0}")]
+    InvalidGenesisScalar(&'static str),
+    /// The Alt DA config is invalid.
+    #[error("Invalid Alt DA config: {0}")]
+    InvalidAltDA(&'static str),
+}
+
+/// A field that differs between two [RollupConfig]s, as returned by [RollupConfig::diff].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RollupConfigDiff {
+    /// The path of the differing field, e.g. `genesis.l2_time`.
+    pub field: &'static str,
+    /// The value of the field in the config being diffed.
+    pub left: String,
+    /// The value of the field in the config it is diffed against.
+    pub right: String,
+}
+
+impl RollupConfigDiff {
+    /// Creates a new [RollupConfigDiff] from the debug representations of both values.
+    pub fn new(field: &'static str, left: &impl Debug, right: &impl Debug) -> Self {
+        Self { field, left: format!("{left:?}"), right: format!("{right:?}") }
+    }
+}
+
+impl Display for RollupConfigDiff {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: {} != {}", self.field, self.left, self.right)
+    }
+}
+
+/// Pushes a [RollupConfigDiff] for each listed field that differs between two values.
+macro_rules! diff_fields {
+    ($diffs:ident, $left:expr, $right:expr, { $($name:literal => $($field:ident).+),* $(,)? }) => {
+        $(
+            if $left.$($field).+ != $right.$($field).+ {
+                $diffs.push(RollupConfigDiff::new($name, &$left.$($field).+, &$right.$($field).+));
+            }
+        )*
+    };
+}
+
+impl RollupConfig {
+    /// Validates the [RollupConfig].
+    ///
+    /// Checks that required fields are set, that hardforks are scheduled in order, that the fields
+    /// required by each scheduled hardfork are set, and that the genesis
+    /// [SystemConfig](crate::SystemConfig) is consistent with the hardforks active at genesis.
+    pub fn validate(&self) -> Result<(), RollupConfigError> {
+        let non_zero = [
+            ("block_time", self.block_time),
+            ("channel_timeout", self.channel_timeout),
+            ("l1_chain_id", self.l1_chain_id),
+            ("l2_chain_id", self.l2_chain_id.id()),
+            ("chain_op_config.eip1559_elasticity", self.chain_op_config.eip1559_elasticity),
+            ("chain_op_config.eip1559_denominator", self.chain_op_config.eip1559_denominator),
+        ];
+        if let Some((field, _)) = non_zero.into_iter().find(|(_, value)| *value == 0) {
+            return Err(RollupConfigError::ZeroField(field));
+        }
+        if self.seq_window_size < MIN_SEQ_WINDOW_SIZE {
+            return Err(RollupConfigError::SeqWindowSizeTooSmall(self.seq_window_size));
+        }
+        if self.l1_chain_id == self.l2_chain_id.id() {
+            return Err(RollupConfigError::SameChainIds(self.l1_chain_id));
+        }
+
+        let non_zero_addresses = [
+            ("batch_inbox_address", self.batch_inbox_address),
+            ("deposit_contract_address", self.deposit_contract_address),
+            ("l1_system_config_address", self.l1_system_config_address),
+        ];
+        if let Some((field, _)) = non_zero_addresses.into_iter().find(|(_, a)| a.is_zero()) {
+            return Err(RollupConfigError::ZeroField(field));
+        }
+        if self.genesis.l1.hash.is_zero() {
+            return Err(RollupConfigError::ZeroField("genesis.l1.hash"));
+        }
+        if self.genesis.l2.hash.is_zero() {
+            return Err(RollupConfigError::ZeroField("genesis.l2.hash"));
+        }
+
+        self.validate_hardforks()?;
+        self.validate_genesis_system_config()?;
+        self.validate_alt_da()
+    }
+
+    /// Returns the fields that differ between this [RollupConfig] and `other`.
+    ///
+    /// Nested fields are compared individually, so that e.g. a single differing hardfork time or
+    /// genesis system config value is reported as its own [RollupConfigDiff].
+    pub fn diff(&self, other: &Self) -> Vec<RollupConfigDiff> {
+        let mut diffs = Vec::new();
+        diff_fields!(diffs, self, other, {
+            "genesis.l1" => genesis.l1,
+            "genesis.l2" => genesis.l2,
+            "genesis.l2_time" => genesis.l2_time,
+        });
+        match (&self.genesis.system_config, &other.genesis.system_config) {
+            (Some(left), Some(right)) => {
+                diff_fields!(diffs, left, right, {
+                    "genesis.system_config.batcher_address" => batcher_address,
+                    "genesis.system_config.overhead" => overhead,
+                    "genesis.system_config.scalar" => scalar,
+                    "genesis.system_config.gas_limit" => gas_limit,
+                    "genesis.system_config.base_fee_scalar" => base_fee_scalar,
+                    "genesis.system_config.blob_base_fee_scalar" => blob_base_fee_scalar,
+                    "genesis.system_config.eip1559_denominator" => eip1559_denominator,
+                    "genesis.system_config.eip1559_elasticity" => eip1559_elasticity,
+                    "genesis.system_config.operator_fee_scalar" => operator_fee_scalar,
+                    "genesis.system_config.operator_fee_constant" => operator_fee_constant,
+                });
+            }
+            (left, right) if left != right => {
+                diffs.push(RollupConfigDiff::new("genesis.system_config", left, right))
+            }
+            _ => {}
+        }
+        diff_fields!(diffs, self, other, {
+            "block_time" => block_time,
+            "max_sequencer_drift" => max_sequencer_drift,
+            "seq_window_size" => seq_window_size,
+            "channel_timeout" => channel_timeout,
+            "granite_channel_timeout" => granite_channel_timeout,
+            "l1_chain_id" => l1_chain_id,
+            "l2_chain_id" => l2_chain_id,
+            "regolith_time" => hardforks.regolith_time,
+            "canyon_time" => hardforks.canyon_time,
+            "delta_time" => hardforks.delta_time,
+            "ecotone_time" => hardforks.ecotone_time,
+            "fjord_time" => hardforks.fjord_time,
+            "granite_time" => hardforks.granite_time,
+            "holocene_time" => hardforks.holocene_time,
+            "pectra_blob_schedule_time" => hardforks.pectra_blob_schedule_time,
+            "isthmus_time" => hardforks.isthmus_time,
+            "jovian_time" => hardforks.jovian_time,
+            "interop_time" => hardforks.interop_time,
+            "batch_inbox_address" => batch_inbox_address,
+            "deposit_contract_address" => deposit_contract_address,
+            "l1_system_config_address" => l1_system_config_address,
+            "protocol_versions_address" => protocol_versions_address,
+            "superchain_config_address" => superchain_config_address,
+            "blobs_enabled_l1_timestamp" => blobs_enabled_l1_timestamp,
+            "da_challenge_address" => da_challenge_address,
+            "interop_message_expiry_window" => interop_message_expiry_window,
+            "alt_da" => alt_da_config,
+            "chain_op_config.eip1559_elasticity" => chain_op_config.eip1559_elasticity,
+            "chain_op_config.eip1559_denominator" => chain_op_config.eip1559_denominator,
+            "chain_op_config.eip1559_denominator_canyon" =>
+                chain_op_config.eip1559_denominator_canyon,
+        });
+        diffs
+    }
+
+    /// Checks that the hardforks are scheduled in order, and that the fields they require are set.
+    fn validate_hardforks(&self) -> Result<(), RollupConfigError> {
+        let hf = &self.hardforks;
+
+        // Regolith is commonly left unscheduled, and the Pectra blob schedule is an optional fix
+        // that only applies to some chains, so unscheduled forks are skipped. When scheduled, the
+        // Pectra blob schedule must activate after Holocene and before Isthmus.
+        let ordered = [
+            ("Regolith", hf.regolith_time),
+            ("Canyon", hf.canyon_time),
+            ("Delta", hf.delta_time),
+            ("Ecotone", hf.ecotone_time),
+            ("Fjord", hf.fjord_time),
+            ("Granite", hf.granite_time),
+            ("Holocene", hf.holocene_time),
+            ("Pectra Blob Schedule", hf.pectra_blob_schedule_time),
+            ("Isthmus", hf.isthmus_time),
+            ("Jovian", hf.jovian_time),
+            ("Interop", hf.interop_time),
+        ];
+        let mut previous: Option<(&'static str, u64)> = None;
+        for (fork, time) in ordered {
+            let Some(time) = time else {
+                continue;
+            };
+            if let Some((previous, previous_time)) = previous {
+                if time < previous_time {
+                    return Err(RollupConfigError::HardforkOrder {
+                        fork,
+                        time,
+                        previous,
+                        previous_time,
+                    });
+                }
+            }
+            previous = Some((fork, time));
+        }
+
+        let dependencies = [
+            ("Delta", hf.delta_time, "Canyon", hf.canyon_time),
+            ("Ecotone", hf.ecotone_time, "Delta", hf.delta_time),
+            ("Fjord", hf.fjord_time, "Ecotone", hf.ecotone_time),
+            ("Granite", hf.granite_time, "Fjord", hf.fjord_time),
+            ("Holocene", hf.holocene_time, "Granite", hf.granite_time),
+            ("Isthmus", hf.isthmus_time, "Holocene", hf.holocene_time),
+            ("Jovian", hf.jovian_time, "Isthmus", hf.isthmus_time),
+            ("Interop", hf.interop_time, "Isthmus", hf.isthmus_time),
+            ("Pectra Blob Schedule", hf.pectra_blob_schedule_time, "Ecotone", hf.ecotone_time),
+        ];
+        for (fork, time, previous, previous_time) in dependencies {
+            if time.is_some() && previous_time.is_none() {
+                return Err(RollupConfigError::MissingPreviousHardfork { fork, previous });
+            }
+        }
+
+        let required = [
+            (
+                "Canyon",
+                hf.canyon_time,
+                "chain_op_config.eip1559_denominator_canyon",
+                self.chain_op_config.eip1559_denominator_canyon,
+            ),
+            ("Granite", hf.granite_time, "granite_channel_timeout", self.granite_channel_timeout),
+            (
+                "Interop",
+                hf.interop_time,
+                "interop_message_expiry_window",
+                self.interop_message_expiry_window,
+            ),
+        ];
+        for (fork, time, field, value) in required {
+            if time.is_some() && value == 0 {
+                return Err(RollupConfigError::MissingForkField { fork, field });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the genesis system config is set, and consistent with the hardforks that are
+    /// active at genesis.
+    fn validate_genesis_system_config(&self) -> Result<(), RollupConfigError> {
+        let system_config =
+            self.genesis.system_config.ok_or(RollupConfigError::MissingGenesisSystemConfig)?;
+        if system_config.gas_limit == 0 {
+            return Err(RollupConfigError::ZeroField("genesis.system_config.gas_limit"));
+        }
+        if system_config.batcher_address.is_zero() {
+            return Err(RollupConfigError::ZeroField("genesis.system_config.batcher_address"));
+        }
+        if self.is_ecotone_active(self.genesis.l2_time) {
+            Self::check_ecotone_l1_system_config_scalar(system_config.scalar.to_be_bytes())
+                .map_err(RollupConfigError::InvalidGenesisScalar)?;
+        }
+        Ok(())
+    }
+
+    /// Checks that the Alt DA config, if any, is complete for its commitment type.
+    fn validate_alt_da(&self) -> Result<(), RollupConfigError> {
+        let Some(alt_da) = &self.alt_da_config else {
+            return Ok(());
+        };
+        match alt_da.da_commitment_type.as_deref() {
+            Some(KECCAK_COMMITMENT) => {
+                if alt_da.da_challenge_address.is_none_or(|a| a.is_zero()) {
+                    return Err(RollupConfigError::InvalidAltDA("missing challenge address"));
+                }
+                if alt_da.da_challenge_window.is_none_or(|w| w == 0) {
+                    return Err(RollupConfigError::InvalidAltDA("missing challenge window"));
+                }
+                if alt_da.da_resolve_window.is_none_or(|w| w == 0) {
+                    return Err(RollupConfigError::InvalidAltDA("missing resolve window"));
+                }
+                Ok(())
+            }
+            Some(GENERIC_COMMITMENT) => Ok(()),
+            Some(_) => Err(RollupConfigError::InvalidAltDA("unknown commitment type")),
+            None => Err(RollupConfigError::InvalidAltDA("missing commitment type")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BaseFeeConfig, ChainGenesis, HardForkConfig, SystemConfig};
+    use alloc::{string::ToString, vec};
+    use alloy_eips::BlockNumHash;
+    use alloy_primitives::{Address, B256, U256, address};
+
+    fn valid_config() -> RollupConfig {
+        RollupConfig {
+            genesis: ChainGenesis {
+                l1: BlockNumHash { number: 1, hash: B256::repeat_byte(1) },
+                l2: BlockNumHash { number: 0, hash: B256::repeat_byte(2) },
+                l2_time: 0,
+                system_config: Some(SystemConfig {
+                    batcher_address: address!("6887246668a3b87F54DeB3b94Ba47a6f63F32985"),
+                    scalar: U256::from(0xa6fe0),
+                    gas_limit: 30_000_000,
+                    ..Default::default()
+                }),
+            },
+            block_time: 2,
+            max_sequencer_drift: 600,
+            seq_window_size: 3600,
+            channel_timeout: 300,
+            l1_chain_id: 1,
+            l2_chain_id: 10.into(),
+            hardforks: HardForkConfig {
+                canyon_time: Some(10),
+                delta_time: Some(20),
+                ecotone_time: Some(30),
+                ..Default::default()
+            },
+            batch_inbox_address: Address::repeat_byte(3),
+            deposit_contract_address: Address::repeat_byte(4),
+            l1_system_config_address: Address::repeat_byte(5),
+            chain_op_config: BaseFeeConfig::optimism(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_valid_config() {
+        assert_eq!(valid_config().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_zero_fields() {
+        let config = RollupConfig { block_time: 0, ..valid_config() };
+        assert_eq!(config.validate(), Err(RollupConfigError::ZeroField("block_time")));
+
+        let config = RollupConfig { batch_inbox_address: Address::ZERO, ..valid_config() };
+        assert_eq!(config.validate(), Err(RollupConfigError::ZeroField("batch_inbox_address")));
+
+        let config = RollupConfig { l2_chain_id: 1.into(), ..valid_config() };
+        assert_eq!(config.validate(), Err(RollupConfigError::SameChainIds(1)));
+    }
+
+    #[test]
+    fn test_validate_hardfork_order() {
+        let mut config = valid_config();
+        config.hardforks.delta_time = Some(5);
+        assert_eq!(
+            config.validate(),
+            Err(RollupConfigError::HardforkOrder {
+                fork: "Delta",
+                time: 5,
+                previous: "Canyon",
+                previous_time: 10,
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_pectra_blob_schedule_order() {
+        let mut config = valid_config();
+        config.hardforks.fjord_time = Some(40);
+        config.hardforks.granite_time = Some(50);
+        config.hardforks.holocene_time = Some(60);
+        config.hardforks.isthmus_time = Some(80);
+        config.hardforks.pectra_blob_schedule_time = Some(70);
+        assert_eq!(config.validate(), Ok(()));
+
+        config.hardforks.pectra_blob_schedule_time = Some(90);
+        assert_eq!(
+            config.validate(),
+            Err(RollupConfigError::HardforkOrder {
+                fork: "Isthmus",
+                time: 80,
+                previous: "Pectra Blob Schedule",
+                previous_time: 90,
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_missing_previous_hardfork() {
+        let mut config = valid_config();
+        config.hardforks.delta_time = None;
+        assert_eq!(
+            config.validate(),
+            Err(RollupConfigError::MissingPreviousHardfork { fork: "Ecotone", previous: "Delta" })
+        );
+    }
+
+    #[test]
+    fn test_validate_missing_fork_field() {
+        let mut config = valid_config();
+        config.hardforks.fjord_time = Some(40);
+        config.hardforks.granite_time = Some(50);
+        config.granite_channel_timeout = 0;
+        assert_eq!(
+            config.validate(),
+            Err(RollupConfigError::MissingForkField {
+                fork: "Granite",
+                field: "granite_channel_timeout",
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_genesis_system_config() {
+        let mut config = valid_config();
+        config.genesis.system_config = None;
+        assert_eq!(config.validate(), Err(RollupConfigError::MissingGenesisSystemConfig));
+
+        let mut config = valid_config();
+        config.hardforks = HardForkConfig {
+            canyon_time: Some(0),
+            delta_time: Some(0),
+            ecotone_time: Some(0),
+            ..Default::default()
+        };
+        config.genesis.system_config.as_mut().unwrap().scalar = U256::from(2) << 248;
+        assert_eq!(
+            config.validate(),
+            Err(RollupConfigError::InvalidGenesisScalar("Unrecognized scalar version"))
+        );
+    }
+
+    #[test]
+    fn test_validate_alt_da() {
+        let mut config = valid_config();
+        config.alt_da_config = Some(crate::AltDAConfig {
+            da_commitment_type: Some(KECCAK_COMMITMENT.to_string()),
+            ..Default::default()
+        });
+        assert_eq!(
+            config.validate(),
+            Err(RollupConfigError::InvalidAltDA("missing challenge address"))
+        );
+
+        config.alt_da_config.as_mut().unwrap().da_commitment_type =
+            Some(GENERIC_COMMITMENT.to_string());
+        assert_eq!(config.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_diff() {
+        let left = valid_config();
+        assert!(left.diff(&left).is_empty());
+
+        let mut right = valid_config();
+        right.hardforks.ecotone_time = Some(31);
+        right.genesis.system_config.as_mut().unwrap().gas_limit = 60_000_000;
+        let diffs = left.diff(&right);
+        assert_eq!(
+            diffs,
+            vec![
+                RollupConfigDiff::new(
+                    "genesis.system_config.gas_limit",
+                    &30_000_000u64,
+                    &60_000_000u64
+                ),
+                RollupConfigDiff::new("ecotone_time", &Some(30u64), &Some(31u64)),
+            ]
+        );
+        assert_eq!(diffs[1].to_string(), "ecotone_time: Some(30) != Some(31)");
+    }
+}
@@ -100,6 +100,17 @@ pub enum SuperRootError {
     /// Invalid super root version byte
     #[error("Invalid super root version byte")]
     InvalidVersionByte,
+    /// No codec is registered for the super root version byte
+    #[error("Unknown super root version: {0}")]
+    UnknownVersion(u8),
+    /// The super root cannot be encoded with the codec of the given version byte
+    #[error("Super root of version {actual} cannot be encoded as version {expected}")]
+    VersionMismatch {
+        /// The version byte of the codec.
+        expected: u8,
+        /// The version byte of the super root.
+        actual: u8,
+    },
     /// Unexpected encoded super root length
     #[error("Unexpected encoded super root length")]
     UnexpectedLength,
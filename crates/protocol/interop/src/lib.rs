@@ -32,7 +32,10 @@ pub use errors::{
 };
 
 mod root;
-pub use root::{ChainRootInfo, OutputRootWithChain, SuperRoot, SuperRootOutput};
+pub use root::{
+    ChainRootInfo, CustomSuperRoot, OutputRootWithChain, SuperRoot, SuperRootCodec,
+    SuperRootOutput, SuperRootRegistry, VersionedSuperRoot,
+};
 
 mod message;
 pub use message::{
@@ -85,6 +85,208 @@ impl SuperRoot {
     }
 }
 
+/// The encoder and decoder of a super root version, registered in a [SuperRootRegistry].
+#[derive(Debug, Clone, Copy)]
+pub struct SuperRootCodec {
+    /// The super root version byte.
+    pub version: u8,
+    /// Encodes a super root of this version into the given buffer, including its version byte.
+    pub encode: fn(&VersionedSuperRoot, &mut dyn BufMut) -> SuperRootResult<()>,
+    /// Decodes an encoded super root of this version, including its version byte.
+    pub decode: fn(&mut &[u8]) -> SuperRootResult<VersionedSuperRoot>,
+}
+
+impl SuperRootCodec {
+    /// The codec of the V1 [SuperRoot].
+    pub const V1: Self = Self { version: SUPER_ROOT_VERSION, encode: encode_v1, decode: decode_v1 };
+
+    /// Encodes the given super root with this codec into the given buffer, including its version
+    /// byte.
+    pub fn encode(
+        &self,
+        super_root: &VersionedSuperRoot,
+        out: &mut dyn BufMut,
+    ) -> SuperRootResult<()> {
+        if super_root.version() != self.version {
+            return Err(SuperRootError::VersionMismatch {
+                expected: self.version,
+                actual: super_root.version(),
+            });
+        }
+        (self.encode)(super_root, out)
+    }
+
+    /// Decodes an encoded super root with this codec, including its version byte.
+    pub fn decode(&self, buf: &mut &[u8]) -> SuperRootResult<VersionedSuperRoot> {
+        (self.decode)(buf)
+    }
+}
+
+/// Encodes a V1 [SuperRoot].
+fn encode_v1(super_root: &VersionedSuperRoot, out: &mut dyn BufMut) -> SuperRootResult<()> {
+    match super_root {
+        VersionedSuperRoot::V1(super_root) => {
+            super_root.encode(out);
+            Ok(())
+        }
+        other => Err(SuperRootError::VersionMismatch {
+            expected: SUPER_ROOT_VERSION,
+            actual: other.version(),
+        }),
+    }
+}
+
+/// Decodes a V1 [SuperRoot].
+fn decode_v1(buf: &mut &[u8]) -> SuperRootResult<VersionedSuperRoot> {
+    SuperRoot::decode(buf).map(VersionedSuperRoot::V1)
+}
+
+/// A registry of [SuperRootCodec]s, keyed by super root version byte.
+///
+/// Registered codecs take precedence over the built-in [SuperRootCodec::V1], in reverse
+/// registration order. Supporting a new super root version requires a [VersionedSuperRoot]
+/// variant and a codec registered here.
+#[derive(Debug, Clone, Default)]
+pub struct SuperRootRegistry {
+    /// The registered codecs.
+    codecs: Vec<SuperRootCodec>,
+}
+
+impl SuperRootRegistry {
+    /// Creates a new [SuperRootRegistry] with only the built-in codecs.
+    pub const fn new() -> Self {
+        Self { codecs: Vec::new() }
+    }
+
+    /// Registers the given [SuperRootCodec].
+    pub fn register(&mut self, codec: SuperRootCodec) -> &mut Self {
+        self.codecs.push(codec);
+        self
+    }
+
+    /// Returns the registry with the given [SuperRootCodec] registered.
+    pub fn with(mut self, codec: SuperRootCodec) -> Self {
+        self.register(codec);
+        self
+    }
+
+    /// Returns the [SuperRootCodec] for the given super root version byte.
+    pub fn get(&self, version: u8) -> Option<&SuperRootCodec> {
+        const BUILT_IN: [SuperRootCodec; 1] = [SuperRootCodec::V1];
+
+        self.codecs.iter().rev().chain(&BUILT_IN).find(|codec| codec.version == version)
+    }
+
+    /// Encodes a super root with the codec registered for its version byte into the given
+    /// buffer.
+    pub fn encode(
+        &self,
+        super_root: &VersionedSuperRoot,
+        out: &mut dyn BufMut,
+    ) -> SuperRootResult<()> {
+        let version = super_root.version();
+        self.get(version).ok_or(SuperRootError::UnknownVersion(version))?.encode(super_root, out)
+    }
+
+    /// Encodes a super root with the codec registered for its version byte, and hashes it using
+    /// [keccak256].
+    pub fn hash(&self, super_root: &VersionedSuperRoot) -> SuperRootResult<B256> {
+        let mut buf = Vec::new();
+        self.encode(super_root, &mut buf)?;
+        Ok(keccak256(&buf))
+    }
+
+    /// Decodes a super root with the codec registered for its version byte.
+    pub fn decode(&self, buf: &mut &[u8]) -> SuperRootResult<VersionedSuperRoot> {
+        let version = *buf.first().ok_or(SuperRootError::UnexpectedLength)?;
+        self.get(version).ok_or(SuperRootError::UnknownVersion(version))?.decode(buf)
+    }
+}
+
+/// A versioned super root.
+///
+/// Hashing, encoding and decoding super roots through this type dispatches on the version byte
+/// through the built-in [SuperRootRegistry], so callers do not need to know which super root
+/// formats exist. Super roots of versions without a dedicated variant are decoded into
+/// [VersionedSuperRoot::Custom] by codecs registered in a [SuperRootRegistry].
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum VersionedSuperRoot {
+    /// A V1 [SuperRoot].
+    V1(SuperRoot),
+    /// A super root of a version without a dedicated variant.
+    Custom(CustomSuperRoot),
+}
+
+/// A super root of a version without a dedicated [VersionedSuperRoot] variant, encoded and decoded
+/// by a registered [SuperRootCodec].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CustomSuperRoot {
+    /// The super root version byte.
+    pub version: u8,
+    /// The timestamp of the superchain snapshot, in seconds.
+    pub timestamp: u64,
+    /// The chain IDs and output root commitments of all chains within the dependency set.
+    pub output_roots: Vec<OutputRootWithChain>,
+    /// The fields of the super root that are specific to its version, in the layout of its codec.
+    pub extra: Bytes,
+}
+
+impl From<SuperRoot> for VersionedSuperRoot {
+    fn from(super_root: SuperRoot) -> Self {
+        Self::V1(super_root)
+    }
+}
+
+impl VersionedSuperRoot {
+    /// Returns the version byte of the super root.
+    pub const fn version(&self) -> u8 {
+        match self {
+            Self::V1(_) => SUPER_ROOT_VERSION,
+            Self::Custom(super_root) => super_root.version,
+        }
+    }
+
+    /// Returns the timestamp of the superchain snapshot, in seconds.
+    pub const fn timestamp(&self) -> u64 {
+        match self {
+            Self::V1(super_root) => super_root.timestamp,
+            Self::Custom(super_root) => super_root.timestamp,
+        }
+    }
+
+    /// Returns the chain IDs and output root commitments of all chains within the dependency set.
+    pub fn output_roots(&self) -> &[OutputRootWithChain] {
+        match self {
+            Self::V1(super_root) => &super_root.output_roots,
+            Self::Custom(super_root) => &super_root.output_roots,
+        }
+    }
+
+    /// Decodes a super root with the built-in codec for its version byte.
+    ///
+    /// Use a [SuperRootRegistry] to decode super roots with registered codecs.
+    pub fn decode(buf: &mut &[u8]) -> SuperRootResult<Self> {
+        SuperRootRegistry::new().decode(buf)
+    }
+
+    /// Encodes the super root with the built-in codec for its version byte into the given buffer,
+    /// including its version byte.
+    ///
+    /// Use a [SuperRootRegistry] to encode super roots with registered codecs.
+    pub fn encode(&self, out: &mut dyn BufMut) -> SuperRootResult<()> {
+        SuperRootRegistry::new().encode(self, out)
+    }
+
+    /// Encodes the super root with the built-in codec for its version byte, and hashes it using
+    /// [keccak256].
+    ///
+    /// Use a [SuperRootRegistry] to hash super roots with registered codecs.
+    pub fn hash(&self) -> SuperRootResult<B256> {
+        SuperRootRegistry::new().hash(self)
+    }
+}
+
 /// Chain Root Info
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -143,10 +345,14 @@ impl OutputRootWithChain {
 
 #[cfg(test)]
 mod test {
-    use crate::{SUPER_ROOT_VERSION, errors::SuperRootError};
+    use crate::{SUPER_ROOT_VERSION, SuperRootResult, errors::SuperRootError};
 
-    use super::{OutputRootWithChain, SuperRoot};
-    use alloy_primitives::{B256, b256};
+    use super::{
+        CustomSuperRoot, OutputRootWithChain, SuperRoot, SuperRootCodec, SuperRootRegistry,
+        VersionedSuperRoot,
+    };
+    use alloy_primitives::{B256, Bytes, b256, keccak256};
+    use alloy_rlp::BufMut;
 
     #[test]
     fn test_super_root_sorts_outputs() {
@@ -180,6 +386,96 @@ mod test {
         ));
     }
 
+    #[test]
+    fn test_versioned_super_root_unknown_version() {
+        let buf = vec![0xFF];
+        assert!(matches!(
+            VersionedSuperRoot::decode(&mut buf.as_slice()).unwrap_err(),
+            SuperRootError::UnknownVersion(0xFF)
+        ));
+    }
+
+    #[test]
+    fn test_versioned_super_root_roundtrip() {
+        let super_root = VersionedSuperRoot::from(SuperRoot::new(
+            10,
+            vec![OutputRootWithChain::new(1, B256::repeat_byte(1))],
+        ));
+        let mut buf = Vec::new();
+        super_root.encode(&mut buf).unwrap();
+
+        assert_eq!(super_root.version(), SUPER_ROOT_VERSION);
+        assert_eq!(buf[0], SUPER_ROOT_VERSION);
+        assert_eq!(VersionedSuperRoot::decode(&mut buf.as_slice()).unwrap(), super_root);
+    }
+
+    #[test]
+    fn test_super_root_registry() {
+        const V2: u8 = SUPER_ROOT_VERSION + 1;
+
+        // A codec for a hypothetical V2 super root, which prefixes the V1 layout with a 32 byte
+        // extra field.
+        fn encode_v2(super_root: &VersionedSuperRoot, out: &mut dyn BufMut) -> SuperRootResult<()> {
+            let VersionedSuperRoot::Custom(super_root) = super_root else {
+                return Err(SuperRootError::VersionMismatch {
+                    expected: V2,
+                    actual: super_root.version(),
+                });
+            };
+            let mut v1 = Vec::new();
+            SuperRoot::new(super_root.timestamp, super_root.output_roots.clone()).encode(&mut v1);
+            out.put_u8(V2);
+            out.put_slice(&super_root.extra);
+            out.put_slice(&v1[1..]);
+            Ok(())
+        }
+        fn decode_v2(buf: &mut &[u8]) -> SuperRootResult<VersionedSuperRoot> {
+            if buf.len() < 33 {
+                return Err(SuperRootError::UnexpectedLength);
+            }
+            let extra = Bytes::copy_from_slice(&buf[1..33]);
+            let v1 = [&[SUPER_ROOT_VERSION], &buf[33..]].concat();
+            *buf = &[];
+            let v1 = SuperRoot::decode(&mut v1.as_slice())?;
+            Ok(VersionedSuperRoot::Custom(CustomSuperRoot {
+                version: V2,
+                timestamp: v1.timestamp,
+                output_roots: v1.output_roots,
+                extra,
+            }))
+        }
+        let codec = SuperRootCodec { version: V2, encode: encode_v2, decode: decode_v2 };
+
+        let v1 = SuperRoot::new(10, vec![OutputRootWithChain::new(1, B256::repeat_byte(1))]);
+        let mut encoded = vec![V2];
+        encoded.extend_from_slice(&[0xff; 32]);
+        encoded.extend_from_slice(&10u64.to_be_bytes());
+        encoded.extend_from_slice(B256::with_last_byte(1).as_slice());
+        encoded.extend_from_slice(B256::repeat_byte(1).as_slice());
+
+        assert!(matches!(
+            SuperRootRegistry::new().decode(&mut encoded.as_slice()).unwrap_err(),
+            SuperRootError::UnknownVersion(V2)
+        ));
+        let registry = SuperRootRegistry::new().with(codec);
+        let super_root = registry.decode(&mut encoded.as_slice()).unwrap();
+        assert_eq!(super_root.version(), V2);
+        assert_eq!(super_root.timestamp(), v1.timestamp);
+        assert_eq!(super_root.output_roots(), v1.output_roots.as_slice());
+
+        let mut buf = Vec::new();
+        registry.encode(&super_root, &mut buf).unwrap();
+        assert_eq!(buf, encoded);
+        assert_eq!(registry.hash(&super_root).unwrap(), keccak256(&encoded));
+
+        // The built-in registry has no codec for the custom version.
+        assert!(matches!(super_root.hash().unwrap_err(), SuperRootError::UnknownVersion(V2)));
+        assert!(matches!(
+            registry.get(V2).unwrap().encode(&v1.into(), &mut Vec::new()).unwrap_err(),
+            SuperRootError::VersionMismatch { expected: V2, actual: SUPER_ROOT_VERSION }
+        ));
+    }
+
     #[test]
     fn test_super_root_invalid_length_at_timestamp() {
         let buf = vec![SUPER_ROOT_VERSION, 0x00];
@@ -0,0 +1,423 @@
+//! Contains the [`Decompressor`] trait and the [`DecompressorRegistry`] used by the
+//! [`BatchReader`] and the [`StreamingBatchReader`].
+//!
+//! [`BatchReader`]: crate::BatchReader
+//! [`StreamingBatchReader`]: crate::StreamingBatchReader
+
+use crate::{BatchReader, DecompressionError, brotli::BrotliStreamDecompressor, decompress_brotli};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::fmt::Debug;
+use kona_genesis::RollupConfig;
+use miniz_oxide::{
+    DataFormat, MZFlush, MZStatus,
+    inflate::{
+        decompress_to_vec_zlib,
+        stream::{InflateState, inflate},
+    },
+};
+
+/// A decompressor for channel data, selected by the channel version byte.
+pub trait Decompressor: Debug + Send + Sync {
+    /// Returns whether the decompressor handles channel data starting with the given version
+    /// byte.
+    fn supports(&self, version: u8) -> bool;
+
+    /// Returns whether batches decompressed by this decompressor are valid at the given L2
+    /// timestamp. Decompressors introduced by a hardfork return `false` before its activation.
+    fn is_active(&self, _cfg: &RollupConfig, _timestamp: u64) -> bool {
+        true
+    }
+
+    /// Decompresses the given channel data, including its version byte, into at most
+    /// `max_rlp_bytes_per_channel` bytes.
+    fn decompress(
+        &self,
+        data: &[u8],
+        max_rlp_bytes_per_channel: usize,
+    ) -> Result<Vec<u8>, DecompressionError>;
+
+    /// Returns a [`StreamDecoder`] for a new channel, if the decompressor supports incremental
+    /// decompression. Channels of decompressors without one are decompressed at once with
+    /// [`Decompressor::decompress`].
+    fn stream_decoder(&self) -> Option<Box<dyn StreamDecoder>> {
+        None
+    }
+}
+
+/// An incremental decoder for the data of a single channel, used by the [`StreamingBatchReader`] to
+/// decompress a channel one chunk at a time.
+///
+/// [`StreamingBatchReader`]: crate::StreamingBatchReader
+pub trait StreamDecoder: Debug + Send {
+    /// Decompresses the given input into the given output buffer.
+    ///
+    /// The input is the channel data that has not been consumed yet, so the input of the first
+    /// call starts with the channel version byte. Returns the number of input bytes consumed, the
+    /// number of output bytes written and whether the stream has ended. A call that neither
+    /// consumes nor writes any bytes also ends the stream.
+    fn decompress(
+        &mut self,
+        input: &[u8],
+        output: &mut [u8],
+    ) -> Result<(usize, usize, bool), DecompressionError>;
+}
+
+/// The zlib [`StreamDecoder`].
+#[derive(Debug)]
+struct ZlibStreamDecoder(Box<InflateState>);
+
+impl StreamDecoder for ZlibStreamDecoder {
+    fn decompress(
+        &mut self,
+        input: &[u8],
+        output: &mut [u8],
+    ) -> Result<(usize, usize, bool), DecompressionError> {
+        let result = inflate(&mut self.0, input, output, MZFlush::None);
+        let finished = match result.status {
+            Ok(MZStatus::StreamEnd) => true,
+            Ok(_) => false,
+            Err(_) => return Err(DecompressionError::ZlibError),
+        };
+        Ok((result.bytes_consumed, result.bytes_written, finished))
+    }
+}
+
+/// The brotli [`StreamDecoder`], which skips the channel version byte.
+#[derive(Debug)]
+struct BrotliStreamDecoder {
+    /// The brotli decompressor.
+    decompressor: Box<BrotliStreamDecompressor>,
+    /// Whether the channel version byte has been consumed.
+    started: bool,
+}
+
+impl StreamDecoder for BrotliStreamDecoder {
+    fn decompress(
+        &mut self,
+        input: &[u8],
+        output: &mut [u8],
+    ) -> Result<(usize, usize, bool), DecompressionError> {
+        let skip = usize::from(!self.started).min(input.len());
+        self.started = true;
+        let (consumed, written, finished) = self.decompressor.decompress(&input[skip..], output);
+        Ok((skip + consumed, written, finished))
+    }
+}
+
+/// The zlib [`Decompressor`], used since Bedrock.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ZlibDecompressor;
+
+impl Decompressor for ZlibDecompressor {
+    fn supports(&self, version: u8) -> bool {
+        (version & 0x0F) == BatchReader::ZLIB_DEFLATE_COMPRESSION_METHOD ||
+            (version & 0x0F) == BatchReader::ZLIB_RESERVED_COMPRESSION_METHOD
+    }
+
+    fn decompress(
+        &self,
+        data: &[u8],
+        max_rlp_bytes_per_channel: usize,
+    ) -> Result<Vec<u8>, DecompressionError> {
+        let decompressed =
+            decompress_to_vec_zlib(data).map_err(|_| DecompressionError::ZlibError)?;
+
+        // Check the size of the decompressed channel RLP.
+        if decompressed.len() > max_rlp_bytes_per_channel {
+            return Err(DecompressionError::RlpTooLarge(
+                decompressed.len(),
+                max_rlp_bytes_per_channel,
+            ));
+        }
+        Ok(decompressed)
+    }
+
+    fn stream_decoder(&self) -> Option<Box<dyn StreamDecoder>> {
+        Some(Box::new(ZlibStreamDecoder(InflateState::new_boxed(DataFormat::Zlib))))
+    }
+}
+
+/// The brotli [`Decompressor`], activated by the Fjord hardfork.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BrotliDecompressor;
+
+impl Decompressor for BrotliDecompressor {
+    fn supports(&self, version: u8) -> bool {
+        version == BatchReader::CHANNEL_VERSION_BROTLI
+    }
+
+    fn is_active(&self, cfg: &RollupConfig, timestamp: u64) -> bool {
+        cfg.is_fjord_active(timestamp)
+    }
+
+    fn decompress(
+        &self,
+        data: &[u8],
+        max_rlp_bytes_per_channel: usize,
+    ) -> Result<Vec<u8>, DecompressionError> {
+        Ok(decompress_brotli(&data[1..], max_rlp_bytes_per_channel)?)
+    }
+
+    fn stream_decoder(&self) -> Option<Box<dyn StreamDecoder>> {
+        Some(Box::new(BrotliStreamDecoder {
+            decompressor: Box::new(BrotliStreamDecompressor::new()),
+            started: false,
+        }))
+    }
+}
+
+/// The zstd [`Decompressor`].
+///
+/// Zstd is not part of the OP Stack protocol. It is meant for chains that enable it through a
+/// custom hardfork, so both the channel version byte and the activation time are configurable.
+/// The fault proof program does not register it, so chains that enable zstd cannot be proven with
+/// `kona-client`.
+#[cfg(feature = "zstd")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZstdDecompressor {
+    /// The channel version byte of zstd compressed channels.
+    pub version: u8,
+    /// The L2 timestamp at which zstd compressed channels become valid, if they are enabled.
+    pub activation_time: Option<u64>,
+}
+
+#[cfg(feature = "zstd")]
+impl ZstdDecompressor {
+    /// The default channel version byte of zstd compressed channels.
+    pub const DEFAULT_CHANNEL_VERSION: u8 = 2;
+
+    /// Creates a new [`ZstdDecompressor`] for the given channel version byte and activation time.
+    pub const fn new(version: u8, activation_time: Option<u64>) -> Self {
+        Self { version, activation_time }
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl Decompressor for ZstdDecompressor {
+    fn supports(&self, version: u8) -> bool {
+        version == self.version
+    }
+
+    fn is_active(&self, _cfg: &RollupConfig, timestamp: u64) -> bool {
+        self.activation_time.is_some_and(|time| timestamp >= time)
+    }
+
+    fn decompress(
+        &self,
+        data: &[u8],
+        max_rlp_bytes_per_channel: usize,
+    ) -> Result<Vec<u8>, DecompressionError> {
+        use ruzstd::{decoding::StreamingDecoder, io::Read};
+
+        let mut decoder =
+            StreamingDecoder::new(&data[1..]).map_err(|_| DecompressionError::ZstdError)?;
+
+        // Decompress in chunks, so that oversized channels are rejected without decompressing
+        // them fully.
+        let mut decompressed = Vec::new();
+        let mut chunk = [0u8; 4096];
+        loop {
+            let len = decoder.read(&mut chunk).map_err(|_| DecompressionError::ZstdError)?;
+            if len == 0 {
+                break;
+            }
+            decompressed.extend_from_slice(&chunk[..len]);
+            if decompressed.len() > max_rlp_bytes_per_channel {
+                return Err(DecompressionError::RlpTooLarge(
+                    decompressed.len(),
+                    max_rlp_bytes_per_channel,
+                ));
+            }
+        }
+        Ok(decompressed)
+    }
+
+    fn stream_decoder(&self) -> Option<Box<dyn StreamDecoder>> {
+        Some(Box::new(ZstdStreamDecoder {
+            decoder: ruzstd::decoding::FrameDecoder::new(),
+            started: false,
+        }))
+    }
+}
+
+/// The zstd [`StreamDecoder`], which skips the channel version byte and decodes one block at a
+/// time.
+#[cfg(feature = "zstd")]
+struct ZstdStreamDecoder {
+    /// The frame decoder.
+    decoder: ruzstd::decoding::FrameDecoder,
+    /// Whether the channel version byte and the frame header have been consumed.
+    started: bool,
+}
+
+#[cfg(feature = "zstd")]
+impl Debug for ZstdStreamDecoder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ZstdStreamDecoder").field("started", &self.started).finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl StreamDecoder for ZstdStreamDecoder {
+    fn decompress(
+        &mut self,
+        input: &[u8],
+        output: &mut [u8],
+    ) -> Result<(usize, usize, bool), DecompressionError> {
+        use ruzstd::{decoding::BlockDecodingStrategy, io::Read};
+
+        let mut source = input;
+        if !self.started {
+            source = source.get(1..).ok_or(DecompressionError::EmptyData)?;
+            self.decoder.init(&mut source).map_err(|_| DecompressionError::ZstdError)?;
+            self.started = true;
+        }
+
+        // Only decode the next block once the decoded bytes have been read, so that at most one
+        // block and the window are buffered.
+        if self.decoder.can_collect() == 0 && !self.decoder.is_finished() {
+            self.decoder
+                .decode_blocks(&mut source, BlockDecodingStrategy::UptoBlocks(1))
+                .map_err(|_| DecompressionError::ZstdError)?;
+        }
+        let written = self.decoder.read(output).map_err(|_| DecompressionError::ZstdError)?;
+        let finished = self.decoder.is_finished() && self.decoder.can_collect() == 0;
+        Ok((input.len() - source.len(), written, finished))
+    }
+}
+
+/// A registry of [`Decompressor`]s, selected by the channel version byte.
+///
+/// Registered decompressors take precedence over the built-in [`ZlibDecompressor`] and
+/// [`BrotliDecompressor`], in reverse registration order.
+#[derive(Debug, Clone, Default)]
+pub struct DecompressorRegistry {
+    /// The registered decompressors.
+    decompressors: Vec<Arc<dyn Decompressor>>,
+}
+
+impl DecompressorRegistry {
+    /// Creates a new [`DecompressorRegistry`] with only the built-in decompressors.
+    pub const fn new() -> Self {
+        Self { decompressors: Vec::new() }
+    }
+
+    /// Registers the given [`Decompressor`].
+    pub fn register<D: Decompressor + 'static>(&mut self, decompressor: D) -> &mut Self {
+        self.register_shared(Arc::new(decompressor))
+    }
+
+    /// Registers the given shared [`Decompressor`].
+    pub fn register_shared(&mut self, decompressor: Arc<dyn Decompressor>) -> &mut Self {
+        self.decompressors.push(decompressor);
+        self
+    }
+
+    /// Returns the registry with the given [`Decompressor`] registered.
+    pub fn with<D: Decompressor + 'static>(mut self, decompressor: D) -> Self {
+        self.register(decompressor);
+        self
+    }
+
+    /// Returns the [`Decompressor`] for channel data starting with the given version byte.
+    pub fn get(&self, version: u8) -> Option<&dyn Decompressor> {
+        const BUILT_IN: [&dyn Decompressor; 2] = [&ZlibDecompressor, &BrotliDecompressor];
+
+        self.decompressors
+            .iter()
+            .rev()
+            .map(|decompressor| decompressor.as_ref())
+            .chain(BUILT_IN)
+            .find(|decompressor| decompressor.supports(version))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use kona_genesis::HardForkConfig;
+
+    #[derive(Debug)]
+    struct MockDecompressor;
+
+    impl Decompressor for MockDecompressor {
+        fn supports(&self, version: u8) -> bool {
+            version == BatchReader::CHANNEL_VERSION_BROTLI
+        }
+
+        fn decompress(&self, data: &[u8], _: usize) -> Result<Vec<u8>, DecompressionError> {
+            Ok(data[1..].to_vec())
+        }
+    }
+
+    #[test]
+    fn test_registry_built_in() {
+        let registry = DecompressorRegistry::new();
+        let cfg = RollupConfig {
+            hardforks: HardForkConfig { fjord_time: Some(10), ..Default::default() },
+            ..Default::default()
+        };
+
+        assert!(registry.get(0x78).is_some_and(|d| d.is_active(&cfg, 0)));
+        let brotli = registry.get(BatchReader::CHANNEL_VERSION_BROTLI).unwrap();
+        assert!(!brotli.is_active(&cfg, 9));
+        assert!(brotli.is_active(&cfg, 10));
+        assert!(registry.get(2).is_none());
+    }
+
+    #[test]
+    fn test_registry_overrides_built_in() {
+        let registry = DecompressorRegistry::new().with(MockDecompressor);
+        let decompressor = registry.get(BatchReader::CHANNEL_VERSION_BROTLI).unwrap();
+        assert_eq!(decompressor.decompress(&[1, 2, 3], 0).unwrap(), [2, 3]);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_zstd_decompressor() {
+        let raw = alloc::vec![0xAB; 1024];
+        let mut data = alloc::vec![ZstdDecompressor::DEFAULT_CHANNEL_VERSION];
+        data.extend(ruzstd::encoding::compress_to_vec(
+            raw.as_slice(),
+            ruzstd::encoding::CompressionLevel::Fastest,
+        ));
+
+        let registry = DecompressorRegistry::new()
+            .with(ZstdDecompressor::new(ZstdDecompressor::DEFAULT_CHANNEL_VERSION, Some(100)));
+        let zstd = registry.get(ZstdDecompressor::DEFAULT_CHANNEL_VERSION).unwrap();
+        assert!(!zstd.is_active(&RollupConfig::default(), 99));
+        assert!(zstd.is_active(&RollupConfig::default(), 100));
+        assert_eq!(zstd.decompress(&data, 1024).unwrap(), raw);
+        assert!(matches!(
+            zstd.decompress(&data, 1000).unwrap_err(),
+            DecompressionError::RlpTooLarge(_, 1000)
+        ));
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_zstd_stream_decoder() {
+        let raw = (0..200_000u32).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        let mut data = alloc::vec![ZstdDecompressor::DEFAULT_CHANNEL_VERSION];
+        data.extend(ruzstd::encoding::compress_to_vec(
+            raw.as_slice(),
+            ruzstd::encoding::CompressionLevel::Fastest,
+        ));
+
+        let zstd = ZstdDecompressor::new(ZstdDecompressor::DEFAULT_CHANNEL_VERSION, Some(0));
+        let mut decoder = zstd.stream_decoder().unwrap();
+        let (mut offset, mut decompressed) = (0, Vec::new());
+        loop {
+            let mut chunk = [0u8; 1000];
+            let (consumed, written, finished) =
+                decoder.decompress(&data[offset..], &mut chunk).unwrap();
+            offset += consumed;
+            decompressed.extend_from_slice(&chunk[..written]);
+            if finished || (consumed == 0 && written == 0) {
+                break;
+            }
+        }
+        assert_eq!(decompressed, raw);
+        assert_eq!(offset, data.len());
+    }
+}
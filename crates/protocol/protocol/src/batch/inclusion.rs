@@ -1,6 +1,8 @@
 //! Module containing the [`BatchWithInclusionBlock`] struct.
 
-use crate::{Batch, BatchValidationProvider, BatchValidity, BlockInfo, L2BlockInfo};
+use crate::{
+    Batch, BatchValidationProvider, BatchValidity, BatchValidityReport, BlockInfo, L2BlockInfo,
+};
 use kona_genesis::RollupConfig;
 
 /// A batch with its inclusion block.
@@ -30,13 +32,33 @@ impl BatchWithInclusionBlock {
         l2_safe_head: L2BlockInfo,
         fetcher: &mut BF,
     ) -> BatchValidity {
+        self.check_batch_report(cfg, l1_blocks, l2_safe_head, fetcher).await.validity
+    }
+
+    /// Validates the batch can be applied on top of the specified L2 safe head, returning a
+    /// [`BatchValidityReport`] that explains which rule decided the outcome.
+    ///
+    /// See [`Self::check_batch`] for the expected inputs.
+    pub async fn check_batch_report<BF: BatchValidationProvider>(
+        &self,
+        cfg: &RollupConfig,
+        l1_blocks: &[BlockInfo],
+        l2_safe_head: L2BlockInfo,
+        fetcher: &mut BF,
+    ) -> BatchValidityReport {
         match &self.batch {
             Batch::Single(single_batch) => {
-                single_batch.check_batch(cfg, l1_blocks, l2_safe_head, &self.inclusion_block)
+                single_batch.check_batch_report(cfg, l1_blocks, l2_safe_head, &self.inclusion_block)
             }
             Batch::Span(span_batch) => {
                 span_batch
-                    .check_batch(cfg, l1_blocks, l2_safe_head, &self.inclusion_block, fetcher)
+                    .check_batch_report(
+                        cfg,
+                        l1_blocks,
+                        l2_safe_head,
+                        &self.inclusion_block,
+                        fetcher,
+                    )
                     .await
             }
         }
@@ -46,7 +68,7 @@ impl BatchWithInclusionBlock {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::TestBatchValidator;
+    use crate::{BatchType, BatchValidityRule, test_utils::TestBatchValidator};
     use alloc::vec;
 
     #[tokio::test]
@@ -72,4 +94,18 @@ mod tests {
         let result = batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &mut validator).await;
         assert_eq!(result, BatchValidity::Undecided);
     }
+
+    #[tokio::test]
+    async fn test_span_batch_report_with_inclusion_block() {
+        let batch =
+            BatchWithInclusionBlock::new(BlockInfo::default(), Batch::Span(Default::default()));
+        let l1_blocks = vec![BlockInfo::default()];
+        let l2_safe_head = L2BlockInfo::default();
+        let cfg = RollupConfig::default();
+        let mut validator = TestBatchValidator::default();
+        let report = batch.check_batch_report(&cfg, &l1_blocks, l2_safe_head, &mut validator).await;
+        assert_eq!(report.batch_type, BatchType::Span);
+        assert_eq!(report.validity, BatchValidity::Undecided);
+        assert_eq!(report.rule, BatchValidityRule::EmptySpan);
+    }
 }
@@ -24,7 +24,8 @@
 //! # Key Components
 //!
 //! - **Batch Types**: [`SingleBatch`], [`SpanBatch`] for different batch formats
-//! - **Batch Reading**: [`BatchReader`] for decoding batch data from channels
+//! - **Batch Reading**: [`BatchReader`] for decoding batch data from channels, and
+//!   [`StreamingBatchReader`] for decoding it incrementally with bounded memory
 //! - **Validation**: [`BatchValidationProvider`] for batch validity checking
 //! - **Transaction Data**: Specialized transaction formats for span batches
 //! - **Error Handling**: Comprehensive error types for batch processing failures
@@ -35,6 +36,16 @@ pub use r#type::*;
 mod reader;
 pub use reader::{BatchReader, DecompressionError};
 
+mod decompressor;
+#[cfg(feature = "zstd")]
+pub use decompressor::ZstdDecompressor;
+pub use decompressor::{
+    BrotliDecompressor, Decompressor, DecompressorRegistry, StreamDecoder, ZlibDecompressor,
+};
+
+mod stream;
+pub use stream::{StreamingBatchReader, StreamingBatchReaderError};
+
 mod tx;
 pub use tx::BatchTransaction;
 
@@ -71,6 +82,9 @@ pub use element::{MAX_SPAN_BATCH_ELEMENTS, SpanBatchElement};
 mod validity;
 pub use validity::BatchValidity;
 
+mod report;
+pub use report::{BatchValidityReport, BatchValidityRule};
+
 mod single;
 pub use single::SingleBatch;
 
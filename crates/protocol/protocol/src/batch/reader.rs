@@ -1,11 +1,10 @@
 //! Contains the [`BatchReader`] which is used to iteratively consume batches from raw data.
 
-use crate::{Batch, BrotliDecompressionError, decompress_brotli};
+use crate::{Batch, BrotliDecompressionError, DecompressorRegistry};
 use alloc::vec::Vec;
 use alloy_primitives::Bytes;
 use alloy_rlp::Decodable;
 use kona_genesis::RollupConfig;
-use miniz_oxide::inflate::decompress_to_vec_zlib;
 
 /// Error type for decompression failures.
 #[derive(Debug, thiserror::Error)]
@@ -22,6 +21,9 @@ pub enum DecompressionError {
     /// A zlib decompression error.
     #[error("zlib decompression error")]
     ZlibError,
+    /// A zstd decompression error.
+    #[error("zstd decompression error")]
+    ZstdError,
     /// The RLP data is too large for the configured maximum.
     #[error("the RLP data is too large: {0} bytes, maximum allowed: {1} bytes")]
     RlpTooLarge(usize, usize),
@@ -43,6 +45,10 @@ pub struct BatchReader {
     max_rlp_bytes_per_channel: usize,
     /// Whether brotli decompression was used.
     pub brotli_used: bool,
+    /// The decompressors, selected by the channel version byte.
+    decompressors: DecompressorRegistry,
+    /// The channel version byte of the decompressed data.
+    version: Option<u8>,
 }
 
 impl BatchReader {
@@ -67,9 +73,17 @@ impl BatchReader {
             cursor: 0,
             max_rlp_bytes_per_channel,
             brotli_used: false,
+            decompressors: DecompressorRegistry::new(),
+            version: None,
         }
     }
 
+    /// Sets the [`DecompressorRegistry`] used to decompress the data.
+    pub fn with_decompressors(mut self, decompressors: DecompressorRegistry) -> Self {
+        self.decompressors = decompressors;
+        self
+    }
+
     /// Helper method to decompress the data contained in the reader.
     pub fn decompress(&mut self) -> Result<(), DecompressionError> {
         if let Some(data) = self.data.take() {
@@ -79,25 +93,13 @@ impl BatchReader {
             }
 
             let compression_type = data[0];
-            if (compression_type & 0x0F) == Self::ZLIB_DEFLATE_COMPRESSION_METHOD ||
-                (compression_type & 0x0F) == Self::ZLIB_RESERVED_COMPRESSION_METHOD
-            {
-                self.decompressed =
-                    decompress_to_vec_zlib(&data).map_err(|_| DecompressionError::ZlibError)?;
-
-                // Check the size of the decompressed channel RLP.
-                if self.decompressed.len() > self.max_rlp_bytes_per_channel {
-                    return Err(DecompressionError::RlpTooLarge(
-                        self.decompressed.len(),
-                        self.max_rlp_bytes_per_channel,
-                    ));
-                }
-            } else if compression_type == Self::CHANNEL_VERSION_BROTLI {
-                self.brotli_used = true;
-                self.decompressed = decompress_brotli(&data[1..], self.max_rlp_bytes_per_channel)?;
-            } else {
-                return Err(DecompressionError::UnsupportedType(compression_type));
-            }
+            let decompressor = self
+                .decompressors
+                .get(compression_type)
+                .ok_or(DecompressionError::UnsupportedType(compression_type))?;
+            self.decompressed = decompressor.decompress(&data, self.max_rlp_bytes_per_channel)?;
+            self.brotli_used = compression_type == Self::CHANNEL_VERSION_BROTLI;
+            self.version = Some(compression_type);
         }
         Ok(())
     }
@@ -114,8 +116,10 @@ impl BatchReader {
             return None;
         };
 
-        // Confirm that brotli decompression was performed *after* the Fjord hardfork.
-        if self.brotli_used && !cfg.is_fjord_active(batch.timestamp()) {
+        // Confirm that the decompressor was active at the batch timestamp, e.g. that brotli
+        // decompression was performed *after* the Fjord hardfork.
+        let decompressor = self.version.and_then(|version| self.decompressors.get(version))?;
+        if !decompressor.is_active(cfg, batch.timestamp()) {
             return None;
         }
 
@@ -131,6 +135,7 @@ mod test {
     use kona_genesis::{
         HardForkConfig, MAX_RLP_BYTES_PER_CHANNEL_BEDROCK, MAX_RLP_BYTES_PER_CHANNEL_FJORD,
     };
+    use miniz_oxide::inflate::decompress_to_vec_zlib;
 
     fn new_compressed_batch_data() -> Bytes {
         let file_contents =
@@ -0,0 +1,321 @@
+//! Contains the [`BatchValidityReport`] and the [`BatchValidityRule`] that decided it.
+
+use crate::{BatchType, BatchValidity};
+use alloy_eips::BlockNumHash;
+use alloy_primitives::B256;
+
+/// The batch validity rule that decided the [`BatchValidity`] of a batch, along with the values
+/// that were compared.
+///
+/// Rules are listed roughly in the order the [derivation spec][specs] applies them.
+///
+/// [specs]: https://specs.optimism.io/protocol/derivation.html#batch-queue
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "rule", rename_all = "camelCase"))]
+#[cfg_attr(feature = "serde", serde(rename_all_fields = "camelCase"))]
+pub enum BatchValidityRule {
+    /// Every rule passed.
+    Accepted,
+    /// No L1 blocks were provided to check the batch against.
+    MissingL1Blocks,
+    /// The span batch does not contain any blocks.
+    EmptySpan,
+    /// The batch advances the epoch, but the next L1 block is not yet known.
+    NextEpochUnavailable {
+        /// The current L1 epoch number.
+        epoch: u64,
+    },
+    /// The span batch's L1 origin is before the Delta hardfork.
+    PreDelta {
+        /// The L1 origin timestamp of the batch.
+        origin_timestamp: u64,
+    },
+    /// The batch timestamp is ahead of the next expected L2 timestamp.
+    ///
+    /// After Holocene the batch is dropped, before it is kept for future processing.
+    FutureTimestamp {
+        /// The batch timestamp.
+        timestamp: u64,
+        /// The next expected L2 timestamp, the safe head timestamp plus the block time.
+        next_timestamp: u64,
+        /// Whether Holocene ordering rules applied at the inclusion block.
+        holocene: bool,
+    },
+    /// The batch timestamp is behind the next expected L2 timestamp. For span batches, this means
+    /// the span has no new blocks after the safe head.
+    ///
+    /// After Holocene the batch is marked as [`BatchValidity::Past`], before it is dropped.
+    PastTimestamp {
+        /// The batch timestamp. For span batches this is the final block timestamp.
+        timestamp: u64,
+        /// The next expected L2 timestamp, the safe head timestamp plus the block time.
+        next_timestamp: u64,
+        /// Whether Holocene ordering rules applied at the inclusion block.
+        holocene: bool,
+    },
+    /// The overlapping span batch timestamp is not aligned to the safe chain.
+    MisalignedTimestamp {
+        /// The span batch starting timestamp.
+        timestamp: u64,
+        /// The L2 safe head timestamp.
+        safe_head_timestamp: u64,
+        /// The L2 block time.
+        block_time: u64,
+    },
+    /// The L2 parent block of an overlapping span batch could not be fetched.
+    ParentUnavailable {
+        /// The L2 parent block number.
+        number: u64,
+    },
+    /// The batch does not build on top of its expected L2 parent block.
+    ParentHashMismatch {
+        /// The expected L2 parent block.
+        parent: BlockNumHash,
+    },
+    /// The batch was included after its sequencing window expired.
+    SequenceWindowExpired {
+        /// The batch epoch number.
+        epoch: u64,
+        /// The L1 inclusion block number.
+        inclusion_block: u64,
+        /// The sequencing window size.
+        seq_window_size: u64,
+    },
+    /// The batch epoch is older than the L1 origin of its parent.
+    EpochTooOld {
+        /// The batch epoch number.
+        epoch: u64,
+        /// The minimum allowed epoch number.
+        min_epoch: u64,
+    },
+    /// The batch epoch is more than one epoch ahead of the L1 origin of its parent.
+    EpochTooFarAhead {
+        /// The batch epoch number.
+        epoch: u64,
+        /// The maximum allowed epoch number.
+        max_epoch: u64,
+    },
+    /// The L1 block of the batch epoch is not yet known.
+    OriginUnavailable {
+        /// The batch epoch number.
+        epoch: u64,
+    },
+    /// The batch epoch hash does not match the canonical L1 block.
+    EpochHashMismatch {
+        /// The batch epoch number.
+        epoch: u64,
+        /// The canonical L1 block hash of the epoch.
+        expected: B256,
+    },
+    /// The L2 block timestamp is before its L1 origin timestamp.
+    TimestampBeforeOrigin {
+        /// The L2 block timestamp.
+        timestamp: u64,
+        /// The L1 origin timestamp.
+        origin_timestamp: u64,
+    },
+    /// The maximum sequencer drift overflows the L1 origin timestamp.
+    DriftOverflow {
+        /// The L1 origin timestamp.
+        origin_timestamp: u64,
+        /// The maximum sequencer drift.
+        max_drift: u64,
+    },
+    /// A non-empty L2 block exceeded the maximum sequencer drift.
+    SequencerDriftExceeded {
+        /// The L2 block timestamp.
+        timestamp: u64,
+        /// The maximum timestamp allowed by the sequencer drift.
+        max_timestamp: u64,
+    },
+    /// An empty L2 block exceeded the maximum sequencer drift, and the next L1 origin is not yet
+    /// known.
+    NextOriginUnavailable {
+        /// The L2 block timestamp.
+        timestamp: u64,
+        /// The maximum timestamp allowed by the sequencer drift.
+        max_timestamp: u64,
+    },
+    /// An empty L2 block exceeded the maximum sequencer drift while the next L1 origin could have
+    /// been adopted.
+    NextOriginAdoptable {
+        /// The L2 block timestamp.
+        timestamp: u64,
+        /// The next L1 origin timestamp.
+        next_origin_timestamp: u64,
+    },
+    /// The first block of the interop hardfork contains user transactions.
+    InteropTransitionTransactions {
+        /// The L2 block timestamp.
+        timestamp: u64,
+    },
+    /// The batch contains an empty transaction.
+    EmptyTransaction {
+        /// The L2 block timestamp.
+        timestamp: u64,
+        /// The index of the transaction in the block.
+        index: usize,
+    },
+    /// The batch contains a deposit transaction.
+    DepositTransaction {
+        /// The L2 block timestamp.
+        timestamp: u64,
+        /// The index of the transaction in the block.
+        index: usize,
+    },
+    /// The batch contains an EIP-7702 transaction before Isthmus.
+    PreIsthmusEip7702 {
+        /// The L2 block timestamp.
+        timestamp: u64,
+        /// The index of the transaction in the block.
+        index: usize,
+    },
+    /// An overlapped safe L2 block could not be fetched.
+    OverlapUnavailable {
+        /// The overlapped L2 block number.
+        number: u64,
+    },
+    /// The overlapped safe L2 block's transaction count does not match the batch.
+    OverlapTxCountMismatch {
+        /// The overlapped L2 block number.
+        number: u64,
+        /// The number of non-deposit transactions in the safe block.
+        safe_txs: usize,
+        /// The number of transactions in the batch.
+        batch_txs: usize,
+    },
+    /// An overlapped safe L2 block's transaction does not match the batch.
+    OverlapTxMismatch {
+        /// The overlapped L2 block number.
+        number: u64,
+        /// The index of the mismatched transaction in the batch.
+        index: usize,
+    },
+    /// The [`crate::L2BlockInfo`] of an overlapped safe L2 block could not be extracted.
+    OverlapInvalidBlock {
+        /// The overlapped L2 block number.
+        number: u64,
+    },
+    /// The overlapped safe L2 block's L1 origin does not match the batch.
+    OverlapOriginMismatch {
+        /// The overlapped L2 block number.
+        number: u64,
+        /// The L1 origin number of the safe block.
+        safe_origin: u64,
+        /// The epoch number of the batch.
+        batch_origin: u64,
+    },
+}
+
+impl BatchValidityRule {
+    /// Returns the name of the rule, used as a metric label.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Accepted => "accepted",
+            Self::MissingL1Blocks => "missing_l1_blocks",
+            Self::EmptySpan => "empty_span",
+            Self::NextEpochUnavailable { .. } => "next_epoch_unavailable",
+            Self::PreDelta { .. } => "pre_delta",
+            Self::FutureTimestamp { .. } => "future_timestamp",
+            Self::PastTimestamp { .. } => "past_timestamp",
+            Self::MisalignedTimestamp { .. } => "misaligned_timestamp",
+            Self::ParentUnavailable { .. } => "parent_unavailable",
+            Self::ParentHashMismatch { .. } => "parent_hash_mismatch",
+            Self::SequenceWindowExpired { .. } => "sequence_window_expired",
+            Self::EpochTooOld { .. } => "epoch_too_old",
+            Self::EpochTooFarAhead { .. } => "epoch_too_far_ahead",
+            Self::OriginUnavailable { .. } => "origin_unavailable",
+            Self::EpochHashMismatch { .. } => "epoch_hash_mismatch",
+            Self::TimestampBeforeOrigin { .. } => "timestamp_before_origin",
+            Self::DriftOverflow { .. } => "drift_overflow",
+            Self::SequencerDriftExceeded { .. } => "sequencer_drift_exceeded",
+            Self::NextOriginUnavailable { .. } => "next_origin_unavailable",
+            Self::NextOriginAdoptable { .. } => "next_origin_adoptable",
+            Self::InteropTransitionTransactions { .. } => "interop_transition_transactions",
+            Self::EmptyTransaction { .. } => "empty_transaction",
+            Self::DepositTransaction { .. } => "deposit_transaction",
+            Self::PreIsthmusEip7702 { .. } => "pre_isthmus_eip7702",
+            Self::OverlapUnavailable { .. } => "overlap_unavailable",
+            Self::OverlapTxCountMismatch { .. } => "overlap_tx_count_mismatch",
+            Self::OverlapTxMismatch { .. } => "overlap_tx_mismatch",
+            Self::OverlapInvalidBlock { .. } => "overlap_invalid_block",
+            Self::OverlapOriginMismatch { .. } => "overlap_origin_mismatch",
+        }
+    }
+}
+
+impl core::fmt::Display for BatchValidityRule {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A structured explanation of a batch validity check.
+///
+/// The report records the [`BatchValidity`] outcome of the check along with the
+/// [`BatchValidityRule`] that decided it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct BatchValidityReport {
+    /// The type of the checked batch.
+    pub batch_type: BatchType,
+    /// The timestamp of the batch. For span batches, this is the starting timestamp.
+    pub timestamp: u64,
+    /// The L1 block the batch was included in.
+    pub inclusion_block: BlockNumHash,
+    /// The L2 safe head the batch was checked against.
+    pub l2_safe_head: BlockNumHash,
+    /// The outcome of the check.
+    pub validity: BatchValidity,
+    /// The rule that decided the outcome.
+    pub rule: BatchValidityRule,
+}
+
+impl core::fmt::Display for BatchValidityReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:?} batch at timestamp {} (inclusion block {}, safe head {}): {} by rule {:?}",
+            self.batch_type,
+            self.timestamp,
+            self.inclusion_block.number,
+            self.l2_safe_head.number,
+            self.validity,
+            self.rule
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_rule_name_display() {
+        assert_eq!(BatchValidityRule::Accepted.to_string(), "accepted");
+        let rule = BatchValidityRule::SequencerDriftExceeded { timestamp: 10, max_timestamp: 5 };
+        assert_eq!(rule.to_string(), "sequencer_drift_exceeded");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_report_serde_roundtrip() {
+        let report = BatchValidityReport {
+            batch_type: BatchType::Span,
+            timestamp: 12,
+            inclusion_block: BlockNumHash { number: 3, hash: B256::ZERO },
+            l2_safe_head: BlockNumHash { number: 5, hash: B256::ZERO },
+            validity: BatchValidity::Drop,
+            rule: BatchValidityRule::EpochTooOld { epoch: 1, min_epoch: 2 },
+        };
+        let json = serde_json::to_value(report).unwrap();
+        assert_eq!(json["batchType"], "span");
+        assert_eq!(json["rule"]["rule"], "epochTooOld");
+        assert_eq!(json["rule"]["minEpoch"], 2);
+        let decoded: BatchValidityReport = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, report);
+    }
+}
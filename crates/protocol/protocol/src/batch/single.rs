@@ -1,6 +1,8 @@
 //! This module contains the [`SingleBatch`] type.
 
-use crate::{BatchValidity, BlockInfo, L2BlockInfo};
+use crate::{
+    BatchType, BatchValidity, BatchValidityReport, BatchValidityRule, BlockInfo, L2BlockInfo,
+};
 use alloc::vec::Vec;
 use alloy_eips::BlockNumHash;
 use alloy_primitives::{BlockHash, Bytes};
@@ -43,20 +45,7 @@ impl SingleBatch {
         l2_safe_head: L2BlockInfo,
         inclusion_block: &BlockInfo,
     ) -> BatchValidity {
-        let next_timestamp = l2_safe_head.block_info.timestamp + cfg.block_time;
-        if self.timestamp > next_timestamp {
-            if cfg.is_holocene_active(inclusion_block.timestamp) {
-                return BatchValidity::Drop;
-            }
-            return BatchValidity::Future;
-        }
-        if self.timestamp < next_timestamp {
-            if cfg.is_holocene_active(inclusion_block.timestamp) {
-                return BatchValidity::Past;
-            }
-            return BatchValidity::Drop;
-        }
-        BatchValidity::Accept
+        self.timestamp_rule(cfg, l2_safe_head, inclusion_block).0
     }
 
     /// Checks if the batch is valid.
@@ -71,34 +60,112 @@ impl SingleBatch {
         l2_safe_head: L2BlockInfo,
         inclusion_block: &BlockInfo,
     ) -> BatchValidity {
+        self.check_batch_report(cfg, l1_blocks, l2_safe_head, inclusion_block).validity
+    }
+
+    /// Checks if the batch is valid, returning a [`BatchValidityReport`] that explains which
+    /// [`BatchValidityRule`] decided the outcome.
+    pub fn check_batch_report(
+        &self,
+        cfg: &RollupConfig,
+        l1_blocks: &[BlockInfo],
+        l2_safe_head: L2BlockInfo,
+        inclusion_block: &BlockInfo,
+    ) -> BatchValidityReport {
+        let (validity, rule) = self.check_batch_rule(cfg, l1_blocks, l2_safe_head, inclusion_block);
+        BatchValidityReport {
+            batch_type: BatchType::Single,
+            timestamp: self.timestamp,
+            inclusion_block: inclusion_block.id(),
+            l2_safe_head: l2_safe_head.block_info.id(),
+            validity,
+            rule,
+        }
+    }
+
+    /// Validates the batch timestamp against the next expected L2 timestamp.
+    fn timestamp_rule(
+        &self,
+        cfg: &RollupConfig,
+        l2_safe_head: L2BlockInfo,
+        inclusion_block: &BlockInfo,
+    ) -> (BatchValidity, BatchValidityRule) {
+        let next_timestamp = l2_safe_head.block_info.timestamp + cfg.block_time;
+        let holocene = cfg.is_holocene_active(inclusion_block.timestamp);
+        if self.timestamp > next_timestamp {
+            let rule = BatchValidityRule::FutureTimestamp {
+                timestamp: self.timestamp,
+                next_timestamp,
+                holocene,
+            };
+            if holocene {
+                return (BatchValidity::Drop, rule);
+            }
+            return (BatchValidity::Future, rule);
+        }
+        if self.timestamp < next_timestamp {
+            let rule = BatchValidityRule::PastTimestamp {
+                timestamp: self.timestamp,
+                next_timestamp,
+                holocene,
+            };
+            if holocene {
+                return (BatchValidity::Past, rule);
+            }
+            return (BatchValidity::Drop, rule);
+        }
+        (BatchValidity::Accept, BatchValidityRule::Accepted)
+    }
+
+    /// Applies the batch validity rules, returning the outcome and the rule that decided it.
+    fn check_batch_rule(
+        &self,
+        cfg: &RollupConfig,
+        l1_blocks: &[BlockInfo],
+        l2_safe_head: L2BlockInfo,
+        inclusion_block: &BlockInfo,
+    ) -> (BatchValidity, BatchValidityRule) {
         // Cannot have empty l1_blocks for batch validation.
         if l1_blocks.is_empty() {
-            return BatchValidity::Undecided;
+            return (BatchValidity::Undecided, BatchValidityRule::MissingL1Blocks);
         }
 
         let epoch = l1_blocks[0];
 
         // If the batch is not accepted by the timestamp check, return the result.
-        let timestamp_check = self.check_batch_timestamp(cfg, l2_safe_head, inclusion_block);
-        if !timestamp_check.is_accept() {
+        let timestamp_check = self.timestamp_rule(cfg, l2_safe_head, inclusion_block);
+        if !timestamp_check.0.is_accept() {
             return timestamp_check;
         }
 
         // Dependent on the above timestamp check.
         // If the timestamp is correct, then it must build on top of the safe head.
         if self.parent_hash != l2_safe_head.block_info.hash {
-            return BatchValidity::Drop;
+            return (
+                BatchValidity::Drop,
+                BatchValidityRule::ParentHashMismatch { parent: l2_safe_head.block_info.id() },
+            );
         }
 
         // Filter out batches that were included too late.
         if self.epoch_num + cfg.seq_window_size < inclusion_block.number {
-            return BatchValidity::Drop;
+            return (
+                BatchValidity::Drop,
+                BatchValidityRule::SequenceWindowExpired {
+                    epoch: self.epoch_num,
+                    inclusion_block: inclusion_block.number,
+                    seq_window_size: cfg.seq_window_size,
+                },
+            );
         }
 
         // Check the L1 origin of the batch
         let mut batch_origin = epoch;
         if self.epoch_num < epoch.number {
-            return BatchValidity::Drop;
+            return (
+                BatchValidity::Drop,
+                BatchValidityRule::EpochTooOld { epoch: self.epoch_num, min_epoch: epoch.number },
+            );
         } else if self.epoch_num == epoch.number {
             // Batch is sticking to the current epoch, continue.
         } else if self.epoch_num == epoch.number + 1 {
@@ -108,20 +175,41 @@ impl SingleBatch {
             // more information otherwise the eager algorithm may diverge from a non-eager
             // algorithm.
             if l1_blocks.len() < 2 {
-                return BatchValidity::Undecided;
+                return (
+                    BatchValidity::Undecided,
+                    BatchValidityRule::NextEpochUnavailable { epoch: epoch.number },
+                );
             }
             batch_origin = l1_blocks[1];
         } else {
-            return BatchValidity::Drop;
+            return (
+                BatchValidity::Drop,
+                BatchValidityRule::EpochTooFarAhead {
+                    epoch: self.epoch_num,
+                    max_epoch: epoch.number + 1,
+                },
+            );
         }
 
         // Validate the batch epoch hash
         if self.epoch_hash != batch_origin.hash {
-            return BatchValidity::Drop;
+            return (
+                BatchValidity::Drop,
+                BatchValidityRule::EpochHashMismatch {
+                    epoch: batch_origin.number,
+                    expected: batch_origin.hash,
+                },
+            );
         }
 
         if self.timestamp < batch_origin.timestamp {
-            return BatchValidity::Drop;
+            return (
+                BatchValidity::Drop,
+                BatchValidityRule::TimestampBeforeOrigin {
+                    timestamp: self.timestamp,
+                    origin_timestamp: batch_origin.timestamp,
+                },
+            );
         }
 
         // Check if we ran out of sequencer time drift
@@ -129,7 +217,13 @@ impl SingleBatch {
         let max = if let Some(max) = batch_origin.timestamp.checked_add(max_drift) {
             max
         } else {
-            return BatchValidity::Drop;
+            return (
+                BatchValidity::Drop,
+                BatchValidityRule::DriftOverflow {
+                    origin_timestamp: batch_origin.timestamp,
+                    max_drift,
+                },
+            );
         };
 
         let no_txs = self.transactions.is_empty();
@@ -137,7 +231,13 @@ impl SingleBatch {
             // If the sequencer is ignoring the time drift rule, then drop the batch and force an
             // empty batch instead, as the sequencer is not allowed to include anything
             // past this point without moving to the next epoch.
-            return BatchValidity::Drop;
+            return (
+                BatchValidity::Drop,
+                BatchValidityRule::SequencerDriftExceeded {
+                    timestamp: self.timestamp,
+                    max_timestamp: max,
+                },
+            );
         }
         if self.timestamp > max && no_txs {
             // If the sequencer is co-operating by producing an empty batch,
@@ -146,12 +246,24 @@ impl SingleBatch {
             // epoch advancement regardless of time drift is allowed.
             if epoch.number == batch_origin.number {
                 if l1_blocks.len() < 2 {
-                    return BatchValidity::Undecided;
+                    return (
+                        BatchValidity::Undecided,
+                        BatchValidityRule::NextOriginUnavailable {
+                            timestamp: self.timestamp,
+                            max_timestamp: max,
+                        },
+                    );
                 }
                 let next_origin = l1_blocks[1];
                 // Check if the next L1 Origin could have been adopted
                 if self.timestamp >= next_origin.timestamp {
-                    return BatchValidity::Drop;
+                    return (
+                        BatchValidity::Drop,
+                        BatchValidityRule::NextOriginAdoptable {
+                            timestamp: self.timestamp,
+                            next_origin_timestamp: next_origin.timestamp,
+                        },
+                    );
                 }
             }
         }
@@ -163,26 +275,39 @@ impl SingleBatch {
                 target: "single_batch",
                 "Sequencer included user transactions in interop transition block. Dropping batch."
             );
-            return BatchValidity::Drop;
+            return (
+                BatchValidity::Drop,
+                BatchValidityRule::InteropTransitionTransactions { timestamp: self.timestamp },
+            );
         }
 
         // We can do this check earlier, but it's intensive so we do it last for the sad-path.
-        for tx in self.transactions.iter() {
+        for (index, tx) in self.transactions.iter().enumerate() {
+            let timestamp = self.timestamp;
             if tx.is_empty() {
-                return BatchValidity::Drop;
+                return (
+                    BatchValidity::Drop,
+                    BatchValidityRule::EmptyTransaction { timestamp, index },
+                );
             }
             if tx.as_ref().first() == Some(&(OpTxType::Deposit as u8)) {
-                return BatchValidity::Drop;
+                return (
+                    BatchValidity::Drop,
+                    BatchValidityRule::DepositTransaction { timestamp, index },
+                );
             }
             // If isthmus is not active yet and the transaction is a 7702, drop the batch.
             if !cfg.is_isthmus_active(self.timestamp) &&
                 tx.as_ref().first() == Some(&(OpTxType::Eip7702 as u8))
             {
-                return BatchValidity::Drop;
+                return (
+                    BatchValidity::Drop,
+                    BatchValidityRule::PreIsthmusEip7702 { timestamp, index },
+                );
             }
         }
 
-        BatchValidity::Accept
+        (BatchValidity::Accept, BatchValidityRule::Accepted)
     }
 }
 
@@ -245,6 +370,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_check_batch_report_parent_hash_mismatch() {
+        let cfg = RollupConfig::default();
+        let l1_blocks = vec![BlockInfo::default(), BlockInfo::default()];
+        let safe_head_hash = BlockHash::from([0x01; 32]);
+        let l2_safe_head = L2BlockInfo {
+            block_info: BlockInfo { number: 3, hash: safe_head_hash, ..Default::default() },
+            ..Default::default()
+        };
+        let inclusion_block = BlockInfo { number: 7, ..Default::default() };
+        let batch = SingleBatch { parent_hash: BlockHash::from([0x02; 32]), ..Default::default() };
+        let report = batch.check_batch_report(&cfg, &l1_blocks, l2_safe_head, &inclusion_block);
+        assert_eq!(report.batch_type, BatchType::Single);
+        assert_eq!(report.inclusion_block.number, 7);
+        assert_eq!(report.validity, BatchValidity::Drop);
+        assert_eq!(
+            report.rule,
+            BatchValidityRule::ParentHashMismatch {
+                parent: BlockNumHash { number: 3, hash: safe_head_hash }
+            }
+        );
+    }
+
+    #[test]
+    fn test_check_batch_report_holocene_past() {
+        let cfg = RollupConfig {
+            block_time: 2,
+            hardforks: HardForkConfig { holocene_time: Some(0), ..Default::default() },
+            ..Default::default()
+        };
+        let l1_blocks = vec![BlockInfo::default()];
+        let l2_safe_head = L2BlockInfo {
+            block_info: BlockInfo { timestamp: 2, ..Default::default() },
+            ..Default::default()
+        };
+        let inclusion_block = BlockInfo { timestamp: 1, ..Default::default() };
+        let batch = SingleBatch { timestamp: 3, ..Default::default() };
+        let report = batch.check_batch_report(&cfg, &l1_blocks, l2_safe_head, &inclusion_block);
+        assert_eq!(report.validity, BatchValidity::Past);
+        assert_eq!(
+            report.rule,
+            BatchValidityRule::PastTimestamp { timestamp: 3, next_timestamp: 4, holocene: true }
+        );
+    }
+
+    #[test]
+    fn test_check_batch_report_sequencer_drift_exceeded() {
+        let cfg = RollupConfig { max_sequencer_drift: 1, block_time: 10, ..Default::default() };
+        let l1_blocks = vec![BlockInfo::default()];
+        let l2_safe_head = L2BlockInfo::default();
+        let inclusion_block = BlockInfo::default();
+        let batch = SingleBatch {
+            timestamp: 10,
+            transactions: vec![Bytes::from(vec![0x02])],
+            ..Default::default()
+        };
+        let report = batch.check_batch_report(&cfg, &l1_blocks, l2_safe_head, &inclusion_block);
+        assert_eq!(report.validity, BatchValidity::Drop);
+        assert_eq!(
+            report.rule,
+            BatchValidityRule::SequencerDriftExceeded { timestamp: 10, max_timestamp: 1 }
+        );
+    }
+
     #[test]
     fn test_check_batch_timestamp_holocene_inactive_future() {
         let cfg = RollupConfig::default();
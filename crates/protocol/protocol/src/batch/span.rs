@@ -13,9 +13,9 @@ use op_alloy_consensus::OpTxType;
 use tracing::{info, warn};
 
 use crate::{
-    BatchValidationProvider, BatchValidity, BlockInfo, L2BlockInfo, RawSpanBatch, SingleBatch,
-    SpanBatchBits, SpanBatchElement, SpanBatchError, SpanBatchPayload, SpanBatchPrefix,
-    SpanBatchTransactions,
+    BatchType, BatchValidationProvider, BatchValidity, BatchValidityReport, BatchValidityRule,
+    BlockInfo, L2BlockInfo, RawSpanBatch, SingleBatch, SpanBatchBits, SpanBatchElement,
+    SpanBatchError, SpanBatchPayload, SpanBatchPrefix, SpanBatchTransactions,
 };
 
 /// Container for the inputs required to build a span of L2 blocks in derived form.
@@ -378,10 +378,92 @@ impl SpanBatch {
         inclusion_block: &BlockInfo,
         fetcher: &mut BV,
     ) -> BatchValidity {
-        let (prefix_validity, parent_block) =
-            self.check_batch_prefix(cfg, l1_blocks, l2_safe_head, inclusion_block, fetcher).await;
+        self.check_batch_report(cfg, l1_blocks, l2_safe_head, inclusion_block, fetcher)
+            .await
+            .validity
+    }
+
+    /// Checks if the span batch is valid, returning a [`BatchValidityReport`] that explains which
+    /// [`BatchValidityRule`] decided the outcome.
+    pub async fn check_batch_report<BV: BatchValidationProvider>(
+        &self,
+        cfg: &RollupConfig,
+        l1_blocks: &[BlockInfo],
+        l2_safe_head: L2BlockInfo,
+        inclusion_block: &BlockInfo,
+        fetcher: &mut BV,
+    ) -> BatchValidityReport {
+        let (validity, rule) =
+            self.check_batch_rule(cfg, l1_blocks, l2_safe_head, inclusion_block, fetcher).await;
+        self.report(l2_safe_head, inclusion_block, validity, rule)
+    }
+
+    /// Checks the validity of the batch's prefix.
+    ///
+    /// This function is used for post-Holocene hardfork to perform batch validation
+    /// as each batch is being loaded in.
+    pub async fn check_batch_prefix<BF: BatchValidationProvider>(
+        &self,
+        cfg: &RollupConfig,
+        l1_origins: &[BlockInfo],
+        l2_safe_head: L2BlockInfo,
+        inclusion_block: &BlockInfo,
+        fetcher: &mut BF,
+    ) -> (BatchValidity, Option<L2BlockInfo>) {
+        let (report, parent_block) = self
+            .check_batch_prefix_report(cfg, l1_origins, l2_safe_head, inclusion_block, fetcher)
+            .await;
+        (report.validity, parent_block)
+    }
+
+    /// Checks the validity of the batch's prefix, returning a [`BatchValidityReport`] that
+    /// explains which [`BatchValidityRule`] decided the outcome.
+    pub async fn check_batch_prefix_report<BF: BatchValidationProvider>(
+        &self,
+        cfg: &RollupConfig,
+        l1_origins: &[BlockInfo],
+        l2_safe_head: L2BlockInfo,
+        inclusion_block: &BlockInfo,
+        fetcher: &mut BF,
+    ) -> (BatchValidityReport, Option<L2BlockInfo>) {
+        let (validity, rule, parent_block) = self
+            .check_batch_prefix_rule(cfg, l1_origins, l2_safe_head, inclusion_block, fetcher)
+            .await;
+        (self.report(l2_safe_head, inclusion_block, validity, rule), parent_block)
+    }
+
+    /// Builds a [`BatchValidityReport`] for the span batch.
+    fn report(
+        &self,
+        l2_safe_head: L2BlockInfo,
+        inclusion_block: &BlockInfo,
+        validity: BatchValidity,
+        rule: BatchValidityRule,
+    ) -> BatchValidityReport {
+        BatchValidityReport {
+            batch_type: BatchType::Span,
+            timestamp: self.batches.first().map(|b| b.timestamp).unwrap_or_default(),
+            inclusion_block: inclusion_block.id(),
+            l2_safe_head: l2_safe_head.block_info.id(),
+            validity,
+            rule,
+        }
+    }
+
+    /// Applies the span batch validity rules, returning the outcome and the rule that decided it.
+    async fn check_batch_rule<BV: BatchValidationProvider>(
+        &self,
+        cfg: &RollupConfig,
+        l1_blocks: &[BlockInfo],
+        l2_safe_head: L2BlockInfo,
+        inclusion_block: &BlockInfo,
+        fetcher: &mut BV,
+    ) -> (BatchValidity, BatchValidityRule) {
+        let (prefix_validity, prefix_rule, parent_block) = self
+            .check_batch_prefix_rule(cfg, l1_blocks, l2_safe_head, inclusion_block, fetcher)
+            .await;
         if !matches!(prefix_validity, BatchValidity::Accept) {
-            return prefix_validity;
+            return (prefix_validity, prefix_rule);
         }
 
         let starting_epoch_num = self.starting_epoch_num();
@@ -416,12 +498,19 @@ impl SpanBatch {
                     l1_origin.timestamp,
                     l1_origin.id()
                 );
-                return BatchValidity::Drop;
+                return (
+                    BatchValidity::Drop,
+                    BatchValidityRule::TimestampBeforeOrigin {
+                        timestamp: block_timestamp,
+                        origin_timestamp: l1_origin.timestamp,
+                    },
+                );
             }
 
             // Check if we ran out of sequencer time drift
             let max_drift = cfg.max_sequencer_drift(l1_origin.timestamp);
-            if block_timestamp > l1_origin.timestamp + max_drift {
+            let max_timestamp = l1_origin.timestamp + max_drift;
+            if block_timestamp > max_timestamp {
                 if batch.transactions.is_empty() {
                     // If the sequencer is co-operating by producing an empty batch,
                     // then allow the batch if it was the right thing to do to maintain the L2 time
@@ -434,15 +523,28 @@ impl SpanBatch {
                                 target: "batch_span",
                                 "without the next L1 origin we cannot determine yet if this empty batch that exceeds the time drift is still valid"
                             );
-                            return BatchValidity::Undecided;
+                            return (
+                                BatchValidity::Undecided,
+                                BatchValidityRule::NextOriginUnavailable {
+                                    timestamp: block_timestamp,
+                                    max_timestamp,
+                                },
+                            );
                         }
-                        if block_timestamp >= l1_blocks[origin_index + 1].timestamp {
+                        let next_origin = l1_blocks[origin_index + 1];
+                        if block_timestamp >= next_origin.timestamp {
                             // check if the next L1 origin could have been adopted
                             info!(
                                 target: "batch_span",
                                 "batch exceeded sequencer time drift without adopting next origin, and next L1 origin would have been valid"
                             );
-                            return BatchValidity::Drop;
+                            return (
+                                BatchValidity::Drop,
+                                BatchValidityRule::NextOriginAdoptable {
+                                    timestamp: block_timestamp,
+                                    next_origin_timestamp: next_origin.timestamp,
+                                },
+                            );
                         } else {
                             info!(
                                 target: "batch_span",
@@ -457,9 +559,15 @@ impl SpanBatch {
                     warn!(
                         target: "batch_span",
                         "batch exceeded sequencer time drift, sequencer must adopt new L1 origin to include transactions again, max_time: {}",
-                        l1_origin.timestamp + max_drift
+                        max_timestamp
+                    );
+                    return (
+                        BatchValidity::Drop,
+                        BatchValidityRule::SequencerDriftExceeded {
+                            timestamp: block_timestamp,
+                            max_timestamp,
+                        },
                     );
-                    return BatchValidity::Drop;
                 }
             }
 
@@ -471,7 +579,13 @@ impl SpanBatch {
                         "transaction data must not be empty, but found empty tx, tx_index: {}",
                         i
                     );
-                    return BatchValidity::Drop;
+                    return (
+                        BatchValidity::Drop,
+                        BatchValidityRule::EmptyTransaction {
+                            timestamp: block_timestamp,
+                            index: i,
+                        },
+                    );
                 }
                 if tx.as_ref().first() == Some(&(OpTxType::Deposit as u8)) {
                     warn!(
@@ -479,7 +593,13 @@ impl SpanBatch {
                         "sequencers may not embed any deposits into batch data, but found tx that has one, tx_index: {}",
                         i
                     );
-                    return BatchValidity::Drop;
+                    return (
+                        BatchValidity::Drop,
+                        BatchValidityRule::DepositTransaction {
+                            timestamp: block_timestamp,
+                            index: i,
+                        },
+                    );
                 }
 
                 // If isthmus is not active yet and the transaction is a 7702, drop the batch.
@@ -487,7 +607,13 @@ impl SpanBatch {
                     tx.as_ref().first() == Some(&(OpTxType::Eip7702 as u8))
                 {
                     warn!(target: "batch_span", "EIP-7702 transactions are not supported pre-isthmus. tx_index: {}", i);
-                    return BatchValidity::Drop;
+                    return (
+                        BatchValidity::Drop,
+                        BatchValidityRule::PreIsthmusEip7702 {
+                            timestamp: block_timestamp,
+                            index: i,
+                        },
+                    );
                 }
             }
         }
@@ -502,7 +628,10 @@ impl SpanBatch {
                     Ok(p) => p,
                     Err(e) => {
                         warn!(target: "batch_span", "failed to fetch block number {safe_block_num}: {e}");
-                        return BatchValidity::Undecided;
+                        return (
+                            BatchValidity::Undecided,
+                            BatchValidityRule::OverlapUnavailable { number: safe_block_num },
+                        );
                     }
                 };
                 let safe_block = &safe_block_payload.body;
@@ -520,7 +649,14 @@ impl SpanBatch {
                         safe_block.transactions.len(),
                         batch_txs.len()
                     );
-                    return BatchValidity::Drop;
+                    return (
+                        BatchValidity::Drop,
+                        BatchValidityRule::OverlapTxCountMismatch {
+                            number: safe_block_num,
+                            safe_txs: safe_block.transactions.len() - deposit_count,
+                            batch_txs: batch_txs.len(),
+                        },
+                    );
                 }
                 let batch_txs_len = batch_txs.len();
                 #[allow(clippy::needless_range_loop)]
@@ -529,7 +665,13 @@ impl SpanBatch {
                     safe_block.transactions[j + deposit_count].encode_2718(&mut buf);
                     if buf != batch_txs[j].0 {
                         warn!(target: "batch_span", "overlapped block's transaction does not match");
-                        return BatchValidity::Drop;
+                        return (
+                            BatchValidity::Drop,
+                            BatchValidityRule::OverlapTxMismatch {
+                                number: safe_block_num,
+                                index: j,
+                            },
+                        );
                     }
                 }
                 let safe_block_ref = match L2BlockInfo::from_block_and_genesis(
@@ -543,7 +685,10 @@ impl SpanBatch {
                             "failed to extract L2BlockInfo from execution payload, hash: {}, err: {e}",
                             safe_block_payload.header.hash_slow()
                         );
-                        return BatchValidity::Drop;
+                        return (
+                            BatchValidity::Drop,
+                            BatchValidityRule::OverlapInvalidBlock { number: safe_block_num },
+                        );
                     }
                 };
                 if safe_block_ref.l1_origin.number != self.batches[i as usize].epoch_num {
@@ -551,37 +696,43 @@ impl SpanBatch {
                         "overlapped block's L1 origin number does not match {}, {}",
                         safe_block_ref.l1_origin.number, self.batches[i as usize].epoch_num
                     );
-                    return BatchValidity::Drop;
+                    return (
+                        BatchValidity::Drop,
+                        BatchValidityRule::OverlapOriginMismatch {
+                            number: safe_block_num,
+                            safe_origin: safe_block_ref.l1_origin.number,
+                            batch_origin: self.batches[i as usize].epoch_num,
+                        },
+                    );
                 }
             }
         }
 
-        BatchValidity::Accept
+        (BatchValidity::Accept, BatchValidityRule::Accepted)
     }
 
-    /// Checks the validity of the batch's prefix.
-    ///
-    /// This function is used for post-Holocene hardfork to perform batch validation
-    /// as each batch is being loaded in.
-    pub async fn check_batch_prefix<BF: BatchValidationProvider>(
+    /// Applies the span batch prefix validity rules, returning the outcome, the rule that decided
+    /// it and the parent block of the span batch if it was accepted.
+    async fn check_batch_prefix_rule<BF: BatchValidationProvider>(
         &self,
         cfg: &RollupConfig,
         l1_origins: &[BlockInfo],
         l2_safe_head: L2BlockInfo,
         inclusion_block: &BlockInfo,
         fetcher: &mut BF,
-    ) -> (BatchValidity, Option<L2BlockInfo>) {
+    ) -> (BatchValidity, BatchValidityRule, Option<L2BlockInfo>) {
         if l1_origins.is_empty() {
             warn!(target: "batch_span", "missing L1 block input, cannot proceed with batch checking");
-            return (BatchValidity::Undecided, None);
+            return (BatchValidity::Undecided, BatchValidityRule::MissingL1Blocks, None);
         }
         if self.batches.is_empty() {
             warn!(target: "batch_span", "empty span batch, cannot proceed with batch checking");
-            return (BatchValidity::Undecided, None);
+            return (BatchValidity::Undecided, BatchValidityRule::EmptySpan, None);
         }
 
         let epoch = l1_origins[0];
         let next_timestamp = l2_safe_head.block_info.timestamp + cfg.block_time;
+        let holocene = cfg.is_holocene_active(inclusion_block.timestamp);
 
         let starting_epoch_num = self.starting_epoch_num();
         let mut batch_origin = epoch;
@@ -592,7 +743,11 @@ impl SpanBatch {
                     "eager batch wants to advance current epoch {:?}, but could not without more L1 blocks",
                     epoch.id()
                 );
-                return (BatchValidity::Undecided, None);
+                return (
+                    BatchValidity::Undecided,
+                    BatchValidityRule::NextEpochUnavailable { epoch: epoch.number },
+                    None,
+                );
             }
             batch_origin = l1_origins[1];
         }
@@ -603,7 +758,11 @@ impl SpanBatch {
                 batch_origin.id(),
                 batch_origin.timestamp
             );
-            return (BatchValidity::Drop, None);
+            return (
+                BatchValidity::Drop,
+                BatchValidityRule::PreDelta { origin_timestamp: batch_origin.timestamp },
+                None,
+            );
         }
 
         if self.starting_timestamp() > next_timestamp {
@@ -614,20 +773,30 @@ impl SpanBatch {
                 next_timestamp
             );
 
+            let rule = BatchValidityRule::FutureTimestamp {
+                timestamp: self.starting_timestamp(),
+                next_timestamp,
+                holocene,
+            };
             // After holocene is activated, gaps are disallowed.
-            if cfg.is_holocene_active(inclusion_block.timestamp) {
-                return (BatchValidity::Drop, None);
+            if holocene {
+                return (BatchValidity::Drop, rule, None);
             }
-            return (BatchValidity::Future, None);
+            return (BatchValidity::Future, rule, None);
         }
 
         // Drop the batch if it has no new blocks after the safe head.
         if self.final_timestamp() < next_timestamp {
             warn!(target: "batch_span", "span batch has no new blocks after safe head");
-            return if cfg.is_holocene_active(inclusion_block.timestamp) {
-                (BatchValidity::Past, None)
+            let rule = BatchValidityRule::PastTimestamp {
+                timestamp: self.final_timestamp(),
+                next_timestamp,
+                holocene,
+            };
+            return if holocene {
+                (BatchValidity::Past, rule, None)
             } else {
-                (BatchValidity::Drop, None)
+                (BatchValidity::Drop, rule, None)
             };
         }
 
@@ -637,15 +806,20 @@ impl SpanBatch {
         let mut parent_num = l2_safe_head.block_info.number;
         let mut parent_block = l2_safe_head;
         if self.starting_timestamp() < next_timestamp {
+            let misaligned = BatchValidityRule::MisalignedTimestamp {
+                timestamp: self.starting_timestamp(),
+                safe_head_timestamp: l2_safe_head.block_info.timestamp,
+                block_time: cfg.block_time,
+            };
             if self.starting_timestamp() > l2_safe_head.block_info.timestamp {
                 // Batch timestamp cannot be between safe head and next timestamp.
                 warn!(target: "batch_span", "batch has misaligned timestamp, block time is too short");
-                return (BatchValidity::Drop, None);
+                return (BatchValidity::Drop, misaligned, None);
             }
             if (l2_safe_head.block_info.timestamp - self.starting_timestamp()) % cfg.block_time != 0
             {
                 warn!(target: "batch_span", "batch has misaligned timestamp, not overlapped exactly");
-                return (BatchValidity::Drop, None);
+                return (BatchValidity::Drop, misaligned, None);
             }
             parent_num = l2_safe_head.block_info.number -
                 (l2_safe_head.block_info.timestamp - self.starting_timestamp()) / cfg.block_time -
@@ -655,7 +829,11 @@ impl SpanBatch {
                 Err(e) => {
                     warn!(target: "batch_span", "failed to fetch L2 block number {parent_num}: {e}");
                     // Unable to validate the batch for now. Retry later.
-                    return (BatchValidity::Undecided, None);
+                    return (
+                        BatchValidity::Undecided,
+                        BatchValidityRule::ParentUnavailable { number: parent_num },
+                        None,
+                    );
                 }
             };
         }
@@ -665,13 +843,25 @@ impl SpanBatch {
                 "parent block mismatch, expected: {parent_num}, received: {}. parent hash: {}, parent hash check: {}",
                 parent_block.block_info.number, parent_block.block_info.hash, self.parent_check,
             );
-            return (BatchValidity::Drop, None);
+            return (
+                BatchValidity::Drop,
+                BatchValidityRule::ParentHashMismatch { parent: parent_block.block_info.id() },
+                None,
+            );
         }
 
         // Filter out batches that were included too late.
         if starting_epoch_num + cfg.seq_window_size < inclusion_block.number {
             warn!(target: "batch_span", "batch was included too late, sequence window expired");
-            return (BatchValidity::Drop, None);
+            return (
+                BatchValidity::Drop,
+                BatchValidityRule::SequenceWindowExpired {
+                    epoch: starting_epoch_num,
+                    inclusion_block: inclusion_block.number,
+                    seq_window_size: cfg.seq_window_size,
+                },
+                None,
+            );
         }
 
         // Check the L1 origin of the batch
@@ -682,7 +872,14 @@ impl SpanBatch {
                 starting_epoch_num,
                 parent_block.l1_origin.number + 1
             );
-            return (BatchValidity::Drop, None);
+            return (
+                BatchValidity::Drop,
+                BatchValidityRule::EpochTooFarAhead {
+                    epoch: starting_epoch_num,
+                    max_epoch: parent_block.l1_origin.number + 1,
+                },
+                None,
+            );
         }
 
         // Verify the l1 origin hash for each l1 block.
@@ -698,7 +895,14 @@ impl SpanBatch {
                         "batch is for different L1 chain, epoch hash does not match, expected: {}",
                         l1_block.hash
                     );
-                    return (BatchValidity::Drop, None);
+                    return (
+                        BatchValidity::Drop,
+                        BatchValidityRule::EpochHashMismatch {
+                            epoch: end_epoch_num,
+                            expected: l1_block.hash,
+                        },
+                        None,
+                    );
                 }
                 origin_checked = true;
                 break;
@@ -706,15 +910,26 @@ impl SpanBatch {
         }
         if !origin_checked {
             info!(target: "batch_span", "need more l1 blocks to check entire origins of span batch");
-            return (BatchValidity::Undecided, None);
+            return (
+                BatchValidity::Undecided,
+                BatchValidityRule::OriginUnavailable { epoch: end_epoch_num },
+                None,
+            );
         }
 
         if starting_epoch_num < parent_block.l1_origin.number {
             warn!(target: "batch_span", "dropped batch, epoch is too old, minimum: {:?}", parent_block.block_info.id());
-            return (BatchValidity::Drop, None);
+            return (
+                BatchValidity::Drop,
+                BatchValidityRule::EpochTooOld {
+                    epoch: starting_epoch_num,
+                    min_epoch: parent_block.l1_origin.number,
+                },
+                None,
+            );
         }
 
-        (BatchValidity::Accept, Some(parent_block))
+        (BatchValidity::Accept, BatchValidityRule::Accepted, Some(parent_block))
     }
 }
 
@@ -2251,4 +2466,86 @@ mod tests {
         );
         assert!(trace_store.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_check_batch_report_future() {
+        let cfg = RollupConfig {
+            hardforks: HardForkConfig { delta_time: Some(0), ..Default::default() },
+            block_time: 10,
+            ..Default::default()
+        };
+        let block = BlockInfo { number: 10, timestamp: 10, ..Default::default() };
+        let l1_blocks = vec![block];
+        let l2_safe_head = L2BlockInfo {
+            block_info: BlockInfo { number: 5, timestamp: 10, ..Default::default() },
+            ..Default::default()
+        };
+        let inclusion_block = BlockInfo { number: 11, ..Default::default() };
+        let mut fetcher: TestBatchValidator = TestBatchValidator::default();
+        let first = SpanBatchElement { epoch_num: 10, timestamp: 21, ..Default::default() };
+        let batch = SpanBatch { batches: vec![first], ..Default::default() };
+        let report = batch
+            .check_batch_report(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher)
+            .await;
+        assert_eq!(report.batch_type, BatchType::Span);
+        assert_eq!(report.timestamp, 21);
+        assert_eq!(report.inclusion_block.number, 11);
+        assert_eq!(report.l2_safe_head.number, 5);
+        assert_eq!(report.validity, BatchValidity::Future);
+        assert_eq!(
+            report.rule,
+            BatchValidityRule::FutureTimestamp {
+                timestamp: 21,
+                next_timestamp: 20,
+                holocene: false
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_check_batch_prefix_report_epoch_too_far_ahead() {
+        let cfg = RollupConfig {
+            seq_window_size: 100,
+            hardforks: HardForkConfig { delta_time: Some(0), ..Default::default() },
+            block_time: 10,
+            ..Default::default()
+        };
+        let l1_block_hash =
+            b256!("3333333333333333333333333333333333333333000000000000000000000000");
+        let block =
+            BlockInfo { number: 11, timestamp: 10, hash: l1_block_hash, ..Default::default() };
+        let l1_blocks = vec![block];
+        let parent_hash = b256!("1111111111111111111111111111111111111111000000000000000000000000");
+        let l2_safe_head = L2BlockInfo {
+            block_info: BlockInfo {
+                number: 41,
+                timestamp: 10,
+                hash: parent_hash,
+                ..Default::default()
+            },
+            l1_origin: BlockNumHash { number: 8, ..Default::default() },
+            ..Default::default()
+        };
+        let inclusion_block = BlockInfo { number: 50, ..Default::default() };
+        let mut fetcher: TestBatchValidator = TestBatchValidator::default();
+        let first = SpanBatchElement { epoch_num: 10, timestamp: 20, ..Default::default() };
+        let batch = SpanBatch {
+            batches: vec![first],
+            parent_check: FixedBytes::<20>::from_slice(&parent_hash[..20]),
+            l1_origin_check: FixedBytes::<20>::from_slice(&l1_block_hash[..20]),
+            ..Default::default()
+        };
+        let (report, parent) = batch
+            .check_batch_prefix_report(
+                &cfg,
+                &l1_blocks,
+                l2_safe_head,
+                &inclusion_block,
+                &mut fetcher,
+            )
+            .await;
+        assert!(parent.is_none());
+        assert_eq!(report.validity, BatchValidity::Drop);
+        assert_eq!(report.rule, BatchValidityRule::EpochTooFarAhead { epoch: 10, max_epoch: 9 });
+    }
 }
@@ -0,0 +1,1084 @@
+//! Contains the [`StreamingBatchReader`], which incrementally decodes batches from compressed
+//! channel data.
+
+use crate::{
+    Batch, BatchDecodingError, BatchReader, DecompressionError, DecompressorRegistry, RawSpanBatch,
+    SINGLE_BATCH_TYPE, SPAN_BATCH_TYPE, SingleBatch, SpanBatchError, SpanBatchPayload,
+    SpanBatchPrefix, SpanDecodingError, StreamDecoder, batch::transactions::SpanBatchTxCursor,
+    read_tx_data,
+};
+use alloc::{boxed::Box, vec::Vec};
+use alloy_rlp::{Decodable, Header};
+use kona_genesis::RollupConfig;
+
+/// The number of bytes decompressed at a time.
+const CHUNK_SIZE: usize = 16 * 1024;
+
+/// The maximum length of an RLP header.
+const MAX_RLP_HEADER_LEN: usize = 9;
+
+/// The maximum length of an unsigned varint encoded `u64`.
+const MAX_VARINT_LEN: usize = 10;
+
+/// An error returned by the [`StreamingBatchReader`].
+#[derive(Debug, thiserror::Error)]
+pub enum StreamingBatchReaderError {
+    /// The channel data could not be decompressed.
+    #[error("decompression error: {0}")]
+    Decompression(#[from] DecompressionError),
+    /// The RLP encoding of a batch is invalid.
+    #[error("invalid batch RLP: {0}")]
+    Rlp(#[from] alloy_rlp::Error),
+    /// The batch type is unknown.
+    #[error("unknown batch type: {0}")]
+    UnknownBatchType(u8),
+    /// The batch could not be decoded.
+    #[error("batch decoding error: {0}")]
+    Batch(#[from] BatchDecodingError),
+    /// The span batch could not be decoded.
+    #[error("span batch decoding error: {0}")]
+    SpanBatch(#[from] SpanBatchError),
+    /// A brotli compressed batch was read before the Fjord hardfork.
+    #[error("brotli compressed batch before the Fjord hardfork")]
+    BrotliBeforeFjord,
+    /// A batch was read from a channel whose decompressor is not active at the batch timestamp.
+    #[error("decompressor for channel version {0} is not active")]
+    InactiveDecompressor(u8),
+}
+
+/// The compressed data of a channel, along with the decompressors it is read with.
+#[derive(Debug)]
+struct Channel {
+    /// The compressed channel data.
+    data: Vec<u8>,
+    /// The decompressors, selected by the channel version byte.
+    decompressors: DecompressorRegistry,
+}
+
+/// The decompressor of a [`StreamingBatchReader`].
+#[derive(Debug)]
+enum ChannelDecompressor {
+    /// A [`StreamDecoder`], which decompresses the channel one chunk at a time.
+    Stream(Box<dyn StreamDecoder>),
+    /// A channel decompressed at once by a [`Decompressor`] without a [`StreamDecoder`], which
+    /// is read from memory.
+    ///
+    /// [`Decompressor`]: crate::Decompressor
+    Buffered {
+        /// The decompressed channel.
+        decompressed: Vec<u8>,
+        /// The offset of the next byte to read.
+        offset: usize,
+    },
+}
+
+impl ChannelDecompressor {
+    /// Creates the decompressor for the given channel, selected by its version byte from the
+    /// channel's [`DecompressorRegistry`].
+    fn new(
+        channel: &Channel,
+        max_rlp_bytes_per_channel: usize,
+    ) -> Result<Self, DecompressionError> {
+        let data = channel.data.as_slice();
+        let compression_type = *data.first().ok_or(DecompressionError::EmptyData)?;
+        let decompressor = channel
+            .decompressors
+            .get(compression_type)
+            .ok_or(DecompressionError::UnsupportedType(compression_type))?;
+        match decompressor.stream_decoder() {
+            Some(decoder) => Ok(Self::Stream(decoder)),
+            None => {
+                let decompressed = decompressor.decompress(data, max_rlp_bytes_per_channel)?;
+                Ok(Self::Buffered { decompressed, offset: 0 })
+            }
+        }
+    }
+
+    /// Decompresses the given input into the given output buffer.
+    ///
+    /// Returns the number of input bytes consumed, the number of output bytes written and whether
+    /// the stream has ended.
+    fn decompress(
+        &mut self,
+        input: &[u8],
+        output: &mut [u8],
+    ) -> Result<(usize, usize, bool), DecompressionError> {
+        match self {
+            Self::Stream(decoder) => decoder.decompress(input, output),
+            Self::Buffered { decompressed, offset } => {
+                let len = output.len().min(decompressed.len() - *offset);
+                output[..len].copy_from_slice(&decompressed[*offset..*offset + len]);
+                *offset += len;
+                Ok((input.len(), len, *offset == decompressed.len()))
+            }
+        }
+    }
+}
+
+/// A cursor over the decompressed bytes of a channel, which decompresses the channel on demand
+/// and only buffers the bytes that have not been consumed yet.
+#[derive(Debug)]
+struct DecompressedStream {
+    /// The offset of the next compressed byte to decompress.
+    input_offset: usize,
+    /// The decompressor, created when the first bytes are decompressed.
+    decompressor: Option<ChannelDecompressor>,
+    /// Whether the decompressed stream has ended.
+    finished: bool,
+    /// The decompressed bytes that have not been fully consumed.
+    buffer: Vec<u8>,
+    /// The offset of the next unconsumed byte in the buffer.
+    cursor: usize,
+    /// The total number of decompressed bytes.
+    decompressed_len: usize,
+    /// The maximum RLP bytes per channel.
+    max_rlp_bytes_per_channel: usize,
+    /// The largest capacity of the buffer so far.
+    peak_buffered_len: usize,
+}
+
+impl DecompressedStream {
+    /// Creates a new [`DecompressedStream`] positioned at the start of the channel.
+    const fn new(max_rlp_bytes_per_channel: usize, peak_buffered_len: usize) -> Self {
+        Self {
+            input_offset: 0,
+            decompressor: None,
+            finished: false,
+            buffer: Vec::new(),
+            cursor: 0,
+            decompressed_len: 0,
+            max_rlp_bytes_per_channel,
+            peak_buffered_len,
+        }
+    }
+
+    /// Returns the buffered bytes that have not been consumed yet.
+    fn buffered(&self) -> &[u8] {
+        &self.buffer[self.cursor..]
+    }
+
+    /// Returns the position of the next unconsumed byte in the decompressed channel.
+    const fn position(&self) -> usize {
+        self.decompressed_len - (self.buffer.len() - self.cursor)
+    }
+
+    /// Consumes `len` buffered bytes.
+    const fn consume(&mut self, len: usize) {
+        self.cursor += len;
+    }
+
+    /// Decompresses until at least `len` bytes are buffered or the stream ends.
+    fn fill(&mut self, channel: &Channel, len: usize) -> Result<(), StreamingBatchReaderError> {
+        while self.buffer.len() - self.cursor < len && !self.finished {
+            self.decompress_chunk(channel)?;
+        }
+        Ok(())
+    }
+
+    /// Consumes the decompressed bytes up to the given position, without buffering more than a
+    /// chunk at a time.
+    fn skip_to(
+        &mut self,
+        channel: &Channel,
+        position: usize,
+    ) -> Result<(), StreamingBatchReaderError> {
+        while self.position() < position {
+            let len = (position - self.position()).min(self.buffer.len() - self.cursor);
+            if len == 0 {
+                if self.finished {
+                    return Err(alloy_rlp::Error::InputTooShort.into());
+                }
+                self.decompress_chunk(channel)?;
+            }
+            self.consume(len);
+        }
+        Ok(())
+    }
+
+    /// Decompresses the next chunk of the channel into the buffer.
+    fn decompress_chunk(&mut self, channel: &Channel) -> Result<(), StreamingBatchReaderError> {
+        let decompressor = match &mut self.decompressor {
+            Some(decompressor) => decompressor,
+            slot => slot.insert(ChannelDecompressor::new(channel, self.max_rlp_bytes_per_channel)?),
+        };
+
+        // Drop the consumed bytes before growing the buffer.
+        self.buffer.drain(..self.cursor);
+        self.cursor = 0;
+
+        // Decompress at most one byte past the limit, to detect channels that exceed it.
+        let limit = self.max_rlp_bytes_per_channel.saturating_add(1) - self.decompressed_len;
+        let len = self.buffer.len();
+        self.buffer.resize(len + CHUNK_SIZE.min(limit), 0);
+        self.peak_buffered_len = self.peak_buffered_len.max(self.buffer.capacity());
+        let (consumed, written, finished) =
+            decompressor.decompress(&channel.data[self.input_offset..], &mut self.buffer[len..])?;
+        self.buffer.truncate(len + written);
+        self.input_offset += consumed;
+        self.decompressed_len += written;
+        self.finished = finished || (consumed == 0 && written == 0);
+
+        if self.decompressed_len > self.max_rlp_bytes_per_channel {
+            return Err(DecompressionError::RlpTooLarge(
+                self.decompressed_len,
+                self.max_rlp_bytes_per_channel,
+            )
+            .into());
+        }
+        Ok(())
+    }
+}
+
+/// Returns the encoded length of a span batch transaction data at the start of `r`, which is an
+/// optional transaction type followed by an RLP list.
+fn tx_data_len(r: &[u8]) -> Result<usize, SpanBatchError> {
+    let offset = usize::from(r.first().is_some_and(|b| *b <= 0x7F));
+    let header = Header::decode(&mut &r[offset.min(r.len())..])
+        .map_err(|_| SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData))?;
+    if !header.list {
+        return Err(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData));
+    }
+    Ok(offset + header.length() + header.payload_length)
+}
+
+/// The blocks of a span batch that have not been yielded yet.
+///
+/// The transaction data of a span batch is the only column whose size is not bounded by its
+/// transaction count, so it is not held. Instead, the transaction data of each block is read from
+/// a second pass over the decompressed channel when the block is yielded.
+#[derive(Debug)]
+struct SpanBatchBlocks {
+    /// The span batch, without its transaction data.
+    batch: RawSpanBatch,
+    /// The index of the next block.
+    block: u64,
+    /// The epoch number of the next block.
+    epoch_num: u64,
+    /// The cursor over the transactions of the span batch.
+    cursor: SpanBatchTxCursor,
+    /// The stream that the transaction data is read from.
+    tx_datas: DecompressedStream,
+    /// The position of the first transaction data in the decompressed channel.
+    tx_datas_start: usize,
+    /// The position of the end of the span batch in the decompressed channel.
+    end: usize,
+}
+
+impl SpanBatchBlocks {
+    /// Creates a new [`SpanBatchBlocks`] from a span batch decoded without its transaction data.
+    fn new(
+        batch: RawSpanBatch,
+        tx_datas: DecompressedStream,
+        tx_datas_start: usize,
+        end: usize,
+    ) -> Result<Self, SpanBatchError> {
+        // The prefix holds the L1 origin number of the last block, and each origin bit after the
+        // first block marks an increment of the L1 origin number.
+        let mut origin_changes = 0u64;
+        for i in 1..batch.payload.block_count as usize {
+            let bit = batch
+                .payload
+                .origin_bits
+                .get_bit(i)
+                .ok_or(SpanBatchError::Decoding(SpanDecodingError::L1OriginCheck))?;
+            origin_changes += u64::from(bit);
+        }
+        let epoch_num = batch
+            .prefix
+            .l1_origin_num
+            .checked_sub(origin_changes)
+            .ok_or(SpanBatchError::Decoding(SpanDecodingError::L1OriginNumber))?;
+
+        Ok(Self {
+            batch,
+            block: 0,
+            epoch_num,
+            cursor: SpanBatchTxCursor::default(),
+            tx_datas,
+            tx_datas_start,
+            end,
+        })
+    }
+
+    /// Returns the next block of the span batch as a [`SingleBatch`], reading and expanding only
+    /// the transactions of that block.
+    fn next_block(
+        &mut self,
+        channel: &Channel,
+        cfg: &RollupConfig,
+    ) -> Result<Option<SingleBatch>, StreamingBatchReaderError> {
+        if self.block >= self.batch.payload.block_count {
+            return Ok(None);
+        }
+
+        let index = self.block;
+        if index > 0 && self.batch.payload.origin_bits.get_bit(index as usize) == Some(1) {
+            self.epoch_num += 1;
+        }
+
+        self.tx_datas.skip_to(channel, self.tx_datas_start)?;
+        let tx_count = self.batch.payload.block_tx_counts[index as usize] as usize;
+        let mut transactions = Vec::with_capacity(tx_count);
+        for _ in 0..tx_count {
+            let idx = self.cursor.idx;
+            let tx_data = self.next_tx_data(channel)?;
+            let txs = &mut self.batch.payload.txs;
+            let slot = txs
+                .tx_datas
+                .get_mut(idx)
+                .ok_or(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData))?;
+            *slot = tx_data;
+            let tx = txs.next_full_tx(&mut self.cursor, cfg.l2_chain_id.id())?;
+            transactions.push(tx.into());
+            // The transaction data is no longer needed once the transaction is expanded.
+            txs.tx_datas[idx] = Vec::new();
+        }
+        self.block += 1;
+
+        Ok(Some(SingleBatch {
+            epoch_num: self.epoch_num,
+            timestamp: cfg.genesis.l2_time +
+                self.batch.prefix.rel_timestamp +
+                cfg.block_time * index,
+            transactions,
+            ..Default::default()
+        }))
+    }
+
+    /// Reads the next transaction data from the transaction data stream.
+    fn next_tx_data(&mut self, channel: &Channel) -> Result<Vec<u8>, StreamingBatchReaderError> {
+        let stream = &mut self.tx_datas;
+        stream.fill(channel, 1 + MAX_RLP_HEADER_LEN)?;
+        let len = tx_data_len(stream.buffered())?;
+        stream.fill(channel, len)?;
+        let r = stream.buffered();
+        if r.len() < len {
+            return Err(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData).into());
+        }
+        let (tx_data, _) = read_tx_data(&mut &r[..len])?;
+        stream.consume(len);
+        Ok(tx_data)
+    }
+}
+
+/// A streaming alternative to the [`BatchReader`] for memory constrained environments.
+///
+/// The [`BatchReader`] decompresses a whole channel, up to the maximum RLP bytes per channel,
+/// before decoding its first batch. The [`StreamingBatchReader`] instead decompresses the channel
+/// incrementally while decoding, and only buffers the bytes of the section being decoded.
+///
+/// [`StreamingBatchReader::next_full_batch`] yields whole [`Batch`]es, so that at most one batch is
+/// held in memory at a time. [`StreamingBatchReader::next_batch`] yields the blocks of a span batch
+/// lazily as [`SingleBatch`]es, so that at most one block is held in memory at a time. The two must
+/// not be mixed on the same reader.
+///
+/// Span batches store their transactions column by column, with the transaction data of every
+/// block preceding the nonces and gas limits. To yield one block at a time, the compact columns
+/// of the span batch are decoded first, which are bounded by its transaction count, while the
+/// transaction data is skipped. The transaction data of each block is then read from a second
+/// pass over the channel as the block is yielded. The first pass is dropped before the second
+/// pass starts, so that only one decompressor is held at a time, at the cost of decompressing
+/// the channel up to the span batch a second time.
+///
+/// The [`SingleBatch`]es of span batches do not contain a parent hash or an epoch hash, as they
+/// are populated by the Batch Queue stage. The prefix of the current span batch, which holds the
+/// parent and L1 origin checks, is available through [`StreamingBatchReader::span_prefix`].
+///
+/// Unlike the [`BatchReader`], the blocks of a span batch are yielded before the transactions of
+/// later blocks are decoded. If an error is returned while reading a span batch, the blocks
+/// already yielded from it must be discarded. No further batches should be read after an error.
+#[derive(Debug)]
+pub struct StreamingBatchReader {
+    /// The compressed channel.
+    channel: Channel,
+    /// The decompressed channel.
+    stream: DecompressedStream,
+    /// The position of the end of the current batch in the decompressed channel.
+    batch_end: usize,
+    /// Whether the channel is brotli compressed.
+    brotli_used: bool,
+    /// The blocks of the current span batch that have not been yielded yet.
+    span: Option<SpanBatchBlocks>,
+}
+
+impl StreamingBatchReader {
+    /// Creates a new [`StreamingBatchReader`] from the given compressed channel data and max
+    /// decompressed RLP bytes per channel.
+    pub fn new<T>(data: T, max_rlp_bytes_per_channel: usize) -> Self
+    where
+        T: Into<Vec<u8>>,
+    {
+        let data = data.into();
+        let brotli_used = data.first() == Some(&BatchReader::CHANNEL_VERSION_BROTLI);
+        Self {
+            channel: Channel { data, decompressors: DecompressorRegistry::new() },
+            stream: DecompressedStream::new(max_rlp_bytes_per_channel, 0),
+            batch_end: 0,
+            brotli_used,
+            span: None,
+        }
+    }
+
+    /// Sets the [`DecompressorRegistry`] used to decompress the channel.
+    ///
+    /// Channels are decompressed incrementally with the [`StreamDecoder`] of their decompressor.
+    /// Decompressors without one decompress the whole channel at once.
+    pub fn with_decompressors(mut self, decompressors: DecompressorRegistry) -> Self {
+        self.channel.decompressors = decompressors;
+        self
+    }
+
+    /// Returns whether the channel is brotli compressed.
+    pub const fn brotli_used(&self) -> bool {
+        self.brotli_used
+    }
+
+    /// Returns the number of bytes of the channel decompressed so far.
+    pub fn decompressed_len(&self) -> usize {
+        self.span
+            .as_ref()
+            .map_or(self.stream.decompressed_len, |span| span.tx_datas.decompressed_len)
+            .max(self.stream.decompressed_len)
+    }
+
+    /// Returns the largest number of decompressed bytes that were buffered at once.
+    pub fn peak_buffered_len(&self) -> usize {
+        self.span
+            .as_ref()
+            .map_or(0, |span| span.tx_datas.peak_buffered_len)
+            .max(self.stream.peak_buffered_len)
+    }
+
+    /// Returns the prefix of the span batch whose blocks are currently being yielded, if any.
+    pub fn span_prefix(&self) -> Option<&SpanBatchPrefix> {
+        self.span.as_ref().map(|span| &span.batch.prefix)
+    }
+
+    /// Returns the next [`SingleBatch`] in the channel, or `None` once the channel is exhausted.
+    ///
+    /// The blocks of span batches are yielded one at a time.
+    pub fn next_batch(
+        &mut self,
+        cfg: &RollupConfig,
+    ) -> Result<Option<SingleBatch>, StreamingBatchReaderError> {
+        loop {
+            if let Some(mut span) = self.span.take() {
+                if let Some(batch) = span.next_block(&self.channel, cfg)? {
+                    self.span = Some(span);
+                    return Ok(Some(batch));
+                }
+
+                // Continue after the span batch from the transaction data stream.
+                span.tx_datas.skip_to(&self.channel, span.end)?;
+                span.tx_datas.peak_buffered_len =
+                    span.tx_datas.peak_buffered_len.max(self.stream.peak_buffered_len);
+                self.stream = span.tx_datas;
+            }
+
+            if !self.start_batch()? {
+                return Ok(None);
+            }
+
+            match self.decode_batch_type()? {
+                SINGLE_BATCH_TYPE => {
+                    let batch = self.decode_with(self.remaining(), SingleBatch::decode)?;
+                    self.check_active(cfg, batch.timestamp)?;
+                    return Ok(Some(batch));
+                }
+                SPAN_BATCH_TYPE => {
+                    let (batch, tx_datas_start) = self.decode_span_batch(cfg, false)?;
+
+                    // Drop the first pass over the channel, and read the transaction data from a
+                    // second pass.
+                    let max = self.stream.max_rlp_bytes_per_channel;
+                    let peak = self.stream.peak_buffered_len;
+                    self.stream = DecompressedStream::new(max, peak);
+                    let tx_datas = DecompressedStream::new(max, peak);
+                    self.span = Some(SpanBatchBlocks::new(
+                        batch,
+                        tx_datas,
+                        tx_datas_start,
+                        self.batch_end,
+                    )?);
+                }
+                batch_type => return Err(StreamingBatchReaderError::UnknownBatchType(batch_type)),
+            }
+        }
+    }
+
+    /// Returns the next [`Batch`] in the channel, or `None` once the channel is exhausted.
+    ///
+    /// Unlike [`StreamingBatchReader::next_batch`], span batches are decoded whole, as the
+    /// [`BatchReader`] would decode them.
+    pub fn next_full_batch(
+        &mut self,
+        cfg: &RollupConfig,
+    ) -> Result<Option<Batch>, StreamingBatchReaderError> {
+        if !self.start_batch()? {
+            return Ok(None);
+        }
+
+        let batch = match self.decode_batch_type()? {
+            SINGLE_BATCH_TYPE => {
+                Batch::Single(self.decode_with(self.remaining(), SingleBatch::decode)?)
+            }
+            SPAN_BATCH_TYPE => {
+                let (mut batch, _) = self.decode_span_batch(cfg, true)?;
+                Batch::Span(batch.derive(
+                    cfg.block_time,
+                    cfg.genesis.l2_time,
+                    cfg.l2_chain_id.id(),
+                )?)
+            }
+            batch_type => return Err(StreamingBatchReaderError::UnknownBatchType(batch_type)),
+        };
+
+        self.check_active(cfg, batch.timestamp())?;
+        Ok(Some(batch))
+    }
+
+    /// Confirms that the decompressor of the channel was active at the given batch timestamp,
+    /// e.g. that brotli decompression was performed *after* the Fjord hardfork.
+    fn check_active(
+        &self,
+        cfg: &RollupConfig,
+        timestamp: u64,
+    ) -> Result<(), StreamingBatchReaderError> {
+        let version = self.channel.data.first().copied().unwrap_or_default();
+        match self.channel.decompressors.get(version) {
+            Some(decompressor) if decompressor.is_active(cfg, timestamp) => Ok(()),
+            _ if self.brotli_used => Err(StreamingBatchReaderError::BrotliBeforeFjord),
+            _ => Err(StreamingBatchReaderError::InactiveDecompressor(version)),
+        }
+    }
+
+    /// Skips the rest of the current batch and reads the RLP header of the next one.
+    ///
+    /// Returns `false` if the channel is exhausted.
+    fn start_batch(&mut self) -> Result<bool, StreamingBatchReaderError> {
+        // Like the [`BatchReader`], ignore any trailing bytes of the previous batch.
+        self.stream.skip_to(&self.channel, self.batch_end)?;
+
+        self.stream.fill(&self.channel, MAX_RLP_HEADER_LEN)?;
+        let mut r = self.stream.buffered();
+        if r.is_empty() {
+            return Ok(false);
+        }
+        let len = r.len();
+        let header = Header::decode(&mut r)?;
+        if header.list {
+            return Err(alloy_rlp::Error::UnexpectedList.into());
+        }
+        let header_len = len - r.len();
+        self.stream.consume(header_len);
+        self.batch_end = self.stream.position() + header.payload_length;
+        Ok(true)
+    }
+
+    /// Decodes the type of the current batch.
+    fn decode_batch_type(&mut self) -> Result<u8, StreamingBatchReaderError> {
+        self.decode_with(1, |r| {
+            let batch_type = r.first().copied().ok_or(BatchDecodingError::EmptyBuffer)?;
+            *r = &r[1..];
+            Ok::<_, BatchDecodingError>(batch_type)
+        })
+    }
+
+    /// Decodes the sections of a span batch one at a time.
+    ///
+    /// If `keep_tx_datas` is `false`, the transaction data is validated and then dropped. Returns
+    /// the span batch along with the position of its first transaction data.
+    fn decode_span_batch(
+        &mut self,
+        cfg: &RollupConfig,
+        keep_tx_datas: bool,
+    ) -> Result<(RawSpanBatch, usize), StreamingBatchReaderError> {
+        let mut prefix = SpanBatchPrefix::default();
+        self.decode_with(MAX_VARINT_LEN, |r| prefix.decode_rel_timestamp(r))?;
+        self.decode_with(MAX_VARINT_LEN, |r| prefix.decode_l1_origin_num(r))?;
+        self.decode_exact(20, SpanDecodingError::ParentCheck, |r| prefix.decode_parent_check(r))?;
+        self.decode_exact(20, SpanDecodingError::L1OriginCheck, |r| {
+            prefix.decode_l1_origin_check(r)
+        })?;
+
+        // Confirm that the decompressor was active before decoding the rest of the span batch.
+        self.check_active(cfg, cfg.genesis.l2_time + prefix.rel_timestamp)?;
+
+        let mut payload = SpanBatchPayload::default();
+        self.decode_with(MAX_VARINT_LEN, |r| payload.decode_block_count(r))?;
+        let block_count = payload.block_count as usize;
+        self.decode_with(block_count.div_ceil(8), |r| payload.decode_origin_bits(r))?;
+        self.decode_with(MAX_VARINT_LEN * block_count, |r| payload.decode_block_tx_counts(r))?;
+
+        payload.txs.total_block_tx_count = payload.total_block_tx_count()?;
+        let txs = &mut payload.txs;
+        let tx_count = txs.total_block_tx_count as usize;
+        self.decode_with(tx_count.div_ceil(8), |r| txs.decode_contract_creation_bits(r))?;
+        self.decode_exact(
+            tx_count.div_ceil(8) + 64 * tx_count,
+            SpanDecodingError::InvalidTransactionSignature,
+            |r| txs.decode_tx_sigs(r),
+        )?;
+        let to_count = tx_count - txs.contract_creation_count() as usize;
+        self.decode_exact(20 * to_count, SpanDecodingError::InvalidTransactionData, |r| {
+            txs.decode_tx_tos(r)
+        })?;
+        let tx_datas_start = self.stream.position();
+        for _ in 0..tx_count {
+            let len = self.fill_batch(1 + MAX_RLP_HEADER_LEN)?;
+            let len = tx_data_len(&self.stream.buffered()[..len])?;
+            self.decode_exact(len, SpanDecodingError::InvalidTransactionData, |r| {
+                txs.decode_tx_data(r)
+            })?;
+            if !keep_tx_datas {
+                // Only the transaction type is needed until the transaction's block is yielded.
+                if let Some(tx_data) = txs.tx_datas.last_mut() {
+                    *tx_data = Vec::new();
+                }
+            }
+        }
+        self.decode_with(MAX_VARINT_LEN * tx_count, |r| txs.decode_tx_nonces(r))?;
+        self.decode_with(MAX_VARINT_LEN * tx_count, |r| txs.decode_tx_gases(r))?;
+        let legacy_tx_count = txs.legacy_tx_count as usize;
+        self.decode_with(legacy_tx_count.div_ceil(8), |r| txs.decode_protected_bits(r))?;
+
+        Ok((RawSpanBatch { prefix, payload }, tx_datas_start))
+    }
+
+    /// Returns the number of unconsumed bytes of the current batch.
+    const fn remaining(&self) -> usize {
+        self.batch_end.saturating_sub(self.stream.position())
+    }
+
+    /// Decodes a value from up to `len` bytes of the current batch, consuming the bytes read by
+    /// the decoder.
+    fn decode_with<T, E>(
+        &mut self,
+        len: usize,
+        decode: impl FnOnce(&mut &[u8]) -> Result<T, E>,
+    ) -> Result<T, StreamingBatchReaderError>
+    where
+        StreamingBatchReaderError: From<E>,
+    {
+        let len = self.fill_batch(len)?;
+        let mut r = &self.stream.buffered()[..len];
+        let value = decode(&mut r)?;
+        let consumed = len - r.len();
+        self.stream.consume(consumed);
+        Ok(value)
+    }
+
+    /// Decodes a value from exactly `len` bytes of the current batch, returning the given error
+    /// if the batch is shorter.
+    fn decode_exact<T, E>(
+        &mut self,
+        len: usize,
+        error: SpanDecodingError,
+        decode: impl FnOnce(&mut &[u8]) -> Result<T, E>,
+    ) -> Result<T, StreamingBatchReaderError>
+    where
+        StreamingBatchReaderError: From<E>,
+    {
+        if self.fill_batch(len)? < len {
+            return Err(SpanBatchError::Decoding(error).into());
+        }
+        self.decode_with(len, decode)
+    }
+
+    /// Buffers up to `len` bytes of the current batch, returning the number of bytes available.
+    fn fill_batch(&mut self, len: usize) -> Result<usize, StreamingBatchReaderError> {
+        let len = len.min(self.remaining());
+        self.stream.fill(&self.channel, len)?;
+        Ok(len.min(self.stream.buffered().len()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Batch, SpanBatch};
+    use alloc::vec;
+    use alloy_consensus::{Signed, TxEip1559, TxEnvelope, TxLegacy};
+    use alloy_primitives::{Address, B256, Bytes, Signature, TxKind, U256};
+    use alloy_rlp::Encodable;
+    use kona_genesis::{
+        ChainGenesis, HardForkConfig, MAX_RLP_BYTES_PER_CHANNEL_BEDROCK,
+        MAX_RLP_BYTES_PER_CHANNEL_FJORD,
+    };
+    use proptest::{collection::vec, prelude::*};
+
+    const CHAIN_ID: u64 = 10;
+
+    fn new_compressed_batch_data() -> Bytes {
+        let file_contents =
+            alloc::string::String::from_utf8_lossy(include_bytes!("../../testdata/batch.hex"));
+        let file_contents = &(&*file_contents)[..file_contents.len() - 1];
+        let data = alloy_primitives::hex::decode(file_contents).unwrap();
+        data.into()
+    }
+
+    /// Reads all batches of a channel with the [`BatchReader`].
+    fn read_full_batches(data: &[u8], max: usize, cfg: &RollupConfig) -> Vec<Batch> {
+        let mut reader = BatchReader::new(data, max);
+        let mut batches = Vec::new();
+        while let Some(batch) = reader.next_batch(cfg) {
+            batches.push(batch);
+        }
+        batches
+    }
+
+    /// Reads all batches of a channel with the [`BatchReader`], flattening span batches.
+    fn read_batches(data: &[u8], max: usize, cfg: &RollupConfig) -> Vec<SingleBatch> {
+        let mut batches = Vec::new();
+        for batch in read_full_batches(data, max, cfg) {
+            match batch {
+                Batch::Single(batch) => batches.push(batch),
+                Batch::Span(batch) => {
+                    batches.extend(batch.batches.into_iter().map(|element| SingleBatch {
+                        epoch_num: element.epoch_num,
+                        timestamp: element.timestamp,
+                        transactions: element.transactions,
+                        ..Default::default()
+                    }))
+                }
+            }
+        }
+        batches
+    }
+
+    /// Reads all batches of a channel with [`StreamingBatchReader::next_full_batch`].
+    fn stream_full_batches(
+        data: &[u8],
+        max: usize,
+        cfg: &RollupConfig,
+    ) -> Result<Vec<Batch>, StreamingBatchReaderError> {
+        let mut stream = StreamingBatchReader::new(data, max);
+        let mut batches = Vec::new();
+        while let Some(batch) = stream.next_full_batch(cfg)? {
+            batches.push(batch);
+        }
+        Ok(batches)
+    }
+
+    /// Reads all batches of a channel with the [`StreamingBatchReader`].
+    fn stream_batches(
+        data: &[u8],
+        max: usize,
+        cfg: &RollupConfig,
+    ) -> Result<Vec<SingleBatch>, StreamingBatchReaderError> {
+        let mut stream = StreamingBatchReader::new(data, max);
+        let mut batches = Vec::new();
+        while let Some(batch) = stream.next_batch(cfg)? {
+            batches.push(batch);
+        }
+        Ok(batches)
+    }
+
+    fn test_config(block_time: u64, l2_time: u64) -> RollupConfig {
+        RollupConfig {
+            l2_chain_id: CHAIN_ID.into(),
+            block_time,
+            genesis: ChainGenesis { l2_time, ..Default::default() },
+            hardforks: HardForkConfig { fjord_time: Some(0), ..Default::default() },
+            ..Default::default()
+        }
+    }
+
+    /// Builds a transaction from the given parameters, returning its network encoding.
+    fn build_tx(
+        (kind, nonce, gas, to, input, r, s, parity): (
+            u8,
+            u64,
+            u64,
+            Option<[u8; 20]>,
+            Vec<u8>,
+            u64,
+            u64,
+            bool,
+        ),
+    ) -> Bytes {
+        let to = to.map_or(TxKind::Create, |to| TxKind::Call(Address::from(to)));
+        let signature = Signature::new(U256::from(r), U256::from(s), parity);
+        let tx = match kind {
+            0 | 1 => {
+                let chain_id = (kind == 1).then_some(CHAIN_ID);
+                let tx = TxLegacy {
+                    chain_id,
+                    nonce,
+                    gas_limit: gas,
+                    to,
+                    input: input.into(),
+                    ..Default::default()
+                };
+                TxEnvelope::Legacy(Signed::new_unchecked(tx, signature, B256::ZERO))
+            }
+            _ => {
+                let tx = TxEip1559 {
+                    chain_id: CHAIN_ID,
+                    nonce,
+                    gas_limit: gas,
+                    to,
+                    input: input.into(),
+                    ..Default::default()
+                };
+                TxEnvelope::Eip1559(Signed::new_unchecked(tx, signature, B256::ZERO))
+            }
+        };
+        let mut buf = Vec::new();
+        tx.encode(&mut buf);
+        buf.into()
+    }
+
+    /// Builds a channel of batches from the given blocks, encoding the first `single_count` blocks
+    /// as single batches and the remaining blocks as a span batch.
+    fn build_channel(
+        blocks: Vec<(bool, Vec<Bytes>)>,
+        single_count: usize,
+        cfg: &RollupConfig,
+    ) -> Vec<u8> {
+        let mut batches = Vec::new();
+        let mut span_batch = SpanBatch {
+            chain_id: CHAIN_ID,
+            genesis_timestamp: cfg.genesis.l2_time,
+            ..Default::default()
+        };
+        let mut epoch_num = 100;
+        for (i, (new_epoch, transactions)) in blocks.into_iter().enumerate() {
+            epoch_num += new_epoch as u64;
+            let batch = SingleBatch {
+                epoch_num,
+                timestamp: cfg.genesis.l2_time + cfg.block_time * (i as u64 + 1),
+                transactions,
+                ..Default::default()
+            };
+            if i < single_count {
+                batches.push(Batch::Single(batch));
+            } else {
+                span_batch.append_singular_batch(batch, 1).unwrap();
+            }
+        }
+        if !span_batch.batches.is_empty() {
+            batches.push(Batch::Span(span_batch));
+        }
+
+        let mut channel = Vec::new();
+        for batch in batches {
+            let mut buf = Vec::new();
+            batch.encode(&mut buf).unwrap();
+            Bytes::from(buf).encode(&mut channel);
+        }
+        channel
+    }
+
+    fn compress_brotli(data: &[u8]) -> Vec<u8> {
+        let mut compressed = vec![BatchReader::CHANNEL_VERSION_BROTLI];
+        let params = brotli::enc::BrotliEncoderParams::default();
+        brotli::enc::BrotliCompress(&mut &data[..], &mut compressed, &params).unwrap();
+        compressed
+    }
+
+    #[test]
+    fn test_batch_stream_matches_reader() {
+        let raw = new_compressed_batch_data();
+        let cfg = RollupConfig::default();
+        let max = MAX_RLP_BYTES_PER_CHANNEL_BEDROCK as usize;
+
+        let expected = read_batches(&raw, max, &cfg);
+        assert!(!expected.is_empty());
+        assert_eq!(stream_batches(&raw, max, &cfg).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_batch_stream_full_batches_match_reader() {
+        let raw = new_compressed_batch_data();
+        let cfg = RollupConfig::default();
+        let max = MAX_RLP_BYTES_PER_CHANNEL_BEDROCK as usize;
+
+        let expected = read_full_batches(&raw, max, &cfg);
+        assert!(!expected.is_empty());
+        assert_eq!(stream_full_batches(&raw, max, &cfg).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_batch_stream_buffers_one_block() {
+        const BLOCK_COUNT: usize = 32;
+        const INPUT_LEN: usize = 64 * 1024;
+
+        let cfg = test_config(2, 1_000);
+        let blocks = (0..BLOCK_COUNT)
+            .map(|i| {
+                let input = (0..INPUT_LEN).map(|j| (i * 31 + j * 7) as u8).collect();
+                let tx = build_tx((2, i as u64, 21_000, Some([0x11; 20]), input, 1, 1, false));
+                (i % 4 == 0, vec![tx])
+            })
+            .collect();
+        let channel = build_channel(blocks, 0, &cfg);
+        assert!(channel.len() > BLOCK_COUNT * INPUT_LEN);
+        let compressed = miniz_oxide::deflate::compress_to_vec_zlib(&channel, 6);
+
+        let max = MAX_RLP_BYTES_PER_CHANNEL_FJORD as usize;
+        let mut stream = StreamingBatchReader::new(compressed.as_slice(), max);
+        let mut batches = Vec::new();
+        while let Some(batch) = stream.next_batch(&cfg).unwrap() {
+            batches.push(batch);
+        }
+        assert_eq!(batches, read_batches(&compressed, max, &cfg));
+
+        // The span batch holds every block, but only about one block is buffered at a time.
+        assert!(
+            stream.peak_buffered_len() < 4 * INPUT_LEN,
+            "buffered {} bytes of a {} byte channel",
+            stream.peak_buffered_len(),
+            channel.len()
+        );
+    }
+
+    #[test]
+    fn test_batch_stream_registered_decompressor() {
+        #[derive(Debug)]
+        struct IdentityDecompressor;
+
+        impl crate::Decompressor for IdentityDecompressor {
+            fn supports(&self, version: u8) -> bool {
+                version == 2
+            }
+
+            fn decompress(&self, data: &[u8], _: usize) -> Result<Vec<u8>, DecompressionError> {
+                Ok(data[1..].to_vec())
+            }
+        }
+
+        let cfg = test_config(2, 1_000);
+        let blocks = (0..4u64)
+            .map(|i| (i == 2, vec![build_tx((2, i, 21_000, None, vec![1; 32], 1, 1, false))]))
+            .collect();
+        let mut data = vec![2];
+        data.extend(build_channel(blocks, 1, &cfg));
+        let decompressors = DecompressorRegistry::new().with(IdentityDecompressor);
+
+        let max = MAX_RLP_BYTES_PER_CHANNEL_FJORD as usize;
+        let mut stream = StreamingBatchReader::new(data, max).with_decompressors(decompressors);
+        let mut batches = Vec::new();
+        while let Some(batch) = stream.next_batch(&cfg).unwrap() {
+            batches.push(batch);
+        }
+        assert_eq!(batches.len(), 4);
+        assert_eq!(batches[2].epoch_num, 101);
+        assert_eq!(batches[3].transactions.len(), 1);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_batch_stream_zstd_streams_channel() {
+        use crate::ZstdDecompressor;
+
+        const INPUT_LEN: usize = 64 * 1024;
+
+        let cfg = test_config(2, 1_000);
+        let blocks = (0..32u64)
+            .map(|i| {
+                let input = (0..INPUT_LEN).map(|j| (j as u64 * 31 + i) as u8).collect();
+                (i % 4 == 0, vec![build_tx((2, i, 21_000, None, input, 1, 1, false))])
+            })
+            .collect();
+        let mut data = vec![ZstdDecompressor::DEFAULT_CHANNEL_VERSION];
+        data.extend(ruzstd::encoding::compress_to_vec(
+            build_channel(blocks, 0, &cfg).as_slice(),
+            ruzstd::encoding::CompressionLevel::Fastest,
+        ));
+        let decompressors = DecompressorRegistry::new()
+            .with(ZstdDecompressor::new(ZstdDecompressor::DEFAULT_CHANNEL_VERSION, Some(0)));
+
+        let max = MAX_RLP_BYTES_PER_CHANNEL_FJORD as usize;
+        let mut stream = StreamingBatchReader::new(data, max).with_decompressors(decompressors);
+        let mut batches = Vec::new();
+        while let Some(batch) = stream.next_batch(&cfg).unwrap() {
+            batches.push(batch);
+        }
+        assert_eq!(batches.len(), 32);
+        assert!(stream.peak_buffered_len() < 4 * INPUT_LEN);
+    }
+
+    #[test]
+    fn test_batch_stream_brotli_before_fjord() {
+        let cfg = RollupConfig { hardforks: HardForkConfig::default(), ..test_config(2, 0) };
+        let channel = build_channel(vec![(false, vec![])], 1, &cfg);
+        let compressed = compress_brotli(&channel);
+
+        let err = stream_batches(&compressed, MAX_RLP_BYTES_PER_CHANNEL_FJORD as usize, &cfg)
+            .unwrap_err();
+        assert!(matches!(err, StreamingBatchReaderError::BrotliBeforeFjord));
+    }
+
+    #[test]
+    fn test_batch_stream_rlp_too_large() {
+        let raw = new_compressed_batch_data();
+        let mut stream = StreamingBatchReader::new(raw, 16);
+        let err = stream.next_batch(&RollupConfig::default()).unwrap_err();
+        assert!(matches!(
+            err,
+            StreamingBatchReaderError::Decompression(DecompressionError::RlpTooLarge(17, 16))
+        ));
+    }
+
+    #[test]
+    fn test_batch_stream_unsupported_compression() {
+        let mut stream = StreamingBatchReader::new(vec![0x02, 0x00], 1024);
+        let err = stream.next_batch(&RollupConfig::default()).unwrap_err();
+        assert!(matches!(
+            err,
+            StreamingBatchReaderError::Decompression(DecompressionError::UnsupportedType(0x02))
+        ));
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn test_batch_stream_matches_reader_random_channels(
+            blocks in vec(
+                (
+                    any::<bool>(),
+                    vec(
+                        (
+                            0u8..3,
+                            any::<u64>(),
+                            any::<u64>(),
+                            any::<Option<[u8; 20]>>(),
+                            vec(any::<u8>(), 0..256),
+                            1u64..,
+                            1u64..,
+                            any::<bool>(),
+                        ),
+                        0..8,
+                    ),
+                ),
+                1..32,
+            ),
+            single_count in 0usize..4,
+            brotli in any::<bool>(),
+        ) {
+            let cfg = test_config(2, 1_000);
+            let block_count = blocks.len();
+            let blocks = blocks
+                .into_iter()
+                .map(|(new_epoch, txs)| (new_epoch, txs.into_iter().map(build_tx).collect()))
+                .collect();
+            let channel = build_channel(blocks, single_count, &cfg);
+            let compressed = if brotli {
+                compress_brotli(&channel)
+            } else {
+                miniz_oxide::deflate::compress_to_vec_zlib(&channel, 6)
+            };
+
+            let max = MAX_RLP_BYTES_PER_CHANNEL_FJORD as usize;
+            let expected = read_batches(&compressed, max, &cfg);
+            prop_assert_eq!(expected.len(), block_count);
+            prop_assert_eq!(stream_batches(&compressed, max, &cfg).unwrap(), expected);
+            prop_assert_eq!(
+                stream_full_batches(&compressed, max, &cfg).unwrap(),
+                read_full_batches(&compressed, max, &cfg)
+            );
+        }
+    }
+}
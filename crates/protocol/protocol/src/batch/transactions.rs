@@ -11,6 +11,20 @@ use alloy_eips::eip2718::Encodable2718;
 use alloy_primitives::{Address, Bytes, Signature, U256, bytes};
 use alloy_rlp::{Buf, Decodable, Encodable};
 
+/// A cursor over the transactions of a [`SpanBatchTransactions`].
+///
+/// The `to` addresses and protected bits are only present for some transactions, so their indices
+/// are tracked separately from the transaction index.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SpanBatchTxCursor {
+    /// The index of the next transaction.
+    pub(crate) idx: usize,
+    /// The index of the `to` address of the next transaction that is not a contract creation.
+    to_idx: usize,
+    /// The index of the protected bit of the next legacy transaction.
+    protected_bit_idx: usize,
+}
+
 /// This struct contains the decoded information for transactions in a span batch.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct SpanBatchTransactions {
@@ -203,23 +217,27 @@ impl SpanBatchTransactions {
 
     /// Decode the transaction data from a reader.
     pub fn decode_tx_datas(&mut self, r: &mut &[u8]) -> Result<(), SpanBatchError> {
-        let mut tx_datas = Vec::new();
-        let mut tx_types = Vec::new();
+        self.tx_datas.clear();
+        self.tx_types.clear();
 
         // Do not need the transaction data header because the RLP stream already includes the
         // length information.
         for _ in 0..self.total_block_tx_count {
-            let (tx_data, tx_type) = read_tx_data(r)?;
-            tx_datas.push(tx_data);
-            tx_types.push(tx_type);
-            if matches!(tx_type, TxType::Legacy) {
-                self.legacy_tx_count += 1;
-            }
+            self.decode_tx_data(r)?;
         }
 
-        self.tx_datas = tx_datas;
-        self.tx_types = tx_types;
+        Ok(())
+    }
 
+    /// Decode the data of a single transaction from a reader, appending it to the transaction
+    /// data.
+    pub fn decode_tx_data(&mut self, r: &mut &[u8]) -> Result<(), SpanBatchError> {
+        let (tx_data, tx_type) = read_tx_data(r)?;
+        self.tx_datas.push(tx_data);
+        self.tx_types.push(tx_type);
+        if matches!(tx_type, TxType::Legacy) {
+            self.legacy_tx_count += 1;
+        }
         Ok(())
     }
 
@@ -230,50 +248,60 @@ impl SpanBatchTransactions {
 
     /// Retrieve all of the raw transactions from the [`SpanBatchTransactions`].
     pub fn full_txs(&self, chain_id: u64) -> Result<Vec<Vec<u8>>, SpanBatchError> {
-        let mut txs = Vec::new();
-        let mut to_idx = 0;
-        let mut protected_bit_idx = 0;
-        for idx in 0..self.total_block_tx_count {
-            let mut datas = self.tx_datas[idx as usize].as_slice();
-            let tx = SpanBatchTransactionData::decode(&mut datas)
-                .map_err(|_| SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData))?;
-            let nonce = self
-                .tx_nonces
-                .get(idx as usize)
-                .ok_or(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData))?;
-            let gas = self
-                .tx_gases
-                .get(idx as usize)
-                .ok_or(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData))?;
-            let bit = self
-                .contract_creation_bits
-                .get_bit(idx as usize)
-                .ok_or(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData))?;
-            let to = if bit == 0 {
-                if self.tx_tos.len() <= to_idx {
-                    return Err(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData));
-                }
-                to_idx += 1;
-                Some(self.tx_tos[to_idx - 1])
-            } else {
-                None
-            };
-            let sig = *self
-                .tx_sigs
-                .get(idx as usize)
-                .ok_or(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData))?;
-            let is_protected = if tx.tx_type() == TxType::Legacy {
-                protected_bit_idx += 1;
-                self.protected_bits.get_bit(protected_bit_idx - 1).unwrap_or_default() == 1
-            } else {
-                true
-            };
-            let tx_envelope = tx.to_signed_tx(*nonce, *gas, to, chain_id, sig, is_protected)?;
-            let mut buf = Vec::new();
-            tx_envelope.encode_2718(&mut buf);
-            txs.push(buf);
-        }
-        Ok(txs)
+        let mut cursor = SpanBatchTxCursor::default();
+        (0..self.total_block_tx_count).map(|_| self.next_full_tx(&mut cursor, chain_id)).collect()
+    }
+
+    /// Retrieves the raw transaction at the given [`SpanBatchTxCursor`] and advances the cursor.
+    pub(crate) fn next_full_tx(
+        &self,
+        cursor: &mut SpanBatchTxCursor,
+        chain_id: u64,
+    ) -> Result<Vec<u8>, SpanBatchError> {
+        let idx = cursor.idx;
+        let mut datas = self
+            .tx_datas
+            .get(idx)
+            .ok_or(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData))?
+            .as_slice();
+        let tx = SpanBatchTransactionData::decode(&mut datas)
+            .map_err(|_| SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData))?;
+        let nonce = self
+            .tx_nonces
+            .get(idx)
+            .ok_or(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData))?;
+        let gas = self
+            .tx_gases
+            .get(idx)
+            .ok_or(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData))?;
+        let bit = self
+            .contract_creation_bits
+            .get_bit(idx)
+            .ok_or(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData))?;
+        let to = if bit == 0 {
+            if self.tx_tos.len() <= cursor.to_idx {
+                return Err(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData));
+            }
+            cursor.to_idx += 1;
+            Some(self.tx_tos[cursor.to_idx - 1])
+        } else {
+            None
+        };
+        let sig = *self
+            .tx_sigs
+            .get(idx)
+            .ok_or(SpanBatchError::Decoding(SpanDecodingError::InvalidTransactionData))?;
+        let is_protected = if tx.tx_type() == TxType::Legacy {
+            cursor.protected_bit_idx += 1;
+            self.protected_bits.get_bit(cursor.protected_bit_idx - 1).unwrap_or_default() == 1
+        } else {
+            true
+        };
+        let tx_envelope = tx.to_signed_tx(*nonce, *gas, to, chain_id, sig, is_protected)?;
+        let mut buf = Vec::new();
+        tx_envelope.encode_2718(&mut buf);
+        cursor.idx += 1;
+        Ok(buf)
     }
 
     /// Add raw transactions into the [`SpanBatchTransactions`].
@@ -19,7 +19,9 @@ pub const SINGLE_BATCH_TYPE: u8 = 0x00;
 pub const SPAN_BATCH_TYPE: u8 = 0x01;
 
 /// The Batch Type.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 #[repr(u8)]
 pub enum BatchType {
     /// Single Batch.
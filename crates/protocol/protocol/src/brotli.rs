@@ -1,6 +1,6 @@
 //! Contains brotli decompression utilities.
 
-use alloc::{vec, vec::Vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 use alloc_no_stdlib::*;
 use brotli::*;
 use core::ops;
@@ -75,6 +75,86 @@ pub fn decompress_brotli(
     Ok(output)
 }
 
+/// A heap allocator for the [`BrotliStreamDecompressor`], which allocates memory on demand rather
+/// than from a fixed size pool.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct BrotliHeapAllocator;
+
+/// A memory cell allocated by the [`BrotliHeapAllocator`].
+#[derive(Debug, Default)]
+pub(crate) struct BrotliHeapCell<T>(Box<[T]>);
+
+impl<T> SliceWrapper<T> for BrotliHeapCell<T> {
+    fn slice(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T> SliceWrapperMut<T> for BrotliHeapCell<T> {
+    fn slice_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+impl<T: Default + Clone> Allocator<T> for BrotliHeapAllocator {
+    type AllocatedMemory = BrotliHeapCell<T>;
+
+    fn alloc_cell(&mut self, len: usize) -> Self::AllocatedMemory {
+        BrotliHeapCell(vec![T::default(); len].into_boxed_slice())
+    }
+
+    fn free_cell(&mut self, _data: Self::AllocatedMemory) {}
+}
+
+/// A streaming brotli decompressor, which decompresses data incrementally into caller provided
+/// output buffers instead of a single buffer holding the whole output.
+pub(crate) struct BrotliStreamDecompressor {
+    /// The decompressor state.
+    state: BrotliState<BrotliHeapAllocator, BrotliHeapAllocator, BrotliHeapAllocator>,
+    /// The total number of bytes written.
+    total_out: usize,
+}
+
+impl core::fmt::Debug for BrotliStreamDecompressor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BrotliStreamDecompressor")
+            .field("total_out", &self.total_out)
+            .finish_non_exhaustive()
+    }
+}
+
+impl BrotliStreamDecompressor {
+    /// Creates a new [`BrotliStreamDecompressor`].
+    pub(crate) fn new() -> Self {
+        let state = BrotliState::new(BrotliHeapAllocator, BrotliHeapAllocator, BrotliHeapAllocator);
+        Self { state, total_out: 0 }
+    }
+
+    /// Decompresses the given input into the given output buffer.
+    ///
+    /// Returns the number of input bytes consumed, the number of output bytes written and whether
+    /// the stream has ended. Like [`decompress_brotli`], a corrupt or truncated stream ends the
+    /// stream after the bytes that could be decompressed.
+    pub(crate) fn decompress(&mut self, input: &[u8], output: &mut [u8]) -> (usize, usize, bool) {
+        let mut available_in = input.len();
+        let mut input_offset = 0;
+        let mut available_out = output.len();
+        let mut output_offset = 0;
+        let result = brotli::BrotliDecompressStream(
+            &mut available_in,
+            &mut input_offset,
+            input,
+            &mut available_out,
+            &mut output_offset,
+            output,
+            &mut self.total_out,
+            &mut self.state,
+        );
+        let finished = !matches!(result, brotli::BrotliResult::NeedsMoreOutput);
+        (input_offset, output_offset, finished)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -91,6 +171,25 @@ mod test {
         assert_eq!(decompressed, expected);
     }
 
+    #[test]
+    fn test_brotli_stream_decompressor() {
+        let expected = hex!("75ed184249e9bc19675e");
+        let compressed = hex!("8b048075ed184249e9bc19675e03");
+
+        let mut decompressor = BrotliStreamDecompressor::new();
+        let (mut input, mut output) = (&compressed[..], Vec::new());
+        loop {
+            let mut chunk = [0u8; 3];
+            let (consumed, written, finished) = decompressor.decompress(input, &mut chunk);
+            input = &input[consumed..];
+            output.extend_from_slice(&chunk[..written]);
+            if finished {
+                break;
+            }
+        }
+        assert_eq!(output, expected);
+    }
+
     #[test]
     fn test_decompress_batch_brotli() {
         let raw_batch_decompressed = hex!(
@@ -10,14 +10,18 @@
 extern crate alloc;
 
 mod batch;
+#[cfg(feature = "zstd")]
+pub use batch::ZstdDecompressor;
 pub use batch::{
     Batch, BatchDecodingError, BatchEncodingError, BatchReader, BatchTransaction, BatchType,
-    BatchValidationProvider, BatchValidity, BatchWithInclusionBlock, DecompressionError,
-    MAX_SPAN_BATCH_ELEMENTS, RawSpanBatch, SINGLE_BATCH_TYPE, SPAN_BATCH_TYPE, SingleBatch,
-    SpanBatch, SpanBatchBits, SpanBatchEip1559TransactionData, SpanBatchEip2930TransactionData,
-    SpanBatchEip7702TransactionData, SpanBatchElement, SpanBatchError,
-    SpanBatchLegacyTransactionData, SpanBatchPayload, SpanBatchPrefix, SpanBatchTransactionData,
-    SpanBatchTransactions, SpanDecodingError,
+    BatchValidationProvider, BatchValidity, BatchValidityReport, BatchValidityRule,
+    BatchWithInclusionBlock, BrotliDecompressor, DecompressionError, Decompressor,
+    DecompressorRegistry, MAX_SPAN_BATCH_ELEMENTS, RawSpanBatch, SINGLE_BATCH_TYPE,
+    SPAN_BATCH_TYPE, SingleBatch, SpanBatch, SpanBatchBits, SpanBatchEip1559TransactionData,
+    SpanBatchEip2930TransactionData, SpanBatchEip7702TransactionData, SpanBatchElement,
+    SpanBatchError, SpanBatchLegacyTransactionData, SpanBatchPayload, SpanBatchPrefix,
+    SpanBatchTransactionData, SpanBatchTransactions, SpanDecodingError, StreamDecoder,
+    StreamingBatchReader, StreamingBatchReaderError, ZlibDecompressor,
 };
 
 mod brotli;
@@ -65,7 +69,13 @@ mod predeploys;
 pub use predeploys::Predeploys;
 
 mod output_root;
-pub use output_root::OutputRoot;
+pub use output_root::{
+    CustomOutputRoot, OutputRoot, OutputRootCodec, OutputRootError, OutputRootRegistry,
+    VersionedOutputRoot,
+};
+
+mod system_config;
+pub use system_config::SystemConfigUpdateRecord;
 
 #[cfg(any(test, feature = "test-utils"))]
 pub mod test_utils;
@@ -1,8 +1,236 @@
-//! The [`OutputRoot`] type.
+//! The [`OutputRoot`] and [`VersionedOutputRoot`] types, and the [`OutputRootRegistry`].
 
-use alloy_primitives::{B256, keccak256};
+use alloc::vec::Vec;
+use alloy_primitives::{B256, Bytes, keccak256};
 use derive_more::Display;
 
+/// An error that occurs while decoding an output root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum OutputRootError {
+    /// No codec is registered for the output root version.
+    #[error("unknown output root version: {0}")]
+    UnknownVersion(B256),
+    /// The output root cannot be encoded with the codec of the given version.
+    #[error("output root of version {actual} cannot be encoded as version {expected}")]
+    VersionMismatch {
+        /// The version of the codec.
+        expected: B256,
+        /// The version of the output root.
+        actual: B256,
+    },
+    /// The encoded output root has an unexpected length.
+    #[error("unexpected output root length: expected {expected}, got {actual}")]
+    UnexpectedLength {
+        /// The expected length.
+        expected: usize,
+        /// The actual length.
+        actual: usize,
+    },
+}
+
+/// The encoder and decoder of an output root version, registered in an [`OutputRootRegistry`].
+#[derive(Debug, Clone, Copy)]
+pub struct OutputRootCodec {
+    /// The output root version.
+    pub version: B256,
+    /// Encodes an output root of this version, including its version prefix.
+    pub encode: fn(&VersionedOutputRoot) -> Result<Vec<u8>, OutputRootError>,
+    /// Decodes an encoded output root of this version, including its version prefix.
+    pub decode: fn(&[u8]) -> Result<VersionedOutputRoot, OutputRootError>,
+}
+
+impl OutputRootCodec {
+    /// The codec of the V0 [`OutputRoot`].
+    pub const V0: Self =
+        Self { version: OutputRoot::VERSION_HASH, encode: encode_v0, decode: decode_v0 };
+
+    /// Encodes the given output root with this codec, including its version prefix.
+    pub fn encode(&self, output_root: &VersionedOutputRoot) -> Result<Vec<u8>, OutputRootError> {
+        if output_root.version() != self.version {
+            return Err(OutputRootError::VersionMismatch {
+                expected: self.version,
+                actual: output_root.version(),
+            });
+        }
+        (self.encode)(output_root)
+    }
+
+    /// Decodes an encoded output root with this codec, including its version prefix.
+    pub fn decode(&self, data: &[u8]) -> Result<VersionedOutputRoot, OutputRootError> {
+        (self.decode)(data)
+    }
+}
+
+/// Encodes a V0 output root.
+fn encode_v0(output_root: &VersionedOutputRoot) -> Result<Vec<u8>, OutputRootError> {
+    match output_root {
+        VersionedOutputRoot::V0(output_root) => Ok(output_root.encode().to_vec()),
+        other => Err(OutputRootError::VersionMismatch {
+            expected: OutputRoot::VERSION_HASH,
+            actual: other.version(),
+        }),
+    }
+}
+
+/// Decodes a V0 output root.
+fn decode_v0(data: &[u8]) -> Result<VersionedOutputRoot, OutputRootError> {
+    OutputRoot::decode(data).map(VersionedOutputRoot::V0)
+}
+
+/// A registry of [`OutputRootCodec`]s, keyed by output root version.
+///
+/// Registered codecs take precedence over the built-in [`OutputRootCodec::V0`], in reverse
+/// registration order. Supporting a new output root version requires a [`VersionedOutputRoot`]
+/// variant and a codec registered here.
+#[derive(Debug, Clone, Default)]
+pub struct OutputRootRegistry {
+    /// The registered codecs.
+    codecs: Vec<OutputRootCodec>,
+}
+
+impl OutputRootRegistry {
+    /// Creates a new [`OutputRootRegistry`] with only the built-in codecs.
+    pub const fn new() -> Self {
+        Self { codecs: Vec::new() }
+    }
+
+    /// Registers the given [`OutputRootCodec`].
+    pub fn register(&mut self, codec: OutputRootCodec) -> &mut Self {
+        self.codecs.push(codec);
+        self
+    }
+
+    /// Returns the registry with the given [`OutputRootCodec`] registered.
+    pub fn with(mut self, codec: OutputRootCodec) -> Self {
+        self.register(codec);
+        self
+    }
+
+    /// Returns the [`OutputRootCodec`] for the given output root version.
+    pub fn get(&self, version: B256) -> Option<&OutputRootCodec> {
+        const BUILT_IN: [OutputRootCodec; 1] = [OutputRootCodec::V0];
+
+        self.codecs.iter().rev().chain(&BUILT_IN).find(|codec| codec.version == version)
+    }
+
+    /// Encodes an output root with the codec registered for its version, including its version
+    /// prefix.
+    pub fn encode(&self, output_root: &VersionedOutputRoot) -> Result<Vec<u8>, OutputRootError> {
+        let version = output_root.version();
+        self.get(version).ok_or(OutputRootError::UnknownVersion(version))?.encode(output_root)
+    }
+
+    /// Encodes and hashes an output root with the codec registered for its version.
+    pub fn hash(&self, output_root: &VersionedOutputRoot) -> Result<B256, OutputRootError> {
+        self.encode(output_root).map(keccak256)
+    }
+
+    /// Decodes an output root with the codec registered for its version.
+    pub fn decode(&self, data: &[u8]) -> Result<VersionedOutputRoot, OutputRootError> {
+        if data.len() < 32 {
+            return Err(OutputRootError::UnexpectedLength { expected: 32, actual: data.len() });
+        }
+        let version = B256::from_slice(&data[..32]);
+        self.get(version).ok_or(OutputRootError::UnknownVersion(version))?.decode(data)
+    }
+}
+
+/// A versioned output root.
+///
+/// Hashing, encoding and decoding output roots through this type dispatches on the version through
+/// the built-in [`OutputRootRegistry`], so callers do not need to know which output root formats
+/// exist. Output roots of versions without a dedicated variant are decoded into
+/// [`VersionedOutputRoot::Custom`] by codecs registered in an [`OutputRootRegistry`].
+#[derive(Debug, Display, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VersionedOutputRoot {
+    /// A V0 output root.
+    V0(OutputRoot),
+    /// An output root of a version without a dedicated variant.
+    Custom(CustomOutputRoot),
+}
+
+/// An output root of a version without a dedicated [`VersionedOutputRoot`] variant, encoded and
+/// decoded by a registered [`OutputRootCodec`].
+#[derive(Debug, Display, Clone, PartialEq, Eq, Hash)]
+#[display("OutputRoot({version}, {state_root}, {bridge_storage_root}, {block_hash})")]
+pub struct CustomOutputRoot {
+    /// The output root version.
+    pub version: B256,
+    /// The state root of the block corresponding to the output root.
+    pub state_root: B256,
+    /// The storage root of the `L2ToL1MessagePasser` predeploy at the block corresponding to the
+    /// output root.
+    pub bridge_storage_root: B256,
+    /// The block hash that the output root represents.
+    pub block_hash: B256,
+    /// The fields of the output root that are specific to its version, in the layout of its codec.
+    pub extra: Bytes,
+}
+
+impl From<OutputRoot> for VersionedOutputRoot {
+    fn from(output_root: OutputRoot) -> Self {
+        Self::V0(output_root)
+    }
+}
+
+impl VersionedOutputRoot {
+    /// Returns the version of the output root.
+    pub const fn version(&self) -> B256 {
+        match self {
+            Self::V0(output_root) => output_root.version(),
+            Self::Custom(output_root) => output_root.version,
+        }
+    }
+
+    /// Returns the state root of the block corresponding to the output root.
+    pub const fn state_root(&self) -> B256 {
+        match self {
+            Self::V0(output_root) => output_root.state_root,
+            Self::Custom(output_root) => output_root.state_root,
+        }
+    }
+
+    /// Returns the storage root of the `L2ToL1MessagePasser` predeploy at the block corresponding
+    /// to the output root.
+    pub const fn bridge_storage_root(&self) -> B256 {
+        match self {
+            Self::V0(output_root) => output_root.bridge_storage_root,
+            Self::Custom(output_root) => output_root.bridge_storage_root,
+        }
+    }
+
+    /// Returns the block hash that the output root represents.
+    pub const fn block_hash(&self) -> B256 {
+        match self {
+            Self::V0(output_root) => output_root.block_hash,
+            Self::Custom(output_root) => output_root.block_hash,
+        }
+    }
+
+    /// Encodes the output root with the built-in codec for its version, including its version
+    /// prefix.
+    ///
+    /// Use an [`OutputRootRegistry`] to encode output roots with registered codecs.
+    pub fn encode(&self) -> Result<Vec<u8>, OutputRootError> {
+        OutputRootRegistry::new().encode(self)
+    }
+
+    /// Encodes and hashes the output root with the built-in codec for its version.
+    ///
+    /// Use an [`OutputRootRegistry`] to hash output roots with registered codecs.
+    pub fn hash(&self) -> Result<B256, OutputRootError> {
+        OutputRootRegistry::new().hash(self)
+    }
+
+    /// Decodes an output root with the built-in codec for its version.
+    ///
+    /// Use an [`OutputRootRegistry`] to decode output roots with registered codecs.
+    pub fn decode(data: &[u8]) -> Result<Self, OutputRootError> {
+        OutputRootRegistry::new().decode(data)
+    }
+}
+
 /// The [`OutputRoot`] is a high-level commitment to an L2 block. It lifts the state root from the
 /// block header as well as the storage root of the [Predeploys::L2_TO_L1_MESSAGE_PASSER] account
 /// into the top-level commitment construction.
@@ -30,10 +258,13 @@ impl OutputRoot {
     /// commitment.
     pub const VERSION: u8 = 0;
 
+    /// The version of the [`OutputRoot`], as the 32 byte prefix of its encoding.
+    pub const VERSION_HASH: B256 = B256::ZERO;
+
     /// Returns the version of the [`OutputRoot`]. Currently, the protocol only supports the version
     /// number 0.
     pub const fn version(&self) -> B256 {
-        B256::ZERO
+        Self::VERSION_HASH
     }
 
     /// Constructs a V0 [`OutputRoot`] from its parts.
@@ -55,12 +286,35 @@ impl OutputRoot {
     pub fn hash(&self) -> B256 {
         keccak256(self.encode())
     }
+
+    /// Decodes a V0 [`OutputRoot`], including its version prefix.
+    pub fn decode(data: &[u8]) -> Result<Self, OutputRootError> {
+        if data.len() != Self::ENCODED_LENGTH {
+            return Err(OutputRootError::UnexpectedLength {
+                expected: Self::ENCODED_LENGTH,
+                actual: data.len(),
+            });
+        }
+        let version = B256::from_slice(&data[..32]);
+        if version != Self::VERSION_HASH {
+            return Err(OutputRootError::UnknownVersion(version));
+        }
+        Ok(Self::from_parts(
+            B256::from_slice(&data[32..64]),
+            B256::from_slice(&data[64..96]),
+            B256::from_slice(&data[96..128]),
+        ))
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::OutputRoot;
-    use alloy_primitives::{B256, Bytes, b256, bytes};
+    use super::{
+        CustomOutputRoot, OutputRoot, OutputRootCodec, OutputRootError, OutputRootRegistry,
+        VersionedOutputRoot,
+    };
+    use alloc::vec::Vec;
+    use alloy_primitives::{B256, Bytes, b256, bytes, keccak256};
 
     fn test_or() -> OutputRoot {
         OutputRoot::from_parts(
@@ -93,4 +347,102 @@ mod test {
 
         assert_eq!(root.encode().as_ref(), EXPECTED_ENCODING.as_ref());
     }
+
+    #[test]
+    fn test_versioned_output_root_roundtrip() {
+        let root = VersionedOutputRoot::from(test_or());
+        assert_eq!(root.version(), B256::ZERO);
+        assert_eq!(root.hash(), Ok(test_or().hash()));
+        assert_eq!(VersionedOutputRoot::decode(&root.encode().unwrap()), Ok(root));
+    }
+
+    #[test]
+    fn test_decode_unknown_version() {
+        let mut encoded = test_or().encode();
+        encoded[31] = 1;
+        assert_eq!(
+            VersionedOutputRoot::decode(&encoded),
+            Err(OutputRootError::UnknownVersion(B256::with_last_byte(1)))
+        );
+    }
+
+    #[test]
+    fn test_decode_unexpected_length() {
+        let encoded = test_or().encode();
+        assert_eq!(
+            VersionedOutputRoot::decode(&encoded[..64]),
+            Err(OutputRootError::UnexpectedLength { expected: 128, actual: 64 })
+        );
+        assert_eq!(
+            VersionedOutputRoot::decode(&encoded[..16]),
+            Err(OutputRootError::UnexpectedLength { expected: 32, actual: 16 })
+        );
+    }
+
+    #[test]
+    fn test_registry_encode() {
+        let registry = OutputRootRegistry::new();
+        let root = VersionedOutputRoot::from(test_or());
+        let encoded = registry.encode(&root).unwrap();
+        assert_eq!(encoded, test_or().encode());
+        assert_eq!(registry.hash(&root), Ok(test_or().hash()));
+        assert_eq!(registry.decode(&encoded), Ok(root));
+    }
+
+    #[test]
+    fn test_registry_register() {
+        const V1: B256 = B256::with_last_byte(1);
+
+        // A codec for a hypothetical V1 output root, which appends an extra field to the V0
+        // layout.
+        fn encode_v1(root: &VersionedOutputRoot) -> Result<Vec<u8>, OutputRootError> {
+            let VersionedOutputRoot::Custom(root) = root else {
+                return Err(OutputRootError::VersionMismatch {
+                    expected: V1,
+                    actual: root.version(),
+                });
+            };
+            let mut encoded = V1.to_vec();
+            encoded.extend_from_slice(root.state_root.as_slice());
+            encoded.extend_from_slice(root.bridge_storage_root.as_slice());
+            encoded.extend_from_slice(root.block_hash.as_slice());
+            encoded.extend_from_slice(&root.extra);
+            Ok(encoded)
+        }
+        fn decode_v1(data: &[u8]) -> Result<VersionedOutputRoot, OutputRootError> {
+            let mut v0 = data[..OutputRoot::ENCODED_LENGTH].to_vec();
+            v0[31] = 0;
+            let v0 = OutputRoot::decode(&v0)?;
+            Ok(VersionedOutputRoot::Custom(CustomOutputRoot {
+                version: V1,
+                state_root: v0.state_root,
+                bridge_storage_root: v0.bridge_storage_root,
+                block_hash: v0.block_hash,
+                extra: Bytes::copy_from_slice(&data[OutputRoot::ENCODED_LENGTH..]),
+            }))
+        }
+        let codec = OutputRootCodec { version: V1, encode: encode_v1, decode: decode_v1 };
+
+        let mut encoded = test_or().encode().to_vec();
+        encoded[31] = 1;
+        encoded.extend_from_slice(&[0xff; 32]);
+        assert_eq!(
+            OutputRootRegistry::new().decode(&encoded),
+            Err(OutputRootError::UnknownVersion(V1))
+        );
+
+        let registry = OutputRootRegistry::new().with(codec);
+        let root = registry.decode(&encoded).unwrap();
+        assert_eq!(root.version(), V1);
+        assert_eq!(root.block_hash(), test_or().block_hash);
+        assert_eq!(registry.encode(&root), Ok(encoded.clone()));
+        assert_eq!(registry.hash(&root), Ok(keccak256(&encoded)));
+
+        // The built-in registry has no codec for the custom version.
+        assert_eq!(root.hash(), Err(OutputRootError::UnknownVersion(V1)));
+        assert_eq!(
+            registry.get(V1).unwrap().encode(&test_or().into()),
+            Err(OutputRootError::VersionMismatch { expected: V1, actual: B256::ZERO })
+        );
+    }
 }
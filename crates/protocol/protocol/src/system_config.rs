@@ -0,0 +1,73 @@
+//! Contains the [`SystemConfigUpdateRecord`] type.
+
+use crate::BlockInfo;
+use alloc::vec::Vec;
+use kona_genesis::{SystemConfig, SystemConfigUpdate};
+
+/// A [`SystemConfigUpdate`] emitted by the L1 system config contract, along with its position on
+/// L1 and the [`SystemConfig`] that resulted from applying it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct SystemConfigUpdateRecord {
+    /// The L1 block that contains the update.
+    pub l1_block: BlockInfo,
+    /// The index of the update log within the L1 block.
+    pub log_index: u64,
+    /// The decoded update.
+    pub update: SystemConfigUpdate,
+    /// The system config after the update was applied.
+    pub system_config: SystemConfig,
+}
+
+impl SystemConfigUpdateRecord {
+    /// Applies the given updates to the `system_config` in order, returning a record for each of
+    /// them.
+    pub fn apply_all(
+        l1_block: BlockInfo,
+        system_config: &mut SystemConfig,
+        updates: impl IntoIterator<Item = (u64, SystemConfigUpdate)>,
+    ) -> Vec<Self> {
+        updates
+            .into_iter()
+            .map(|(log_index, update)| {
+                update.apply(system_config);
+                Self { l1_block, log_index, update, system_config: *system_config }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloy_primitives::{Address, U256};
+    use kona_genesis::{BatcherUpdate, GasConfigUpdate};
+
+    #[test]
+    fn test_apply_all() {
+        let mut system_config = SystemConfig::default();
+        let batcher = Address::repeat_byte(0xbe);
+        let updates = vec![
+            (2, SystemConfigUpdate::Batcher(BatcherUpdate { batcher_address: batcher })),
+            (
+                5,
+                SystemConfigUpdate::GasConfig(GasConfigUpdate {
+                    scalar: Some(U256::from(7)),
+                    overhead: Some(U256::from(1)),
+                }),
+            ),
+        ];
+        let block = BlockInfo { number: 10, ..Default::default() };
+        let records = SystemConfigUpdateRecord::apply_all(block, &mut system_config, updates);
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].log_index, 2);
+        assert_eq!(records[0].system_config.batcher_address, batcher);
+        assert_eq!(records[0].system_config.scalar, U256::ZERO);
+        assert_eq!(records[1].system_config.scalar, U256::from(7));
+        assert_eq!(records[1].system_config, system_config);
+        assert!(records.iter().all(|r| r.l1_block == block));
+    }
+}
@@ -0,0 +1,90 @@
+//! Error types for loading and merging registries at runtime.
+
+use alloc::string::String;
+use std::path::PathBuf;
+
+/// An error that occurs while loading a [crate::Registry] from disk or merging it over
+/// another registry.
+#[derive(Debug, thiserror::Error)]
+pub enum RegistryError {
+    /// Failed to read a file or directory.
+    #[error("Failed to read {path}: {source}")]
+    Io {
+        /// The path that could not be read.
+        path: PathBuf,
+        /// The underlying I/O error.
+        #[source]
+        source: std::io::Error,
+    },
+    /// Failed to parse a TOML file.
+    #[error("Failed to parse TOML file {path}: {source}")]
+    Toml {
+        /// The path of the malformed file.
+        path: PathBuf,
+        /// The underlying parse error.
+        #[source]
+        source: toml::de::Error,
+    },
+    /// Failed to parse a JSON file.
+    #[error("Failed to parse JSON file {path}: {source}")]
+    Json {
+        /// The path of the malformed file.
+        path: PathBuf,
+        /// The underlying parse error.
+        #[source]
+        source: serde_json::Error,
+    },
+    /// A superchain directory is missing its `superchain.toml`.
+    #[error("Missing superchain.toml in {0}")]
+    MissingSuperchainConfig(PathBuf),
+    /// A superchain config is missing its protocol versions address.
+    #[error("Missing protocol versions address for superchain {0}")]
+    MissingProtocolVersions(String),
+    /// The same chain ID is defined more than once by the loaded files.
+    #[error("Chain ID {0} is defined more than once")]
+    DuplicateChainId(u64),
+    /// A chain ID is already registered under a different identifier.
+    #[error(
+        "Chain ID {chain_id} is registered as {existing}, but the overlay defines it as {overlay}"
+    )]
+    ConflictingChainId {
+        /// The conflicting chain ID.
+        chain_id: u64,
+        /// The identifier already registered for the chain ID.
+        existing: String,
+        /// The identifier defined by the overlay.
+        overlay: String,
+    },
+    /// A chain identifier is already registered under a different chain ID.
+    #[error(
+        "Chain {identifier} is registered with chain ID {existing}, but the overlay uses {overlay}"
+    )]
+    ConflictingIdentifier {
+        /// The conflicting chain identifier.
+        identifier: String,
+        /// The chain ID already registered for the identifier.
+        existing: u64,
+        /// The chain ID defined by the overlay.
+        overlay: u64,
+    },
+    /// An overlay changes a hardfork timestamp that is already scheduled.
+    #[error(
+        "Chain ID {chain_id} schedules {fork} at {existing}, but the overlay schedules it at {overlay:?}"
+    )]
+    ConflictingHardfork {
+        /// The chain ID of the conflicting rollup config.
+        chain_id: u64,
+        /// The name of the hardfork.
+        fork: &'static str,
+        /// The activation time already scheduled.
+        existing: u64,
+        /// The activation time defined by the overlay, if any.
+        overlay: Option<u64>,
+    },
+    /// An overlay that may only add new chains defines a rollup config for an existing chain ID.
+    #[error("Chain ID {0} is already defined by the built-in registry")]
+    ExistingChain(u64),
+    /// The global registry was already initialized, so the overlay can no longer be applied.
+    #[error("The registry was already initialized")]
+    AlreadyInitialized,
+}
@@ -18,12 +18,32 @@ pub use chain_list::{Chain, ChainList};
 pub mod superchain;
 pub use superchain::Registry;
 
+#[cfg(feature = "std")]
+mod errors;
+#[cfg(feature = "std")]
+pub use errors::RegistryError;
+
+#[cfg(feature = "std")]
+pub mod loader;
+#[cfg(feature = "std")]
+use loader::initial_registry;
+#[cfg(feature = "std")]
+pub use loader::{set_custom_chains_path, set_registry_path};
+
+/// Returns the registry that the registry statics are initialized with.
+#[cfg(not(feature = "std"))]
+fn initial_registry() -> Registry {
+    Registry::from_chain_list()
+}
+
 #[cfg(test)]
 pub mod test_utils;
 
 lazy_static::lazy_static! {
     /// Private initializer that loads the superchain configurations.
-    static ref _INIT: Registry = Registry::from_chain_list();
+    ///
+    /// With the `std` feature, this includes any registry installed with [set_registry_path].
+    static ref _INIT: Registry = initial_registry();
 
     /// Chain configurations exported from the registry
     pub static ref CHAINS: ChainList = _INIT.chain_list.clone();
@@ -67,6 +87,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rollup_configs_are_valid() {
+        for (chain_id, config) in ROLLUP_CONFIGS.iter() {
+            if let Err(e) = config.validate() {
+                panic!("Invalid rollup config for chain {chain_id}: {e}");
+            }
+        }
+    }
+
     #[test]
     fn test_chain_by_ident() {
         const ALLOY_BASE: AlloyChain = AlloyChain::base_mainnet();
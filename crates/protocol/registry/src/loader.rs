@@ -0,0 +1,429 @@
+//! Runtime loading of superchain registries from disk.
+//!
+//! The built-in registry is baked into the binary at build time. This module allows a local
+//! superchain-registry checkout, or a directory of custom chain configs, to be merged over it at
+//! startup so that new devnets and hardfork schedules can be picked up without a rebuild.
+
+use crate::{Chain, ChainList, Registry, RegistryError, chain_list::SuperchainParent};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use kona_genesis::{ChainConfig, SuperchainConfig};
+use serde::de::DeserializeOwned;
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        OnceLock,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+/// The registry installed by [set_registry_path], if any.
+static OVERLAY: OnceLock<Registry> = OnceLock::new();
+
+/// Whether the registry statics have been initialized.
+static INITIALIZED: AtomicBool = AtomicBool::new(false);
+
+/// Loads the registry at `path`, merges it over the built-in registry, and installs the result as
+/// the registry backing [crate::CHAINS], [crate::OPCHAINS] and [crate::ROLLUP_CONFIGS].
+///
+/// This must be called before any of the registry statics are accessed, otherwise
+/// [RegistryError::AlreadyInitialized] is returned. See [Registry::from_path] for the supported
+/// layouts.
+pub fn set_registry_path(path: impl AsRef<Path>) -> Result<(), RegistryError> {
+    install_registry(path, Registry::merge)
+}
+
+/// Like [set_registry_path], but only allows the registry at `path` to add new chains, see
+/// [Registry::merge_new_chains].
+///
+/// Fault proof programs load the rollup config of every built-in chain from the registry that
+/// they were built with, so a runtime registry may not change the config of such a chain on the
+/// host side only.
+pub fn set_custom_chains_path(path: impl AsRef<Path>) -> Result<(), RegistryError> {
+    install_registry(path, Registry::merge_new_chains)
+}
+
+/// Loads the registry at `path`, merges it over the built-in registry with `merge`, and installs
+/// the result.
+fn install_registry(
+    path: impl AsRef<Path>,
+    merge: fn(&mut Registry, Registry) -> Result<(), RegistryError>,
+) -> Result<(), RegistryError> {
+    if INITIALIZED.load(Ordering::Acquire) {
+        return Err(RegistryError::AlreadyInitialized);
+    }
+    let mut registry = Registry::from_chain_list();
+    merge(&mut registry, Registry::from_path(path)?)?;
+    OVERLAY.set(registry).map_err(|_| RegistryError::AlreadyInitialized)
+}
+
+/// Returns the registry that the registry statics are initialized with.
+pub(crate) fn initial_registry() -> Registry {
+    INITIALIZED.store(true, Ordering::Release);
+    OVERLAY.get().cloned().unwrap_or_else(Registry::from_chain_list)
+}
+
+impl Registry {
+    /// Loads a [Registry] from a local directory.
+    ///
+    /// The directory may either be a superchain-registry checkout, containing a `chainList.json`
+    /// and a `superchain/configs` directory, or be laid out like `superchain/configs` itself: one
+    /// directory per superchain, each holding a `superchain.toml` and one TOML or JSON
+    /// [ChainConfig] per chain. Chains that are missing from the chain list are added to it with
+    /// the identifier `<superchain>/<file stem>`.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, RegistryError> {
+        let path = path.as_ref();
+
+        let chain_list_path = path.join("chainList.json");
+        let chain_list = if chain_list_path.is_file() {
+            read_json::<ChainList>(&chain_list_path)?
+        } else {
+            ChainList::default()
+        };
+
+        let configs_dir = path.join("superchain").join("configs");
+        let configs_dir = if configs_dir.is_dir() { configs_dir } else { path.to_path_buf() };
+
+        let mut registry = Self { chain_list, ..Default::default() };
+        for superchain_dir in read_dir_sorted(&configs_dir)? {
+            if superchain_dir.is_dir() {
+                registry.load_superchain(&superchain_dir)?;
+            }
+        }
+        Ok(registry)
+    }
+
+    /// Merges the `overlay` registry over this one.
+    ///
+    /// Chains and configs defined by the overlay replace the existing entries. The merge is
+    /// rejected, leaving this registry untouched, if the overlay registers a chain ID or chain
+    /// identifier that is already taken by a different chain, or changes the activation time of a
+    /// hardfork that is already scheduled. Scheduling a hardfork that was previously unscheduled
+    /// is allowed.
+    pub fn merge(&mut self, overlay: Self) -> Result<(), RegistryError> {
+        for chain in &overlay.chain_list.chains {
+            if let Some(existing) = self.chain_list.get_chain_by_id(chain.chain_id) {
+                if !existing.identifier.eq_ignore_ascii_case(&chain.identifier) {
+                    return Err(RegistryError::ConflictingChainId {
+                        chain_id: chain.chain_id,
+                        existing: existing.identifier.clone(),
+                        overlay: chain.identifier.clone(),
+                    });
+                }
+            }
+            if let Some(existing) = self.chain_list.get_chain_by_ident(&chain.identifier) {
+                if existing.chain_id != chain.chain_id {
+                    return Err(RegistryError::ConflictingIdentifier {
+                        identifier: chain.identifier.clone(),
+                        existing: existing.chain_id,
+                        overlay: chain.chain_id,
+                    });
+                }
+            }
+        }
+
+        for (chain_id, rollup_config) in &overlay.rollup_configs {
+            let Some(existing) = self.rollup_configs.get(chain_id) else {
+                continue;
+            };
+            let forks = existing.hardforks.iter().zip(rollup_config.hardforks.iter());
+            for ((fork, existing), (_, overlay)) in forks {
+                if let Some(existing) = existing.filter(|time| overlay != Some(*time)) {
+                    return Err(RegistryError::ConflictingHardfork {
+                        chain_id: *chain_id,
+                        fork,
+                        existing,
+                        overlay,
+                    });
+                }
+            }
+        }
+
+        for chain in overlay.chain_list.chains {
+            if let Some(existing) =
+                self.chain_list.chains.iter_mut().find(|c| c.chain_id == chain.chain_id)
+            {
+                *existing = chain;
+                continue;
+            }
+            self.chain_list.chains.push(chain);
+        }
+        self.op_chains.extend(overlay.op_chains);
+        self.rollup_configs.extend(overlay.rollup_configs);
+        Ok(())
+    }
+
+    /// Merges the `overlay` registry over this one, like [Registry::merge], but rejects the merge
+    /// if the overlay defines a rollup config for a chain ID that this registry already has one
+    /// for.
+    pub fn merge_new_chains(&mut self, overlay: Self) -> Result<(), RegistryError> {
+        let mut chain_ids = overlay.rollup_configs.keys().copied().collect::<Vec<_>>();
+        chain_ids.sort_unstable();
+        if let Some(chain_id) =
+            chain_ids.into_iter().find(|id| self.rollup_configs.contains_key(id))
+        {
+            return Err(RegistryError::ExistingChain(chain_id));
+        }
+        self.merge(overlay)
+    }
+
+    /// Loads the superchain in the given directory into the registry.
+    fn load_superchain(&mut self, dir: &Path) -> Result<(), RegistryError> {
+        let superchain_name =
+            dir.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+
+        let superchain_path = dir.join("superchain.toml");
+        if !superchain_path.is_file() {
+            return Err(RegistryError::MissingSuperchainConfig(dir.to_path_buf()));
+        }
+        let superchain = read_toml::<SuperchainConfig>(&superchain_path)?;
+        let protocol_versions_address = superchain
+            .protocol_versions_addr
+            .ok_or_else(|| RegistryError::MissingProtocolVersions(superchain_name.clone()))?;
+
+        for path in read_dir_sorted(dir)? {
+            if path == superchain_path {
+                continue;
+            }
+            let chain_config = match path.extension().and_then(|ext| ext.to_str()) {
+                Some("toml") => read_toml::<ChainConfig>(&path)?,
+                Some("json") => read_json::<ChainConfig>(&path)?,
+                _ => continue,
+            };
+
+            if self.op_chains.contains_key(&chain_config.chain_id) {
+                return Err(RegistryError::DuplicateChainId(chain_config.chain_id));
+            }
+            if self.chain_list.get_chain_by_id(chain_config.chain_id).is_none() {
+                let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+                let identifier = format!("{superchain_name}/{stem}");
+                self.chain_list.chains.push(chain_entry(
+                    identifier,
+                    &superchain_name,
+                    &chain_config,
+                ));
+            }
+            self.insert_chain(&superchain, protocol_versions_address, chain_config);
+        }
+        Ok(())
+    }
+}
+
+/// Builds a chain list entry for a [ChainConfig] that is missing from the chain list.
+fn chain_entry(identifier: String, superchain: &str, config: &ChainConfig) -> Chain {
+    Chain {
+        name: config.name.clone(),
+        identifier,
+        chain_id: config.chain_id,
+        rpc: vec![config.public_rpc.clone()],
+        explorers: vec![config.explorer.clone()],
+        superchain_level: config.superchain_level as u64,
+        data_availability_type: config.data_availability_type.clone(),
+        parent: SuperchainParent { r#type: "L2".to_string(), chain: superchain.to_string() },
+    }
+}
+
+/// Returns the entries of a directory, sorted by path.
+fn read_dir_sorted(dir: &Path) -> Result<Vec<PathBuf>, RegistryError> {
+    let io_err = |source| RegistryError::Io { path: dir.to_path_buf(), source };
+    let mut entries = std::fs::read_dir(dir)
+        .map_err(io_err)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(io_err)?;
+    entries.sort();
+    Ok(entries)
+}
+
+/// Reads and deserializes a TOML file.
+fn read_toml<T: DeserializeOwned>(path: &Path) -> Result<T, RegistryError> {
+    let raw = std::fs::read_to_string(path)
+        .map_err(|source| RegistryError::Io { path: path.to_path_buf(), source })?;
+    toml::from_str(&raw).map_err(|source| RegistryError::Toml { path: path.to_path_buf(), source })
+}
+
+/// Reads and deserializes a JSON file.
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T, RegistryError> {
+    let raw = std::fs::read_to_string(path)
+        .map_err(|source| RegistryError::Io { path: path.to_path_buf(), source })?;
+    serde_json::from_str(&raw)
+        .map_err(|source| RegistryError::Json { path: path.to_path_buf(), source })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUPERCHAIN_TOML: &str = r#"
+        name = "Devnets"
+        protocol_versions_addr = "0x0000000000000000000000000000000000000001"
+        [l1]
+        chain_id = 1
+        public_rpc = "https://l1.rpc"
+        explorer = "https://l1.explorer"
+        [hardforks]
+    "#;
+
+    /// Writes a superchain directory holding the given chain configs as JSON files.
+    fn write_superchain(root: &Path, name: &str, chains: &[(&str, ChainConfig)]) {
+        let dir = root.join(name);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("superchain.toml"), SUPERCHAIN_TOML).unwrap();
+        for (file, config) in chains {
+            let raw = serde_json::to_string(config).unwrap();
+            std::fs::write(dir.join(format!("{file}.json")), raw).unwrap();
+        }
+    }
+
+    fn base_config() -> ChainConfig {
+        Registry::from_chain_list().op_chains.get(&8453).cloned().unwrap()
+    }
+
+    #[test]
+    fn test_from_path_overlay_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = base_config();
+        config.chain_id = 424242;
+        config.name = "Custom".to_string();
+        write_superchain(dir.path(), "devnets", &[("custom", config)]);
+
+        let registry = Registry::from_path(dir.path()).unwrap();
+        let chain = registry.chain_list.get_chain_by_ident("devnets/custom").unwrap();
+        assert_eq!(chain.chain_id, 424242);
+        assert_eq!(chain.name, "Custom");
+        assert_eq!(registry.op_chains.get(&424242).unwrap().l1_chain_id, 1);
+        let rollup_config = registry.rollup_configs.get(&424242).unwrap();
+        assert_eq!(rollup_config.l2_chain_id, 424242);
+        assert_eq!(
+            rollup_config.protocol_versions_address,
+            "0x0000000000000000000000000000000000000001".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_path_duplicate_chain_id() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = base_config();
+        config.chain_id = 424242;
+        write_superchain(dir.path(), "devnets", &[("a", config.clone()), ("b", config)]);
+
+        let err = Registry::from_path(dir.path()).unwrap_err();
+        assert!(matches!(err, RegistryError::DuplicateChainId(424242)));
+    }
+
+    #[test]
+    fn test_from_path_missing_superchain_config() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("devnets")).unwrap();
+
+        let err = Registry::from_path(dir.path()).unwrap_err();
+        assert!(matches!(err, RegistryError::MissingSuperchainConfig(_)));
+    }
+
+    #[test]
+    fn test_merge_adds_custom_chain() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = base_config();
+        config.chain_id = 424242;
+        write_superchain(dir.path(), "devnets", &[("custom", config)]);
+
+        let mut registry = Registry::from_chain_list();
+        let builtin_len = registry.chain_list.len();
+        registry.merge(Registry::from_path(dir.path()).unwrap()).unwrap();
+        assert_eq!(registry.chain_list.len(), builtin_len + 1);
+        assert!(registry.rollup_configs.contains_key(&424242));
+        assert!(registry.rollup_configs.contains_key(&8453));
+    }
+
+    #[test]
+    fn test_merge_schedules_new_hardfork() {
+        let mut config = base_config();
+        config.hardfork_config.interop_time = Some(u64::MAX);
+        let mut overlay = Registry::default();
+        overlay.insert_chain(&Default::default(), Default::default(), config);
+
+        let mut registry = Registry::from_chain_list();
+        registry.merge(overlay).unwrap();
+        assert_eq!(
+            registry.rollup_configs.get(&8453).unwrap().hardforks.interop_time,
+            Some(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_merge_conflicting_hardfork() {
+        let mut config = base_config();
+        config.hardfork_config.canyon_time = Some(1);
+        let mut overlay = Registry::default();
+        overlay.insert_chain(&Default::default(), Default::default(), config);
+
+        let mut registry = Registry::from_chain_list();
+        let err = registry.merge(overlay).unwrap_err();
+        assert!(matches!(
+            err,
+            RegistryError::ConflictingHardfork {
+                chain_id: 8453,
+                fork: "Canyon",
+                overlay: Some(1),
+                ..
+            }
+        ));
+        assert_eq!(registry, Registry::from_chain_list());
+    }
+
+    #[test]
+    fn test_merge_new_chains() {
+        let mut config = base_config();
+        config.chain_id = 424242;
+        let mut overlay = Registry::default();
+        overlay.insert_chain(&Default::default(), Default::default(), config);
+
+        let mut registry = Registry::from_chain_list();
+        registry.merge_new_chains(overlay).unwrap();
+        assert!(registry.rollup_configs.contains_key(&424242));
+    }
+
+    #[test]
+    fn test_merge_new_chains_rejects_existing_chain() {
+        let mut config = base_config();
+        config.hardfork_config.interop_time = Some(u64::MAX);
+        let mut overlay = Registry::default();
+        overlay.insert_chain(&Default::default(), Default::default(), config);
+
+        let mut registry = Registry::from_chain_list();
+        let err = registry.merge_new_chains(overlay).unwrap_err();
+        assert!(matches!(err, RegistryError::ExistingChain(8453)));
+        assert_eq!(registry, Registry::from_chain_list());
+    }
+
+    #[test]
+    fn test_merge_conflicting_chain_id() {
+        let mut chain =
+            Registry::from_chain_list().chain_list.get_chain_by_id(8453).cloned().unwrap();
+        chain.identifier = "devnets/not-base".to_string();
+        let overlay =
+            Registry { chain_list: ChainList { chains: vec![chain] }, ..Default::default() };
+
+        let err = Registry::from_chain_list().merge(overlay).unwrap_err();
+        assert!(matches!(err, RegistryError::ConflictingChainId { chain_id: 8453, .. }));
+    }
+
+    #[test]
+    fn test_merge_conflicting_identifier() {
+        let mut chain =
+            Registry::from_chain_list().chain_list.get_chain_by_id(8453).cloned().unwrap();
+        chain.chain_id = 424242;
+        let overlay =
+            Registry { chain_list: ChainList { chains: vec![chain] }, ..Default::default() };
+
+        let err = Registry::from_chain_list().merge(overlay).unwrap_err();
+        assert!(matches!(
+            err,
+            RegistryError::ConflictingIdentifier { existing: 8453, overlay: 424242, .. }
+        ));
+    }
+}
@@ -1,8 +1,8 @@
 //! Contains the full superchain data.
 
 use super::ChainList;
-use alloy_primitives::map::HashMap;
-use kona_genesis::{ChainConfig, RollupConfig, Superchains};
+use alloy_primitives::{Address, map::HashMap};
+use kona_genesis::{ChainConfig, RollupConfig, SuperchainConfig, Superchains};
 
 /// The registry containing all the superchain configurations.
 #[derive(Debug, Clone, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -33,27 +33,38 @@ impl Registry {
     pub fn from_chain_list() -> Self {
         let chain_list = Self::read_chain_list();
         let superchains = Self::read_superchain_configs();
-        let mut op_chains = HashMap::default();
-        let mut rollup_configs = HashMap::default();
+        let mut registry = Self { chain_list, ..Default::default() };
 
         for superchain in superchains.superchains {
-            for mut chain_config in superchain.chains {
-                chain_config.l1_chain_id = superchain.config.l1.chain_id;
-                if let Some(a) = &mut chain_config.addresses {
-                    a.zero_proof_addresses();
-                }
-                let mut rollup = chain_config.as_rollup_config();
-                rollup.protocol_versions_address = superchain
-                    .config
-                    .protocol_versions_addr
-                    .expect("Missing protocol versions address");
-                rollup.superchain_config_address = superchain.config.superchain_config_addr;
-                rollup_configs.insert(chain_config.chain_id, rollup);
-                op_chains.insert(chain_config.chain_id, chain_config);
+            let protocol_versions_address = superchain
+                .config
+                .protocol_versions_addr
+                .expect("Missing protocol versions address");
+            for chain_config in superchain.chains {
+                registry.insert_chain(&superchain.config, protocol_versions_address, chain_config);
             }
         }
 
-        Self { chain_list, op_chains, rollup_configs }
+        registry
+    }
+
+    /// Inserts a [ChainConfig] that is part of the superchain described by the given
+    /// [SuperchainConfig], along with the [RollupConfig] derived from it.
+    pub(crate) fn insert_chain(
+        &mut self,
+        superchain: &SuperchainConfig,
+        protocol_versions_address: Address,
+        mut chain_config: ChainConfig,
+    ) {
+        chain_config.l1_chain_id = superchain.l1.chain_id;
+        if let Some(a) = &mut chain_config.addresses {
+            a.zero_proof_addresses();
+        }
+        let mut rollup = chain_config.as_rollup_config();
+        rollup.protocol_versions_address = protocol_versions_address;
+        rollup.superchain_config_address = superchain.superchain_config_addr;
+        self.rollup_configs.insert(chain_config.chain_id, rollup);
+        self.op_chains.insert(chain_config.chain_id, chain_config);
     }
 }
 
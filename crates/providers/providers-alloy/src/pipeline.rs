@@ -5,12 +5,12 @@ use async_trait::async_trait;
 use core::fmt::Debug;
 use kona_derive::{
     DerivationPipeline, EthereumDataSource, IndexedAttributesQueueStage, L2ChainProvider,
-    OriginProvider, Pipeline, PipelineBuilder, PipelineErrorKind, PipelineResult,
+    OriginProvider, Pipeline, PipelineBuilder, PipelineErrorKind, PipelineHooks, PipelineResult,
     PolledAttributesQueueStage, ResetSignal, Signal, SignalReceiver, StatefulAttributesBuilder,
     StepResult,
 };
 use kona_genesis::{RollupConfig, SystemConfig};
-use kona_protocol::{BlockInfo, L2BlockInfo, OpAttributesWithParent};
+use kona_protocol::{BlockInfo, DecompressorRegistry, L2BlockInfo, OpAttributesWithParent};
 use std::sync::Arc;
 
 /// An online polled derivation pipeline.
@@ -62,9 +62,17 @@ impl OnlinePipeline {
         blob_provider: OnlineBlobProvider<OnlineBeaconClient>,
         chain_provider: AlloyChainProvider,
         mut l2_chain_provider: AlloyL2ChainProvider,
+        hooks: PipelineHooks,
+        decompressors: DecompressorRegistry,
     ) -> PipelineResult<Self> {
-        let mut pipeline =
-            Self::new_polled(cfg.clone(), blob_provider, chain_provider, l2_chain_provider.clone());
+        let mut pipeline = Self::new_polled(
+            cfg.clone(),
+            blob_provider,
+            chain_provider,
+            l2_chain_provider.clone(),
+            hooks,
+            decompressors,
+        );
 
         // Reset the pipeline to populate the initial L1/L2 cursor and system configuration in L1
         // Traversal.
@@ -87,7 +95,8 @@ impl OnlinePipeline {
 
     /// Constructs a new polled derivation pipeline that is uninitialized.
     ///
-    /// Uses online providers as specified by the arguments.
+    /// Uses online providers as specified by the arguments. The given [`PipelineHooks`] observe the
+    /// pipeline stages, and channels are decompressed with the given [`DecompressorRegistry`].
     ///
     /// Before using the returned pipeline, a [`ResetSignal`] must be sent to
     /// instantiate the pipeline state. [`Self::new`] is a convenience method that
@@ -97,6 +106,8 @@ impl OnlinePipeline {
         blob_provider: OnlineBlobProvider<OnlineBeaconClient>,
         chain_provider: AlloyChainProvider,
         l2_chain_provider: AlloyL2ChainProvider,
+        hooks: PipelineHooks,
+        decompressors: DecompressorRegistry,
     ) -> Self {
         let attributes = StatefulAttributesBuilder::new(
             cfg.clone(),
@@ -112,6 +123,8 @@ impl OnlinePipeline {
             .chain_provider(chain_provider)
             .builder(attributes)
             .origin(BlockInfo::default())
+            .hooks(hooks)
+            .decompressors(decompressors)
             .build_polled();
 
         Self::Polled(pipeline)
@@ -119,7 +132,8 @@ impl OnlinePipeline {
 
     /// Constructs a new indexed derivation pipeline that is uninitialized.
     ///
-    /// Uses online providers as specified by the arguments.
+    /// Uses online providers as specified by the arguments. The given [`PipelineHooks`] observe the
+    /// pipeline stages, and channels are decompressed with the given [`DecompressorRegistry`].
     ///
     /// Before using the returned pipeline, a [`ResetSignal`] must be sent to
     /// instantiate the pipeline state. [`Self::new`] is a convenience method that
@@ -129,6 +143,8 @@ impl OnlinePipeline {
         blob_provider: OnlineBlobProvider<OnlineBeaconClient>,
         chain_provider: AlloyChainProvider,
         l2_chain_provider: AlloyL2ChainProvider,
+        hooks: PipelineHooks,
+        decompressors: DecompressorRegistry,
     ) -> Self {
         let attributes = StatefulAttributesBuilder::new(
             cfg.clone(),
@@ -144,6 +160,8 @@ impl OnlinePipeline {
             .chain_provider(chain_provider)
             .builder(attributes)
             .origin(BlockInfo::default())
+            .hooks(hooks)
+            .decompressors(decompressors)
             .build_indexed();
 
         Self::Managed(pipeline)
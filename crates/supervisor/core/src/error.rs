@@ -58,6 +58,10 @@ pub enum SupervisorError {
     #[error(transparent)]
     CrossSafetyCheckerError(#[from] CrossSafetyError),
 
+    /// Indicates that a super root could not be encoded with the codec of its version.
+    #[error("failed to encode super root: {0}")]
+    SuperRoot(String),
+
     /// Indicates the L1 block does not match the expected L1 block.
     #[error("L1 block number mismatch. expected: {expected}, but got {got}")]
     L1BlockMismatch {
@@ -108,6 +112,7 @@ impl From<SupervisorError> for ErrorObjectOwned {
             SupervisorError::InteropNotEnabled |
             SupervisorError::L1BlockMismatch { .. } |
             SupervisorError::Initialise(_) |
+            SupervisorError::SuperRoot(_) |
             SupervisorError::ManagedNodeError(_) |
             SupervisorError::ChainProcessorError(_) |
             SupervisorError::CrossSafetyCheckerError(_) |
@@ -6,8 +6,8 @@ use alloy_rpc_client::RpcClient;
 use async_trait::async_trait;
 use core::fmt::Debug;
 use kona_interop::{
-    DependencySet, ExecutingDescriptor, InteropValidator, OutputRootWithChain, SUPER_ROOT_VERSION,
-    SafetyLevel, SuperRoot,
+    DependencySet, ExecutingDescriptor, InteropValidator, OutputRootWithChain, SafetyLevel,
+    SuperRoot, VersionedSuperRoot,
 };
 use kona_protocol::BlockInfo;
 use kona_supervisor_rpc::{ChainRootInfoRpc, SuperRootOutputRpc};
@@ -448,15 +448,17 @@ impl SupervisorService for Supervisor {
             }
         }
 
-        let super_root = SuperRoot { timestamp, output_roots: super_root_chains };
-        let super_root_hash = super_root.hash();
+        let super_root =
+            VersionedSuperRoot::from(SuperRoot { timestamp, output_roots: super_root_chains });
+        let super_root_hash =
+            super_root.hash().map_err(|e| SupervisorError::SuperRoot(e.to_string()))?;
 
         Ok(SuperRootOutputRpc {
             cross_safe_derived_from: cross_safe_source,
             timestamp,
             super_root: super_root_hash,
             chains: chain_infos,
-            version: SUPER_ROOT_VERSION,
+            version: super_root.version(),
         })
     }
 
@@ -1,7 +1,8 @@
 //! [tracing_subscriber] utilities.
 
 use tracing_subscriber::{
-    Layer,
+    Layer, Registry,
+    layer::Identity,
     prelude::__tracing_subscriber_SubscriberExt,
     util::{SubscriberInitExt, TryInitError},
 };
@@ -42,6 +43,19 @@ impl LogConfig {
         &self,
         env_filter: Option<EnvFilter>,
     ) -> Result<(), TryInitError> {
+        self.init_tracing_subscriber_with_layer(env_filter, Identity::new())
+    }
+
+    /// Initializes the tracing subscriber with an additional [Layer], which receives the events
+    /// that pass the environment filter alongside the file and stdout layers.
+    pub fn init_tracing_subscriber_with_layer<L>(
+        &self,
+        env_filter: Option<EnvFilter>,
+        layer: L,
+    ) -> Result<(), TryInitError>
+    where
+        L: Layer<Registry> + Send + Sync + 'static,
+    {
         let file_layer = self.file_logs.as_ref().map(|file_logs| {
             let directory_path = file_logs.directory_path.clone();
 
@@ -82,6 +96,7 @@ impl LogConfig {
             .add_directive(self.global_level.into());
 
         tracing_subscriber::registry()
+            .with(layer)
             .with(env_filter)
             .with(file_layer)
             .with(stdout_layer)
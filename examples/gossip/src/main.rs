@@ -98,9 +98,12 @@ impl GossipCommand {
                 scoring: Default::default(),
                 topic_scoring: Default::default(),
                 monitor_peers: Default::default(),
+                scoring_config: None,
                 bootstore: None,
                 gater_config: Default::default(),
                 bootnodes: Default::default(),
+                static_peers: Default::default(),
+                trusted_peers: Default::default(),
                 rollup_config: rollup_config.clone(),
                 gossip_signer: None,
             }
@@ -0,0 +1,123 @@
+//! Example for recording a deterministic proof fixture for `kona-client` from a live chain
+//!
+//! ## Usage
+//!
+//! ```sh
+//! cargo run --release -p proof-fixture
+//! ```
+//!
+//! ## Inputs
+//!
+//! The proof fixture recorder takes the following inputs:
+//!
+//! - `-v` or `--verbosity`: Verbosity level (0-2)
+//! - `-n` or `--name`: The name of the fixture, i.e. `<hardfork>-<chain>-<l2 block number>`.
+//! - `--l1-rpc`: The L1 execution layer RPC URL to use. Must be archival.
+//! - `--l1-beacon`: The L1 beacon API URL to use.
+//! - `--l2-rpc`: The L2 execution layer RPC URL to use. Must be archival.
+//! - `--l2-chain-id`: The L2 chain ID of a chain in the superchain registry.
+//! - `--l1-head`, `--agreed-l2-head-hash`, `--agreed-l2-output-root`, `--claimed-l2-output-root`
+//!   and `--claimed-l2-block-number`: The boot info of the proof.
+//! - `-o` or `--output-dir`: (Optional) The output directory for the fixture. If not provided,
+//!   defaults to `kona-client`'s `testdata` directory.
+
+use alloy_primitives::B256;
+use anyhow::{Result, anyhow};
+use clap::Parser;
+use kona_cli::{LogConfig, log::LogArgs};
+use kona_host::{fixture::record_single_chain_fixture, single::SingleChainHost};
+use std::path::PathBuf;
+use tracing::info;
+use tracing_subscriber::EnvFilter;
+
+/// The proof fixture recording command.
+#[derive(Parser, Debug, Clone)]
+#[command(about = "Records a deterministic proof fixture for `kona-client` from a live chain")]
+pub struct ProofFixtureCommand {
+    #[command(flatten)]
+    pub v: LogArgs,
+    /// The name of the fixture.
+    #[arg(long, short = 'n')]
+    pub name: String,
+    /// The L1 archive EL to use.
+    #[arg(long)]
+    pub l1_rpc: String,
+    /// The L1 beacon API to use.
+    #[arg(long)]
+    pub l1_beacon: String,
+    /// The L2 archive EL to use.
+    #[arg(long)]
+    pub l2_rpc: String,
+    /// The L2 chain ID.
+    #[arg(long)]
+    pub l2_chain_id: u64,
+    /// Hash of the L1 head block.
+    #[arg(long)]
+    pub l1_head: B256,
+    /// Hash of the agreed upon safe L2 block.
+    #[arg(long)]
+    pub agreed_l2_head_hash: B256,
+    /// Agreed safe L2 output root.
+    #[arg(long)]
+    pub agreed_l2_output_root: B256,
+    /// Claimed L2 output root.
+    #[arg(long)]
+    pub claimed_l2_output_root: B256,
+    /// Number of the L2 block that the claimed output root commits to.
+    #[arg(long)]
+    pub claimed_l2_block_number: u64,
+    /// The output directory for the fixture.
+    #[arg(long, short = 'o')]
+    pub output_dir: Option<PathBuf>,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = ProofFixtureCommand::parse();
+    LogConfig::new(cli.v).init_tracing_subscriber(None::<EnvFilter>)?;
+
+    let output_dir = if let Some(output_dir) = cli.output_dir {
+        output_dir
+    } else {
+        // Default to `bin/client/testdata`
+        let output = std::process::Command::new(env!("CARGO"))
+            .arg("locate-project")
+            .arg("--workspace")
+            .arg("--message-format=plain")
+            .output()?
+            .stdout;
+        let workspace_root: PathBuf = String::from_utf8(output)?.trim().into();
+
+        workspace_root
+            .parent()
+            .ok_or(anyhow!("Failed to locate workspace root"))?
+            .join("bin/client/testdata")
+    };
+
+    let host = SingleChainHost {
+        l1_head: cli.l1_head,
+        agreed_l2_head_hash: cli.agreed_l2_head_hash,
+        agreed_l2_output_root: cli.agreed_l2_output_root,
+        claimed_l2_output_root: cli.claimed_l2_output_root,
+        claimed_l2_block_number: cli.claimed_l2_block_number,
+        l1_node_address: Some(cli.l1_rpc),
+        l2_node_address: Some(cli.l2_rpc),
+        l1_beacon_address: Some(cli.l1_beacon),
+        l2_chain_id: Some(cli.l2_chain_id),
+        native: true,
+        ..Default::default()
+    };
+
+    let fixture = record_single_chain_fixture(&host, cli.name.as_str()).await?;
+    let fixture_path = output_dir.join(format!("{}.json.zlib", fixture.name));
+    fixture.save(&fixture_path)?;
+
+    info!(
+        target: "proof_fixture",
+        preimages = fixture.preimages.len(),
+        status = fixture.expected_status,
+        "Successfully recorded proof fixture at {}",
+        fixture_path.display()
+    );
+    Ok(())
+}